@@ -0,0 +1,105 @@
+//! Cost of addressing a large value range by cell: the borrowed
+//! [`ValueRange::view`] against the owned `value_range_to_cells` conversion.
+//!
+//! A counting allocator reports the allocations and bytes of each path, and
+//! the time is the best of several runs.
+//!
+//! ```text
+//! cargo bench --bench borrowed_reads
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use gsheet_api::models::{Dimension, ValueRange};
+use gsheet_api::utils::value_range_to_cells;
+
+/// Rows of the benchmarked range.
+const ROWS: usize = 10_000;
+
+/// Columns of the benchmarked range.
+const COLUMNS: usize = 20;
+
+/// Runs of each path, the fastest being reported.
+const RUNS: usize = 10;
+
+/// The system allocator, counting allocations and allocated bytes.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Runs `path` [`RUNS`] times, returning its fastest time and the allocations
+/// and bytes of a single run.
+fn measure(mut path: impl FnMut() -> usize) -> (Duration, usize, usize) {
+    let mut best = Duration::MAX;
+    let mut counts = (0, 0);
+    for _ in 0..RUNS {
+        let (allocations, bytes) = (
+            ALLOCATIONS.load(Ordering::Relaxed),
+            BYTES.load(Ordering::Relaxed),
+        );
+        let started = Instant::now();
+        black_box(path());
+        best = best.min(started.elapsed());
+        counts = (
+            ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+            BYTES.load(Ordering::Relaxed) - bytes,
+        );
+    }
+    (best, counts.0, counts.1)
+}
+
+fn main() {
+    let value_range = ValueRange {
+        range: Some("Sheet1!A1:T10000".to_string()),
+        major_dimension: Some(Dimension::Rows),
+        values: Some(
+            (0..ROWS)
+                .map(|row| (0..COLUMNS).map(|col| format!("r{row}c{col}")).collect())
+                .collect(),
+        ),
+    };
+
+    let cells = measure(|| {
+        value_range_to_cells("spreadsheet-id", "Sheet1", &value_range)
+            .unwrap()
+            .iter()
+            .map(|cell| cell.value.as_deref().map_or(0, str::len))
+            .sum()
+    });
+    let view = measure(|| {
+        value_range
+            .view()
+            .unwrap()
+            .cells()
+            .map(|cell| cell.value.len())
+            .sum()
+    });
+
+    println!("{ROWS} rows x {COLUMNS} columns, best of {RUNS} runs");
+    for (name, (time, allocations, bytes)) in [("cells", cells), ("view", view)] {
+        println!(
+            "{name:>6}: {:>8.2} ms, {allocations:>8} allocations, {:>8.1} MiB",
+            time.as_secs_f64() * 1000.0,
+            bytes as f64 / (1024.0 * 1024.0)
+        );
+    }
+}
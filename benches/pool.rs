@@ -0,0 +1,130 @@
+//! Throughput of a thousand-request workload under different pool settings.
+//!
+//! The requests are served by a local HTTP/1.1 stub, so the run measures the
+//! client side only: connection setup over loopback, without TLS or network
+//! latency. Real workloads also pay a TLS handshake for every new connection, so
+//! the gap between the settings only widens against the API.
+//!
+//! ```text
+//! cargo bench --bench pool
+//! ```
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use gsheet_api::auth::ApiKeyAuth;
+use gsheet_api::client::{GoogleSheetClient, GoogleSheetClientBuilder};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+
+/// Applies the pool settings under test to a client builder.
+type Configure = fn(GoogleSheetClientBuilder) -> GoogleSheetClientBuilder;
+
+/// Requests sent by each run.
+const REQUESTS: usize = 1_000;
+
+/// Requests in flight at once.
+const CONCURRENCY: usize = 32;
+
+/// The value range returned for every request.
+const BODY: &str =
+    r#"{"range": "Sheet1!A1:B2", "majorDimension": "ROWS", "values": [["a", "b"], ["c", "d"]]}"#;
+
+/// Serves every request of every connection with [`BODY`], counting connections.
+async fn serve(listener: tokio::net::TcpListener, connections: Arc<AtomicUsize>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        connections.fetch_add(1, Ordering::Relaxed);
+        tokio::spawn(async move {
+            let mut stream = BufReader::new(stream);
+            loop {
+                let mut length = 0;
+                let mut line = String::new();
+                if stream.read_line(&mut line).await.unwrap_or(0) == 0 {
+                    return;
+                }
+                loop {
+                    let mut header = String::new();
+                    if stream.read_line(&mut header).await.unwrap_or(0) == 0 {
+                        return;
+                    }
+                    if header == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        length = value.trim().parse().unwrap_or(0);
+                    }
+                }
+                let mut content = vec![0; length];
+                if stream.read_exact(&mut content).await.is_err() {
+                    return;
+                }
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                    BODY.len(),
+                    BODY
+                );
+                if stream
+                    .get_mut()
+                    .write_all(response.as_bytes())
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        });
+    }
+}
+
+/// Sends [`REQUESTS`] value reads through a client configured by `configure`,
+/// returning the elapsed time and the connections opened.
+async fn run(configure: Configure) -> (Duration, usize) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let connections = Arc::new(AtomicUsize::new(0));
+    tokio::spawn(serve(listener, connections.clone()));
+
+    let builder = GoogleSheetClient::builder()
+        .auth_client(Arc::new(ApiKeyAuth::new("bench")))
+        .api_base_url(&base_url);
+    let gsheet_client = configure(builder).build().unwrap();
+    let sheet = gsheet_client.spreadsheet("spreadsheet-id").sheet("Sheet1");
+
+    let started = Instant::now();
+    futures::stream::iter(0..REQUESTS)
+        .map(|_| {
+            let operation = sheet.get_all_value();
+            async move { operation.execute().await.unwrap() }
+        })
+        .buffer_unordered(CONCURRENCY)
+        .for_each(|_| async {})
+        .await;
+    (started.elapsed(), connections.load(Ordering::Relaxed))
+}
+
+#[tokio::main]
+async fn main() {
+    let settings: [(&str, Configure); 3] = [
+        ("no pooling", |builder| builder.pool_max_idle_per_host(0)),
+        ("default pool", |builder| builder),
+        ("tuned pool", |builder| {
+            builder
+                .pool_max_idle_per_host(CONCURRENCY)
+                .pool_idle_timeout(Duration::from_secs(90))
+                .tcp_keepalive(Duration::from_secs(60))
+        }),
+    ];
+
+    println!("{REQUESTS} requests, {CONCURRENCY} in flight");
+    for (name, configure) in settings {
+        let (elapsed, connections) = run(configure).await;
+        println!(
+            "{name:>12}: {:>8.1} ms, {:>7.0} requests/s, {connections:>4} connections",
+            elapsed.as_secs_f64() * 1000.0,
+            REQUESTS as f64 / elapsed.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,333 @@
+//! # Audit Module
+//!
+//! This module provides an optional journal of the write requests issued by
+//! the client.
+//!
+//! ## Overview
+//!
+//! When an [`AuditSink`] is configured on the
+//! [`GoogleSheetClient`](crate::client::GoogleSheetClient), every request that
+//! could modify data (any method other than `GET`, except `POST` requests that
+//! change no spreadsheet data, such as `values:batchGetByDataFilter` and the
+//! Drive channels of a change watch) is described by an
+//! [`AuditRecord`] and handed to the sink before it is sent: the endpoint, the
+//! spreadsheet, the A1 ranges written, the kinds of batch update requests, the
+//! number of cells written, a SHA-256 hash of the payload and a timestamp. The
+//! payload itself is not recorded, so the journal holds no cell values, while
+//! the hash still proves which payload was sent when the payload is kept
+//! elsewhere.
+//!
+//! Recording happens before sending, and a request whose record cannot be
+//! stored fails with [`GSheetError::Audit`] without being sent, so no write
+//! escapes the journal. Requests captured by a dry run are not sent and not
+//! recorded.
+//!
+//! [`MemoryAuditSink`] keeps records in memory, for tests, and
+//! [`JsonLinesAuditSink`] appends them as JSON Lines to any writer, such as a
+//! file.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::audit::JsonLinesAuditSink;
+//! use std::fs::OpenOptions;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//!
+//! let journal = OpenOptions::new().create(true).append(true).open("writes.jsonl")?;
+//! let gsheet_client = GoogleSheetClient::builder()
+//!     .auth_client(auth_client)
+//!     .audit_sink(Arc::new(JsonLinesAuditSink::new(journal)))
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::Write;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::dry_run::redact_api_key;
+use crate::error::GSheetError;
+
+/// The description of a write request, recorded before it is sent.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditRecord {
+    /// When the request was issued.
+    pub timestamp: DateTime<Utc>,
+    /// The HTTP method of the request (e.g., "POST").
+    pub method: String,
+    /// The full URL of the request, with API keys redacted.
+    pub url: String,
+    /// The spreadsheet the request targets, if it targets one.
+    pub spreadsheet_id: Option<String>,
+    /// The A1 ranges written or cleared, from the URL and the payload.
+    pub ranges: Vec<String>,
+    /// The kinds of the batch update requests (e.g., "updateCells"), in order.
+    pub requests: Vec<String>,
+    /// The number of cells written by the payload.
+    pub cell_count: usize,
+    /// The hex-encoded SHA-256 hash of the payload, if there is one.
+    pub payload_hash: Option<String>,
+}
+
+impl AuditRecord {
+    /// Describes a built request.
+    ///
+    /// # Arguments
+    /// * `request` - The request about to be sent
+    /// * `base_url` - The base URL of the Sheets API, to find the spreadsheet ID
+    pub(crate) fn capture(request: &reqwest::Request, base_url: &str) -> Self {
+        let bytes = request.body().and_then(reqwest::Body::as_bytes);
+        let body: Option<Value> = bytes.and_then(|bytes| serde_json::from_slice(bytes).ok());
+        let (spreadsheet_id, ranges) = request_targets(request, base_url, body.as_ref());
+
+        let mut requests = Vec::new();
+        let mut cell_count = 0;
+        if let Some(body) = &body {
+            cell_count = count_cells(body);
+            for request in body
+                .get("requests")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                if let Some(kind) = request.as_object().and_then(|kind| kind.keys().next()) {
+                    requests.push(kind.clone());
+                }
+            }
+        }
+
+        Self {
+            timestamp: Utc::now(),
+            method: request.method().to_string(),
+            url: redact_api_key(request.url()),
+            spreadsheet_id,
+            ranges,
+            requests,
+            cell_count,
+            payload_hash: bytes.map(|bytes| {
+                Sha256::digest(bytes)
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect()
+            }),
+        }
+    }
+}
+
+/// Returns the spreadsheet a request targets and the A1 ranges it names, in
+/// its URL, its `ranges` query parameters or its payload.
+///
+/// # Arguments
+/// * `request` - The built request
+/// * `base_url` - The base URL of the Sheets API, to find the spreadsheet ID
+/// * `body` - The parsed payload of the request, if any
+pub(crate) fn request_targets(
+    request: &reqwest::Request,
+    base_url: &str,
+    body: Option<&Value>,
+) -> (Option<String>, Vec<String>) {
+    let url = request.url();
+    let path = url
+        .as_str()
+        .strip_prefix(base_url.trim_end_matches('/'))
+        .map(|path| path.split(['?', '#']).next().unwrap_or_default());
+    let spreadsheet_id = path
+        .and_then(|path| path.trim_start_matches('/').split(['/', ':']).next())
+        .filter(|id| !id.is_empty())
+        .map(str::to_string);
+
+    let mut ranges = Vec::new();
+    // Single-range endpoints name the range in the path: values/{range}:append.
+    if let Some(segment) = url.path().split_once("/values/").map(|(_, range)| range) {
+        // Ranges contain colons too, so only the methods of values/{range} are split off.
+        let range = segment
+            .rsplit_once(':')
+            .filter(|(_, method)| matches!(*method, "append" | "clear"))
+            .map_or(segment, |(range, _)| range);
+        let decoded = percent_decode(range);
+        if !decoded.is_empty() {
+            ranges.push(decoded);
+        }
+    }
+    ranges.extend(
+        url.query_pairs()
+            .filter(|(name, _)| name == "ranges")
+            .map(|(_, range)| range.into_owned()),
+    );
+    // The range of a single-range endpoint is repeated in its payload.
+    if ranges.is_empty()
+        && let Some(body) = body
+    {
+        collect_ranges(body, &mut ranges);
+    }
+    (spreadsheet_id, ranges)
+}
+
+/// Collects the A1 ranges named by a values payload: the `range` of value
+/// ranges and the `ranges` of batch clears.
+fn collect_ranges(body: &Value, ranges: &mut Vec<String>) {
+    let value_ranges = body
+        .get("data")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or(std::slice::from_ref(body));
+    for value_range in value_ranges {
+        if let Some(range) = value_range.get("range").and_then(Value::as_str) {
+            ranges.push(range.to_string());
+        }
+    }
+    for range in body
+        .get("ranges")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+    {
+        ranges.push(range.to_string());
+    }
+}
+
+/// Counts the cells written by a payload: the values of value ranges, and the
+/// cells of row data in batch update requests.
+fn count_cells(value: &Value) -> usize {
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| match (key.as_str(), value) {
+                ("values", Value::Array(items)) => items
+                    .iter()
+                    .map(|item| match item {
+                        // A row of a value range.
+                        Value::Array(cells) => cells.len(),
+                        // A cell of row data.
+                        _ => 1,
+                    })
+                    .sum(),
+                _ => count_cells(value),
+            })
+            .sum(),
+        Value::Array(items) => items.iter().map(count_cells).sum(),
+        _ => 0,
+    }
+}
+
+/// Decodes a percent-encoded path segment, keeping malformed escapes as is.
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escape = (bytes[index] == b'%')
+            .then(|| segment.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escape {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Trait for sinks storing audit records.
+///
+/// Implementations must be safe to share between tasks, as the same sink is
+/// used by every clone of the client.
+pub trait AuditSink: Send + Sync {
+    /// Stores a record. The request is not sent if this fails.
+    ///
+    /// # Arguments
+    /// * `record` - The description of the request about to be sent
+    ///
+    /// # Returns
+    /// A `Result` indicating success or a [`GSheetError`].
+    fn record(&self, record: &AuditRecord) -> Result<(), GSheetError>;
+}
+
+/// Audit sink keeping records in memory.
+#[derive(Debug, Default)]
+pub struct MemoryAuditSink {
+    /// The records, in order.
+    records: Mutex<Vec<AuditRecord>>,
+}
+
+impl MemoryAuditSink {
+    /// Creates an empty sink.
+    ///
+    /// # Returns
+    /// A new [`MemoryAuditSink`] instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the records stored so far, in order.
+    pub fn records(&self) -> Vec<AuditRecord> {
+        self.records
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+impl AuditSink for MemoryAuditSink {
+    fn record(&self, record: &AuditRecord) -> Result<(), GSheetError> {
+        self.records
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(record.clone());
+        Ok(())
+    }
+}
+
+/// Audit sink appending records as JSON Lines to a writer, flushed after each
+/// record.
+pub struct JsonLinesAuditSink<W: Write + Send> {
+    /// The writer, such as a file opened in append mode.
+    writer: Mutex<W>,
+}
+
+impl<W: Write + Send> JsonLinesAuditSink<W> {
+    /// Creates a sink writing to a writer.
+    ///
+    /// # Arguments
+    /// * `writer` - The writer, such as a file opened in append mode
+    ///
+    /// # Returns
+    /// A new [`JsonLinesAuditSink`] instance.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+        }
+    }
+
+    /// Returns the writer, once the sink is no longer needed.
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<W: Write + Send> AuditSink for JsonLinesAuditSink<W> {
+    fn record(&self, record: &AuditRecord) -> Result<(), GSheetError> {
+        let line = serde_json::to_string(record)
+            .map_err(|e| GSheetError::Audit(format!("Failed to serialize record: {}", e)))?;
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        writeln!(writer, "{}", line)
+            .and_then(|_| writer.flush())
+            .map_err(|e| GSheetError::Audit(format!("Failed to write record: {}", e)))
+    }
+}
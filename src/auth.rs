@@ -6,8 +6,9 @@
 //! ## Overview
 //!
 //! The authentication system is built around the [`AuthProvider`] trait, which
-//! defines the interface for authentication providers. Currently, the library
-//! supports service account authentication via [`ServiceAccountAuthClient`].
+//! defines the interface for authentication providers. The library supports
+//! service account authentication via [`ServiceAccountAuthClient`], and API key
+//! authentication for reads of public spreadsheets via [`ApiKeyAuth`].
 //!
 //! ## Service Account Authentication
 //!
@@ -18,11 +19,14 @@
 //!
 //! ```rust,no_run
 //! use gsheet_api::auth::ServiceAccountAuthClient;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //!
 //! let auth_client = ServiceAccountAuthClient::builder()
 //!     .service_account_path("path/to/service-account.json")
 //!     .build()
 //!     .await?;
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! ## Security Considerations
@@ -31,11 +35,18 @@
 //! - Use environment variables or secure key management systems for key file paths
 //! - Regularly rotate service account keys
 //! - Limit service account permissions to only what's necessary
+//!
+//! Access tokens and private keys are held as [`SecretString`]s, which are
+//! zeroized when dropped and print as `[REDACTED]` in debug output, so they
+//! don't leak into logs.
 
+pub mod api_key;
 pub mod error;
 pub mod service_account;
 pub mod token;
 
+pub use api_key::ApiKeyAuth;
+pub use secrecy::{ExposeSecret, SecretString};
 pub use service_account::ServiceAccountAuthClient;
 pub use token::AccessToken;
 
@@ -43,22 +54,92 @@ pub use token::AccessToken;
 ///
 /// This trait defines the interface that all authentication providers must implement.
 /// It provides methods for getting access tokens and ensuring they remain valid.
+///
+/// Providers must be `Send + Sync` so the client, and every operation built from
+/// it, can be moved into spawned tasks. Both methods take `&self`: providers keep
+/// their token behind interior mutability, so the client shares them without a
+/// lock.
 #[async_trait::async_trait]
-pub trait AuthProvider {
-    /// Returns the current access token as a string slice.
+pub trait AuthProvider: Send + Sync {
+    /// Returns the current access token.
     ///
     /// # Returns
-    /// A string slice containing the access token.
-    fn get_token(&self) -> &str;
+    /// An owned copy of the access token, so no lock is held by the caller. The
+    /// token is zeroized on drop and redacted in debug output.
+    fn get_token(&self) -> SecretString;
 
     /// Ensures that the access token is valid and refreshes it if necessary.
     ///
     /// This method should check if the current token is expired and refresh it
-    /// if needed. Implementations should handle token refresh logic internally.
+    /// if needed. Implementations should handle token refresh logic internally,
+    /// and may be called concurrently from several tasks.
     ///
     /// # Returns
     /// A `Result` indicating success or an [`AuthError`](error::AuthError).
-    async fn ensure_valid_token(&mut self) -> Result<(), AuthError>;
+    async fn ensure_valid_token(&self) -> Result<(), AuthError>;
+
+    /// Returns the API key authenticating requests instead of the access token,
+    /// if the provider uses one.
+    ///
+    /// # Returns
+    /// The API key, sent as the `key` query parameter, or `None` to send the
+    /// access token as a bearer token. The default implementation returns `None`.
+    fn api_key(&self) -> Option<SecretString> {
+        None
+    }
+
+    /// Returns the email address of the principal requests are made as, if the
+    /// provider knows it.
+    ///
+    /// # Returns
+    /// The email address, such as the `client_email` of a service account key,
+    /// or `None`. The default implementation returns `None`.
+    fn email(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the principal requests are made as, if the provider knows it.
+    ///
+    /// # Returns
+    /// The [`Identity`] of the principal, or `None`. The default implementation
+    /// builds it from [`email`](Self::email) alone.
+    fn identity(&self) -> Option<Identity> {
+        self.email().map(|email| Identity {
+            email,
+            subject: None,
+            project_id: None,
+            key_id: None,
+        })
+    }
+}
+
+/// The principal an [`AuthProvider`] makes requests as.
+///
+/// # Examples
+/// ```rust,no_run
+/// use gsheet_api::auth::{AuthProvider, ServiceAccountAuthClient};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+///
+/// let auth_client = ServiceAccountAuthClient::builder()
+///     .service_account_path("path/to/service-account.json")
+///     .build()
+///     .await?;
+/// if let Some(identity) = auth_client.identity() {
+///     println!("Editing as {}", identity.email);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identity {
+    /// The email address of the principal.
+    pub email: String,
+    /// The unique ID of the principal, the `sub` of its tokens, if known.
+    pub subject: Option<String>,
+    /// The Google Cloud project of the principal, if known.
+    pub project_id: Option<String>,
+    /// The ID of the private key used to sign token requests, if any.
+    pub key_id: Option<String>,
 }
 
 pub use error::AuthError;
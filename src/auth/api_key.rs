@@ -0,0 +1,91 @@
+//! API key authentication implementation.
+//!
+//! This module provides [`ApiKeyAuth`], which authenticates requests with an API
+//! key sent as the `key` query parameter instead of an OAuth access token.
+//!
+//! API keys identify the calling project but no user, so they only grant read
+//! access to public spreadsheets, shared with "anyone with the link". They suit
+//! lightweight read-only tools that should not need a service account.
+
+use secrecy::SecretString;
+
+use crate::auth::AuthProvider;
+use crate::auth::error::AuthError;
+
+/// Authentication with an API key, for reads of public spreadsheets.
+///
+/// # Examples
+/// ```rust,no_run
+/// use gsheet_api::auth::ApiKeyAuth;
+/// use gsheet_api::client::GoogleSheetClient;
+/// use std::sync::Arc;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+///
+/// let gsheet_client = GoogleSheetClient::builder()
+///     .auth_client(Arc::new(ApiKeyAuth::new("api-key")))
+///     .build()?;
+///
+/// let values = gsheet_client
+///     .spreadsheet("public-spreadsheet-id")
+///     .sheet("Sheet1")
+///     .get_all_value()
+///     .execute()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ApiKeyAuth {
+    /// The API key, redacted in debug output.
+    key: SecretString,
+}
+
+impl ApiKeyAuth {
+    /// Creates a provider authenticating with an API key.
+    ///
+    /// # Arguments
+    /// * `key` - The API key, restricted to the Sheets API in the Cloud Console
+    ///
+    /// # Returns
+    /// A new [`ApiKeyAuth`] instance.
+    pub fn new(key: &str) -> Self {
+        Self {
+            key: SecretString::from(key),
+        }
+    }
+
+    /// Creates a provider with the API key held by an environment variable.
+    ///
+    /// # Arguments
+    /// * `var` - The name of the environment variable
+    ///
+    /// # Returns
+    /// A `Result` containing the [`ApiKeyAuth`] or an [`AuthError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the variable is not set or empty.
+    pub fn from_env(var: &str) -> Result<Self, AuthError> {
+        match std::env::var(var) {
+            Ok(key) if !key.trim().is_empty() => Ok(Self::new(key.trim())),
+            _ => Err(AuthError::Other(format!(
+                "Environment variable {} holds no API key",
+                var
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthProvider for ApiKeyAuth {
+    fn get_token(&self) -> SecretString {
+        SecretString::from("")
+    }
+
+    async fn ensure_valid_token(&self) -> Result<(), AuthError> {
+        Ok(())
+    }
+
+    fn api_key(&self) -> Option<SecretString> {
+        Some(self.key.clone())
+    }
+}
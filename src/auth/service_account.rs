@@ -7,12 +7,26 @@
 use chrono::{Duration, Utc};
 use jsonwebtoken::Header;
 use jsonwebtoken::{Algorithm, EncodingKey, encode};
+use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 
-use crate::auth::AuthProvider;
 use crate::auth::error::AuthError;
 use crate::auth::token::{AccessToken, TokenProvider};
+use crate::auth::{AuthProvider, Identity};
+
+/// OAuth 2.0 scope granting full access to Google Sheets.
+pub const SPREADSHEETS_SCOPE: &str = "https://www.googleapis.com/auth/spreadsheets";
+
+/// OAuth 2.0 scope granting read-only access to files on Google Drive.
+pub const DRIVE_READONLY_SCOPE: &str = "https://www.googleapis.com/auth/drive.readonly";
+
+/// OAuth 2.0 scope granting full access to Google Drive.
+pub const DRIVE_SCOPE: &str = "https://www.googleapis.com/auth/drive";
+
+/// The mutual TLS variant of Google's OAuth 2.0 token endpoint.
+pub const MTLS_TOKEN_URI: &str = "https://oauth2.mtls.googleapis.com/token";
 
 /// Service account key structure as defined by Google.
 ///
@@ -27,8 +41,8 @@ pub struct ServiceAccountKey {
     pub project_id: String,
     /// The unique identifier for the private key.
     pub private_key_id: String,
-    /// The private key in PEM format.
-    pub private_key: String,
+    /// The private key in PEM format, redacted in debug output.
+    pub private_key: SecretString,
     /// The email address of the service account.
     pub client_email: String,
     /// The client ID of the service account.
@@ -68,7 +82,7 @@ pub struct Claims {
 #[derive(Debug, Clone, Deserialize)]
 pub struct TokenResponse {
     /// The access token that can be used to authenticate API requests.
-    pub access_token: String,
+    pub access_token: SecretString,
     /// The type of token, typically "Bearer".
     pub token_type: String,
     /// The number of seconds until the access token expires.
@@ -82,6 +96,9 @@ pub struct TokenResponse {
 #[derive(Debug)]
 pub struct ServiceAccountAuthClientBuilder {
     service_account_path: Option<String>,
+    scopes: Vec<String>,
+    token_uri: Option<String>,
+    client: Option<reqwest::Client>,
 }
 
 impl ServiceAccountAuthClientBuilder {
@@ -89,6 +106,9 @@ impl ServiceAccountAuthClientBuilder {
     pub fn new() -> Self {
         Self {
             service_account_path: None,
+            scopes: Vec::new(),
+            token_uri: None,
+            client: None,
         }
     }
 
@@ -104,6 +124,50 @@ impl ServiceAccountAuthClientBuilder {
         self
     }
 
+    /// Adds an OAuth 2.0 scope to request for the access token.
+    ///
+    /// When no scope is added, only [`SPREADSHEETS_SCOPE`] is requested. Features
+    /// built on the Drive API, such as change watching, also need a Drive scope.
+    ///
+    /// # Arguments
+    /// * `scope` - The scope URL (e.g., "https://www.googleapis.com/auth/drive.readonly")
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn scope(mut self, scope: &str) -> Self {
+        self.scopes.push(scope.to_string());
+        self
+    }
+
+    /// Sets the token endpoint tokens are requested from, instead of the
+    /// `token_uri` of the key file.
+    ///
+    /// Use [`MTLS_TOKEN_URI`] for mutual TLS, or the URL of a mock server in
+    /// tests. The assertion is still addressed to the `token_uri` of the key.
+    ///
+    /// # Arguments
+    /// * `url` - The URL of the token endpoint
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn token_uri(mut self, url: &str) -> Self {
+        self.token_uri = Some(url.to_string());
+        self
+    }
+
+    /// Sets a custom HTTP client for token requests, such as one presenting a
+    /// client certificate for mutual TLS.
+    ///
+    /// # Arguments
+    /// * `client` - The reqwest client to use for token requests
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn client(mut self, client: &reqwest::Client) -> Self {
+        self.client = Some(client.clone());
+        self
+    }
+
     /// Builds the [`ServiceAccountAuthClient`] instance.
     ///
     /// This method reads the service account key file, parses it, creates an initial
@@ -124,20 +188,37 @@ impl ServiceAccountAuthClientBuilder {
             .service_account_path
             .ok_or_else(|| AuthError::Other("Service account path is required".into()))?;
 
-        let service_account_content = std::fs::read_to_string(&service_account_path)?;
+        // The file holds the private key, so it is zeroized once parsed
+        let service_account_content =
+            SecretString::from(std::fs::read_to_string(&service_account_path)?);
 
         // Parse service account key
-        let service_account: ServiceAccountKey = serde_json::from_str(&service_account_content)?;
+        let service_account: ServiceAccountKey =
+            serde_json::from_str(service_account_content.expose_secret())?;
 
-        // Create HTTP client
-        let client = reqwest::Client::new();
+        let scope = if self.scopes.is_empty() {
+            SPREADSHEETS_SCOPE.to_string()
+        } else {
+            self.scopes.join(" ")
+        };
+
+        let client = self.client.unwrap_or_default();
+        let token_uri = self
+            .token_uri
+            .unwrap_or_else(|| service_account.token_uri.clone());
 
         // Get initial access token
-        let token = ServiceAccountAuthClient::get_access_token(&client, &service_account).await?;
+        let token = ServiceAccountAuthClient::get_access_token(
+            &client,
+            &service_account,
+            &token_uri,
+            &scope,
+        )
+        .await?;
 
         // Create AccessToken
         let access_token = AccessToken::builder()
-            .token(&token.access_token)
+            .token(token.access_token)
             .expires_in(token.expires_in)
             .build()?;
 
@@ -145,7 +226,10 @@ impl ServiceAccountAuthClientBuilder {
         Ok(ServiceAccountAuthClient {
             service_account,
             client,
-            token: Box::new(access_token),
+            token_uri,
+            scope,
+            token: Arc::new(RwLock::new(Box::new(access_token))),
+            refresh: Arc::new(tokio::sync::Mutex::new(())),
         })
     }
 }
@@ -161,14 +245,24 @@ impl Default for ServiceAccountAuthClientBuilder {
 /// This struct handles authentication with Google APIs using a service account.
 /// It automatically manages access token refresh and provides the necessary
 /// authentication headers for API requests.
+///
+/// Reading the token only takes a short read lock, and concurrent refreshes are
+/// serialized so the token endpoint is called once per expiry. Clones share the
+/// same token.
 #[derive(Clone)]
 pub struct ServiceAccountAuthClient {
     /// The service account key information.
     service_account: ServiceAccountKey,
     /// The HTTP client for making token requests.
     client: reqwest::Client,
+    /// The URL of the token endpoint.
+    token_uri: String,
+    /// The space-separated OAuth 2.0 scopes requested for access tokens.
+    scope: String,
     /// The token provider that manages the access token.
-    token: Box<dyn TokenProvider>,
+    token: Arc<RwLock<Box<dyn TokenProvider>>>,
+    /// Lock held while refreshing the token.
+    refresh: Arc<tokio::sync::Mutex<()>>,
 }
 
 impl ServiceAccountAuthClient {
@@ -180,18 +274,22 @@ impl ServiceAccountAuthClient {
     /// # Arguments
     /// * `client` - The HTTP client to use for the request.
     /// * `service_account` - The service account key information.
+    /// * `token_uri` - The URL of the token endpoint.
+    /// * `scope` - The space-separated scopes to request.
     ///
     /// # Returns
     /// A `Result` containing the [`TokenResponse`] or an [`AuthError`].
     async fn get_access_token(
         client: &reqwest::Client,
         service_account: &ServiceAccountKey,
+        token_uri: &str,
+        scope: &str,
     ) -> Result<TokenResponse, AuthError> {
         // Create JWT claims
         let now = Utc::now();
         let claims = Claims {
             iss: service_account.client_email.clone(),
-            scope: "https://www.googleapis.com/auth/spreadsheets".to_string(),
+            scope: scope.to_string(),
             aud: service_account.token_uri.clone(),
             iat: now.timestamp(),
             exp: (now + Duration::hours(1)).timestamp(),
@@ -201,7 +299,8 @@ impl ServiceAccountAuthClient {
         let header = Header::new(Algorithm::RS256);
 
         // Encode private key
-        let encoding_key = EncodingKey::from_rsa_pem(service_account.private_key.as_bytes())?;
+        let encoding_key =
+            EncodingKey::from_rsa_pem(service_account.private_key.expose_secret().as_bytes())?;
 
         // Generate JWT
         let jwt = encode(&header, &claims, &encoding_key)?;
@@ -211,11 +310,7 @@ impl ServiceAccountAuthClient {
         params.insert("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer");
         params.insert("assertion", &jwt);
 
-        let response = client
-            .post(&service_account.token_uri)
-            .form(&params)
-            .send()
-            .await?;
+        let response = client.post(token_uri).form(&params).send().await?;
 
         if !response.status().is_success() {
             let error_text = response.text().await?;
@@ -236,6 +331,14 @@ impl ServiceAccountAuthClient {
     pub fn builder() -> ServiceAccountAuthClientBuilder {
         ServiceAccountAuthClientBuilder::new()
     }
+
+    /// Returns whether the current access token has expired.
+    fn is_expired(&self) -> bool {
+        self.token
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_expired()
+    }
 }
 
 #[async_trait::async_trait]
@@ -243,26 +346,60 @@ impl AuthProvider for ServiceAccountAuthClient {
     /// Returns the current access token.
     ///
     /// # Returns
-    /// A string slice containing the current access token.
-    fn get_token(&self) -> &str {
-        &self.token.get_access_token()
+    /// A copy of the current access token.
+    fn get_token(&self) -> SecretString {
+        self.token
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_access_token()
+            .clone()
     }
 
     /// Ensures the access token is valid, refreshing it if necessary.
     ///
     /// This method checks if the current token is expired and requests a new one
-    /// from Google if needed.
+    /// from Google if needed. Concurrent callers wait for a single refresh.
     ///
     /// # Returns
     /// A `Result` indicating success or an [`AuthError`].
-    async fn ensure_valid_token(&mut self) -> Result<(), AuthError> {
-        if self.token.is_expired() {
-            let new_token =
-                ServiceAccountAuthClient::get_access_token(&self.client, &self.service_account)
-                    .await?;
-            self.token
-                .set_token(new_token.access_token, new_token.expires_in);
+    async fn ensure_valid_token(&self) -> Result<(), AuthError> {
+        if !self.is_expired() {
+            return Ok(());
         }
+
+        let _refresh = self.refresh.lock().await;
+        // Another task may have refreshed the token while we were waiting.
+        if !self.is_expired() {
+            return Ok(());
+        }
+
+        let new_token = ServiceAccountAuthClient::get_access_token(
+            &self.client,
+            &self.service_account,
+            &self.token_uri,
+            &self.scope,
+        )
+        .await?;
+        self.token
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .set_token(new_token.access_token, new_token.expires_in);
         Ok(())
     }
+
+    /// Returns the `client_email` of the service account key.
+    fn email(&self) -> Option<String> {
+        Some(self.service_account.client_email.clone())
+    }
+
+    /// Returns the identity of the service account, read from its key.
+    fn identity(&self) -> Option<Identity> {
+        let key = &self.service_account;
+        Some(Identity {
+            email: key.client_email.clone(),
+            subject: Some(key.client_id.clone()),
+            project_id: Some(key.project_id.clone()),
+            key_id: Some(key.private_key_id.clone()),
+        })
+    }
 }
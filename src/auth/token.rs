@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use secrecy::SecretString;
 
 use crate::auth::error::AuthError;
 
@@ -9,9 +10,9 @@ pub trait TokenProviderClone {
 
 // Token provider trait
 pub trait TokenProvider: Send + Sync + TokenProviderClone {
-    fn get_access_token(&self) -> &str;
+    fn get_access_token(&self) -> &SecretString;
     fn is_expired(&self) -> bool;
-    fn set_token(&mut self, token: String, expires_in: i64);
+    fn set_token(&mut self, token: SecretString, expires_in: i64);
 }
 
 // Type T implement TokenProviderClone must implement TokenProvider, Clone, 'static lifetime trail
@@ -20,34 +21,26 @@ where
     T: TokenProvider + Clone + 'static,
 {
     fn clone_box(&self) -> Box<dyn TokenProvider> {
-        return Box::new(self.clone());
+        Box::new(self.clone())
     }
 }
 
 // Implement Clone box for TokenProvider
 impl Clone for Box<dyn TokenProvider> {
     fn clone(&self) -> Self {
-        return self.clone_box();
+        self.clone_box()
     }
 }
 
+#[derive(Default)]
 pub struct AccessTokenBuilder {
-    token: Option<String>,
+    token: Option<SecretString>,
     expires_in: Option<i64>,
 }
 
-impl Default for AccessTokenBuilder {
-    fn default() -> Self {
-        Self {
-            token: None,
-            expires_in: None,
-        }
-    }
-}
-
 impl AccessTokenBuilder {
-    pub fn token(mut self, token: &str) -> Self {
-        self.token = Some(token.to_string());
+    pub fn token(mut self, token: impl Into<SecretString>) -> Self {
+        self.token = Some(token.into());
         self
     }
 
@@ -62,8 +55,11 @@ impl AccessTokenBuilder {
         }
 
         Ok(AccessToken::new(
-            self.token
-                .unwrap_or_else(|| AuthError::Other("token must be set".into()).to_string()),
+            self.token.unwrap_or_else(|| {
+                AuthError::Other("token must be set".into())
+                    .to_string()
+                    .into()
+            }),
             self.expires_in.unwrap_or(0),
         ))
     }
@@ -71,12 +67,12 @@ impl AccessTokenBuilder {
 
 #[derive(Debug, Clone)]
 pub struct AccessToken {
-    token: String,
+    token: SecretString,
     expires_at: DateTime<Utc>,
 }
 
 impl AccessToken {
-    pub fn new(token: String, expires_in: i64) -> Self {
+    pub fn new(token: SecretString, expires_in: i64) -> Self {
         Self {
             token,
             expires_at: Utc::now() + chrono::Duration::seconds(expires_in - 10),
@@ -89,7 +85,7 @@ impl AccessToken {
 }
 
 impl TokenProvider for AccessToken {
-    fn get_access_token(&self) -> &str {
+    fn get_access_token(&self) -> &SecretString {
         &self.token
     }
 
@@ -97,7 +93,7 @@ impl TokenProvider for AccessToken {
         self.expires_at < Utc::now()
     }
 
-    fn set_token(&mut self, token: String, expires_in: i64) {
+    fn set_token(&mut self, token: SecretString, expires_in: i64) {
         self.token = token;
         self.expires_at = Utc::now() + chrono::Duration::seconds(expires_in - 10);
     }
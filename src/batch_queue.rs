@@ -0,0 +1,462 @@
+//! # Batch Queue Module
+//!
+//! This module provides [`BatchQueue`], a background service coalescing
+//! single-range reads and writes into batch API calls.
+//!
+//! ## Overview
+//!
+//! Callers enqueue reads with [`BatchQueue::get`] and writes with
+//! [`BatchQueue::update`], and await the result as if the request was sent on
+//! its own. Behind the scenes the queue collects requests for a short window, or
+//! until enough of them are pending, and sends them as a single
+//! `values:batchUpdate` and a single `values:batchGet` call. Writes of a batch are
+//! sent before its reads, so a read enqueued after a write sees its values.
+//!
+//! This trades a few milliseconds of latency for far fewer API calls, which
+//! keeps high-frequency bots within their quota.
+//!
+//! A batch call succeeds or fails as a whole: an invalid range fails every
+//! request of the same batch call. Should the API return fewer results than
+//! requests, the requests left without one fail with a
+//! [`GSheetError::ResponseParseError`].
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use std::time::Duration;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//! let queue = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .batch_queue()
+//!     .window(Duration::from_millis(20))
+//!     .build()?;
+//!
+//! // Both reads are sent in a single values:batchGet call.
+//! let (prices, stock) = tokio::join!(queue.get("Prices!A1:B10"), queue.get("Stock!A1:C10"));
+//! queue.update("Log!A1", vec![vec!["synced".to_string()]]).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+
+use crate::client::url::QueryParams;
+use crate::error::{GSheetError, decode_json};
+use crate::models::{
+    BatchUpdateValuesResponse, BatchValueRanges, DateTimeRenderOption, Dimension,
+    UpdateValuesResponse, ValueInputOption, ValueRange, ValueRenderOption,
+};
+use crate::operations::spreadsheet::SpreadsheetOperations;
+
+/// The default time requests are collected before a batch is sent.
+pub const DEFAULT_BATCH_WINDOW: Duration = Duration::from_millis(50);
+
+/// The default maximum number of requests sent in a single batch.
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 100;
+
+/// A request waiting in the queue.
+enum Job {
+    /// A read of a single range.
+    Get {
+        range: String,
+        reply: oneshot::Sender<Result<ValueRange, GSheetError>>,
+    },
+    /// A write of a single range.
+    Update {
+        range: String,
+        values: Vec<Vec<String>>,
+        reply: oneshot::Sender<Result<UpdateValuesResponse, GSheetError>>,
+    },
+}
+
+/// Builder for creating [`BatchQueue`] instances.
+#[derive(Default)]
+pub struct BatchQueueBuilder {
+    /// The spreadsheet the queued requests target.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// How long requests are collected before a batch is sent.
+    window: Option<Duration>,
+    /// The maximum number of requests sent in a single batch.
+    max_batch_size: Option<usize>,
+    /// How written values are interpreted.
+    value_input_option: Option<ValueInputOption>,
+    /// How read values are rendered.
+    value_render_option: Option<ValueRenderOption>,
+    /// How read dates and times are rendered.
+    date_time_render_option: Option<DateTimeRenderOption>,
+    /// Optional timeout of each batch call.
+    timeout: Option<Duration>,
+}
+
+impl BatchQueueBuilder {
+    /// Creates a new builder for a queue targeting the spreadsheet.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet the queued requests target
+    ///
+    /// # Returns
+    /// A new [`BatchQueueBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets how long requests are collected before a batch is sent.
+    ///
+    /// The window starts with the first request of a batch, so an idle queue
+    /// doesn't delay requests by more than the window.
+    ///
+    /// # Arguments
+    /// * `window` - The coalescing window, [`DEFAULT_BATCH_WINDOW`] by default
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Sets the maximum number of requests sent in a single batch.
+    ///
+    /// A batch is sent as soon as this many requests are pending, without
+    /// waiting for the end of the window.
+    ///
+    /// # Arguments
+    /// * `size` - The maximum batch size, [`DEFAULT_MAX_BATCH_SIZE`] by default
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn max_batch_size(mut self, size: usize) -> Self {
+        self.max_batch_size = Some(size);
+        self
+    }
+
+    /// Sets how written values are interpreted, instead of the value options of
+    /// the spreadsheet.
+    ///
+    /// # Arguments
+    /// * `option` - The value input option of every write
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn value_input_option(mut self, option: ValueInputOption) -> Self {
+        self.value_input_option = Some(option);
+        self
+    }
+
+    /// Sets how read values are rendered, instead of the value options of the
+    /// spreadsheet.
+    ///
+    /// # Arguments
+    /// * `option` - The value render option of every read
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.value_render_option = Some(option);
+        self
+    }
+
+    /// Sets how read dates and times are rendered, instead of the value options
+    /// of the spreadsheet.
+    ///
+    /// # Arguments
+    /// * `option` - The date time render option of every read
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.date_time_render_option = Some(option);
+        self
+    }
+
+    /// Sets the timeout of each batch call.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of a batch call
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`BatchQueue`] and starts its background task.
+    ///
+    /// The task stops once every clone of the queue has been dropped and the
+    /// pending requests have been sent.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`BatchQueue`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set, the maximum
+    /// batch size is 0, or it is called outside of a Tokio runtime.
+    pub fn build(self) -> Result<BatchQueue, GSheetError> {
+        let spreadsheet = self
+            .spreadsheet
+            .ok_or_else(|| GSheetError::Other("Spreadsheet is required".into()))?;
+        let max_batch_size = self.max_batch_size.unwrap_or(DEFAULT_MAX_BATCH_SIZE);
+        if max_batch_size == 0 {
+            return Err(GSheetError::ValidationError(
+                "Maximum batch size must be at least 1".into(),
+            ));
+        }
+        let runtime = tokio::runtime::Handle::try_current().map_err(|e| {
+            GSheetError::Other(format!("Batch queue requires a Tokio runtime: {e}"))
+        })?;
+
+        let defaults = &spreadsheet.value_options;
+        let value_input_option = self
+            .value_input_option
+            .unwrap_or_else(|| defaults.value_input_option.clone());
+        let value_render_option = self
+            .value_render_option
+            .unwrap_or_else(|| defaults.value_render_option.clone());
+        let date_time_render_option = self
+            .date_time_render_option
+            .unwrap_or_else(|| defaults.date_time_render_option.clone());
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let worker = BatchWorker {
+            spreadsheet,
+            window: self.window.unwrap_or(DEFAULT_BATCH_WINDOW),
+            max_batch_size,
+            value_input_option,
+            value_render_option,
+            date_time_render_option,
+            timeout: self.timeout,
+        };
+        runtime.spawn(worker.run(receiver));
+
+        Ok(BatchQueue { sender })
+    }
+}
+
+/// Queue coalescing single-range reads and writes into batch API calls.
+///
+/// Cloning the queue is cheap, and every clone feeds the same background task.
+#[derive(Clone)]
+pub struct BatchQueue {
+    /// The channel feeding the background task.
+    sender: mpsc::UnboundedSender<Job>,
+}
+
+impl BatchQueue {
+    /// Reads the values of a range with the next batch.
+    ///
+    /// # Arguments
+    /// * `range` - The A1 notation of the range, including the sheet (e.g., "Sheet1!A1:B10")
+    ///
+    /// # Returns
+    /// A `Result` containing the [`ValueRange`] read or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the batch call fails.
+    pub async fn get(&self, range: &str) -> Result<ValueRange, GSheetError> {
+        let (reply, response) = oneshot::channel();
+        self.enqueue(Job::Get {
+            range: range.to_string(),
+            reply,
+        })?;
+        response.await.map_err(|_| queue_closed())?
+    }
+
+    /// Writes values to a range with the next batch.
+    ///
+    /// # Arguments
+    /// * `range` - The A1 notation of the range, including the sheet (e.g., "Sheet1!A1")
+    /// * `values` - The rows of values to write
+    ///
+    /// # Returns
+    /// A `Result` containing the [`UpdateValuesResponse`] of the range or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the batch call fails.
+    pub async fn update(
+        &self,
+        range: &str,
+        values: Vec<Vec<String>>,
+    ) -> Result<UpdateValuesResponse, GSheetError> {
+        let (reply, response) = oneshot::channel();
+        self.enqueue(Job::Update {
+            range: range.to_string(),
+            values,
+            reply,
+        })?;
+        response.await.map_err(|_| queue_closed())?
+    }
+
+    /// Sends a job to the background task.
+    fn enqueue(&self, job: Job) -> Result<(), GSheetError> {
+        self.sender.send(job).map_err(|_| queue_closed())
+    }
+}
+
+/// Returns the error reported when the background task is gone.
+fn queue_closed() -> GSheetError {
+    GSheetError::Other("Batch queue is closed".into())
+}
+
+/// Sends each job of a batch its result, in request order, failing the jobs left
+/// without one when the API returned fewer results than requests.
+fn resolve<T>(
+    replies: Vec<oneshot::Sender<Result<T, GSheetError>>>,
+    results: Vec<T>,
+    method: &str,
+) {
+    let (requested, returned) = (replies.len(), results.len());
+    let mut results = results.into_iter();
+    for reply in replies {
+        let result = results.next().ok_or_else(|| {
+            GSheetError::ResponseParseError(format!(
+                "{method} returned {returned} results for {requested} ranges"
+            ))
+        });
+        let _ = reply.send(result);
+    }
+}
+
+/// Background task sending the queued requests.
+struct BatchWorker {
+    /// The spreadsheet the queued requests target.
+    spreadsheet: SpreadsheetOperations,
+    /// How long requests are collected before a batch is sent.
+    window: Duration,
+    /// The maximum number of requests sent in a single batch.
+    max_batch_size: usize,
+    /// How written values are interpreted.
+    value_input_option: ValueInputOption,
+    /// How read values are rendered.
+    value_render_option: ValueRenderOption,
+    /// How read dates and times are rendered.
+    date_time_render_option: DateTimeRenderOption,
+    /// Optional timeout of each batch call.
+    timeout: Option<Duration>,
+}
+
+impl BatchWorker {
+    /// Collects jobs into batches and sends them until the queue is dropped.
+    async fn run(self, mut receiver: mpsc::UnboundedReceiver<Job>) {
+        while let Some(first) = receiver.recv().await {
+            let deadline = Instant::now() + self.window;
+            let mut jobs = vec![first];
+            while jobs.len() < self.max_batch_size {
+                match tokio::time::timeout_at(deadline, receiver.recv()).await {
+                    Ok(Some(job)) => jobs.push(job),
+                    Ok(None) | Err(_) => break,
+                }
+            }
+            self.flush(jobs).await;
+        }
+    }
+
+    /// Sends a batch, writes first, and resolves every job of it.
+    async fn flush(&self, jobs: Vec<Job>) {
+        let mut gets = Vec::new();
+        let mut updates = Vec::new();
+        for job in jobs {
+            match job {
+                Job::Get { range, reply } => gets.push((range, reply)),
+                Job::Update {
+                    range,
+                    values,
+                    reply,
+                } => updates.push((range, values, reply)),
+            }
+        }
+
+        if !updates.is_empty() {
+            let (data, replies): (Vec<_>, Vec<_>) = updates
+                .into_iter()
+                .map(|(range, values, reply)| {
+                    let value_range = ValueRange {
+                        range: Some(range),
+                        major_dimension: Some(Dimension::Rows),
+                        values: Some(values),
+                    };
+                    (value_range, reply)
+                })
+                .unzip();
+            match self.batch_update(data).await {
+                Ok(response) => resolve(replies, response.responses, "values:batchUpdate"),
+                Err(error) => {
+                    for reply in replies {
+                        let _ = reply.send(Err(error.duplicate()));
+                    }
+                }
+            }
+        }
+
+        if !gets.is_empty() {
+            let (ranges, replies): (Vec<_>, Vec<_>) = gets.into_iter().unzip();
+            match self.batch_get(&ranges).await {
+                Ok(response) => resolve(replies, response.value_ranges, "values:batchGet"),
+                Err(error) => {
+                    for reply in replies {
+                        let _ = reply.send(Err(error.duplicate()));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Sends a `values:batchGet` call for the ranges.
+    async fn batch_get(&self, ranges: &[String]) -> Result<BatchValueRanges, GSheetError> {
+        let gsheet_client = &self.spreadsheet.gsheet_client;
+        let url =
+            gsheet_client.spreadsheet_url(&self.spreadsheet.spreadsheet_id, "/values:batchGet");
+
+        let query = QueryParams::new()
+            .value_render_option(&self.value_render_option)
+            .date_time_render_option(&self.date_time_render_option)
+            .ranges(ranges);
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
+        let response = gsheet_client.send(request).await?;
+
+        if response.status().is_success() {
+            Ok(decode_json(response).await?)
+        } else {
+            Err(GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await)
+        }
+    }
+
+    /// Sends a `values:batchUpdate` call for the value ranges.
+    async fn batch_update(
+        &self,
+        data: Vec<ValueRange>,
+    ) -> Result<BatchUpdateValuesResponse, GSheetError> {
+        let gsheet_client = &self.spreadsheet.gsheet_client;
+        let url =
+            gsheet_client.spreadsheet_url(&self.spreadsheet.spreadsheet_id, "/values:batchUpdate");
+        let body = serde_json::json!({
+            "valueInputOption": self.value_input_option,
+            "data": data,
+        });
+
+        let request = gsheet_client
+            .request(reqwest::Method::POST, &url, self.timeout)
+            .await?
+            .json(&body);
+        let response = gsheet_client.send(request).await?;
+
+        if response.status().is_success() {
+            gsheet_client.invalidate_cache(&self.spreadsheet.spreadsheet_id);
+            Ok(decode_json(response).await?)
+        } else {
+            Err(GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await)
+        }
+    }
+}
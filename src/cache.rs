@@ -0,0 +1,215 @@
+//! # Cache Module
+//!
+//! This module provides an optional read-through cache for value reads.
+//!
+//! ## Overview
+//!
+//! When a [`CacheProvider`] is configured on the
+//! [`GoogleSheetClient`](crate::client::GoogleSheetClient), value reads are first
+//! looked up in the cache and only hit the API on a miss. Entries are keyed by
+//! spreadsheet, range and render options, so reads of the same range with
+//! different options never share an entry.
+//!
+//! Providers may also cache [`SpreadsheetMetadata`], such as the locale and sheet
+//! properties of a spreadsheet, which is looked up far more often than it changes.
+//!
+//! Writes issued through the same client invalidate every cached entry of the
+//! spreadsheet they modify, since a write to one range can change the computed
+//! values of formulas anywhere else in the spreadsheet. Changes made outside the
+//! client (by users or other processes) are only picked up once entries expire.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::cache::InMemoryCache;
+//! use std::time::Duration;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//!
+//! let gsheet_client = GoogleSheetClient::builder()
+//!     .auth_client(auth_client)
+//!     .cache(Arc::new(InMemoryCache::new(Duration::from_secs(10))))
+//!     .build()?;
+//!
+//! // The second read within 10 seconds is served from the cache.
+//! let sheet = gsheet_client.spreadsheet("spreadsheet-id").sheet("Dashboard");
+//! let first = sheet.get_all_value().execute().await?;
+//! let second = sheet.get_all_value().execute().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::metadata::SpreadsheetMetadata;
+use crate::models::{DateTimeRenderOption, Dimension, ValueRange, ValueRenderOption};
+
+/// Key identifying a cached value read.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    /// The ID of the spreadsheet the values were read from.
+    pub spreadsheet_id: String,
+    /// The A1 notation range that was read.
+    pub range: String,
+    /// The major dimension the values were read with.
+    pub major_dimension: String,
+    /// The value render option the values were read with.
+    pub value_render_option: String,
+    /// The date time render option the values were read with.
+    pub date_time_render_option: String,
+}
+
+impl CacheKey {
+    /// Creates a new cache key for a value read.
+    ///
+    /// # Arguments
+    /// * `spreadsheet_id` - The ID of the spreadsheet
+    /// * `range` - The A1 notation range that is read
+    /// * `major_dimension` - The major dimension of the read
+    /// * `value_render_option` - The value render option of the read
+    /// * `date_time_render_option` - The date time render option of the read
+    ///
+    /// # Returns
+    /// A new [`CacheKey`] instance.
+    pub fn new(
+        spreadsheet_id: &str,
+        range: &str,
+        major_dimension: &Dimension,
+        value_render_option: &ValueRenderOption,
+        date_time_render_option: &DateTimeRenderOption,
+    ) -> Self {
+        Self {
+            spreadsheet_id: spreadsheet_id.to_string(),
+            range: range.to_string(),
+            major_dimension: major_dimension.to_string(),
+            value_render_option: value_render_option.to_string(),
+            date_time_render_option: date_time_render_option.to_string(),
+        }
+    }
+}
+
+/// Trait for cache providers storing value reads.
+///
+/// Implementations must be safe to share between tasks, as the same provider is
+/// used by every clone of the client.
+pub trait CacheProvider: Send + Sync {
+    /// Returns the cached values for the key, if present and still valid.
+    fn get(&self, key: &CacheKey) -> Option<ValueRange>;
+
+    /// Stores the values read for the key.
+    fn insert(&self, key: CacheKey, value: ValueRange);
+
+    /// Returns the cached metadata of the spreadsheet, if present and still valid.
+    ///
+    /// The default implementation does not cache metadata.
+    fn get_metadata(&self, _spreadsheet_id: &str) -> Option<SpreadsheetMetadata> {
+        None
+    }
+
+    /// Stores the metadata of a spreadsheet.
+    ///
+    /// The default implementation does not cache metadata.
+    fn insert_metadata(&self, _metadata: SpreadsheetMetadata) {}
+
+    /// Removes every entry belonging to the spreadsheet, including its metadata.
+    fn invalidate_spreadsheet(&self, spreadsheet_id: &str);
+
+    /// Removes every entry.
+    fn clear(&self);
+}
+
+/// In-memory cache provider expiring entries after a fixed time-to-live.
+#[derive(Debug)]
+pub struct InMemoryCache {
+    /// How long entries remain valid after being inserted.
+    ttl: Duration,
+    /// The cached entries with their insertion time.
+    entries: Mutex<HashMap<CacheKey, (Instant, ValueRange)>>,
+    /// The cached spreadsheet metadata with its insertion time, by spreadsheet ID.
+    metadata: Mutex<HashMap<String, (Instant, SpreadsheetMetadata)>>,
+}
+
+impl InMemoryCache {
+    /// Creates a new in-memory cache.
+    ///
+    /// # Arguments
+    /// * `ttl` - How long entries remain valid after being inserted
+    ///
+    /// # Returns
+    /// A new [`InMemoryCache`] instance.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+            metadata: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the time-to-live of cached entries.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+}
+
+impl CacheProvider for InMemoryCache {
+    fn get(&self, key: &CacheKey) -> Option<ValueRange> {
+        let mut entries = self.entries.lock().ok()?;
+        match entries.get(key) {
+            Some((inserted_at, value)) if inserted_at.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: CacheKey, value: ValueRange) {
+        if let Ok(mut entries) = self.entries.lock() {
+            // Drop expired entries so keys that are never read again don't pile up.
+            entries.retain(|_, (inserted_at, _)| inserted_at.elapsed() < self.ttl);
+            entries.insert(key, (Instant::now(), value));
+        }
+    }
+
+    fn get_metadata(&self, spreadsheet_id: &str) -> Option<SpreadsheetMetadata> {
+        let mut metadata = self.metadata.lock().ok()?;
+        match metadata.get(spreadsheet_id) {
+            Some((inserted_at, value)) if inserted_at.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                metadata.remove(spreadsheet_id);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert_metadata(&self, value: SpreadsheetMetadata) {
+        if let Ok(mut metadata) = self.metadata.lock() {
+            metadata.retain(|_, (inserted_at, _)| inserted_at.elapsed() < self.ttl);
+            metadata.insert(value.spreadsheet_id.clone(), (Instant::now(), value));
+        }
+    }
+
+    fn invalidate_spreadsheet(&self, spreadsheet_id: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|key, _| key.spreadsheet_id != spreadsheet_id);
+        }
+        if let Ok(mut metadata) = self.metadata.lock() {
+            metadata.remove(spreadsheet_id);
+        }
+    }
+
+    fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+        if let Ok(mut metadata) = self.metadata.lock() {
+            metadata.clear();
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! # Circuit Breaker Module
+//!
+//! This module provides an optional [`CircuitBreaker`] that stops a client from
+//! hammering the API during sustained outages.
+//!
+//! ## Overview
+//!
+//! The breaker starts closed and lets every request through. After a number of
+//! consecutive server errors (5xx statuses, timeouts or connection failures) it
+//! opens, and requests fail immediately with [`GSheetError::CircuitOpen`] instead
+//! of reaching the API. Once the open duration has elapsed the breaker becomes
+//! half-open and lets a limited number of probe requests through: a successful
+//! probe closes it again, while a failed one reopens it.
+//!
+//! Client errors such as 404 or 429 don't count as failures, as they say nothing
+//! about the health of the API.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::circuit_breaker::CircuitBreaker;
+//! use gsheet_api::error::GSheetError;
+//! use std::time::Duration;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//!
+//! let gsheet_client = GoogleSheetClient::builder()
+//!     .auth_client(auth_client)
+//!     .circuit_breaker(CircuitBreaker::new(5, Duration::from_secs(60)))
+//!     .build()?;
+//!
+//! let sheet = gsheet_client.spreadsheet("spreadsheet-id").sheet("Sheet1");
+//! match sheet.get_all_value().execute().await {
+//!     Err(GSheetError::CircuitOpen { retry_after }) => tokio::time::sleep(retry_after).await,
+//!     result => println!("{:?}", result?),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::error::GSheetError;
+
+/// The state of a [`CircuitBreaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent to the API.
+    Closed,
+    /// Requests are rejected until the open duration has elapsed.
+    Open,
+    /// A limited number of probe requests are sent to check if the API recovered.
+    HalfOpen,
+}
+
+/// Mutable state of a [`CircuitBreaker`].
+#[derive(Debug)]
+struct BreakerState {
+    /// The number of consecutive failures while closed.
+    consecutive_failures: u32,
+    /// When the breaker opened, if it is open or half-open.
+    opened_at: Option<Instant>,
+    /// The number of probes in flight while half-open.
+    probes_in_flight: u32,
+}
+
+/// Circuit breaker rejecting requests after sustained API failures.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    /// The number of consecutive failures opening the breaker.
+    failure_threshold: u32,
+    /// How long the breaker stays open before probing the API.
+    open_duration: Duration,
+    /// The number of concurrent probes allowed while half-open.
+    half_open_probes: u32,
+    /// The mutable state of the breaker.
+    state: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+    /// Creates a new circuit breaker.
+    ///
+    /// # Arguments
+    /// * `failure_threshold` - The number of consecutive failures opening the breaker
+    /// * `open_duration` - How long the breaker stays open before probing the API
+    ///
+    /// # Returns
+    /// A new closed [`CircuitBreaker`] allowing a single probe while half-open.
+    pub fn new(failure_threshold: u32, open_duration: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            open_duration,
+            half_open_probes: 1,
+            state: Mutex::new(BreakerState {
+                consecutive_failures: 0,
+                opened_at: None,
+                probes_in_flight: 0,
+            }),
+        }
+    }
+
+    /// Sets the number of concurrent probe requests allowed while half-open.
+    ///
+    /// # Arguments
+    /// * `probes` - The number of concurrent probes, at least 1
+    ///
+    /// # Returns
+    /// The breaker instance for method chaining.
+    pub fn half_open_probes(mut self, probes: u32) -> Self {
+        self.half_open_probes = probes.max(1);
+        self
+    }
+
+    /// Returns the current state of the breaker.
+    pub fn state(&self) -> CircuitState {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match state.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() < self.open_duration => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Checks whether a request may be sent, reserving a probe while half-open.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`CircuitPermit`] of the request or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return [`GSheetError::CircuitOpen`] if the breaker is open,
+    /// or half-open with every probe already in flight.
+    pub(crate) fn acquire(&self) -> Result<CircuitPermit<'_>, GSheetError> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(opened_at) = state.opened_at else {
+            return Ok(CircuitPermit {
+                breaker: self,
+                probe: false,
+            });
+        };
+
+        let elapsed = opened_at.elapsed();
+        if elapsed < self.open_duration {
+            return Err(GSheetError::CircuitOpen {
+                retry_after: self.open_duration - elapsed,
+            });
+        }
+        if state.probes_in_flight >= self.half_open_probes {
+            return Err(GSheetError::CircuitOpen {
+                retry_after: Duration::ZERO,
+            });
+        }
+        state.probes_in_flight += 1;
+        Ok(CircuitPermit {
+            breaker: self,
+            probe: true,
+        })
+    }
+
+    /// Records the outcome of a request.
+    ///
+    /// # Arguments
+    /// * `success` - Whether the API handled the request, even with a client error
+    fn record(&self, success: bool) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if success {
+            state.consecutive_failures = 0;
+            state.opened_at = None;
+            return;
+        }
+
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        if state.opened_at.is_some() || state.consecutive_failures >= self.failure_threshold {
+            state.opened_at = Some(Instant::now());
+        }
+    }
+}
+
+/// Permission to send a request, handed out by a [`CircuitBreaker`].
+///
+/// A probe permit dropped without a recorded outcome, for example because the
+/// request was cancelled, frees its probe slot.
+pub(crate) struct CircuitPermit<'a> {
+    /// The breaker that handed out the permit.
+    breaker: &'a CircuitBreaker,
+    /// Whether the permit holds one of the half-open probe slots.
+    probe: bool,
+}
+
+impl CircuitPermit<'_> {
+    /// Records the outcome of the request.
+    ///
+    /// # Arguments
+    /// * `success` - Whether the API handled the request, even with a client error
+    pub(crate) fn record(self, success: bool) {
+        self.breaker.record(success);
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if self.probe {
+            let mut state = self.breaker.state.lock().unwrap_or_else(|e| e.into_inner());
+            state.probes_in_flight = state.probes_in_flight.saturating_sub(1);
+        }
+    }
+}
@@ -12,7 +12,7 @@
 //!
 //! ```rust,no_run
 //! use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
-//! use std::sync::{Arc, Mutex};
+//! use std::sync::Arc;
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! let auth_client = ServiceAccountAuthClient::builder()
@@ -20,7 +20,7 @@
 //!     .build()
 //!     .await?;
 //!
-//! let auth_client = Arc::new(Mutex::new(auth_client));
+//! let auth_client = Arc::new(auth_client);
 //!
 //! let gsheet_client = GoogleSheetClient::builder()
 //!     .auth_client(auth_client)
@@ -37,8 +37,9 @@
 //!
 //! ```rust,no_run
 //! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
-//! # use std::sync::{Arc, Mutex};
-//! # let auth_client = Arc::new(Mutex::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap()));
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
 //! let custom_client = reqwest::Client::builder()
 //!     .timeout(std::time::Duration::from_secs(30))
 //!     .build()?;
@@ -48,8 +49,111 @@
 //!     .client(&custom_client)
 //!     .api_base_url("https://sheets.googleapis.com/v4/spreadsheets")
 //!     .build()?;
+//! # Ok(())
+//! # }
 //! ```
+//!
+//! ## Timeouts
+//!
+//! A default timeout can be set on the client and overridden per operation, so a
+//! single slow call cannot hang a whole pipeline:
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use std::time::Duration;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//!
+//! let gsheet_client = GoogleSheetClient::builder()
+//!     .auth_client(auth_client)
+//!     .timeout(Duration::from_secs(30))
+//!     .build()?;
+//!
+//! let values = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .sheet("Sheet1")
+//!     .get_all_value()
+//!     .timeout(Duration::from_secs(120))
+//!     .execute()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Endpoints
+//!
+//! The Sheets and Drive APIs and the token endpoint can each be overridden, to
+//! target a regional endpoint or a mock server. With [`mtls`](GoogleSheetClientBuilder::mtls),
+//! the mutual TLS endpoints become the defaults, and the client presents its
+//! certificate to them and to the token endpoint:
+//!
+//! ```rust,no_run
+//! use gsheet_api::auth::{ServiceAccountAuthClient, service_account::MTLS_TOKEN_URI};
+//! use gsheet_api::client::GoogleSheetClient;
+//! use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//!
+//! let identity = reqwest::Identity::from_pkcs8_pem(
+//!     &std::fs::read("client.pem")?,
+//!     &std::fs::read("client.key")?,
+//! )?;
+//! let http_client = reqwest::Client::builder().identity(identity).build()?;
+//!
+//! let auth_client = ServiceAccountAuthClient::builder()
+//!     .service_account_path("keys.json")
+//!     .token_uri(MTLS_TOKEN_URI)
+//!     .client(&http_client)
+//!     .build()
+//!     .await?;
+//!
+//! let gsheet_client = GoogleSheetClient::builder()
+//!     .auth_client(Arc::new(auth_client))
+//!     .client(&http_client)
+//!     .mtls(true)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Bulk Workloads
+//!
+//! All operations created from a client, and all clones of it, share a single
+//! connection pool. For jobs issuing thousands of requests, the pool can be tuned
+//! so that concurrent requests reuse warm connections instead of opening new ones:
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use std::time::Duration;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//!
+//! let gsheet_client = GoogleSheetClient::builder()
+//!     .auth_client(auth_client)
+//!     .pool_max_idle_per_host(32)
+//!     .pool_idle_timeout(Duration::from_secs(90))
+//!     .tcp_keepalive(Duration::from_secs(60))
+//!     .http2_keep_alive_interval(Duration::from_secs(30))
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Pooled connections are reused without a new TCP and TLS handshake, and HTTP/2
+//! lets concurrent requests share one connection. The `pool` benchmark
+//! (`cargo bench --bench pool`) sends 1,000 value reads, 32 at a time, to a local
+//! stub: with pooling the run opens about 40 connections instead of 1,000 and
+//! completes about 2.5 times faster (31 ms against 75 ms), before any TLS
+//! handshake or network latency is counted. On that workload the default pool
+//! performs as well as the tuned one; the settings above matter when requests
+//! come in bursts separated by idle periods, or through proxies closing idle
+//! connections.
 
 pub mod gsheet_client;
+pub(crate) mod url;
 
-pub use gsheet_client::{GoogleSheetClient, GoogleSheetClientBuilder};
+pub use gsheet_client::{
+    DEFAULT_DRIVE_BASE_URL, DEFAULT_SHEETS_BASE_URL, GoogleSheetClient, GoogleSheetClientBuilder,
+    MTLS_DRIVE_BASE_URL, MTLS_SHEETS_BASE_URL,
+};
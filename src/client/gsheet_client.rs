@@ -3,32 +3,96 @@
 //! This module contains the main client structures for interacting with the Google Sheets API.
 //! The [`GoogleSheetClient`] handles authentication and provides access to spreadsheet operations.
 
-use crate::error::GSheetError;
+use crate::audit::{AuditRecord, AuditSink};
+use crate::auth::ExposeSecret;
+use crate::cache::CacheProvider;
+use crate::circuit_breaker::CircuitBreaker;
+use crate::client::url::encode_path_segment;
+use crate::drive::ListFilesBuilder;
+use crate::dry_run::{DryRunRequest, redact_api_key};
+use crate::error::{GSheetError, SentRequest};
+use crate::fleet::ForEachSpreadsheetBuilder;
+use crate::health::HealthCheckBuilder;
+#[cfg(feature = "metrics")]
+use crate::metrics::RequestMetrics;
+use crate::raw::RawRequestBuilder;
+use crate::template::InstantiateTemplateBuilder;
+#[cfg(feature = "vcr")]
+use crate::vcr::Cassette;
 use crate::{auth::AuthProvider, operations::spreadsheet::SpreadsheetOperations};
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The default base URL of the Google Sheets API.
+pub const DEFAULT_SHEETS_BASE_URL: &str = "https://sheets.googleapis.com/v4/spreadsheets";
+
+/// The default base URL of the Google Drive API.
+pub const DEFAULT_DRIVE_BASE_URL: &str = "https://www.googleapis.com/drive/v3";
+
+/// The base URL of the Google Sheets API for mutual TLS.
+pub const MTLS_SHEETS_BASE_URL: &str = "https://sheets.mtls.googleapis.com/v4/spreadsheets";
+
+/// The base URL of the Google Drive API for mutual TLS.
+pub const MTLS_DRIVE_BASE_URL: &str = "https://www.mtls.googleapis.com/drive/v3";
 
 /// Builder for creating [`GoogleSheetClient`] instances.
 ///
 /// This builder provides a fluent interface for configuring the Google Sheets client
 /// with authentication, HTTP client, and API endpoint settings.
+#[derive(Default)]
 pub struct GoogleSheetClientBuilder {
     /// The authentication provider for API requests.
-    auth_client: Option<Arc<Mutex<dyn AuthProvider>>>,
+    auth_client: Option<Arc<dyn AuthProvider>>,
     /// Optional custom HTTP client.
     client: Option<reqwest::Client>,
     /// Optional custom API base URL.
     api_base_url: Option<String>,
+    /// Optional custom Drive API base URL.
+    drive_base_url: Option<String>,
+    /// Whether the mutual TLS endpoints are the defaults.
+    mtls: bool,
+    /// Optional client certificate presented to the servers.
+    client_identity: Option<reqwest::Identity>,
+    /// Optional default timeout applied to every request.
+    timeout: Option<Duration>,
+    /// Maximum number of idle connections kept per host.
+    pool_max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept in the pool.
+    pool_idle_timeout: Option<Duration>,
+    /// Interval of TCP keep-alive probes on pooled connections.
+    tcp_keepalive: Option<Duration>,
+    /// Interval of HTTP/2 keep-alive pings on pooled connections.
+    http2_keep_alive_interval: Option<Duration>,
+    /// Whether to speak HTTP/2 without negotiating it first.
+    http2_prior_knowledge: bool,
+    /// Optional cache for value reads.
+    cache: Option<Arc<dyn CacheProvider>>,
+    /// Optional circuit breaker for sustained API failures.
+    circuit_breaker: Option<CircuitBreaker>,
+    /// Whether write requests are captured instead of sent.
+    dry_run: bool,
+    /// Whether write requests are rejected.
+    read_only: bool,
+    /// Optional sink recording write requests.
+    audit_sink: Option<Arc<dyn AuditSink>>,
+    /// Optional project billed for quota and usage.
+    quota_project: Option<String>,
+    /// Optional end user quota is attributed to.
+    quota_user: Option<String>,
+    /// Optional cassette recording or replaying interactions.
+    #[cfg(feature = "vcr")]
+    cassette: Option<Arc<Cassette>>,
 }
 
 impl GoogleSheetClientBuilder {
     /// Sets the authentication client for API requests.
     ///
     /// # Arguments
-    /// * `auth_client` - The authentication provider wrapped in an Arc<Mutex<>>
+    /// * `auth_client` - The authentication provider wrapped in an Arc<>
     ///
     /// # Returns
     /// The builder instance for method chaining.
-    pub fn auth_client(mut self, auth_client: Arc<Mutex<dyn AuthProvider>>) -> Self {
+    pub fn auth_client(mut self, auth_client: Arc<dyn AuthProvider>) -> Self {
         self.auth_client = Some(auth_client);
         self
     }
@@ -57,53 +121,383 @@ impl GoogleSheetClientBuilder {
         self
     }
 
+    /// Sets a custom base URL for the Google Drive API.
+    ///
+    /// The Drive API is used by features working on the spreadsheet file rather
+    /// than its contents, such as change watching.
+    ///
+    /// # Arguments
+    /// * `url` - The base URL for Drive API requests
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn drive_base_url(mut self, url: &str) -> Self {
+        self.drive_base_url = Some(url.to_string());
+        self
+    }
+
+    /// Sets whether the mutual TLS endpoints of the Sheets and Drive APIs are
+    /// used, [`MTLS_SHEETS_BASE_URL`] and [`MTLS_DRIVE_BASE_URL`].
+    ///
+    /// Base URLs set with [`api_base_url`](Self::api_base_url) or
+    /// [`drive_base_url`](Self::drive_base_url) take precedence. The client must
+    /// present a certificate, set with [`client_identity`](Self::client_identity)
+    /// or configured on a custom HTTP client.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to use the mutual TLS endpoints
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn mtls(mut self, enabled: bool) -> Self {
+        self.mtls = enabled;
+        self
+    }
+
+    /// Sets the client certificate presented to the servers, for mutual TLS.
+    ///
+    /// Ignored when a custom HTTP client is supplied with [`client`](Self::client).
+    ///
+    /// # Arguments
+    /// * `identity` - The certificate and private key of the client
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn client_identity(mut self, identity: reqwest::Identity) -> Self {
+        self.client_identity = Some(identity);
+        self
+    }
+
+    /// Sets the default timeout applied to every API request.
+    ///
+    /// The timeout covers the whole request, from connecting until the response
+    /// body has been read. Individual operations can override it with their own
+    /// `timeout` setter.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of a single request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept open per host.
+    ///
+    /// Bulk jobs issuing many concurrent requests benefit from a larger pool,
+    /// since every request can then reuse a warm connection instead of paying
+    /// for a new TCP and TLS handshake.
+    ///
+    /// Ignored when a custom HTTP client is supplied with [`client`](Self::client).
+    ///
+    /// # Arguments
+    /// * `max` - The maximum number of idle connections per host
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Sets how long idle connections are kept in the pool before being closed.
+    ///
+    /// Ignored when a custom HTTP client is supplied with [`client`](Self::client).
+    ///
+    /// # Arguments
+    /// * `timeout` - The idle timeout of pooled connections
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Enables TCP keep-alive probes on pooled connections.
+    ///
+    /// Ignored when a custom HTTP client is supplied with [`client`](Self::client).
+    ///
+    /// # Arguments
+    /// * `interval` - The interval between keep-alive probes
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Enables HTTP/2 keep-alive pings, keeping long-lived connections warm
+    /// between bursts of requests.
+    ///
+    /// Ignored when a custom HTTP client is supplied with [`client`](Self::client).
+    ///
+    /// # Arguments
+    /// * `interval` - The interval between keep-alive pings
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http2_keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Sets whether HTTP/2 is used without negotiating it first.
+    ///
+    /// Google APIs support HTTP/2, which multiplexes concurrent requests over a
+    /// single connection. Without this option HTTP/2 is still used when the
+    /// server offers it during the TLS handshake.
+    ///
+    /// Ignored when a custom HTTP client is supplied with [`client`](Self::client).
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to assume HTTP/2 support
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http2_prior_knowledge = enabled;
+        self
+    }
+
+    /// Sets a cache used for value reads.
+    ///
+    /// Reads are served from the cache while their entry is valid, and writes
+    /// issued through the client invalidate the entries of the spreadsheet they
+    /// modify.
+    ///
+    /// # Arguments
+    /// * `cache` - The cache provider shared by all operations of the client
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn cache(mut self, cache: Arc<dyn CacheProvider>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sets a circuit breaker rejecting requests during sustained API failures.
+    ///
+    /// The breaker is shared by every clone of the client.
+    ///
+    /// # Arguments
+    /// * `circuit_breaker` - The circuit breaker policy
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    /// Sets whether the client runs in dry-run mode.
+    ///
+    /// In dry-run mode reads are sent, but the first request that could modify
+    /// data is not: the operation fails with [`GSheetError::DryRun`] holding the
+    /// request it would have sent. See the [`dry_run`](crate::dry_run) module.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to capture write requests instead of sending them
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = enabled;
+        self
+    }
+
+    /// Sets whether the client is read-only.
+    ///
+    /// A read-only client sends reads, but every request that could modify data
+    /// (any method other than `GET`, except `POST` requests that change no
+    /// spreadsheet data, such as `values:batchGetByDataFilter` and the Drive
+    /// channels of a change watch) fails with [`GSheetError::ReadOnlyViolation`]
+    /// before being sent, which protects production sheets from pipelines run in
+    /// a verification mode. Read-only mode takes precedence over dry-run mode.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to reject write requests
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn read_only(mut self, enabled: bool) -> Self {
+        self.read_only = enabled;
+        self
+    }
+
+    /// Sets a sink recording every write request before it is sent.
+    ///
+    /// A request whose record cannot be stored is not sent. See the
+    /// [`audit`](crate::audit) module.
+    ///
+    /// # Arguments
+    /// * `sink` - The audit sink shared by all operations of the client
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn audit_sink(mut self, sink: Arc<dyn AuditSink>) -> Self {
+        self.audit_sink = Some(sink);
+        self
+    }
+
+    /// Sets the Google Cloud project billed for the quota and usage of every
+    /// request, sent as the `x-goog-user-project` header.
+    ///
+    /// The credentials need the `serviceusage.services.use` permission on the
+    /// project.
+    ///
+    /// # Arguments
+    /// * `project_id` - The ID of the quota project
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn quota_project(mut self, project_id: &str) -> Self {
+        self.quota_project = Some(project_id.to_string());
+        self
+    }
+
+    /// Sets the end user that the quota of every request is attributed to, sent
+    /// as the `quotaUser` query parameter.
+    ///
+    /// Per-user quotas then apply to each end user separately, rather than to
+    /// the service account as a whole.
+    ///
+    /// # Arguments
+    /// * `user` - An arbitrary identifier of the user, up to 40 characters
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn quota_user(mut self, user: &str) -> Self {
+        self.quota_user = Some(user.to_string());
+        self
+    }
+
+    /// Sets a cassette recording or replaying the HTTP interactions of the client.
+    ///
+    /// Requires the `vcr` feature. See the [`vcr`](crate::vcr) module.
+    ///
+    /// # Arguments
+    /// * `cassette` - The cassette shared with the test, to save it when recording
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    #[cfg(feature = "vcr")]
+    pub fn cassette(mut self, cassette: Arc<Cassette>) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
     /// Builds the [`GoogleSheetClient`] instance.
     ///
     /// # Returns
     /// A `Result` containing the configured [`GoogleSheetClient`] or a [`GSheetError`].
     ///
     /// # Errors
-    /// This method will return an error if the authentication client is not set.
+    /// This method will return an error if the authentication client is not set
+    /// or the HTTP client cannot be created.
     pub fn build(self) -> Result<GoogleSheetClient, GSheetError> {
         let auth_client = self
             .auth_client
             .ok_or_else(|| GSheetError::Other("Auth client is required".into()))?;
-        let client = self.client.unwrap_or_else(|| reqwest::Client::new());
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut builder = reqwest::Client::builder();
+                if let Some(max) = self.pool_max_idle_per_host {
+                    builder = builder.pool_max_idle_per_host(max);
+                }
+                if let Some(timeout) = self.pool_idle_timeout {
+                    builder = builder.pool_idle_timeout(timeout);
+                }
+                if let Some(interval) = self.tcp_keepalive {
+                    builder = builder.tcp_keepalive(interval);
+                }
+                if let Some(interval) = self.http2_keep_alive_interval {
+                    builder = builder
+                        .http2_keep_alive_interval(interval)
+                        .http2_keep_alive_while_idle(true);
+                }
+                if self.http2_prior_knowledge {
+                    builder = builder.http2_prior_knowledge();
+                }
+                if let Some(identity) = self.client_identity {
+                    builder = builder.identity(identity);
+                }
+                builder.build()?
+            }
+        };
+        let (default_base_url, default_drive_base_url) = if self.mtls {
+            (MTLS_SHEETS_BASE_URL, MTLS_DRIVE_BASE_URL)
+        } else {
+            (DEFAULT_SHEETS_BASE_URL, DEFAULT_DRIVE_BASE_URL)
+        };
         let base_url = self
             .api_base_url
-            .unwrap_or_else(|| "https://sheets.googleapis.com/v4/spreadsheets".to_string());
+            .unwrap_or_else(|| default_base_url.to_string());
+        let drive_base_url = self
+            .drive_base_url
+            .unwrap_or_else(|| default_drive_base_url.to_string());
 
         Ok(GoogleSheetClient {
             auth_client,
             client,
-            base_url,
+            base_url: base_url.into(),
+            drive_base_url: drive_base_url.into(),
+            timeout: self.timeout,
+            cache: self.cache,
+            circuit_breaker: self.circuit_breaker.map(Arc::new),
+            dry_run: self.dry_run,
+            read_only: self.read_only,
+            audit_sink: self.audit_sink,
+            quota_project: self.quota_project.map(Into::into),
+            quota_user: self.quota_user.map(Into::into),
+            #[cfg(feature = "vcr")]
+            cassette: self.cassette,
         })
     }
 }
 
-impl Default for GoogleSheetClientBuilder {
-    fn default() -> Self {
-        Self {
-            auth_client: None,
-            client: None,
-            api_base_url: None,
-        }
-    }
-}
-
 /// Main client for interacting with the Google Sheets API.
 ///
 /// This struct provides the primary interface for making authenticated requests
 /// to the Google Sheets API. It handles authentication token management and
 /// provides access to spreadsheet operations.
+///
+/// Cloning the client is cheap: every field is reference counted, and clones
+/// share the same connection pool, so a single client should be created and
+/// cloned into every task that needs it.
 #[derive(Clone)]
 pub struct GoogleSheetClient {
     /// The authentication provider for managing access tokens.
-    pub auth_client: Arc<Mutex<dyn AuthProvider>>,
+    pub auth_client: Arc<dyn AuthProvider>,
     /// The HTTP client for making API requests.
     pub client: reqwest::Client,
     /// The base URL for Google Sheets API endpoints.
-    pub base_url: String,
+    pub base_url: Arc<str>,
+    /// The base URL for Google Drive API endpoints.
+    pub drive_base_url: Arc<str>,
+    /// The default timeout applied to every request, if any.
+    pub timeout: Option<Duration>,
+    /// The cache used for value reads, if any.
+    pub cache: Option<Arc<dyn CacheProvider>>,
+    /// The circuit breaker guarding requests, if any.
+    pub circuit_breaker: Option<Arc<CircuitBreaker>>,
+    /// Whether write requests are captured instead of sent.
+    pub dry_run: bool,
+    /// Whether write requests are rejected.
+    pub read_only: bool,
+    /// The sink recording write requests, if any.
+    pub audit_sink: Option<Arc<dyn AuditSink>>,
+    /// The project billed for quota and usage, if any.
+    pub quota_project: Option<Arc<str>>,
+    /// The end user quota is attributed to, if any.
+    pub quota_user: Option<Arc<str>>,
+    /// The cassette recording or replaying interactions, if any.
+    #[cfg(feature = "vcr")]
+    pub cassette: Option<Arc<Cassette>>,
 }
 
 impl GoogleSheetClient {
@@ -117,14 +511,25 @@ impl GoogleSheetClient {
     /// # Returns
     /// A new [`GoogleSheetClient`] instance.
     pub fn new(
-        auth_client: Arc<Mutex<dyn AuthProvider>>,
+        auth_client: Arc<dyn AuthProvider>,
         client: reqwest::Client,
         base_url: String,
     ) -> Self {
         GoogleSheetClient {
             auth_client,
             client,
-            base_url,
+            base_url: base_url.into(),
+            drive_base_url: DEFAULT_DRIVE_BASE_URL.into(),
+            timeout: None,
+            cache: None,
+            circuit_breaker: None,
+            dry_run: false,
+            read_only: false,
+            audit_sink: None,
+            quota_project: None,
+            quota_user: None,
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
 
@@ -136,6 +541,73 @@ impl GoogleSheetClient {
         GoogleSheetClientBuilder::default()
     }
 
+    /// Returns a clone of the client with dry-run mode enabled or disabled.
+    ///
+    /// The clone shares the connection pool, cache and circuit breaker of the
+    /// client, so a dry run can be scoped to a few operations.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to capture write requests instead of sending them
+    ///
+    /// # Returns
+    /// A new [`GoogleSheetClient`] instance.
+    pub fn with_dry_run(&self, enabled: bool) -> Self {
+        Self {
+            dry_run: enabled,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a clone of the client with read-only mode enabled or disabled.
+    ///
+    /// The clone shares the connection pool, cache and circuit breaker of the
+    /// client, so a verification run can use a read-only view of a client.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to reject write requests
+    ///
+    /// # Returns
+    /// A new [`GoogleSheetClient`] instance.
+    pub fn with_read_only(&self, enabled: bool) -> Self {
+        Self {
+            read_only: enabled,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a clone of the client attributing quota to another end user.
+    ///
+    /// Deriving a client per end user shards per-user quotas across them, while
+    /// sharing the connection pool, cache and circuit breaker of the client.
+    ///
+    /// # Arguments
+    /// * `user` - An identifier of the user, or `None` to attribute quota to the
+    ///   credentials again
+    ///
+    /// # Returns
+    /// A new [`GoogleSheetClient`] instance.
+    pub fn with_quota_user(&self, user: Option<&str>) -> Self {
+        Self {
+            quota_user: user.map(Into::into),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a clone of the client billing quota to another project.
+    ///
+    /// # Arguments
+    /// * `project_id` - The ID of the quota project, or `None` to bill the
+    ///   project of the credentials again
+    ///
+    /// # Returns
+    /// A new [`GoogleSheetClient`] instance.
+    pub fn with_quota_project(&self, project_id: Option<&str>) -> Self {
+        Self {
+            quota_project: project_id.map(Into::into),
+            ..self.clone()
+        }
+    }
+
     /// Creates a [`SpreadsheetOperations`] instance for the specified spreadsheet.
     ///
     /// This method provides access to operations that can be performed on a specific
@@ -147,6 +619,301 @@ impl GoogleSheetClient {
     /// # Returns
     /// A [`SpreadsheetOperations`] instance for the specified spreadsheet.
     pub fn spreadsheet(&self, spreadsheet_id: &str) -> SpreadsheetOperations {
-        SpreadsheetOperations::new(self.clone(), spreadsheet_id.to_string())
+        SpreadsheetOperations::new(self.clone(), spreadsheet_id.into())
+    }
+
+    /// Creates a builder for copying a template spreadsheet and replacing its
+    /// `{{placeholders}}` with the given values.
+    ///
+    /// See the [`template`](crate::template) module for details.
+    ///
+    /// # Arguments
+    /// * `template_id` - The ID of the template spreadsheet
+    /// * `new_title` - The title of the new spreadsheet
+    /// * `vars` - The placeholder values, by placeholder name without braces
+    ///
+    /// # Returns
+    /// An [`InstantiateTemplateBuilder`] for configuring the instantiation.
+    pub fn instantiate_template<K, V>(
+        &self,
+        template_id: &str,
+        new_title: &str,
+        vars: impl IntoIterator<Item = (K, V)>,
+    ) -> InstantiateTemplateBuilder
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        InstantiateTemplateBuilder::new(self, template_id, new_title).vars(vars)
+    }
+
+    /// Creates a builder listing the spreadsheets visible to the client through
+    /// Google Drive, page by page.
+    ///
+    /// See the [`drive`](crate::drive) module for details.
+    ///
+    /// # Returns
+    /// A [`ListFilesBuilder`] for configuring the listing.
+    pub fn list_spreadsheets(&self) -> ListFilesBuilder {
+        ListFilesBuilder::new(self)
+    }
+
+    /// Creates a builder running the same operation across many spreadsheets,
+    /// with bounded concurrency.
+    ///
+    /// See the [`fleet`](crate::fleet) module for details.
+    ///
+    /// # Arguments
+    /// * `spreadsheet_ids` - The IDs of the spreadsheets
+    ///
+    /// # Returns
+    /// A [`ForEachSpreadsheetBuilder`] for configuring the run.
+    pub fn for_each_spreadsheet<I, S>(&self, spreadsheet_ids: I) -> ForEachSpreadsheetBuilder
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        ForEachSpreadsheetBuilder::new(self, spreadsheet_ids)
+    }
+
+    /// Creates a builder checking that the client can authenticate and, given
+    /// a spreadsheet, reach the API, for readiness probes.
+    ///
+    /// See the [`health`](crate::health) module for details.
+    ///
+    /// # Returns
+    /// A [`HealthCheckBuilder`] for configuring the check.
+    pub fn health_check(&self) -> HealthCheckBuilder {
+        HealthCheckBuilder::new(self)
+    }
+
+    /// Creates a builder sending an arbitrary request to the Sheets API, for
+    /// features the typed operations don't cover yet.
+    ///
+    /// See the [`raw`](crate::raw) module for details.
+    ///
+    /// # Arguments
+    /// * `method` - The HTTP method of the request
+    /// * `path` - The path relative to the Sheets base URL (e.g.,
+    ///   `"{spreadsheetId}:batchUpdate"`), or an absolute URL
+    ///
+    /// # Returns
+    /// A [`RawRequestBuilder`] for configuring the request.
+    pub fn raw_request(&self, method: reqwest::Method, path: &str) -> RawRequestBuilder {
+        RawRequestBuilder::new(self, method, path)
+    }
+
+    /// Returns the URL of a spreadsheet, followed by a suffix such as
+    /// `:batchUpdate` or `/values:batchGet`.
+    ///
+    /// # Arguments
+    /// * `spreadsheet_id` - The ID of the spreadsheet, encoded as a path segment
+    /// * `suffix` - The rest of the path, already encoded
+    pub(crate) fn spreadsheet_url(&self, spreadsheet_id: &str, suffix: &str) -> String {
+        format!(
+            "{}/{}{}",
+            self.base_url,
+            encode_path_segment(spreadsheet_id),
+            suffix
+        )
+    }
+
+    /// Returns the URL of a range of values of a spreadsheet, followed by a
+    /// suffix such as `:append`.
+    ///
+    /// # Arguments
+    /// * `spreadsheet_id` - The ID of the spreadsheet, encoded as a path segment
+    /// * `range` - The A1 range, encoded as a path segment
+    /// * `suffix` - The rest of the path, already encoded
+    pub(crate) fn values_url(&self, spreadsheet_id: &str, range: &str, suffix: &str) -> String {
+        self.spreadsheet_url(
+            spreadsheet_id,
+            &format!("/values/{}{}", encode_path_segment(range), suffix),
+        )
+    }
+
+    /// Returns the Drive URL of a file, followed by a suffix such as `/copy`.
+    ///
+    /// # Arguments
+    /// * `file_id` - The ID of the file, encoded as a path segment
+    /// * `suffix` - The rest of the path, already encoded
+    pub(crate) fn drive_file_url(&self, file_id: &str, suffix: &str) -> String {
+        format!(
+            "{}/files/{}{}",
+            self.drive_base_url,
+            encode_path_segment(file_id),
+            suffix
+        )
+    }
+
+    /// Removes the cached reads of a spreadsheet after it has been modified.
+    ///
+    /// # Arguments
+    /// * `spreadsheet_id` - The ID of the modified spreadsheet
+    pub(crate) fn invalidate_cache(&self, spreadsheet_id: &str) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate_spreadsheet(spreadsheet_id);
+        }
+    }
+
+    /// Creates an authenticated request for the given method and URL.
+    ///
+    /// The access token is refreshed if it has expired, and the per-operation
+    /// `timeout` takes precedence over the client default.
+    ///
+    /// # Arguments
+    /// * `method` - The HTTP method of the request
+    /// * `url` - The full URL of the request
+    /// * `timeout` - The per-operation timeout override, if any
+    ///
+    /// # Returns
+    /// A `Result` containing the prepared [`reqwest::RequestBuilder`] or a [`GSheetError`].
+    pub(crate) async fn request(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        timeout: Option<Duration>,
+    ) -> Result<reqwest::RequestBuilder, GSheetError> {
+        self.auth_client.ensure_valid_token().await?;
+
+        let mut request = self.client.request(method, url);
+        request = match self.auth_client.api_key() {
+            Some(key) => request.query(&[("key", key.expose_secret())]),
+            None => request.bearer_auth(self.auth_client.get_token().expose_secret()),
+        };
+
+        if let Some(timeout) = timeout.or(self.timeout) {
+            request = request.timeout(timeout);
+        }
+        if let Some(project_id) = &self.quota_project {
+            request = request.header("x-goog-user-project", &**project_id);
+        }
+        if let Some(user) = &self.quota_user {
+            request = request.query(&[("quotaUser", &**user)]);
+        }
+
+        Ok(request)
+    }
+
+    /// Sends a request prepared with [`request`](Self::request).
+    ///
+    /// When a circuit breaker is configured, the request is rejected while it is
+    /// open, and server errors, timeouts and connection failures are recorded.
+    /// Requests other than `GET` are writes: in read-only mode, they are
+    /// rejected; in dry-run mode, they are captured instead of sent; otherwise
+    /// they are recorded by the audit sink, if any, before being sent. Reads sent
+    /// as `POST` go through [`send_read`](Self::send_read) instead.
+    ///
+    /// # Arguments
+    /// * `request` - The prepared request
+    ///
+    /// # Returns
+    /// A `Result` containing the [`reqwest::Response`], successful or not, or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the circuit breaker is open, the
+    /// client is read-only and the request is a write, the request is captured
+    /// by a dry run, its audit record cannot be stored, or
+    /// the request could not be sent.
+    pub(crate) async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, GSheetError> {
+        let request = request.build()?;
+        let write = request.method() != reqwest::Method::GET;
+        self.send_classified(request, write).await
+    }
+
+    /// Sends a request prepared with [`request`](Self::request) as a read,
+    /// whatever its method.
+    ///
+    /// Some requests change no spreadsheet data although they are sent as
+    /// `POST`: reads carrying their filters in the body, such as
+    /// `values:batchGetByDataFilter`, and the registration and stop of the
+    /// Drive channels of a change watch. They are sent like any other read:
+    /// allowed in read-only mode, sent in dry-run mode and not audited.
+    ///
+    /// # Arguments
+    /// * `request` - The prepared request
+    ///
+    /// # Returns
+    /// A `Result` containing the [`reqwest::Response`], successful or not, or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the circuit breaker is open or the
+    /// request could not be sent.
+    pub(crate) async fn send_read(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, GSheetError> {
+        let request = request.build()?;
+        self.send_classified(request, false).await
+    }
+
+    /// Sends a built request, applying the read-only, dry-run and audit policies
+    /// to writes.
+    async fn send_classified(
+        &self,
+        request: reqwest::Request,
+        write: bool,
+    ) -> Result<reqwest::Response, GSheetError> {
+        if self.read_only && write {
+            return Err(GSheetError::ReadOnlyViolation {
+                method: request.method().to_string(),
+                url: redact_api_key(request.url()),
+            });
+        }
+        if self.dry_run && write {
+            return Err(GSheetError::DryRun(Box::new(DryRunRequest::capture(
+                &request,
+            ))));
+        }
+        if let Some(audit_sink) = &self.audit_sink
+            && write
+        {
+            audit_sink.record(&AuditRecord::capture(&request, &self.base_url))?;
+        }
+
+        let permit = match &self.circuit_breaker {
+            Some(circuit_breaker) => Some(circuit_breaker.acquire()?),
+            None => None,
+        };
+        let sent = SentRequest::new(&request, &self.base_url);
+        let response = self.execute(request).await;
+        if let Some(permit) = permit {
+            permit
+                .record(matches!(&response, Ok(response) if !response.status().is_server_error()));
+        }
+
+        match response {
+            Ok(mut response) => {
+                if let Some(sent) = sent {
+                    response.extensions_mut().insert(sent);
+                }
+                Ok(response)
+            }
+            Err(error) => Err(error.with_context(sent.map(|sent| sent.context()))),
+        }
+    }
+
+    /// Executes a built request, recording its metrics when the `metrics`
+    /// feature is enabled.
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, GSheetError> {
+        #[cfg(feature = "metrics")]
+        let metrics = RequestMetrics::start(&request);
+        let response = self.transport(request).await;
+        #[cfg(feature = "metrics")]
+        metrics.finish(&response);
+        response
+    }
+
+    /// Sends a built request, through the cassette when one is configured.
+    async fn transport(&self, request: reqwest::Request) -> Result<reqwest::Response, GSheetError> {
+        #[cfg(feature = "vcr")]
+        if let Some(cassette) = &self.cassette {
+            return cassette.execute(&self.client, request).await;
+        }
+
+        Ok(self.client.execute(request).await?)
     }
 }
@@ -0,0 +1,194 @@
+//! URLs of API requests.
+//!
+//! [`QueryParams`] collects the parameters of a request with typed setters for
+//! the render and input options, ranges, field masks and flags, so every
+//! operation names and serializes them the same way. Values are
+//! percent-encoded as URL query components: spaces become `%20` and quotes
+//! `%27`, while the `!`, `:` and `,` of ranges and the parentheses of field
+//! masks are kept readable.
+//!
+//! Spreadsheet IDs and ranges placed in paths, as in `values/{range}:append`,
+//! are encoded by [`encode_path_segment`], which also escapes `/`, `?` and `#`
+//! so a sheet title cannot change the endpoint of a request.
+
+use std::fmt::{self, Display, Write};
+
+use crate::models::{
+    DateTimeRenderOption, Dimension, InsertDataOption, ValueInputOption, ValueRenderOption,
+};
+
+/// Bytes kept as is in query components, besides ASCII letters and digits.
+const QUERY_SAFE: &[u8] = b"-._~!$()*,;:@/";
+
+/// Bytes kept as is in path segments, besides ASCII letters and digits.
+const PATH_SAFE: &[u8] = b"-._~!$()*,:@";
+
+/// The query parameters of a request, in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct QueryParams {
+    /// The parameters, by name, not yet encoded.
+    params: Vec<(String, String)>,
+}
+
+impl QueryParams {
+    /// Creates an empty set of parameters.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a parameter. Parameters can be repeated.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the parameter
+    /// * `value` - The value of the parameter
+    pub(crate) fn push(mut self, name: &str, value: impl Display) -> Self {
+        self.params.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds a boolean parameter, sent as `true` only when set.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the parameter
+    /// * `enabled` - Whether the parameter is sent
+    pub(crate) fn flag(self, name: &str, enabled: bool) -> Self {
+        if enabled { self.push(name, true) } else { self }
+    }
+
+    /// Adds a parameter if it has a value.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the parameter
+    /// * `value` - The value of the parameter, if any
+    pub(crate) fn optional(self, name: &str, value: Option<impl Display>) -> Self {
+        match value {
+            Some(value) => self.push(name, value),
+            None => self,
+        }
+    }
+
+    /// Adds a `ranges` parameter for each range.
+    pub(crate) fn ranges<I, S>(self, ranges: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Display,
+    {
+        ranges
+            .into_iter()
+            .fold(self, |params, range| params.push("ranges", range))
+    }
+
+    /// Sets the field mask of the response.
+    pub(crate) fn fields(self, fields: &str) -> Self {
+        self.push("fields", fields)
+    }
+
+    /// Sets the major dimension of the values read.
+    pub(crate) fn major_dimension(self, dimension: &Dimension) -> Self {
+        self.push("majorDimension", dimension)
+    }
+
+    /// Sets how the values read are rendered.
+    pub(crate) fn value_render_option(self, option: &ValueRenderOption) -> Self {
+        self.push("valueRenderOption", option)
+    }
+
+    /// Sets how the dates read are rendered.
+    pub(crate) fn date_time_render_option(self, option: &DateTimeRenderOption) -> Self {
+        self.push("dateTimeRenderOption", option)
+    }
+
+    /// Sets how the values written are interpreted.
+    pub(crate) fn value_input_option(self, option: &ValueInputOption) -> Self {
+        self.push("valueInputOption", option)
+    }
+
+    /// Sets how appended values change the existing data.
+    pub(crate) fn insert_data_option(self, option: &InsertDataOption) -> Self {
+        self.push("insertDataOption", option)
+    }
+
+    /// Sets whether the written values are returned, and how they are rendered.
+    ///
+    /// # Arguments
+    /// * `include` - Whether the response includes the written values
+    /// * `value_render_option` - How the returned values are rendered
+    /// * `date_time_render_option` - How the returned dates are rendered
+    pub(crate) fn response_values(
+        self,
+        include: bool,
+        value_render_option: &ValueRenderOption,
+        date_time_render_option: &DateTimeRenderOption,
+    ) -> Self {
+        self.push("includeValuesInResponse", include)
+            .push("responseValueRenderOption", value_render_option)
+            .push("responseDateTimeRenderOption", date_time_render_option)
+    }
+
+    /// Returns a URL with the parameters appended to its query.
+    ///
+    /// # Arguments
+    /// * `url` - The URL, with or without a query
+    pub(crate) fn url(&self, url: &str) -> String {
+        if self.params.is_empty() {
+            return url.to_string();
+        }
+        let separator = if url.contains('?') { '&' } else { '?' };
+        format!("{}{}{}", url, separator, self)
+    }
+}
+
+impl Display for QueryParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.params.iter().enumerate() {
+            if i > 0 {
+                f.write_char('&')?;
+            }
+            write!(
+                f,
+                "{}={}",
+                encode_query_component(name),
+                encode_query_component(value)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Percent-encodes a query component, keeping letters, digits and the
+/// characters of [`QUERY_SAFE`].
+///
+/// # Arguments
+/// * `value` - The component to encode
+///
+/// # Returns
+/// The encoded component.
+pub(crate) fn encode_query_component(value: &str) -> String {
+    percent_encode(value, QUERY_SAFE)
+}
+
+/// Percent-encodes a path segment, keeping letters, digits and the characters
+/// of [`PATH_SAFE`]. Non-ASCII characters are encoded as UTF-8.
+///
+/// # Arguments
+/// * `value` - The segment to encode, such as a spreadsheet ID or an A1 range
+///
+/// # Returns
+/// The encoded segment.
+pub(crate) fn encode_path_segment(value: &str) -> String {
+    percent_encode(value, PATH_SAFE)
+}
+
+/// Percent-encodes every byte of a value but ASCII letters, digits and the
+/// given safe bytes.
+fn percent_encode(value: &str, safe: &[u8]) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || safe.contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            let _ = write!(encoded, "%{:02X}", byte);
+        }
+    }
+    encoded
+}
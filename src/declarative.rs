@@ -0,0 +1,849 @@
+//! # Declarative Module
+//!
+//! This module provisions spreadsheets from a description of their desired
+//! state, in the spirit of Terraform.
+//!
+//! ## Overview
+//!
+//! A [`SpreadsheetSpec`] lists the sheets a spreadsheet must have, with their
+//! header row, minimum size and frozen rows, along with named ranges, protected
+//! ranges and conditional format rules. Applying it with
+//! [`SpreadsheetOperations::apply`] reads the live metadata and header rows,
+//! computes a [`Plan`] of the batch update requests needed to reach the desired
+//! state, and sends them in a single atomic `batchUpdate`. Applying the same spec
+//! again finds nothing to change and sends no request, so provisioning code can
+//! run on every deployment.
+//!
+//! Only what the spec declares is managed: sheets, named ranges and protected
+//! ranges it does not mention are left alone, and grids are grown but never
+//! shrunk, so applying a spec never deletes data. Protected ranges are matched
+//! by description. Conditional format rules are managed per sheet, only for
+//! sheets declaring them; they are compared with the rules returned by the API,
+//! so rules declared in another but equivalent form are rewritten on every apply.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::declarative::{ProtectedRangeSpec, SheetSpec, SpreadsheetSpec};
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! let spec = SpreadsheetSpec::new()
+//!     .sheet(
+//!         SheetSpec::new("Orders")
+//!             .header(&["Date", "Customer", "Amount"])
+//!             .frozen_rows(1)
+//!             .min_rows(5000),
+//!     )
+//!     .named_range("OrderAmounts", "Orders!C2:C5000")
+//!     .protected_range(ProtectedRangeSpec::new("Orders header", "Orders!A1:C1").warning_only(true));
+//!
+//! let plan = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .apply(&spec)
+//!     .build()?
+//!     .execute()
+//!     .await?;
+//!
+//! for change in &plan.changes {
+//!     println!("{}", change);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::client::url::QueryParams;
+use crate::error::{GSheetError, decode_json};
+use crate::metadata::SheetIdResolver;
+use crate::models::{
+    AddConditionalFormatRuleRequest, AddNamedRangeRequest, AddProtectedRangeRequest,
+    AddSheetRequest, BatchValueRanges, CellData, ConditionalFormatRule,
+    DeleteConditionalFormatRuleRequest, DeleteNamedRangeRequest, DeleteProtectedRangeRequest,
+    GridCoordinate, GridProperties, NamedRange, ProtectedRange, Request, RowData, SheetProperties,
+    Spreadsheet, UpdateCellsRequest, UpdateSheetPropertiesRequest,
+};
+use crate::operations::spreadsheet::SpreadsheetOperations;
+use crate::utils::{a1_to_grid_range_with_sheet, quote_sheet_title};
+
+/// The fields of the live state compared with a spec.
+const STATE_FIELDS: &str = "namedRanges,sheets(properties(sheetId,title,gridProperties),\
+    conditionalFormats,protectedRanges)";
+
+/// The number of columns of sheets added without a column count.
+const DEFAULT_COLUMN_COUNT: usize = 26;
+
+/// The desired state of a sheet.
+#[derive(Debug, Clone, Default)]
+pub struct SheetSpec {
+    /// The title of the sheet.
+    title: String,
+    /// The names of the first row, if managed.
+    header: Option<Vec<String>>,
+    /// The number of frozen rows, if managed.
+    frozen_rows: Option<usize>,
+    /// The minimum number of rows, if managed.
+    min_rows: Option<usize>,
+    /// The minimum number of columns, if managed.
+    min_columns: Option<usize>,
+    /// The conditional format rules, if managed.
+    conditional_formats: Option<Vec<ConditionalFormatRule>>,
+}
+
+impl SheetSpec {
+    /// Creates the spec of a sheet that must exist.
+    ///
+    /// # Arguments
+    /// * `title` - The title of the sheet
+    ///
+    /// # Returns
+    /// A new [`SheetSpec`] instance.
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the names the first row must hold.
+    ///
+    /// # Arguments
+    /// * `columns` - The column names, from the first column
+    ///
+    /// # Returns
+    /// The spec for method chaining.
+    pub fn header(mut self, columns: &[&str]) -> Self {
+        self.header = Some(columns.iter().map(|column| column.to_string()).collect());
+        self
+    }
+
+    /// Sets the number of frozen rows.
+    ///
+    /// # Arguments
+    /// * `rows` - The number of rows frozen at the top of the sheet
+    ///
+    /// # Returns
+    /// The spec for method chaining.
+    pub fn frozen_rows(mut self, rows: usize) -> Self {
+        self.frozen_rows = Some(rows);
+        self
+    }
+
+    /// Sets the minimum number of rows of the grid, grown if smaller.
+    ///
+    /// # Arguments
+    /// * `rows` - The minimum number of rows
+    ///
+    /// # Returns
+    /// The spec for method chaining.
+    pub fn min_rows(mut self, rows: usize) -> Self {
+        self.min_rows = Some(rows);
+        self
+    }
+
+    /// Sets the minimum number of columns of the grid, grown if smaller.
+    ///
+    /// # Arguments
+    /// * `columns` - The minimum number of columns
+    ///
+    /// # Returns
+    /// The spec for method chaining.
+    pub fn min_columns(mut self, columns: usize) -> Self {
+        self.min_columns = Some(columns);
+        self
+    }
+
+    /// Adds a conditional format rule, making the rules of the sheet managed.
+    ///
+    /// The sheet IDs of the ranges of the rule are set when the spec is applied.
+    ///
+    /// # Arguments
+    /// * `rule` - The rule, in priority order after the rules added before
+    ///
+    /// # Returns
+    /// The spec for method chaining.
+    pub fn conditional_format(mut self, rule: ConditionalFormatRule) -> Self {
+        self.conditional_formats
+            .get_or_insert_with(Vec::new)
+            .push(rule);
+        self
+    }
+
+    /// Sets the conditional format rules of the sheet, replacing any other rule.
+    /// An empty list removes every rule of the sheet.
+    ///
+    /// # Arguments
+    /// * `rules` - The rules, in priority order
+    ///
+    /// # Returns
+    /// The spec for method chaining.
+    pub fn conditional_formats(mut self, rules: Vec<ConditionalFormatRule>) -> Self {
+        self.conditional_formats = Some(rules);
+        self
+    }
+
+    /// Returns the minimum number of columns, which fits the header.
+    fn required_columns(&self) -> Option<usize> {
+        let header = self.header.as_ref().map(Vec::len).filter(|len| *len > 0);
+        match (self.min_columns, header) {
+            (Some(columns), Some(header)) => Some(columns.max(header)),
+            (columns, header) => columns.or(header),
+        }
+    }
+}
+
+/// The desired state of a protected range, identified by its description.
+#[derive(Debug, Clone)]
+pub struct ProtectedRangeSpec {
+    /// The description, unique among the protected ranges of the spreadsheet.
+    description: String,
+    /// The sheet-qualified A1 range.
+    range: String,
+    /// Whether editing only shows a warning.
+    warning_only: bool,
+}
+
+impl ProtectedRangeSpec {
+    /// Creates the spec of a protected range.
+    ///
+    /// # Arguments
+    /// * `description` - The description identifying the protected range
+    /// * `range` - The sheet-qualified A1 range (e.g., "Orders!A1:C1")
+    ///
+    /// # Returns
+    /// A new [`ProtectedRangeSpec`] instance.
+    pub fn new(description: &str, range: &str) -> Self {
+        Self {
+            description: description.to_string(),
+            range: range.to_string(),
+            warning_only: false,
+        }
+    }
+
+    /// Sets whether editing the range only shows a warning instead of being
+    /// prevented.
+    ///
+    /// # Arguments
+    /// * `warning_only` - Whether the protection is a warning
+    ///
+    /// # Returns
+    /// The spec for method chaining.
+    pub fn warning_only(mut self, warning_only: bool) -> Self {
+        self.warning_only = warning_only;
+        self
+    }
+}
+
+/// The desired state of a spreadsheet.
+#[derive(Debug, Clone, Default)]
+pub struct SpreadsheetSpec {
+    /// The sheets that must exist, in order.
+    sheets: Vec<SheetSpec>,
+    /// The named ranges, as names with sheet-qualified A1 ranges.
+    named_ranges: Vec<(String, String)>,
+    /// The protected ranges.
+    protected_ranges: Vec<ProtectedRangeSpec>,
+}
+
+impl SpreadsheetSpec {
+    /// Creates an empty spec, managing nothing.
+    ///
+    /// # Returns
+    /// A new [`SpreadsheetSpec`] instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a sheet that must exist.
+    ///
+    /// # Arguments
+    /// * `sheet` - The spec of the sheet
+    ///
+    /// # Returns
+    /// The spec for method chaining.
+    pub fn sheet(mut self, sheet: SheetSpec) -> Self {
+        self.sheets.push(sheet);
+        self
+    }
+
+    /// Adds a named range that must exist and cover the given range.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the range
+    /// * `range` - The sheet-qualified A1 range (e.g., "Orders!C2:C500")
+    ///
+    /// # Returns
+    /// The spec for method chaining.
+    pub fn named_range(mut self, name: &str, range: &str) -> Self {
+        self.named_ranges
+            .push((name.to_string(), range.to_string()));
+        self
+    }
+
+    /// Adds a protected range that must exist.
+    ///
+    /// # Arguments
+    /// * `protected_range` - The spec of the protected range
+    ///
+    /// # Returns
+    /// The spec for method chaining.
+    pub fn protected_range(mut self, protected_range: ProtectedRangeSpec) -> Self {
+        self.protected_ranges.push(protected_range);
+        self
+    }
+}
+
+/// The changes needed to bring a spreadsheet to the state of a spec.
+#[derive(Debug, Clone, Default)]
+pub struct Plan {
+    /// A description of each change (e.g., "add sheet 'Orders'").
+    pub changes: Vec<String>,
+    /// The batch update requests applying the changes, in order.
+    pub requests: Vec<Request>,
+}
+
+impl Plan {
+    /// Returns whether the spreadsheet already is in the desired state.
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+}
+
+/// Builder for applying a [`SpreadsheetSpec`].
+#[derive(Clone, Default)]
+pub struct ApplySpecBuilder {
+    /// The spreadsheet operations instance.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// The desired state.
+    spec: SpreadsheetSpec,
+    /// Timeout override for each request.
+    timeout: Option<Duration>,
+}
+
+impl ApplySpecBuilder {
+    /// Creates a new builder applying a spec to a spreadsheet.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    /// * `spec` - The desired state
+    ///
+    /// # Returns
+    /// A new [`ApplySpecBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations, spec: &SpreadsheetSpec) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            spec: spec.clone(),
+            timeout: None,
+        }
+    }
+
+    /// Sets the timeout of each request, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of a single request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`ApplySpecOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`ApplySpecOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set, or if the
+    /// spec declares a sheet, named range or protected range twice.
+    pub fn build(self) -> Result<ApplySpecOperations, GSheetError> {
+        let spreadsheet = self.spreadsheet.ok_or_else(|| {
+            GSheetError::Other(
+                "SpreadsheetOperations is required to build ApplySpecOperations".into(),
+            )
+        })?;
+
+        let duplicate = |kind: &str, names: Vec<&str>| {
+            for (index, name) in names.iter().enumerate() {
+                if names[..index].contains(name) {
+                    return Err(GSheetError::ValidationError(format!(
+                        "The spec declares {} '{}' twice",
+                        kind, name
+                    )));
+                }
+            }
+            Ok(())
+        };
+        duplicate(
+            "sheet",
+            self.spec
+                .sheets
+                .iter()
+                .map(|sheet| sheet.title.as_str())
+                .collect(),
+        )?;
+        duplicate(
+            "named range",
+            self.spec
+                .named_ranges
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect(),
+        )?;
+        duplicate(
+            "protected range",
+            self.spec
+                .protected_ranges
+                .iter()
+                .map(|range| range.description.as_str())
+                .collect(),
+        )?;
+
+        Ok(ApplySpecOperations {
+            spreadsheet,
+            spec: self.spec,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation applying a [`SpreadsheetSpec`] to a spreadsheet.
+pub struct ApplySpecOperations {
+    /// The spreadsheet operations instance.
+    spreadsheet: SpreadsheetOperations,
+    /// The desired state.
+    spec: SpreadsheetSpec,
+    /// Timeout override for each request.
+    timeout: Option<Duration>,
+}
+
+impl ApplySpecOperations {
+    /// Computes the changes needed to reach the desired state, without applying
+    /// them.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`Plan`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the live state cannot be read, or if
+    /// a range of the spec is malformed or on a sheet that neither exists nor is
+    /// declared.
+    pub async fn plan(&self) -> Result<Plan, GSheetError> {
+        let state = self.fetch_state().await?;
+        let live_sheets: Vec<SheetProperties> = state
+            .sheets
+            .iter()
+            .flatten()
+            .filter_map(|sheet| sheet.properties.clone())
+            .collect();
+        let headers = self.fetch_headers(&live_sheets).await?;
+
+        let mut plan = Plan::default();
+        let mut ids: HashMap<String, i32> = live_sheets
+            .iter()
+            .filter_map(|properties| Some((properties.title.clone()?, properties.sheet_id?)))
+            .collect();
+        let mut next_id = ids.values().copied().max().unwrap_or(0) + 1;
+
+        for sheet in &self.spec.sheets {
+            let live = live_sheets
+                .iter()
+                .find(|properties| properties.title.as_deref() == Some(&sheet.title));
+            let sheet_id = match live {
+                Some(live) => {
+                    plan_sheet_update(&mut plan, sheet, live);
+                    live.sheet_id.unwrap_or_default()
+                }
+                None => {
+                    let sheet_id = next_id;
+                    next_id += 1;
+                    ids.insert(sheet.title.clone(), sheet_id);
+                    plan_sheet_creation(&mut plan, sheet, sheet_id);
+                    sheet_id
+                }
+            };
+
+            if let Some(header) = &sheet.header {
+                let live_header = headers.get(&sheet.title).cloned().unwrap_or_default();
+                if live_header != *header {
+                    plan.changes
+                        .push(format!("set header of sheet '{}'", sheet.title));
+                    plan.requests.push(header_request(sheet_id, header));
+                }
+            }
+        }
+
+        let resolver = SheetIdResolver::new(ids.clone());
+        self.plan_named_ranges(&mut plan, &state, &resolver)?;
+        self.plan_protected_ranges(&mut plan, &state, &resolver)?;
+        self.plan_conditional_formats(&mut plan, &state, &ids)?;
+        Ok(plan)
+    }
+
+    /// Computes and applies the changes needed to reach the desired state, in a
+    /// single batch update.
+    ///
+    /// # Returns
+    /// A `Result` containing the applied [`Plan`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the plan cannot be computed or if the
+    /// batch update fails, in which case no change is applied.
+    pub async fn execute(&self) -> Result<Plan, GSheetError> {
+        let plan = self.plan().await?;
+        if plan.is_empty() {
+            return Ok(plan);
+        }
+
+        let mut batch_update = self.spreadsheet.batch_update();
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        for request in &plan.requests {
+            batch_update = batch_update.request(request.clone());
+        }
+        batch_update.build()?.execute().await?;
+        Ok(plan)
+    }
+
+    /// Reads the sheets, named ranges, protected ranges and conditional formats.
+    async fn fetch_state(&self) -> Result<Spreadsheet, GSheetError> {
+        let gsheet_client = &self.spreadsheet.gsheet_client;
+        let url = gsheet_client.spreadsheet_url(&self.spreadsheet.spreadsheet_id, "");
+        let query = QueryParams::new().fields(STATE_FIELDS);
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
+        let response = gsheet_client.send(request).await?;
+
+        if response.status().is_success() {
+            Ok(decode_json(response).await?)
+        } else {
+            Err(GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await)
+        }
+    }
+
+    /// Reads the first row of the existing sheets declaring a header, by title.
+    async fn fetch_headers(
+        &self,
+        live_sheets: &[SheetProperties],
+    ) -> Result<HashMap<String, Vec<String>>, GSheetError> {
+        let titles: Vec<&str> = self
+            .spec
+            .sheets
+            .iter()
+            .filter(|sheet| sheet.header.is_some())
+            .map(|sheet| sheet.title.as_str())
+            .filter(|title| {
+                live_sheets
+                    .iter()
+                    .any(|properties| properties.title.as_deref() == Some(title))
+            })
+            .collect();
+        if titles.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let gsheet_client = &self.spreadsheet.gsheet_client;
+        let url =
+            gsheet_client.spreadsheet_url(&self.spreadsheet.spreadsheet_id, "/values:batchGet");
+        let query = QueryParams::new().ranges(
+            titles
+                .iter()
+                .map(|title| format!("{}!1:1", quote_sheet_title(title))),
+        );
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
+        let response = gsheet_client.send(request).await?;
+        if !response.status().is_success() {
+            return Err(
+                GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await,
+            );
+        }
+
+        let batch: BatchValueRanges = decode_json(response).await?;
+        Ok(titles
+            .into_iter()
+            .zip(batch.value_ranges)
+            .map(|(title, value_range)| {
+                let mut header: Vec<String> = value_range
+                    .values
+                    .and_then(|rows| rows.into_iter().next())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|name| name.trim().to_string())
+                    .collect();
+                while header.last().is_some_and(String::is_empty) {
+                    header.pop();
+                }
+                (title.to_string(), header)
+            })
+            .collect())
+    }
+
+    /// Plans the named ranges that are missing or cover another range.
+    fn plan_named_ranges(
+        &self,
+        plan: &mut Plan,
+        state: &Spreadsheet,
+        resolver: &SheetIdResolver,
+    ) -> Result<(), GSheetError> {
+        let live = state.named_ranges.as_deref().unwrap_or_default();
+        for (name, range) in &self.spec.named_ranges {
+            let range = a1_to_grid_range_with_sheet(range, resolver)?;
+            let existing = live
+                .iter()
+                .find(|named_range| named_range.name.as_deref() == Some(name));
+            if let Some(existing) = existing {
+                if existing.range.as_ref() == Some(&range) {
+                    continue;
+                }
+                plan.changes.push(format!("move named range '{}'", name));
+                if let Some(named_range_id) = &existing.named_range_id {
+                    plan.requests
+                        .push(Request::DeleteNamedRange(DeleteNamedRangeRequest {
+                            named_range_id: named_range_id.clone(),
+                        }));
+                }
+            } else {
+                plan.changes.push(format!("add named range '{}'", name));
+            }
+            plan.requests
+                .push(Request::AddNamedRange(AddNamedRangeRequest {
+                    named_range: NamedRange {
+                        named_range_id: None,
+                        name: Some(name.clone()),
+                        range: Some(range),
+                    },
+                }));
+        }
+        Ok(())
+    }
+
+    /// Plans the protected ranges that are missing or differ.
+    fn plan_protected_ranges(
+        &self,
+        plan: &mut Plan,
+        state: &Spreadsheet,
+        resolver: &SheetIdResolver,
+    ) -> Result<(), GSheetError> {
+        let live: Vec<&ProtectedRange> = state
+            .sheets
+            .iter()
+            .flatten()
+            .flat_map(|sheet| sheet.protected_ranges.iter().flatten())
+            .collect();
+        for spec in &self.spec.protected_ranges {
+            let range = a1_to_grid_range_with_sheet(&spec.range, resolver)?;
+            let existing = live.iter().find(|protected_range| {
+                protected_range.description.as_deref() == Some(&spec.description)
+            });
+            if let Some(existing) = existing {
+                if existing.range.as_ref() == Some(&range)
+                    && existing.warning_only.unwrap_or(false) == spec.warning_only
+                {
+                    continue;
+                }
+                plan.changes
+                    .push(format!("update protected range '{}'", spec.description));
+                if let Some(protected_range_id) = existing.protected_range_id {
+                    plan.requests.push(Request::DeleteProtectedRange(
+                        DeleteProtectedRangeRequest { protected_range_id },
+                    ));
+                }
+            } else {
+                plan.changes
+                    .push(format!("add protected range '{}'", spec.description));
+            }
+            plan.requests
+                .push(Request::AddProtectedRange(AddProtectedRangeRequest {
+                    protected_range: ProtectedRange {
+                        range: Some(range),
+                        description: Some(spec.description.clone()),
+                        warning_only: Some(spec.warning_only),
+                        ..Default::default()
+                    },
+                }));
+        }
+        Ok(())
+    }
+
+    /// Plans the replacement of the conditional format rules of the sheets whose
+    /// rules differ from their spec.
+    fn plan_conditional_formats(
+        &self,
+        plan: &mut Plan,
+        state: &Spreadsheet,
+        ids: &HashMap<String, i32>,
+    ) -> Result<(), GSheetError> {
+        for sheet in &self.spec.sheets {
+            let Some(rules) = &sheet.conditional_formats else {
+                continue;
+            };
+            let sheet_id = ids[&sheet.title];
+            let desired: Vec<ConditionalFormatRule> = rules
+                .iter()
+                .cloned()
+                .map(|mut rule| {
+                    for range in rule.ranges.iter_mut().flatten() {
+                        range.sheet_id = Some(sheet_id);
+                    }
+                    rule
+                })
+                .collect();
+            let live: &[ConditionalFormatRule] = state
+                .sheets
+                .iter()
+                .flatten()
+                .find(|live| {
+                    live.properties
+                        .as_ref()
+                        .and_then(|properties| properties.sheet_id)
+                        == Some(sheet_id)
+                })
+                .and_then(|live| live.conditional_formats.as_deref())
+                .unwrap_or_default();
+            if comparable(live)? == comparable(&desired)? {
+                continue;
+            }
+
+            plan.changes.push(format!(
+                "replace conditional formats of sheet '{}'",
+                sheet.title
+            ));
+            for index in (0..live.len()).rev() {
+                plan.requests.push(Request::DeleteConditionalFormatRule(
+                    DeleteConditionalFormatRuleRequest {
+                        index: index as i32,
+                        sheet_id,
+                    },
+                ));
+            }
+            for (index, rule) in desired.into_iter().enumerate() {
+                plan.requests.push(Request::AddConditionalFormatRule(
+                    AddConditionalFormatRuleRequest {
+                        rule,
+                        index: index as i32,
+                    },
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Plans the creation of a missing sheet, with an ID chosen so that the
+/// following requests of the batch can refer to it.
+fn plan_sheet_creation(plan: &mut Plan, sheet: &SheetSpec, sheet_id: i32) {
+    let columns = sheet
+        .required_columns()
+        .filter(|columns| *columns > DEFAULT_COLUMN_COUNT);
+    let grid_properties = GridProperties {
+        row_count: sheet.min_rows.map(|rows| rows as i32),
+        column_count: columns.map(|columns| columns as i32),
+        frozen_row_count: sheet.frozen_rows.map(|rows| rows as i32),
+        ..Default::default()
+    };
+    plan.changes.push(format!("add sheet '{}'", sheet.title));
+    plan.requests.push(Request::AddSheet(AddSheetRequest {
+        properties: SheetProperties {
+            sheet_id: Some(sheet_id),
+            title: Some(sheet.title.clone()),
+            grid_properties: Some(grid_properties),
+            ..Default::default()
+        },
+    }));
+}
+
+/// Plans the growth and frozen rows of an existing sheet, if they differ.
+fn plan_sheet_update(plan: &mut Plan, sheet: &SheetSpec, live: &SheetProperties) {
+    let grid = live.grid_properties.clone().unwrap_or_default();
+    let mut desired = GridProperties::default();
+    let mut fields = Vec::new();
+
+    let rows = grid.row_count.unwrap_or_default();
+    if let Some(min_rows) = sheet.min_rows
+        && rows < min_rows as i32
+    {
+        desired.row_count = Some(min_rows as i32);
+        fields.push("gridProperties.rowCount");
+    }
+    let columns = grid.column_count.unwrap_or_default();
+    if let Some(min_columns) = sheet.required_columns()
+        && columns < min_columns as i32
+    {
+        desired.column_count = Some(min_columns as i32);
+        fields.push("gridProperties.columnCount");
+    }
+    if let Some(frozen_rows) = sheet.frozen_rows
+        && grid.frozen_row_count.unwrap_or_default() != frozen_rows as i32
+    {
+        desired.frozen_row_count = Some(frozen_rows as i32);
+        fields.push("gridProperties.frozenRowCount");
+    }
+    if fields.is_empty() {
+        return;
+    }
+
+    plan.changes
+        .push(format!("update grid of sheet '{}'", sheet.title));
+    plan.requests.push(Request::UpdateSheetProperties(
+        UpdateSheetPropertiesRequest {
+            properties: SheetProperties {
+                sheet_id: live.sheet_id,
+                grid_properties: Some(desired),
+                ..Default::default()
+            },
+            fields: fields.join(","),
+        },
+    ));
+}
+
+/// Builds the request writing a header in the first row of a sheet.
+fn header_request(sheet_id: i32, header: &[String]) -> Request {
+    let values = header
+        .iter()
+        .map(|name| CellData {
+            user_entered_value: Some(name.as_str().into()),
+            ..Default::default()
+        })
+        .collect();
+    Request::UpdateCells(UpdateCellsRequest {
+        rows: vec![RowData {
+            values: Some(values),
+        }],
+        fields: "userEnteredValue".to_string(),
+        start: Some(GridCoordinate {
+            sheet_id: Some(sheet_id),
+            row_index: Some(0),
+            column_index: Some(0),
+        }),
+        range: None,
+    })
+}
+
+/// Returns conditional format rules as JSON without null fields, for comparison.
+fn comparable(rules: &[ConditionalFormatRule]) -> Result<serde_json::Value, GSheetError> {
+    fn strip_nulls(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.retain(|_, value| !value.is_null());
+                map.values_mut().for_each(strip_nulls);
+            }
+            serde_json::Value::Array(values) => values.iter_mut().for_each(strip_nulls),
+            _ => {}
+        }
+    }
+
+    let mut value = serde_json::to_value(rules)
+        .map_err(|e| GSheetError::Other(format!("Failed to serialize rules: {}", e)))?;
+    strip_nulls(&mut value);
+    Ok(value)
+}
@@ -0,0 +1,499 @@
+//! # Diff Module
+//!
+//! This module compares two spreadsheets sheet by sheet and cell by cell.
+//!
+//! ## Overview
+//!
+//! [`diff_spreadsheets`] reads the grid data of two spreadsheets and reports
+//! the sheets found in only one of them and, for the sheets they share by
+//! title, the differences of grid size and of cell values, optionally with
+//! their formats. Cells are compared by their formatted value, as displayed in
+//! the Sheets UI, so a formula and a literal showing the same text are equal.
+//! This suits checking a migrated spreadsheet against its source, or a
+//! spreadsheet generated from a template against the template.
+//!
+//! [`diff_spreadsheet_data`] compares spreadsheet resources already read, and
+//! [`SheetDiff::ranges`] groups the differing cells into rectangular A1 ranges
+//! for reporting.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::diff::diff_spreadsheets;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! let source = gsheet_client.spreadsheet("source-id");
+//! let migrated = gsheet_client.spreadsheet("migrated-id");
+//! let diff = diff_spreadsheets(&source, &migrated)
+//!     .formats(true)
+//!     .build()?
+//!     .execute()
+//!     .await?;
+//!
+//! for sheet in &diff.sheets {
+//!     println!("{}: {:?}", sheet.title, sheet.ranges());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use crate::client::url::QueryParams;
+use crate::error::{GSheetError, decode_json};
+use crate::models::{CellData, Sheet, Spreadsheet};
+use crate::operations::spreadsheet::SpreadsheetOperations;
+use crate::utils::col_index_to_a1;
+
+/// The fields of the grid data compared without formats.
+const VALUE_FIELDS: &str = "sheets(properties(title,gridProperties(rowCount,columnCount)),\
+    data(startRow,startColumn,rowData.values.formattedValue))";
+
+/// The fields of the grid data compared with formats.
+const FORMAT_FIELDS: &str = "sheets(properties(title,gridProperties(rowCount,columnCount)),\
+    data(startRow,startColumn,rowData.values(formattedValue,userEnteredFormat)))";
+
+/// A cell whose value or format differs between two sheets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellDiff {
+    /// The 0-based row of the cell.
+    pub row: usize,
+    /// The 0-based column of the cell.
+    pub column: usize,
+    /// The formatted value in the first spreadsheet, `None` if the cell is empty.
+    pub value_a: Option<String>,
+    /// The formatted value in the second spreadsheet, `None` if the cell is empty.
+    pub value_b: Option<String>,
+    /// Whether the formats differ, when formats are compared.
+    pub format_differs: bool,
+}
+
+impl CellDiff {
+    /// Returns the A1 address of the cell (e.g., "C5").
+    pub fn a1(&self) -> String {
+        format!(
+            "{}{}",
+            col_index_to_a1(self.column + 1).unwrap_or_default(),
+            self.row + 1
+        )
+    }
+
+    /// Returns whether the values differ, as opposed to the format only.
+    pub fn value_differs(&self) -> bool {
+        self.value_a != self.value_b
+    }
+}
+
+/// The differences of a sheet found in both spreadsheets.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SheetDiff {
+    /// The title of the sheet.
+    pub title: String,
+    /// The grid size as rows and columns in both spreadsheets, if it differs.
+    pub size: Option<((i32, i32), (i32, i32))>,
+    /// The differing cells, by row then column.
+    pub cells: Vec<CellDiff>,
+}
+
+impl SheetDiff {
+    /// Returns whether the sheet is the same in both spreadsheets.
+    pub fn is_empty(&self) -> bool {
+        self.size.is_none() && self.cells.is_empty()
+    }
+
+    /// Groups the differing cells into rectangular A1 ranges.
+    ///
+    /// Adjacent differing cells of a row form a run, and runs spanning the same
+    /// columns in consecutive rows form a range.
+    ///
+    /// # Returns
+    /// The A1 ranges, without sheet name, by first row.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gsheet_api::diff::{CellDiff, SheetDiff};
+    ///
+    /// let cell = |row, column| CellDiff {
+    ///     row,
+    ///     column,
+    ///     value_a: None,
+    ///     value_b: Some("x".to_string()),
+    ///     format_differs: false,
+    /// };
+    /// let diff = SheetDiff {
+    ///     title: "Data".to_string(),
+    ///     size: None,
+    ///     cells: vec![cell(0, 1), cell(0, 2), cell(1, 1), cell(1, 2), cell(4, 0)],
+    /// };
+    /// assert_eq!(diff.ranges(), vec!["B1:C2", "A5"]);
+    /// ```
+    pub fn ranges(&self) -> Vec<String> {
+        // Each open range is (first row, last row, first column, last column).
+        let mut ranges: Vec<(usize, usize, usize, usize)> = Vec::new();
+        let mut cells = self.cells.iter().peekable();
+        while let Some(first) = cells.next() {
+            let mut last_column = first.column;
+            while let Some(next) =
+                cells.next_if(|next| next.row == first.row && next.column == last_column + 1)
+            {
+                last_column = next.column;
+            }
+
+            let extended = ranges.iter_mut().find(|(_, last_row, start, end)| {
+                *last_row + 1 == first.row && *start == first.column && *end == last_column
+            });
+            match extended {
+                Some(range) => range.1 = first.row,
+                None => ranges.push((first.row, first.row, first.column, last_column)),
+            }
+        }
+
+        ranges.sort();
+        ranges
+            .into_iter()
+            .map(|(first_row, last_row, first_column, last_column)| {
+                let start = format!(
+                    "{}{}",
+                    col_index_to_a1(first_column + 1).unwrap_or_default(),
+                    first_row + 1
+                );
+                if first_row == last_row && first_column == last_column {
+                    start
+                } else {
+                    format!(
+                        "{}:{}{}",
+                        start,
+                        col_index_to_a1(last_column + 1).unwrap_or_default(),
+                        last_row + 1
+                    )
+                }
+            })
+            .collect()
+    }
+}
+
+/// The differences between two spreadsheets.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpreadsheetDiff {
+    /// The titles of the sheets found only in the first spreadsheet.
+    pub only_in_a: Vec<String>,
+    /// The titles of the sheets found only in the second spreadsheet.
+    pub only_in_b: Vec<String>,
+    /// The differences of the sheets found in both, for the sheets that differ.
+    pub sheets: Vec<SheetDiff>,
+}
+
+impl SpreadsheetDiff {
+    /// Returns whether the spreadsheets have the same sheets and cells.
+    pub fn is_empty(&self) -> bool {
+        self.only_in_a.is_empty() && self.only_in_b.is_empty() && self.sheets.is_empty()
+    }
+}
+
+/// Compares two spreadsheet resources read with their grid data.
+///
+/// Sheets are matched by title. Cells are compared by formatted value and, when
+/// `formats` is set, by user-entered format.
+///
+/// # Arguments
+/// * `a` - The first spreadsheet
+/// * `b` - The second spreadsheet
+/// * `formats` - Whether to compare cell formats
+///
+/// # Returns
+/// The [`SpreadsheetDiff`] of the two spreadsheets.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::diff::diff_spreadsheet_data;
+/// use gsheet_api::models::Spreadsheet;
+///
+/// let spreadsheet = |value: &str| -> Spreadsheet {
+///     serde_json::from_value(serde_json::json!({
+///         "sheets": [{
+///             "properties": {"title": "Data"},
+///             "data": [{"rowData": [{"values": [{"formattedValue": "id"}, {"formattedValue": value}]}]}]
+///         }]
+///     }))
+///     .unwrap()
+/// };
+///
+/// let diff = diff_spreadsheet_data(&spreadsheet("old"), &spreadsheet("new"), false);
+/// assert_eq!(diff.sheets[0].cells[0].a1(), "B1");
+/// assert_eq!(diff.sheets[0].cells[0].value_b.as_deref(), Some("new"));
+/// ```
+pub fn diff_spreadsheet_data(a: &Spreadsheet, b: &Spreadsheet, formats: bool) -> SpreadsheetDiff {
+    let sheets_a: Vec<&Sheet> = a.sheets.iter().flatten().collect();
+    let sheets_b: Vec<&Sheet> = b.sheets.iter().flatten().collect();
+    let mut diff = SpreadsheetDiff::default();
+    for sheet_a in &sheets_a {
+        let title = sheet_title(sheet_a);
+        match find_sheet(&sheets_b, title) {
+            Some(sheet_b) => {
+                let sheet_diff = diff_sheet(sheet_a, sheet_b, formats);
+                if !sheet_diff.is_empty() {
+                    diff.sheets.push(sheet_diff);
+                }
+            }
+            None => diff.only_in_a.push(title.to_string()),
+        }
+    }
+    for sheet_b in &sheets_b {
+        let title = sheet_title(sheet_b);
+        if find_sheet(&sheets_a, title).is_none() {
+            diff.only_in_b.push(title.to_string());
+        }
+    }
+    diff
+}
+
+/// Returns the sheet with the given title.
+fn find_sheet<'a>(sheets: &[&'a Sheet], title: &str) -> Option<&'a Sheet> {
+    sheets
+        .iter()
+        .find(|sheet| sheet_title(sheet) == title)
+        .copied()
+}
+
+/// Returns the title of a sheet, empty if not read.
+fn sheet_title(sheet: &Sheet) -> &str {
+    sheet
+        .properties
+        .as_ref()
+        .and_then(|properties| properties.title.as_deref())
+        .unwrap_or_default()
+}
+
+/// Returns the cells of a sheet as rows from the top-left corner of the grid.
+fn sheet_cells(sheet: &Sheet) -> Vec<Vec<&CellData>> {
+    let mut rows: Vec<Vec<&CellData>> = Vec::new();
+    for grid in sheet.data.iter().flatten() {
+        let start_row = grid.start_row.unwrap_or_default().max(0) as usize;
+        let start_column = grid.start_column.unwrap_or_default().max(0) as usize;
+        for (offset, row) in grid.row_data.iter().flatten().enumerate() {
+            let index = start_row + offset;
+            if rows.len() <= index {
+                rows.resize_with(index + 1, Vec::new);
+            }
+            for (column, cell) in row.values.iter().flatten().enumerate() {
+                let column = start_column + column;
+                let cells = &mut rows[index];
+                if cells.len() <= column {
+                    cells.resize(column + 1, EMPTY_CELL);
+                }
+                cells[column] = cell;
+            }
+        }
+    }
+    rows
+}
+
+/// The data of a cell missing from the grid data.
+const EMPTY_CELL: &CellData = &CellData {
+    user_entered_value: None,
+    effective_value: None,
+    formatted_value: None,
+    user_entered_format: None,
+    effective_format: None,
+    hyperlink: None,
+    note: None,
+    text_format_runs: None,
+    data_validation: None,
+    pivot_table: None,
+    data_source_table: None,
+    data_source_formula: None,
+    chip_runs: None,
+};
+
+/// Compares two sheets with the same title.
+fn diff_sheet(a: &Sheet, b: &Sheet, formats: bool) -> SheetDiff {
+    let size = |sheet: &Sheet| {
+        let grid = sheet
+            .properties
+            .as_ref()
+            .and_then(|properties| properties.grid_properties.as_ref());
+        (
+            grid.and_then(|grid| grid.row_count).unwrap_or_default(),
+            grid.and_then(|grid| grid.column_count).unwrap_or_default(),
+        )
+    };
+    let (size_a, size_b) = (size(a), size(b));
+
+    let (rows_a, rows_b) = (sheet_cells(a), sheet_cells(b));
+    let mut cells = Vec::new();
+    for row in 0..rows_a.len().max(rows_b.len()) {
+        let row_a = rows_a.get(row).map(Vec::as_slice).unwrap_or_default();
+        let row_b = rows_b.get(row).map(Vec::as_slice).unwrap_or_default();
+        for column in 0..row_a.len().max(row_b.len()) {
+            let cell_a = row_a.get(column).copied().unwrap_or(EMPTY_CELL);
+            let cell_b = row_b.get(column).copied().unwrap_or(EMPTY_CELL);
+            let value = |cell: &CellData| cell.formatted_value.clone().filter(|v| !v.is_empty());
+            let (value_a, value_b) = (value(cell_a), value(cell_b));
+            let format_differs = formats
+                && serde_json::to_value(&cell_a.user_entered_format).ok()
+                    != serde_json::to_value(&cell_b.user_entered_format).ok();
+            if value_a != value_b || format_differs {
+                cells.push(CellDiff {
+                    row,
+                    column,
+                    value_a,
+                    value_b,
+                    format_differs,
+                });
+            }
+        }
+    }
+
+    SheetDiff {
+        title: sheet_title(a).to_string(),
+        size: (size_a != size_b).then_some((size_a, size_b)),
+        cells,
+    }
+}
+
+/// Creates a builder comparing two spreadsheets.
+///
+/// # Arguments
+/// * `a` - The first spreadsheet, such as the source of a migration
+/// * `b` - The second spreadsheet, such as the migrated spreadsheet
+///
+/// # Returns
+/// A new [`DiffSpreadsheetsBuilder`] instance.
+pub fn diff_spreadsheets(
+    a: &SpreadsheetOperations,
+    b: &SpreadsheetOperations,
+) -> DiffSpreadsheetsBuilder {
+    DiffSpreadsheetsBuilder::new(a, b)
+}
+
+/// Builder for comparing two spreadsheets.
+#[derive(Clone, Default)]
+pub struct DiffSpreadsheetsBuilder {
+    /// The first spreadsheet.
+    a: Option<SpreadsheetOperations>,
+    /// The second spreadsheet.
+    b: Option<SpreadsheetOperations>,
+    /// Whether to compare cell formats.
+    formats: bool,
+    /// Timeout override for each request.
+    timeout: Option<Duration>,
+}
+
+impl DiffSpreadsheetsBuilder {
+    /// Creates a new builder comparing two spreadsheets.
+    ///
+    /// # Arguments
+    /// * `a` - The first spreadsheet
+    /// * `b` - The second spreadsheet
+    ///
+    /// # Returns
+    /// A new [`DiffSpreadsheetsBuilder`] instance.
+    pub fn new(a: &SpreadsheetOperations, b: &SpreadsheetOperations) -> Self {
+        Self {
+            a: Some(a.clone()),
+            b: Some(b.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether to compare the user-entered formats of cells, in addition to
+    /// their values. Defaults to `false`.
+    ///
+    /// # Arguments
+    /// * `formats` - Whether to compare formats
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn formats(mut self, formats: bool) -> Self {
+        self.formats = formats;
+        self
+    }
+
+    /// Sets the timeout of each request, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of a single request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`DiffSpreadsheetsOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`DiffSpreadsheetsOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if either spreadsheet is not set.
+    pub fn build(self) -> Result<DiffSpreadsheetsOperations, GSheetError> {
+        let (Some(a), Some(b)) = (self.a, self.b) else {
+            return Err(GSheetError::Other(
+                "Both spreadsheets are required to build DiffSpreadsheetsOperations".into(),
+            ));
+        };
+
+        Ok(DiffSpreadsheetsOperations {
+            a,
+            b,
+            formats: self.formats,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation comparing two spreadsheets.
+pub struct DiffSpreadsheetsOperations {
+    /// The first spreadsheet.
+    a: SpreadsheetOperations,
+    /// The second spreadsheet.
+    b: SpreadsheetOperations,
+    /// Whether to compare cell formats.
+    formats: bool,
+    /// Timeout override for each request.
+    timeout: Option<Duration>,
+}
+
+impl DiffSpreadsheetsOperations {
+    /// Reads the grid data of both spreadsheets, concurrently, and compares them.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`SpreadsheetDiff`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if either spreadsheet cannot be read.
+    pub async fn execute(&self) -> Result<SpreadsheetDiff, GSheetError> {
+        let (a, b) = tokio::try_join!(self.fetch(&self.a), self.fetch(&self.b))?;
+        Ok(diff_spreadsheet_data(&a, &b, self.formats))
+    }
+
+    /// Reads the grid data of a spreadsheet, with the compared fields only.
+    async fn fetch(&self, spreadsheet: &SpreadsheetOperations) -> Result<Spreadsheet, GSheetError> {
+        let gsheet_client = &spreadsheet.gsheet_client;
+        let url = gsheet_client.spreadsheet_url(&spreadsheet.spreadsheet_id, "");
+        let fields = if self.formats {
+            FORMAT_FIELDS
+        } else {
+            VALUE_FIELDS
+        };
+        let query = QueryParams::new()
+            .flag("includeGridData", true)
+            .fields(fields);
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
+        let response = gsheet_client.send(request).await?;
+
+        if response.status().is_success() {
+            Ok(decode_json(response).await?)
+        } else {
+            Err(GSheetError::from_response(response, &spreadsheet.spreadsheet_id).await)
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! # Drive Module
+//!
+//! This module provides listing of spreadsheet files through the Google Drive API.
+//!
+//! ## Overview
+//!
+//! [`ListFilesBuilder`] lists the spreadsheets visible to the service account as
+//! an async stream of [`DriveFile`]s. The Drive API returns files page by page;
+//! the stream requests the next page with its `nextPageToken` only once the
+//! current page is consumed, so callers never handle page tokens and can stop
+//! early without fetching the remaining pages.
+//!
+//! Unlike Drive listings, the Sheets `developerMetadata:search` endpoint returns
+//! every match in a single response without page token, so it needs no paginator.
+//!
+//! Listing files needs a Drive scope, such as
+//! [`DRIVE_READONLY_SCOPE`](crate::auth::service_account::DRIVE_READONLY_SCOPE).
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use futures::TryStreamExt;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! let files = gsheet_client
+//!     .list_spreadsheets()
+//!     .query("name contains 'Report'")
+//!     .order_by("modifiedTime desc")
+//!     .stream();
+//! let mut files = std::pin::pin!(files);
+//!
+//! while let Some(file) = files.try_next().await? {
+//!     println!("{} ({})", file.name.unwrap_or_default(), file.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use futures::{Stream, TryStreamExt, stream};
+use serde::{Deserialize, Serialize};
+
+use crate::client::GoogleSheetClient;
+use crate::client::url::QueryParams;
+use crate::error::{GSheetError, decode_json};
+
+/// The MIME type of Google Sheets spreadsheets.
+pub const SPREADSHEET_MIME_TYPE: &str = "application/vnd.google-apps.spreadsheet";
+
+/// The file fields requested when listing files.
+const FILE_FIELDS: &str = "id,name,mimeType,createdTime,modifiedTime,parents,webViewLink";
+
+/// A file stored in Google Drive.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriveFile {
+    /// The ID of the file, which is the spreadsheet ID for spreadsheets.
+    pub id: String,
+    /// The name of the file.
+    pub name: Option<String>,
+    /// The MIME type of the file.
+    pub mime_type: Option<String>,
+    /// When the file was created, in RFC 3339 format.
+    pub created_time: Option<String>,
+    /// When the file was last modified, in RFC 3339 format.
+    pub modified_time: Option<String>,
+    /// The IDs of the folders containing the file.
+    #[serde(default)]
+    pub parents: Vec<String>,
+    /// A link for opening the file in a browser.
+    pub web_view_link: Option<String>,
+}
+
+/// A page of the Drive `files.list` endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileList {
+    /// The files of the page.
+    #[serde(default)]
+    files: Vec<DriveFile>,
+    /// The token of the next page, absent on the last page.
+    next_page_token: Option<String>,
+}
+
+/// Builder for listing Drive files.
+#[derive(Clone, Default)]
+pub struct ListFilesBuilder {
+    /// The Google Sheets client.
+    gsheet_client: Option<GoogleSheetClient>,
+    /// Additional search query, combined with the spreadsheet filter.
+    query: Option<String>,
+    /// Whether only spreadsheets are listed.
+    spreadsheets_only: bool,
+    /// Whether trashed files are listed.
+    include_trashed: bool,
+    /// The sort order of the files.
+    order_by: Option<String>,
+    /// The maximum number of files per page.
+    page_size: Option<u32>,
+    /// Timeout override for each page request.
+    timeout: Option<Duration>,
+}
+
+impl ListFilesBuilder {
+    /// Creates a new builder listing the spreadsheets visible to the client.
+    ///
+    /// # Arguments
+    /// * `gsheet_client` - The Google Sheets client
+    ///
+    /// # Returns
+    /// A new [`ListFilesBuilder`] instance.
+    pub fn new(gsheet_client: &GoogleSheetClient) -> Self {
+        Self {
+            gsheet_client: Some(gsheet_client.clone()),
+            spreadsheets_only: true,
+            ..Default::default()
+        }
+    }
+
+    /// Sets a Drive search query (e.g., "name contains 'Report'").
+    ///
+    /// # Arguments
+    /// * `query` - The query, in the Drive search syntax
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn query(mut self, query: &str) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+
+    /// Restricts the listing to files of a folder.
+    ///
+    /// # Arguments
+    /// * `folder_id` - The ID of the Drive folder
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn in_folder(self, folder_id: &str) -> Self {
+        let clause = format!("'{}' in parents", folder_id.replace('\'', "\\'"));
+        match &self.query {
+            Some(query) => {
+                let query = format!("({}) and {}", query, clause);
+                self.query(&query)
+            }
+            None => self.query(&clause),
+        }
+    }
+
+    /// Sets whether only spreadsheets are listed, which is the default.
+    ///
+    /// # Arguments
+    /// * `spreadsheets_only` - Whether to filter on the spreadsheet MIME type
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn spreadsheets_only(mut self, spreadsheets_only: bool) -> Self {
+        self.spreadsheets_only = spreadsheets_only;
+        self
+    }
+
+    /// Sets whether trashed files are listed.
+    ///
+    /// # Arguments
+    /// * `include` - Whether to include trashed files
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn include_trashed(mut self, include: bool) -> Self {
+        self.include_trashed = include;
+        self
+    }
+
+    /// Sets the sort order of the files (e.g., "modifiedTime desc,name").
+    ///
+    /// # Arguments
+    /// * `order_by` - The comma separated sort keys
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn order_by(mut self, order_by: &str) -> Self {
+        self.order_by = Some(order_by.to_string());
+        self
+    }
+
+    /// Sets the maximum number of files fetched per page.
+    ///
+    /// # Arguments
+    /// * `page_size` - The page size, up to 1000
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the timeout of each page request, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of a single request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the files as a stream, fetching pages as they are consumed.
+    ///
+    /// # Returns
+    /// A stream of [`DriveFile`]s, ending after the last page or after the first
+    /// error.
+    pub fn stream(self) -> impl Stream<Item = Result<DriveFile, GSheetError>> + Send + 'static {
+        stream::try_unfold(Some(None), move |page_token: Option<Option<String>>| {
+            let builder = self.clone();
+            async move {
+                let Some(page_token) = page_token else {
+                    return Ok::<_, GSheetError>(None);
+                };
+                let page = builder.fetch_page(page_token.as_deref()).await?;
+                let next = page.next_page_token.map(Some);
+                Ok(Some((stream::iter(page.files.into_iter().map(Ok)), next)))
+            }
+        })
+        .try_flatten()
+    }
+
+    /// Collects every file of every page.
+    ///
+    /// # Returns
+    /// A `Result` containing the files or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if any page request fails.
+    pub async fn collect(self) -> Result<Vec<DriveFile>, GSheetError> {
+        self.stream().try_collect().await
+    }
+
+    /// Returns the full search query sent to Drive.
+    fn full_query(&self) -> String {
+        let mut clauses = Vec::new();
+        if self.spreadsheets_only {
+            clauses.push(format!("mimeType = '{}'", SPREADSHEET_MIME_TYPE));
+        }
+        if !self.include_trashed {
+            clauses.push("trashed = false".to_string());
+        }
+        if let Some(query) = &self.query {
+            clauses.push(format!("({})", query));
+        }
+        clauses.join(" and ")
+    }
+
+    /// Fetches a single page of files.
+    async fn fetch_page(&self, page_token: Option<&str>) -> Result<FileList, GSheetError> {
+        let gsheet_client = self.gsheet_client.as_ref().ok_or_else(|| {
+            GSheetError::Other("GoogleSheetClient is required to list files".into())
+        })?;
+        let url = format!("{}/files", gsheet_client.drive_base_url);
+
+        let search = self.full_query();
+        let query = QueryParams::new()
+            .fields(&format!("nextPageToken,files({})", FILE_FIELDS))
+            .flag("supportsAllDrives", true)
+            .flag("includeItemsFromAllDrives", true)
+            .optional("q", Some(search).filter(|search| !search.is_empty()))
+            .optional("orderBy", self.order_by.as_ref())
+            .optional("pageSize", self.page_size)
+            .optional("pageToken", page_token);
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
+        let response = gsheet_client.send(request).await?;
+
+        if response.status().is_success() {
+            let page: FileList = decode_json(response).await?;
+            Ok(page)
+        } else {
+            Err(GSheetError::from_response(response, "").await)
+        }
+    }
+}
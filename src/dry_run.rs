@@ -0,0 +1,123 @@
+//! # Dry Run Module
+//!
+//! This module provides [`DryRunRequest`], the request a write operation would
+//! have sent when the client runs in dry-run mode.
+//!
+//! ## Overview
+//!
+//! A client built with
+//! [`dry_run(true)`](crate::client::GoogleSheetClientBuilder::dry_run), or
+//! derived from another one with
+//! [`GoogleSheetClient::with_dry_run`](crate::client::GoogleSheetClient::with_dry_run),
+//! still sends reads, but stops at the first request that could modify data.
+//! Instead of sending it, the operation fails with
+//! [`GSheetError::DryRun`](crate::error::GSheetError::DryRun) holding the method,
+//! URL and JSON body of the request, so complex batches can be reviewed before
+//! they touch production sheets.
+//!
+//! Operations issuing several writes, such as chunked writes, stop at their
+//! first write.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::error::GSheetError;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! let preview = gsheet_client.with_dry_run(true);
+//! let result = preview
+//!     .spreadsheet("spreadsheet-id")
+//!     .sheet("Sheet1")
+//!     .update_from("A1", vec![vec!["Name".to_string(), "Email".to_string()]])
+//!     .execute()
+//!     .await;
+//!
+//! if let Err(GSheetError::DryRun(request)) = result {
+//!     println!("{} {}", request.method, request.url);
+//!     println!("{}", request.body_pretty());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+/// A request captured instead of being sent, in dry-run mode.
+///
+/// Authentication headers are not captured, and API keys are redacted from the
+/// URL.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DryRunRequest {
+    /// The HTTP method of the request (e.g., "POST").
+    pub method: String,
+    /// The full URL of the request, including its query parameters.
+    pub url: String,
+    /// The JSON body of the request, if any.
+    pub body: Option<serde_json::Value>,
+}
+
+impl DryRunRequest {
+    /// Captures a built request.
+    ///
+    /// # Arguments
+    /// * `request` - The request that would have been sent
+    ///
+    /// # Returns
+    /// The captured [`DryRunRequest`]. Bodies that are not JSON are kept as a
+    /// JSON string.
+    pub(crate) fn capture(request: &reqwest::Request) -> Self {
+        let body = request
+            .body()
+            .and_then(reqwest::Body::as_bytes)
+            .map(|bytes| {
+                serde_json::from_slice(bytes).unwrap_or_else(|_| {
+                    serde_json::Value::String(String::from_utf8_lossy(bytes).into_owned())
+                })
+            });
+
+        Self {
+            method: request.method().to_string(),
+            url: redact_api_key(request.url()),
+            body,
+        }
+    }
+
+    /// Returns the body as indented JSON, or an empty string without body.
+    pub fn body_pretty(&self) -> String {
+        self.body
+            .as_ref()
+            .and_then(|body| serde_json::to_string_pretty(body).ok())
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for DryRunRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.url)
+    }
+}
+
+/// Returns a URL with the value of its `key` query parameter redacted.
+pub(crate) fn redact_api_key(url: &reqwest::Url) -> String {
+    if !url.query_pairs().any(|(name, _)| name == "key") {
+        return url.to_string();
+    }
+    let mut redacted = url.clone();
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(name, value)| {
+            let value = if name == "key" {
+                "REDACTED".to_string()
+            } else {
+                value.into_owned()
+            };
+            (name.into_owned(), value)
+        })
+        .collect();
+    redacted.query_pairs_mut().clear().extend_pairs(pairs);
+    redacted.to_string()
+}
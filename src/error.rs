@@ -1,5 +1,15 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use thiserror::Error;
 
+use crate::audit::request_targets;
+use crate::dry_run::redact_api_key;
+
 #[derive(Error, Debug)]
 pub enum GSheetError {
     #[error("HTTP request error: {0}")]
@@ -14,6 +24,464 @@ pub enum GSheetError {
     #[error("Utility function error: {0}")]
     UtilsError(String),
 
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+
+    #[error("Spreadsheet '{spreadsheet_id}' not found")]
+    SpreadsheetNotFound { spreadsheet_id: String },
+
+    #[error("Sheet '{title}' not found")]
+    SheetNotFound { title: String },
+
+    #[error("Sheet '{title}' already exists")]
+    SheetAlreadyExists { title: String },
+
+    #[error("Permission denied on spreadsheet '{spreadsheet_id}': {message}")]
+    PermissionDenied {
+        spreadsheet_id: String,
+        message: String,
+    },
+
+    #[error("Invalid range '{range}': {message}")]
+    InvalidRange { range: String, message: String },
+
+    #[error("Rate limited (retry after {retry_after:?}): {message}")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        quota_limit: Option<String>,
+        message: String,
+    },
+
+    #[error("Circuit breaker open, retry after {retry_after:?}")]
+    CircuitOpen { retry_after: Duration },
+
+    #[error("Operation cancelled after {completed_rows} rows")]
+    Cancelled { completed_rows: usize },
+
+    #[error("Workflow step '{step}' failed: {source}")]
+    WorkflowFailed {
+        step: String,
+        source: Box<GSheetError>,
+        rollback_errors: Vec<String>,
+    },
+
+    #[error("{} of {total} spreadsheets failed", .failures.len())]
+    FleetFailed {
+        total: usize,
+        failures: Vec<(String, GSheetError)>,
+    },
+
+    #[error("Read-only client, {method} request not sent: {url}")]
+    ReadOnlyViolation { method: String, url: String },
+
+    #[error("Audit record not stored, request not sent: {0}")]
+    Audit(String),
+
+    #[error("Dry run, request not sent: {0}")]
+    DryRun(Box<crate::dry_run::DryRunRequest>),
+
+    #[error("{source} ({context})")]
+    WithContext {
+        context: ErrorContext,
+        source: Box<GSheetError>,
+    },
+
     #[error("Other error: {0}")]
     Other(String),
 }
+
+/// Where a failed request was sent, attached to transport, status and
+/// decoding errors so they can be traced back to an operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// The HTTP method of the request (e.g., "GET").
+    pub method: String,
+    /// The full URL of the request, with API keys redacted.
+    pub url: String,
+    /// The spreadsheet the request targeted, if it targeted one.
+    pub spreadsheet_id: Option<String>,
+    /// The A1 ranges named by the request.
+    pub ranges: Vec<String>,
+}
+
+impl ErrorContext {
+    /// Describes a built request.
+    ///
+    /// # Arguments
+    /// * `request` - The request
+    /// * `base_url` - The base URL of the Sheets API, to find the spreadsheet ID
+    pub(crate) fn capture(request: &reqwest::Request, base_url: &str) -> Self {
+        let body = request
+            .body()
+            .and_then(reqwest::Body::as_bytes)
+            .and_then(|bytes| serde_json::from_slice(bytes).ok());
+        let (spreadsheet_id, ranges) = request_targets(request, base_url, body.as_ref());
+        Self {
+            method: request.method().to_string(),
+            url: redact_api_key(request.url()),
+            spreadsheet_id,
+            ranges,
+        }
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.url)?;
+        if let Some(spreadsheet_id) = &self.spreadsheet_id {
+            write!(f, ", spreadsheet '{}'", spreadsheet_id)?;
+        }
+        if !self.ranges.is_empty() {
+            write!(f, ", ranges {}", self.ranges.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// A sent request, kept in the extensions of its response so failures found
+/// while reading the response can be described.
+#[derive(Clone)]
+pub(crate) struct SentRequest {
+    /// A copy of the request.
+    request: Arc<reqwest::Request>,
+    /// The base URL of the Sheets API.
+    base_url: Arc<str>,
+}
+
+impl SentRequest {
+    /// Keeps a copy of a request about to be sent, if its body can be copied.
+    pub(crate) fn new(request: &reqwest::Request, base_url: &Arc<str>) -> Option<Self> {
+        Some(Self {
+            request: Arc::new(request.try_clone()?),
+            base_url: base_url.clone(),
+        })
+    }
+
+    /// Describes the request.
+    pub(crate) fn context(&self) -> ErrorContext {
+        ErrorContext::capture(&self.request, &self.base_url)
+    }
+
+    /// Describes the request a response answers, if it was kept.
+    fn context_of(response: &reqwest::Response) -> Option<ErrorContext> {
+        response.extensions().get::<Self>().map(Self::context)
+    }
+}
+
+/// The number of bytes of a response body kept on each side of a
+/// deserialization error.
+const SNIPPET_RADIUS: usize = 120;
+
+/// Deserializes the JSON body of a successful response.
+///
+/// # Arguments
+/// * `response` - The response
+///
+/// # Returns
+/// A `Result` containing the deserialized body or a [`GSheetError`].
+///
+/// # Errors
+/// This function will return an error, with the context of the request, if the
+/// body cannot be read. It will return a [`GSheetError::ResponseParseError`]
+/// naming the path of the offending field and quoting the body around it if
+/// the body cannot be deserialized.
+pub(crate) async fn decode_json<T: DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<T, GSheetError> {
+    let context = SentRequest::context_of(&response);
+    let body = match response.bytes().await {
+        Ok(body) => body,
+        Err(e) => return Err(GSheetError::from(e).with_context(context)),
+    };
+
+    let deserializer = &mut serde_json::Deserializer::from_slice(&body);
+    serde_path_to_error::deserialize(deserializer).map_err(|e| {
+        let path = e.path().to_string();
+        let inner = e.into_inner();
+        GSheetError::ResponseParseError(format!(
+            "{} (path '{}') in response body: {}",
+            inner,
+            path,
+            snippet(&body, inner.line(), inner.column())
+        ))
+        .with_context(context)
+    })
+}
+
+/// Quotes a response body around a position, truncated to [`SNIPPET_RADIUS`]
+/// bytes on each side.
+///
+/// # Arguments
+/// * `body` - The response body
+/// * `line` - The 1-based line of the position
+/// * `column` - The 1-based column of the position
+fn snippet(body: &[u8], line: usize, column: usize) -> String {
+    let body = String::from_utf8_lossy(body);
+    let offset = body
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>()
+        + column;
+    let offset = body.floor_char_boundary(offset);
+    let start = body.floor_char_boundary(offset.saturating_sub(SNIPPET_RADIUS));
+    let end = body.ceil_char_boundary(offset.saturating_add(SNIPPET_RADIUS));
+
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push_str("...");
+    }
+    snippet.push_str(&body[start..end]);
+    if end < body.len() {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
+/// Error body returned by Google APIs.
+#[derive(Debug, Deserialize)]
+struct ApiErrorBody {
+    /// The error details.
+    error: ApiErrorDetails,
+}
+
+/// Details of an error returned by Google APIs.
+#[derive(Debug, Deserialize)]
+struct ApiErrorDetails {
+    /// A developer-facing description of the error.
+    #[serde(default)]
+    message: String,
+    /// Structured details, such as retry and quota information.
+    #[serde(default)]
+    details: Vec<ApiErrorDetail>,
+}
+
+/// A structured detail of an error returned by Google APIs.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiErrorDetail {
+    /// How long to wait before retrying (e.g., "30s"), set on `RetryInfo` details.
+    retry_delay: Option<String>,
+    /// Metadata of `ErrorInfo` details, including the exceeded quota.
+    #[serde(default)]
+    metadata: HashMap<String, String>,
+}
+
+/// Prefix of the error message returned for ranges the API cannot parse.
+const INVALID_RANGE_PREFIX: &str = "Unable to parse range: ";
+const SHEET_EXISTS_PREFIX: &str = "A sheet with the name \"";
+const SHEET_EXISTS_SUFFIX: &str = "\" already exists";
+
+impl GSheetError {
+    /// Attaches the context of the request that failed, if known.
+    pub(crate) fn with_context(self, context: Option<ErrorContext>) -> Self {
+        match context {
+            Some(context) => GSheetError::WithContext {
+                context,
+                source: Box::new(self),
+            },
+            None => self,
+        }
+    }
+
+    /// Returns the context of the request that failed, if it is attached.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            GSheetError::WithContext { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Returns the error without its request context, for matching on its kind.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gsheet_api::error::GSheetError;
+    /// # fn handle(error: GSheetError) {
+    /// if let GSheetError::HttpRequestError(e) = error.without_context() {
+    ///     eprintln!("timed out: {}", e.is_timeout());
+    /// }
+    /// # }
+    /// ```
+    pub fn without_context(&self) -> &GSheetError {
+        match self {
+            GSheetError::WithContext { source, .. } => source.without_context(),
+            error => error,
+        }
+    }
+
+    /// Returns a copy of the error, for reporting one failure to several callers.
+    ///
+    /// HTTP and authentication errors can't be copied, and are reported as
+    /// [`GSheetError::Other`] with their message.
+    pub(crate) fn duplicate(&self) -> Self {
+        match self {
+            GSheetError::ResponseParseError(message) => {
+                GSheetError::ResponseParseError(message.clone())
+            }
+            GSheetError::UtilsError(message) => GSheetError::UtilsError(message.clone()),
+            GSheetError::ValidationError(message) => GSheetError::ValidationError(message.clone()),
+            GSheetError::SpreadsheetNotFound { spreadsheet_id } => {
+                GSheetError::SpreadsheetNotFound {
+                    spreadsheet_id: spreadsheet_id.clone(),
+                }
+            }
+            GSheetError::SheetNotFound { title } => GSheetError::SheetNotFound {
+                title: title.clone(),
+            },
+            GSheetError::SheetAlreadyExists { title } => GSheetError::SheetAlreadyExists {
+                title: title.clone(),
+            },
+            GSheetError::PermissionDenied {
+                spreadsheet_id,
+                message,
+            } => GSheetError::PermissionDenied {
+                spreadsheet_id: spreadsheet_id.clone(),
+                message: message.clone(),
+            },
+            GSheetError::InvalidRange { range, message } => GSheetError::InvalidRange {
+                range: range.clone(),
+                message: message.clone(),
+            },
+            GSheetError::RateLimited {
+                retry_after,
+                quota_limit,
+                message,
+            } => GSheetError::RateLimited {
+                retry_after: *retry_after,
+                quota_limit: quota_limit.clone(),
+                message: message.clone(),
+            },
+            GSheetError::CircuitOpen { retry_after } => GSheetError::CircuitOpen {
+                retry_after: *retry_after,
+            },
+            GSheetError::Cancelled { completed_rows } => GSheetError::Cancelled {
+                completed_rows: *completed_rows,
+            },
+            GSheetError::WorkflowFailed {
+                step,
+                source,
+                rollback_errors,
+            } => GSheetError::WorkflowFailed {
+                step: step.clone(),
+                source: Box::new(source.duplicate()),
+                rollback_errors: rollback_errors.clone(),
+            },
+            GSheetError::FleetFailed { total, failures } => GSheetError::FleetFailed {
+                total: *total,
+                failures: failures
+                    .iter()
+                    .map(|(id, error)| (id.clone(), error.duplicate()))
+                    .collect(),
+            },
+            GSheetError::ReadOnlyViolation { method, url } => GSheetError::ReadOnlyViolation {
+                method: method.clone(),
+                url: url.clone(),
+            },
+            GSheetError::Audit(message) => GSheetError::Audit(message.clone()),
+            GSheetError::DryRun(request) => GSheetError::DryRun(request.clone()),
+            GSheetError::WithContext { context, source } => GSheetError::WithContext {
+                context: context.clone(),
+                source: Box::new(source.duplicate()),
+            },
+            GSheetError::HttpRequestError(_)
+            | GSheetError::AuthError(_)
+            | GSheetError::Other(_) => GSheetError::Other(self.to_string()),
+        }
+    }
+
+    /// Builds the error of a failed API response.
+    ///
+    /// Not found, permission and range errors are mapped to dedicated variants
+    /// carrying the offending identifier, taken from the API error message for
+    /// ranges. Rate limit errors carry the delay requested by the `Retry-After`
+    /// header or the error details, so callers can schedule their own backoff;
+    /// other statuses are reported as
+    /// [`GSheetError::HttpRequestError`], with the context of the request.
+    ///
+    /// # Arguments
+    /// * `response` - The unsuccessful response
+    /// * `spreadsheet_id` - The ID of the spreadsheet the request targeted
+    ///
+    /// # Returns
+    /// The [`GSheetError`] describing the failure.
+    pub(crate) async fn from_response(response: reqwest::Response, spreadsheet_id: &str) -> Self {
+        let error = match response.error_for_status_ref() {
+            Ok(response) => {
+                return GSheetError::ResponseParseError(format!(
+                    "Unexpected status {}",
+                    response.status()
+                ));
+            }
+            Err(error) => error,
+        };
+        let status = response.status();
+        let context = SentRequest::context_of(&response);
+        let retry_after_header = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+        let (message, details) = response
+            .json::<ApiErrorBody>()
+            .await
+            .map(|body| (body.error.message, body.error.details))
+            .unwrap_or_default();
+
+        match status {
+            reqwest::StatusCode::NOT_FOUND => GSheetError::SpreadsheetNotFound {
+                spreadsheet_id: spreadsheet_id.to_string(),
+            },
+            reqwest::StatusCode::FORBIDDEN => GSheetError::PermissionDenied {
+                spreadsheet_id: spreadsheet_id.to_string(),
+                message,
+            },
+            reqwest::StatusCode::BAD_REQUEST if message.starts_with(INVALID_RANGE_PREFIX) => {
+                GSheetError::InvalidRange {
+                    range: message[INVALID_RANGE_PREFIX.len()..].to_string(),
+                    message,
+                }
+            }
+            reqwest::StatusCode::BAD_REQUEST if message.contains(SHEET_EXISTS_SUFFIX) => {
+                GSheetError::SheetAlreadyExists {
+                    title: parse_existing_sheet_title(&message).unwrap_or_default(),
+                }
+            }
+            reqwest::StatusCode::TOO_MANY_REQUESTS => GSheetError::RateLimited {
+                retry_after: retry_after_header.or_else(|| {
+                    details
+                        .iter()
+                        .find_map(|detail| parse_duration(detail.retry_delay.as_deref()?))
+                }),
+                quota_limit: details
+                    .into_iter()
+                    .find_map(|mut detail| detail.metadata.remove("quota_limit")),
+                message,
+            },
+            _ => GSheetError::HttpRequestError(error).with_context(context),
+        }
+    }
+}
+
+/// Extracts the title from an "A sheet with the name "..." already exists" message.
+fn parse_existing_sheet_title(message: &str) -> Option<String> {
+    let start = message.find(SHEET_EXISTS_PREFIX)? + SHEET_EXISTS_PREFIX.len();
+    let end = message.rfind(SHEET_EXISTS_SUFFIX)?;
+    message.get(start..end).map(str::to_string)
+}
+
+/// Parses a `Retry-After` header, given either in seconds or as an HTTP date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value.trim()).ok()?;
+    (at.with_timezone(&chrono::Utc) - chrono::Utc::now())
+        .to_std()
+        .ok()
+}
+
+/// Parses a protobuf JSON duration (e.g., "30s", "1.5s").
+fn parse_duration(value: &str) -> Option<Duration> {
+    Duration::try_from_secs_f64(value.strip_suffix('s')?.parse().ok()?).ok()
+}
@@ -0,0 +1,593 @@
+//! # Export Module
+//!
+//! This module converts values read from a sheet into formats consumed by other
+//! systems.
+//!
+//! ## Overview
+//!
+//! [`to_json_records`] turns data rows into JSON objects keyed by header name,
+//! and [`write_jsonl`] writes them as JSON Lines, one object per line, ready to
+//! be piped into log shippers, warehouses or `jq`. Cells are exported as the
+//! strings the API returned; empty cells become `null`, and columns without a
+//! header name are left out. Keys are ordered by name, as in `serde_json` maps.
+//!
+//! [`ValueRange::to_json_records`] and [`ValueRange::write_jsonl`] do the same
+//! for a range whose first row is the header.
+//!
+//! [`render_markdown`] and [`render_table`] format a range as a Markdown table or
+//! an aligned plain text table, for bots posting sheet snippets to chat or
+//! issue trackers.
+//!
+//! [`render_csv`] formats a range as CSV, for spreadsheets and tools importing
+//! comma-separated files. [`SheetOperations::export_csv`](crate::operations::sheet::SheetOperations::export_csv)
+//! reads a sheet and renders it, optionally leaving out the rows and columns
+//! hidden by the user or by the basic filter.
+//!
+//! [`render_html`] formats grid data, read with its effective formats, as an
+//! HTML table with inline styles preserving background colors, text colors,
+//! bold, italic and alignment, so it survives email clients that strip style
+//! sheets. [`SheetOperations::export_html`](crate::operations::sheet::SheetOperations::export_html)
+//! reads the grid data and renders it in one call.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! let value_range = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .sheet("Orders")
+//!     .get_all_value()
+//!     .execute()
+//!     .await?;
+//!
+//! let file = std::fs::File::create("orders.jsonl")?;
+//! let written = value_range.write_jsonl(std::io::BufWriter::new(file))?;
+//! println!("Exported {} orders", written);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::Write;
+
+use serde_json::{Map, Value};
+
+use crate::error::GSheetError;
+use crate::models::{
+    CellData, CellFormat, Color, GridData, Header, HorizontalAlign, ValueRange, VerticalAlign,
+};
+
+/// Converts data rows into JSON objects keyed by header name.
+///
+/// Rows whose cells are all empty are skipped. Within a row, empty or missing
+/// cells become `null`.
+///
+/// # Arguments
+/// * `header` - The header naming the columns
+/// * `rows` - The data rows, from the first column of the sheet
+///
+/// # Returns
+/// One JSON object per non-empty row.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::export::to_json_records;
+/// use gsheet_api::models::Header;
+/// use serde_json::json;
+///
+/// let header = Header::new(1, vec!["Name".to_string(), "Age".to_string()]);
+/// let rows = vec![
+///     vec!["Ada".to_string(), "36".to_string()],
+///     vec![],
+///     vec!["Alan".to_string()],
+/// ];
+/// let records = to_json_records(&header, &rows);
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(serde_json::Value::Object(records[1].clone()), json!({"Name": "Alan", "Age": null}));
+/// ```
+pub fn to_json_records(header: &Header, rows: &[Vec<String>]) -> Vec<Map<String, Value>> {
+    rows.iter()
+        .filter(|row| row.iter().any(|value| !value.trim().is_empty()))
+        .map(|row| to_json_record(header, row))
+        .collect()
+}
+
+/// Writes data rows as JSON Lines, one object keyed by header name per line.
+///
+/// # Arguments
+/// * `writer` - The destination, which should be buffered for large exports
+/// * `header` - The header naming the columns
+/// * `rows` - The data rows, from the first column of the sheet
+///
+/// # Returns
+/// A `Result` containing the number of lines written or a [`GSheetError`].
+///
+/// # Errors
+/// This method will return an error if writing to the destination fails.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::export::write_jsonl;
+/// use gsheet_api::models::Header;
+///
+/// let header = Header::new(1, vec!["Name".to_string(), "Age".to_string()]);
+/// let rows = vec![vec!["Ada".to_string(), "36".to_string()]];
+/// let mut output = Vec::new();
+/// write_jsonl(&mut output, &header, &rows).unwrap();
+/// assert_eq!(String::from_utf8(output).unwrap(), "{\"Age\":\"36\",\"Name\":\"Ada\"}\n");
+/// ```
+pub fn write_jsonl<W: Write>(
+    mut writer: W,
+    header: &Header,
+    rows: &[Vec<String>],
+) -> Result<usize, GSheetError> {
+    let mut written = 0;
+    for row in rows
+        .iter()
+        .filter(|row| row.iter().any(|value| !value.trim().is_empty()))
+    {
+        let record = Value::Object(to_json_record(header, row));
+        serde_json::to_writer(&mut writer, &record)
+            .map_err(|e| GSheetError::Other(format!("Failed to write JSON record: {}", e)))?;
+        writer
+            .write_all(b"\n")
+            .map_err(|e| GSheetError::Other(format!("Failed to write JSON record: {}", e)))?;
+        written += 1;
+    }
+    writer
+        .flush()
+        .map_err(|e| GSheetError::Other(format!("Failed to flush JSON records: {}", e)))?;
+    Ok(written)
+}
+
+/// Renders a range as a Markdown table, using its first row as header.
+///
+/// Pipes are escaped and line breaks within cells become `<br>`, so every row
+/// stays on one line. Short rows are padded with empty cells.
+///
+/// # Arguments
+/// * `value_range` - The values to render
+///
+/// # Returns
+/// The Markdown table, or an empty string if the range has no values.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::export::render_markdown;
+/// use gsheet_api::models::ValueRange;
+///
+/// let value_range = ValueRange {
+///     values: Some(vec![
+///         vec!["Name".to_string(), "Plan".to_string()],
+///         vec!["Ada".to_string(), "Pro|Team".to_string()],
+///     ]),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     render_markdown(&value_range),
+///     "| Name | Plan      |\n| ---- | --------- |\n| Ada  | Pro\\|Team |\n"
+/// );
+/// ```
+pub fn render_markdown(value_range: &ValueRange) -> String {
+    let rows: Vec<Vec<String>> = value_range
+        .to_rows()
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|value| {
+                    value
+                        .replace('|', "\\|")
+                        .replace("\r\n", "<br>")
+                        .replace('\n', "<br>")
+                })
+                .collect()
+        })
+        .collect();
+    let Some(widths) = column_widths(&rows, 3) else {
+        return String::new();
+    };
+
+    let mut output = String::new();
+    for (index, row) in rows.iter().enumerate() {
+        output.push_str(&format_row(row, &widths));
+        if index == 0 {
+            let rule: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+            output.push_str(&format_row(&rule, &widths));
+        }
+    }
+    output
+}
+
+/// Renders a range as a plain text table with aligned columns and borders,
+/// using its first row as header.
+///
+/// Line breaks within cells are replaced by spaces. Columns are aligned by
+/// character count, so wide characters such as emoji may shift borders.
+///
+/// # Arguments
+/// * `value_range` - The values to render
+///
+/// # Returns
+/// The table, or an empty string if the range has no values.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::export::render_table;
+/// use gsheet_api::models::ValueRange;
+///
+/// let value_range = ValueRange {
+///     values: Some(vec![
+///         vec!["Name".to_string(), "Age".to_string()],
+///         vec!["Ada".to_string(), "36".to_string()],
+///     ]),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     render_table(&value_range),
+///     "+------+-----+\n\
+///      | Name | Age |\n\
+///      +------+-----+\n\
+///      | Ada  | 36  |\n\
+///      +------+-----+\n"
+/// );
+/// ```
+pub fn render_table(value_range: &ValueRange) -> String {
+    let rows: Vec<Vec<String>> = value_range
+        .to_rows()
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|value| value.replace("\r\n", " ").replace('\n', " "))
+                .collect()
+        })
+        .collect();
+    let Some(widths) = column_widths(&rows, 0) else {
+        return String::new();
+    };
+
+    let border: String = widths.iter().fold("+".to_string(), |border, width| {
+        format!("{}{}+", border, "-".repeat(width + 2))
+    }) + "\n";
+    let mut output = border.clone();
+    for (index, row) in rows.iter().enumerate() {
+        output.push_str(&format_row(row, &widths));
+        if index == 0 {
+            output.push_str(&border);
+        }
+    }
+    if rows.len() > 1 {
+        output.push_str(&border);
+    }
+    output
+}
+
+/// Renders a range as CSV, one line per row.
+///
+/// Values holding a comma, a quote or a line break are quoted, with their quotes
+/// doubled, as in RFC 4180. Lines end with `\n`, and rows shorter than the
+/// widest row are padded with empty values.
+///
+/// # Arguments
+/// * `value_range` - The values to render
+///
+/// # Returns
+/// The CSV text, or an empty string if the range has no values.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::export::render_csv;
+/// use gsheet_api::models::ValueRange;
+///
+/// let value_range = ValueRange {
+///     values: Some(vec![
+///         vec!["Name".to_string(), "Quote".to_string()],
+///         vec!["Ada".to_string(), "Say \"hi\", then go".to_string()],
+///         vec!["Bob".to_string()],
+///     ]),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     render_csv(&value_range),
+///     "Name,Quote\nAda,\"Say \"\"hi\"\", then go\"\nBob,\n"
+/// );
+/// ```
+pub fn render_csv(value_range: &ValueRange) -> String {
+    let rows = value_range.to_rows();
+    let Some(columns) = rows
+        .iter()
+        .map(Vec::len)
+        .max()
+        .filter(|columns| *columns > 0)
+    else {
+        return String::new();
+    };
+
+    let mut output = String::new();
+    for row in rows.iter() {
+        let fields: Vec<String> = (0..columns)
+            .map(|index| csv_field(row.get(index).map(String::as_str).unwrap_or_default()))
+            .collect();
+        output.push_str(&fields.join(","));
+        output.push('\n');
+    }
+    output
+}
+
+/// The style of every cell of HTML tables, drawing the grid lines.
+const HTML_CELL_STYLE: &str = "border:1px solid #d9d9d9;padding:2px 6px";
+
+/// Renders grid data as an HTML table with inline styles.
+///
+/// Cells show their formatted value, as displayed in Sheets. Background colors,
+/// text colors, bold, italic, underline, strikethrough, alignment and links are
+/// kept; default white backgrounds and black text are left out to keep the
+/// markup small. Merged cells are rendered unmerged.
+///
+/// # Arguments
+/// * `grid` - The grid data, read with the formatted values and effective formats
+///
+/// # Returns
+/// The HTML table, or an empty string if the grid has no rows.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::export::render_html;
+/// use gsheet_api::models::{CellData, CellFormat, GridData, RowData, TextFormat};
+///
+/// let cell = CellData {
+///     formatted_value: Some("Total <EUR>".to_string()),
+///     effective_format: Some(CellFormat {
+///         text_format: Some(TextFormat { bold: Some(true), ..Default::default() }),
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+/// let grid = GridData {
+///     row_data: Some(vec![RowData { values: Some(vec![cell]) }]),
+///     ..Default::default()
+/// };
+/// let html = render_html(&grid);
+/// assert!(html.contains("font-weight:bold\">Total &lt;EUR&gt;</td>"));
+/// ```
+pub fn render_html(grid: &GridData) -> String {
+    let rows = grid.row_data.as_deref().unwrap_or_default();
+    if rows.is_empty() {
+        return String::new();
+    }
+    let columns = rows
+        .iter()
+        .map(|row| row.values.as_ref().map_or(0, Vec::len))
+        .max()
+        .unwrap_or(0);
+
+    let mut output = String::from("<table style=\"border-collapse:collapse\">\n");
+    for row in rows {
+        let cells = row.values.as_deref().unwrap_or_default();
+        output.push_str("<tr>");
+        for index in 0..columns {
+            match cells.get(index) {
+                Some(cell) => output.push_str(&html_cell(cell)),
+                None => output.push_str(&format!("<td style=\"{}\"></td>", HTML_CELL_STYLE)),
+            }
+        }
+        output.push_str("</tr>\n");
+    }
+    output.push_str("</table>\n");
+    output
+}
+
+/// Renders a cell as an HTML table cell.
+fn html_cell(cell: &CellData) -> String {
+    let mut style = HTML_CELL_STYLE.to_string();
+    if let Some(format) = &cell.effective_format {
+        for declaration in css_declarations(format) {
+            style.push(';');
+            style.push_str(&declaration);
+        }
+    }
+
+    let mut content =
+        escape_html(cell.formatted_value.as_deref().unwrap_or_default()).replace('\n', "<br>");
+    let link = cell.hyperlink.as_deref().or_else(|| {
+        cell.effective_format
+            .as_ref()
+            .and_then(|format| format.text_format.as_ref())
+            .and_then(|text_format| text_format.link.as_ref())
+            .and_then(|link| link.uri.as_deref())
+    });
+    if let Some(link) = link {
+        content = format!("<a href=\"{}\">{}</a>", escape_html(link), content);
+    }
+    format!("<td style=\"{}\">{}</td>", style, content)
+}
+
+/// Returns the CSS declarations of a cell format.
+fn css_declarations(format: &CellFormat) -> Vec<String> {
+    let mut declarations = Vec::new();
+    let background = format
+        .background_color_style
+        .as_ref()
+        .and_then(|style| style.rgb_color.as_ref())
+        .or(format.background_color.as_ref())
+        .map(css_color)
+        .filter(|color| color != "#ffffff");
+    if let Some(background) = background {
+        declarations.push(format!("background-color:{}", background));
+    }
+    if let Some(text_format) = &format.text_format {
+        let color = text_format
+            .foreground_color_style
+            .as_ref()
+            .and_then(|style| style.rgb_color.as_ref())
+            .or(text_format.foreground_color.as_ref())
+            .map(css_color)
+            .filter(|color| color != "#000000");
+        if let Some(color) = color {
+            declarations.push(format!("color:{}", color));
+        }
+        if text_format.bold == Some(true) {
+            declarations.push("font-weight:bold".to_string());
+        }
+        if text_format.italic == Some(true) {
+            declarations.push("font-style:italic".to_string());
+        }
+        let decorations: Vec<&str> = [
+            (text_format.underline, "underline"),
+            (text_format.strikethrough, "line-through"),
+        ]
+        .into_iter()
+        .filter(|(enabled, _)| *enabled == Some(true))
+        .map(|(_, decoration)| decoration)
+        .collect();
+        if !decorations.is_empty() {
+            declarations.push(format!("text-decoration:{}", decorations.join(" ")));
+        }
+    }
+    match format.horizontal_alignment {
+        Some(HorizontalAlign::Left) => declarations.push("text-align:left".to_string()),
+        Some(HorizontalAlign::Center) => declarations.push("text-align:center".to_string()),
+        Some(HorizontalAlign::Right) => declarations.push("text-align:right".to_string()),
+        _ => {}
+    }
+    match format.vertical_alignment {
+        Some(VerticalAlign::Top) => declarations.push("vertical-align:top".to_string()),
+        Some(VerticalAlign::Middle) => declarations.push("vertical-align:middle".to_string()),
+        Some(VerticalAlign::Bottom) => declarations.push("vertical-align:bottom".to_string()),
+        _ => {}
+    }
+    declarations
+}
+
+/// Returns the CSS hex notation of a color, its missing components being 0.
+fn css_color(color: &Color) -> String {
+    let channel = |value: Option<f64>| (value.unwrap_or(0.0).clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        channel(color.red),
+        channel(color.green),
+        channel(color.blue)
+    )
+}
+
+/// Escapes the characters with a special meaning in HTML text and attributes.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Quotes a CSV field if it holds a comma, a quote or a line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Returns the width of each column in characters, at least `min_width`, or
+/// `None` if there are no cells.
+fn column_widths(rows: &[Vec<String>], min_width: usize) -> Option<Vec<usize>> {
+    let columns = rows
+        .iter()
+        .map(Vec::len)
+        .max()
+        .filter(|columns| *columns > 0)?;
+    let mut widths = vec![min_width; columns];
+    for row in rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.chars().count());
+        }
+    }
+    Some(widths)
+}
+
+/// Formats a row between pipes, padded to the column widths.
+fn format_row(row: &[String], widths: &[usize]) -> String {
+    let cells: Vec<String> = widths
+        .iter()
+        .enumerate()
+        .map(|(index, width)| {
+            let value = row.get(index).map(String::as_str).unwrap_or_default();
+            format!("{}{}", value, " ".repeat(width - value.chars().count()))
+        })
+        .collect();
+    format!("| {} |\n", cells.join(" | "))
+}
+
+/// Converts a row into a JSON object keyed by header name.
+fn to_json_record(header: &Header, row: &[String]) -> Map<String, Value> {
+    let mut record = Map::new();
+    for (index, name) in header.columns.iter().enumerate() {
+        let name = name.trim();
+        if name.is_empty() || record.contains_key(name) {
+            continue;
+        }
+        let value = row
+            .get(index)
+            .filter(|value| !value.is_empty())
+            .map_or(Value::Null, |value| Value::String(value.clone()));
+        record.insert(name.to_string(), value);
+    }
+    record
+}
+
+impl ValueRange {
+    /// Converts the values into JSON objects, using the first row as header.
+    ///
+    /// # Returns
+    /// One JSON object per non-empty data row, as in [`to_json_records`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gsheet_api::models::ValueRange;
+    ///
+    /// let value_range = ValueRange {
+    ///     values: Some(vec![
+    ///         vec!["Name".to_string(), "Age".to_string()],
+    ///         vec!["Ada".to_string(), "36".to_string()],
+    ///     ]),
+    ///     ..Default::default()
+    /// };
+    /// let records = value_range.to_json_records();
+    /// assert_eq!(records[0]["Age"], "36");
+    /// ```
+    pub fn to_json_records(&self) -> Vec<Map<String, Value>> {
+        let rows = self.to_rows();
+        match rows.split_first() {
+            Some((header, data)) => to_json_records(&Header::new(1, header.clone()), data),
+            None => Vec::new(),
+        }
+    }
+
+    /// Writes the values as JSON Lines, using the first row as header.
+    ///
+    /// # Arguments
+    /// * `writer` - The destination, which should be buffered for large exports
+    ///
+    /// # Returns
+    /// A `Result` containing the number of lines written or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if writing to the destination fails.
+    pub fn write_jsonl<W: Write>(&self, writer: W) -> Result<usize, GSheetError> {
+        let rows = self.to_rows();
+        match rows.split_first() {
+            Some((header, data)) => write_jsonl(writer, &Header::new(1, header.clone()), data),
+            None => Ok(0),
+        }
+    }
+}
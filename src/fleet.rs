@@ -0,0 +1,216 @@
+//! # Fleet Module
+//!
+//! This module runs the same operation across many spreadsheets, for
+//! fleet-wide updates such as adding a sheet to every report of a team.
+//!
+//! ## Overview
+//!
+//! [`GoogleSheetClient::for_each_spreadsheet`](crate::client::GoogleSheetClient::for_each_spreadsheet)
+//! creates a [`ForEachSpreadsheetBuilder`] for a list of spreadsheet IDs. The
+//! built [`ForEachSpreadsheet`] calls an async closure with the
+//! [`SpreadsheetOperations`] of each spreadsheet, running at most
+//! `concurrency` calls at a time so the quota is not exhausted by a burst.
+//!
+//! A failure on one spreadsheet does not stop the others. The [`FleetReport`]
+//! holds the result of every spreadsheet in the order of the IDs, and
+//! [`FleetReport::into_result`] turns it into a single error listing every
+//! failed spreadsheet.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! let report = gsheet_client
+//!     .for_each_spreadsheet(["report-1", "report-2", "report-3"])
+//!     .concurrency(2)
+//!     .build()?
+//!     .execute(|spreadsheet| async move {
+//!         spreadsheet.ensure_sheet("Archive", 1000, 26).await?;
+//!         Ok(())
+//!     })
+//!     .await;
+//!
+//! for (spreadsheet_id, error) in report.failures() {
+//!     eprintln!("{}: {}", spreadsheet_id, error);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashSet;
+
+use futures::stream::{self, StreamExt};
+
+use crate::client::GoogleSheetClient;
+use crate::error::GSheetError;
+use crate::operations::spreadsheet::SpreadsheetOperations;
+
+/// The number of spreadsheets processed at a time by default.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Builder for configuring a [`ForEachSpreadsheet`] run.
+#[derive(Clone, Default)]
+pub struct ForEachSpreadsheetBuilder {
+    /// The Google Sheets client.
+    gsheet_client: Option<GoogleSheetClient>,
+    /// The IDs of the spreadsheets, in order.
+    spreadsheet_ids: Vec<String>,
+    /// The maximum number of spreadsheets processed at a time.
+    concurrency: usize,
+}
+
+impl ForEachSpreadsheetBuilder {
+    /// Creates a new builder for a run over the given spreadsheets.
+    ///
+    /// # Arguments
+    /// * `gsheet_client` - The Google Sheets client
+    /// * `spreadsheet_ids` - The IDs of the spreadsheets
+    ///
+    /// # Returns
+    /// A new [`ForEachSpreadsheetBuilder`] instance.
+    pub fn new<I, S>(gsheet_client: &GoogleSheetClient, spreadsheet_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            gsheet_client: Some(gsheet_client.clone()),
+            spreadsheet_ids: spreadsheet_ids.into_iter().map(Into::into).collect(),
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Sets the maximum number of spreadsheets processed at a time.
+    ///
+    /// # Arguments
+    /// * `concurrency` - The maximum number of concurrent calls, defaulting to
+    ///   [`DEFAULT_CONCURRENCY`]
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Builds the [`ForEachSpreadsheet`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`ForEachSpreadsheet`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the client is not set, if the
+    /// concurrency is zero or if a spreadsheet ID is listed twice.
+    pub fn build(self) -> Result<ForEachSpreadsheet, GSheetError> {
+        let gsheet_client = self.gsheet_client.ok_or_else(|| {
+            GSheetError::Other("GoogleSheetClient is required to build ForEachSpreadsheet".into())
+        })?;
+        if self.concurrency == 0 {
+            return Err(GSheetError::ValidationError(
+                "Concurrency must be at least 1".into(),
+            ));
+        }
+        let mut seen = HashSet::new();
+        if let Some(duplicate) = self.spreadsheet_ids.iter().find(|id| !seen.insert(*id)) {
+            return Err(GSheetError::ValidationError(format!(
+                "Spreadsheet '{}' is listed twice",
+                duplicate
+            )));
+        }
+
+        Ok(ForEachSpreadsheet {
+            gsheet_client,
+            spreadsheet_ids: self.spreadsheet_ids,
+            concurrency: self.concurrency,
+        })
+    }
+}
+
+/// A run of the same operation over many spreadsheets, with bounded
+/// concurrency.
+#[derive(Clone)]
+pub struct ForEachSpreadsheet {
+    /// The Google Sheets client.
+    gsheet_client: GoogleSheetClient,
+    /// The IDs of the spreadsheets, in order.
+    spreadsheet_ids: Vec<String>,
+    /// The maximum number of spreadsheets processed at a time.
+    concurrency: usize,
+}
+
+impl ForEachSpreadsheet {
+    /// Calls an operation on every spreadsheet, at most `concurrency` at a time.
+    ///
+    /// # Arguments
+    /// * `operation` - The async operation, given the operations of a spreadsheet
+    ///
+    /// # Returns
+    /// The [`FleetReport`] of every spreadsheet, in the order of the IDs.
+    pub async fn execute<F, Fut, T>(&self, operation: F) -> FleetReport<T>
+    where
+        F: Fn(SpreadsheetOperations) -> Fut,
+        Fut: Future<Output = Result<T, GSheetError>>,
+    {
+        let results = stream::iter(self.spreadsheet_ids.iter().cloned())
+            .map(|spreadsheet_id| {
+                let result = operation(self.gsheet_client.spreadsheet(&spreadsheet_id));
+                async move { (spreadsheet_id, result.await) }
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+        FleetReport { results }
+    }
+}
+
+/// The results of a [`ForEachSpreadsheet`] run, by spreadsheet ID.
+#[derive(Debug)]
+pub struct FleetReport<T> {
+    /// The result of every spreadsheet, in the order of the IDs.
+    pub results: Vec<(String, Result<T, GSheetError>)>,
+}
+
+impl<T> FleetReport<T> {
+    /// Returns whether the operation succeeded on every spreadsheet.
+    pub fn is_success(&self) -> bool {
+        self.results.iter().all(|(_, result)| result.is_ok())
+    }
+
+    /// Iterates over the spreadsheets the operation failed on, with their error.
+    pub fn failures(&self) -> impl Iterator<Item = (&str, &GSheetError)> {
+        self.results
+            .iter()
+            .filter_map(|(id, result)| result.as_ref().err().map(|error| (id.as_str(), error)))
+    }
+
+    /// Returns the outputs of every spreadsheet, or a single error listing the
+    /// failed ones.
+    ///
+    /// # Returns
+    /// A `Result` containing the outputs by spreadsheet ID or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return a [`GSheetError::FleetFailed`] with the error of
+    /// every failed spreadsheet if any failed.
+    pub fn into_result(self) -> Result<Vec<(String, T)>, GSheetError> {
+        let total = self.results.len();
+        let mut outputs = Vec::with_capacity(total);
+        let mut failures = Vec::new();
+        for (id, result) in self.results {
+            match result {
+                Ok(output) => outputs.push((id, output)),
+                Err(error) => failures.push((id, error)),
+            }
+        }
+        if failures.is_empty() {
+            Ok(outputs)
+        } else {
+            Err(GSheetError::FleetFailed { total, failures })
+        }
+    }
+}
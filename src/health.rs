@@ -0,0 +1,214 @@
+//! # Health Module
+//!
+//! This module provides a health check of the client, for readiness probes of
+//! services embedding this crate.
+//!
+//! ## Overview
+//!
+//! [`GoogleSheetClient::health_check`](crate::client::GoogleSheetClient::health_check)
+//! creates a [`HealthCheckBuilder`]. The check first makes sure the
+//! authentication provider holds a valid token, refreshing it if needed. When
+//! a spreadsheet is given, it then reads only the ID of that spreadsheet,
+//! which proves the API is reachable and the principal can open it while
+//! transferring almost nothing.
+//!
+//! Failures are reported in the returned [`HealthStatus`] rather than as
+//! errors, so a probe can always answer with the status and latency.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//! use std::time::Duration;
+//!
+//! let status = gsheet_client
+//!     .health_check()
+//!     .spreadsheet("spreadsheet-id")
+//!     .timeout(Duration::from_secs(5))
+//!     .build()?
+//!     .execute()
+//!     .await;
+//! if !status.healthy {
+//!     eprintln!("Sheets API unavailable: {:?}", status.error);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::{Duration, Instant};
+
+use crate::client::GoogleSheetClient;
+use crate::client::url::QueryParams;
+use crate::error::GSheetError;
+
+/// Builder for configuring a health check.
+#[derive(Clone, Default)]
+pub struct HealthCheckBuilder {
+    /// The Google Sheets client.
+    gsheet_client: Option<GoogleSheetClient>,
+    /// The spreadsheet read by the check, if any.
+    spreadsheet_id: Option<String>,
+    /// Timeout override for the read.
+    timeout: Option<Duration>,
+}
+
+impl HealthCheckBuilder {
+    /// Creates a new builder for a health check of a client.
+    ///
+    /// # Arguments
+    /// * `gsheet_client` - The Google Sheets client
+    ///
+    /// # Returns
+    /// A new [`HealthCheckBuilder`] instance.
+    pub fn new(gsheet_client: &GoogleSheetClient) -> Self {
+        Self {
+            gsheet_client: Some(gsheet_client.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets a spreadsheet the check reads, to probe the API itself. Without
+    /// one, only the authentication is checked.
+    ///
+    /// # Arguments
+    /// * `spreadsheet_id` - The ID of a spreadsheet the principal can open
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn spreadsheet(mut self, spreadsheet_id: &str) -> Self {
+        self.spreadsheet_id = Some(spreadsheet_id.to_string());
+        self
+    }
+
+    /// Sets the timeout of the read, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of the read
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`HealthCheckOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`HealthCheckOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the client is not set or if the
+    /// spreadsheet ID is empty.
+    pub fn build(self) -> Result<HealthCheckOperations, GSheetError> {
+        let gsheet_client = self.gsheet_client.ok_or_else(|| {
+            GSheetError::Other(
+                "GoogleSheetClient is required to build HealthCheckOperations".into(),
+            )
+        })?;
+        if self.spreadsheet_id.as_deref().is_some_and(str::is_empty) {
+            return Err(GSheetError::ValidationError(
+                "The spreadsheet ID of a health check cannot be empty".into(),
+            ));
+        }
+
+        Ok(HealthCheckOperations {
+            gsheet_client,
+            spreadsheet_id: self.spreadsheet_id,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operations for checking the health of a client.
+#[derive(Clone)]
+pub struct HealthCheckOperations {
+    /// The Google Sheets client.
+    gsheet_client: GoogleSheetClient,
+    /// The spreadsheet read by the check, if any.
+    spreadsheet_id: Option<String>,
+    /// Timeout override for the read.
+    timeout: Option<Duration>,
+}
+
+/// The outcome of a health check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthStatus {
+    /// Whether every step of the check succeeded.
+    pub healthy: bool,
+    /// Whether the authentication provider holds a valid token.
+    pub authenticated: bool,
+    /// The HTTP status of the spreadsheet read, if it was sent.
+    pub status: Option<u16>,
+    /// The total duration of the check.
+    pub latency: Duration,
+    /// The error of the failed step, if any.
+    pub error: Option<String>,
+}
+
+impl HealthCheckOperations {
+    /// Executes the health check.
+    ///
+    /// # Returns
+    /// The [`HealthStatus`] of the client. Failures are reported in the status,
+    /// not as errors.
+    pub async fn execute(&self) -> HealthStatus {
+        let started = Instant::now();
+        let mut status = HealthStatus {
+            healthy: false,
+            authenticated: false,
+            status: None,
+            latency: Duration::ZERO,
+            error: None,
+        };
+
+        match self.gsheet_client.auth_client.ensure_valid_token().await {
+            Ok(()) => status.authenticated = true,
+            Err(e) => status.error = Some(GSheetError::from(e).to_string()),
+        }
+        if status.authenticated {
+            match &self.spreadsheet_id {
+                Some(spreadsheet_id) => match self.read(spreadsheet_id).await {
+                    Ok(code) => {
+                        status.status = Some(code);
+                        status.healthy = true;
+                    }
+                    Err((code, e)) => {
+                        status.status = code;
+                        status.error = Some(e.to_string());
+                    }
+                },
+                None => status.healthy = true,
+            }
+        }
+
+        status.latency = started.elapsed();
+        status
+    }
+
+    /// Reads the ID of a spreadsheet, returning the HTTP status.
+    async fn read(&self, spreadsheet_id: &str) -> Result<u16, (Option<u16>, GSheetError)> {
+        let gsheet_client = &self.gsheet_client;
+        let url = gsheet_client.spreadsheet_url(spreadsheet_id, "");
+
+        let query = QueryParams::new().fields("spreadsheetId");
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await
+            .map_err(|e| (None, e))?;
+        let response = gsheet_client.send(request).await.map_err(|e| (None, e))?;
+
+        let code = response.status().as_u16();
+        if !response.status().is_success() {
+            return Err((
+                Some(code),
+                GSheetError::from_response(response, spreadsheet_id).await,
+            ));
+        }
+        Ok(code)
+    }
+}
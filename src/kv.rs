@@ -0,0 +1,347 @@
+//! # Key-Value Store Module
+//!
+//! This module provides [`SheetKvStore`], a key-value store backed by the first
+//! two columns of a sheet.
+//!
+//! ## Overview
+//!
+//! Many teams keep configuration in a sheet: one key per row in column A, its
+//! value in column B, edited by hand by people who never touch the code that
+//! reads it. [`SheetKvStore`] reads such a sheet as a map and writes it back
+//! one cell at a time, leaving the other rows, their formats and any other
+//! columns untouched.
+//!
+//! Reads are served from a snapshot of the sheet kept for a time to live, so a
+//! service can look keys up on every request without spending quota. Writes
+//! never trust the snapshot: [`SheetKvStore::set`] reads the keys again, then
+//! overwrites the value of the row holding the key, or appends a row when there
+//! is none. Writes through the same store and its clones are serialized, so
+//! they cannot append the same key twice; writers in other processes, or hand
+//! edits made between the read and the write, can still interleave, so the
+//! upsert is only atomic-ish.
+//!
+//! Rows with an empty key are ignored. When a key appears on several rows, the
+//! first one wins, both for reads and writes.
+//!
+//! Keys and values are read as they are displayed, whatever the render option
+//! of the spreadsheet. A value written by [`SheetKvStore::set`] is stored as
+//! text and reads back unchanged, but a number or date entered by hand reads
+//! back in its display format, such as "1,000" or "10/17/2026", not as the
+//! underlying value.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use std::time::Duration;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//! let config = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .sheet("Config")
+//!     .kv_store()
+//!     .header(true)
+//!     .ttl(Duration::from_secs(60))
+//!     .build()?;
+//!
+//! let region = config.get("region").await?.unwrap_or_else(|| "eu".to_string());
+//! config.set("last_sync", "2026-10-17T09:30:00Z").await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::error::GSheetError;
+use crate::models::{InsertDataOption, ValueInputOption, ValueRenderOption};
+use crate::operations::sheet::SheetOperations;
+
+/// The default time a snapshot of the sheet serves reads.
+pub const DEFAULT_KV_TTL: Duration = Duration::from_secs(30);
+
+/// The columns holding the keys and values.
+const KV_COLUMNS: &str = "A:B";
+
+/// The entries read from the sheet, and when.
+struct Snapshot {
+    /// When the sheet was read.
+    loaded_at: Instant,
+    /// The values by key.
+    entries: BTreeMap<String, String>,
+}
+
+/// Builder for creating [`SheetKvStore`] instances.
+#[derive(Default)]
+pub struct SheetKvStoreBuilder {
+    /// The sheet holding the keys and values.
+    sheet: Option<SheetOperations>,
+    /// Whether the first row is a header rather than an entry.
+    header: bool,
+    /// How long a snapshot of the sheet serves reads.
+    ttl: Option<Duration>,
+    /// Optional timeout of each API call.
+    timeout: Option<Duration>,
+}
+
+impl SheetKvStoreBuilder {
+    /// Creates a new builder for a store backed by the sheet.
+    ///
+    /// # Arguments
+    /// * `sheet` - The sheet holding the keys and values
+    ///
+    /// # Returns
+    /// A new [`SheetKvStoreBuilder`] instance.
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: Some(sheet.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether the first row is a header, such as "Key | Value", rather
+    /// than an entry.
+    ///
+    /// # Arguments
+    /// * `header` - Whether to skip the first row, `false` by default
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Sets how long a snapshot of the sheet serves reads before it is read
+    /// again. A zero duration reads the sheet on every call.
+    ///
+    /// # Arguments
+    /// * `ttl` - The time to live of a snapshot, [`DEFAULT_KV_TTL`] by default
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Sets the timeout of each API call.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of an API call
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`SheetKvStore`].
+    ///
+    /// # Returns
+    /// A `Result` containing the [`SheetKvStore`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the sheet is not set.
+    pub fn build(self) -> Result<SheetKvStore, GSheetError> {
+        let sheet = self
+            .sheet
+            .ok_or_else(|| GSheetError::Other("Sheet is required".into()))?;
+
+        Ok(SheetKvStore {
+            sheet,
+            header: self.header,
+            ttl: self.ttl.unwrap_or(DEFAULT_KV_TTL),
+            timeout: self.timeout,
+            snapshot: Arc::new(Mutex::new(None)),
+            write_lock: Arc::new(tokio::sync::Mutex::new(())),
+        })
+    }
+}
+
+/// Key-value store backed by the first two columns of a sheet.
+///
+/// Cloning the store is cheap, and every clone shares the same snapshot and
+/// serializes its writes with the others.
+#[derive(Clone)]
+pub struct SheetKvStore {
+    /// The sheet holding the keys and values.
+    sheet: SheetOperations,
+    /// Whether the first row is a header rather than an entry.
+    header: bool,
+    /// How long a snapshot of the sheet serves reads.
+    ttl: Duration,
+    /// Optional timeout of each API call.
+    timeout: Option<Duration>,
+    /// The last snapshot of the sheet.
+    snapshot: Arc<Mutex<Option<Snapshot>>>,
+    /// Held while a write reads the keys and writes the value.
+    write_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl SheetKvStore {
+    /// Returns the value of a key, as displayed in the sheet.
+    ///
+    /// # Arguments
+    /// * `key` - The key, as written in column A
+    ///
+    /// # Returns
+    /// A `Result` containing the value, `None` if no row holds the key, or a
+    /// [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the snapshot is stale and the sheet
+    /// cannot be read.
+    pub async fn get(&self, key: &str) -> Result<Option<String>, GSheetError> {
+        Ok(self.all().await?.remove(key))
+    }
+
+    /// Returns every entry of the store.
+    ///
+    /// # Returns
+    /// A `Result` containing the values by key, or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the snapshot is stale and the sheet
+    /// cannot be read.
+    pub async fn all(&self) -> Result<BTreeMap<String, String>, GSheetError> {
+        if let Some(snapshot) = self.lock_snapshot().as_ref()
+            && snapshot.loaded_at.elapsed() < self.ttl
+        {
+            return Ok(snapshot.entries.clone());
+        }
+
+        let rows = self.read_rows().await?;
+        let entries = entries(&rows);
+        *self.lock_snapshot() = Some(Snapshot {
+            loaded_at: Instant::now(),
+            entries: entries.clone(),
+        });
+        Ok(entries)
+    }
+
+    /// Sets the value of a key, overwriting the value of the row holding it, or
+    /// appending a row when there is none.
+    ///
+    /// # Arguments
+    /// * `key` - The key, as written in column A
+    /// * `value` - The value, written raw in column B
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - The key is empty
+    /// - The sheet cannot be read
+    /// - The value cannot be written
+    pub async fn set(&self, key: &str, value: &str) -> Result<(), GSheetError> {
+        if key.is_empty() {
+            return Err(GSheetError::ValidationError("Key must not be empty".into()));
+        }
+        let _guard = self.write_lock.lock().await;
+
+        let rows = self.read_rows().await?;
+        let existing = rows
+            .iter()
+            .position(|(row_key, _)| row_key == key)
+            .map(|index| self.first_row() + index);
+
+        match existing {
+            Some(row) => {
+                let mut update = self
+                    .sheet
+                    .update_from(&format!("B{row}"), vec![vec![value.to_string()]])
+                    .value_input_option(ValueInputOption::Raw);
+                if let Some(timeout) = self.timeout {
+                    update = update.timeout(timeout);
+                }
+                update.execute().await?;
+            }
+            None => {
+                let mut append = self
+                    .sheet
+                    .append(vec![vec![key.to_string(), value.to_string()]])
+                    .table_range(KV_COLUMNS)
+                    .value_input_option(ValueInputOption::Raw)
+                    .insert_data_option(InsertDataOption::InsertRows);
+                if let Some(timeout) = self.timeout {
+                    append = append.timeout(timeout);
+                }
+                append.execute().await?;
+            }
+        }
+
+        let mut entries = entries(&rows);
+        entries.insert(key.to_string(), value.to_string());
+        *self.lock_snapshot() = Some(Snapshot {
+            loaded_at: Instant::now(),
+            entries,
+        });
+        Ok(())
+    }
+
+    /// Discards the snapshot, so the next read reads the sheet again.
+    pub fn invalidate(&self) {
+        *self.lock_snapshot() = None;
+    }
+
+    /// Returns the 1-based row of the first entry.
+    fn first_row(&self) -> usize {
+        if self.header { 2 } else { 1 }
+    }
+
+    /// Reads the keys and values of the entry rows as displayed, in order,
+    /// bypassing the client cache.
+    async fn read_rows(&self) -> Result<Vec<(String, String)>, GSheetError> {
+        let range = format!("A{}:B", self.first_row());
+        let mut read = self
+            .sheet
+            .batch_get_value_range()
+            .range(&range)
+            .value_render_option(ValueRenderOption::FormattedValue)
+            .use_cache(false);
+        if let Some(timeout) = self.timeout {
+            read = read.timeout(timeout);
+        }
+        let batch = read.execute().await?;
+
+        let values = batch
+            .value_ranges
+            .into_iter()
+            .next()
+            .and_then(|value_range| value_range.values)
+            .unwrap_or_default();
+        Ok(values
+            .into_iter()
+            .map(|row| {
+                let mut cells = row.into_iter();
+                let key = cells.next().unwrap_or_default();
+                let value = cells.next().unwrap_or_default();
+                (key, value)
+            })
+            .collect())
+    }
+
+    /// Locks the snapshot, recovering it from a poisoned lock.
+    fn lock_snapshot(&self) -> std::sync::MutexGuard<'_, Option<Snapshot>> {
+        self.snapshot
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Returns the values by key of the rows, the first row of a key winning and
+/// rows with an empty key ignored.
+fn entries(rows: &[(String, String)]) -> BTreeMap<String, String> {
+    let mut entries = BTreeMap::new();
+    for (key, value) in rows {
+        if !key.is_empty() {
+            entries.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+    }
+    entries
+}
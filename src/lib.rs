@@ -17,7 +17,7 @@
 //!
 //! ```rust,no_run
 //! use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
-//! use std::sync::{Arc, Mutex};
+//! use std::sync::Arc;
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -27,7 +27,7 @@
 //!         .build()
 //!         .await?;
 //!
-//!     let auth_client = Arc::new(Mutex::new(auth_client));
+//!     let auth_client = Arc::new(auth_client);
 //!
 //!     // Create Google Sheets client
 //!     let gsheet_client = GoogleSheetClient::builder()
@@ -58,11 +58,14 @@
 //!
 //! ```rust,no_run
 //! use gsheet_api::auth::ServiceAccountAuthClient;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //!
 //! let auth_client = ServiceAccountAuthClient::builder()
 //!     .service_account_path("keys.json")
 //!     .build()
 //!     .await?;
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! ## Reading Data
@@ -72,40 +75,49 @@
 //! ### Get All Values
 //! ```rust,no_run
 //! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
-//! # use std::sync::{Arc, Mutex};
-//! # let auth_client = Arc::new(Mutex::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap()));
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
 //! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
 //! let spreadsheet = gsheet_client.spreadsheet("spreadsheet-id");
 //! let values = spreadsheet.sheet("Sheet1")
 //!     .get_all_value()
 //!     .execute()
 //!     .await?;
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! ### Get Values as Cells
 //! ```rust,no_run
 //! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
-//! # use std::sync::{Arc, Mutex};
-//! # let auth_client = Arc::new(Mutex::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap()));
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
 //! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
 //! let spreadsheet = gsheet_client.spreadsheet("spreadsheet-id");
 //! let cells = spreadsheet.sheet("Sheet1")
 //!     .get_all_cell()
 //!     .execute()
 //!     .await?;
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! ### Get Values as HashMap
 //! ```rust,no_run
 //! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
-//! # use std::sync::{Arc, Mutex};
-//! # let auth_client = Arc::new(Mutex::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap()));
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
 //! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
 //! let spreadsheet = gsheet_client.spreadsheet("spreadsheet-id");
 //! let cell_map = spreadsheet.sheet("Sheet1")
 //!     .get_hash_map_cell()
 //!     .execute()
 //!     .await?;
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! ## Writing Data
@@ -113,8 +125,9 @@
 //! ### Batch Update Values
 //! ```rust,no_run
 //! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
-//! # use std::sync::{Arc, Mutex};
-//! # let auth_client = Arc::new(Mutex::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap()));
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
 //! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
 //! let spreadsheet = gsheet_client.spreadsheet("spreadsheet-id");
 //! let response = spreadsheet.sheet("Sheet1")
@@ -125,36 +138,106 @@
 //!     ])
 //!     .execute()
 //!     .await?;
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! ## Error Handling
 //!
 //! The library uses `thiserror` for comprehensive error handling:
 //!
-//! ```rust
+//! ```rust,no_run
 //! use gsheet_api::error::GSheetError;
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//! # let operation = gsheet_client.spreadsheet("spreadsheet-id").sheet("Sheet1").get_all_value();
 //!
 //! match operation.execute().await {
 //!     Ok(result) => println!("Success: {:?}", result),
 //!     Err(GSheetError::AuthError(e)) => println!("Authentication error: {}", e),
-//!     Err(GSheetError::HttpRequestError(e)) => println!("HTTP error: {}", e),
+//!     Err(GSheetError::SpreadsheetNotFound { spreadsheet_id }) => println!("No spreadsheet {}", spreadsheet_id),
+//!     Err(GSheetError::PermissionDenied { message, .. }) => println!("Not shared: {}", message),
+//!     // The API reports a value read of a missing sheet as a range it cannot parse.
+//!     Err(GSheetError::InvalidRange { range, .. }) => println!("Bad range or missing sheet {}", range),
+//!     Err(GSheetError::WithContext { context, source }) => println!("{} failed: {}", context.url, source),
+//!     Err(GSheetError::ValidationError(e)) => println!("Invalid request: {}", e),
 //!     Err(e) => println!("Other error: {}", e),
 //! }
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! ## Modules
 //!
+//! - [`audit`]: Optional journal of the write requests issued by the client
 //! - [`auth`]: Authentication providers and service account handling
+//! - [`batch_queue`]: Background queue coalescing single-range requests into batch calls
+//! - [`cache`]: Optional read-through cache for value reads
+//! - [`circuit_breaker`]: Optional circuit breaker for sustained API failures
 //! - [`client`]: Main client for interacting with Google Sheets API
+//! - [`declarative`]: Desired-state specs applied as minimal batch updates
+//! - [`diff`]: Comparison of two spreadsheets by sheet and cell
+//! - [`drive`]: Paginated listing of spreadsheets through Google Drive
+//! - [`dry_run`]: Dry-run mode capturing write requests instead of sending them
+//! - [`fleet`]: The same operation run across many spreadsheets with bounded concurrency
+//! - [`health`]: Health checks of authentication and API reachability for readiness probes
+//! - [`kv`]: Key-value store backed by the first two columns of a sheet
 //! - [`models`]: Data models representing Google Sheets structures
 //! - [`operations`]: High-level operations for spreadsheets and sheets
+//! - [`progress`]: Progress reporting and cancellation for chunked bulk operations
+//! - [`raw`]: Arbitrary Sheets API requests for features not covered by typed operations
+//! - [`report`]: Formatted tables written from typed records in one transaction
+//! - [`rows`]: Typed conversion of sheet rows with per-row errors
+//! - [`schema`]: Validation of sheet contents against expected columns
+//! - [`template`]: Spreadsheet generation from templates with placeholders
+//! - [`types`]: Client-side options configuring how operations behave
 //! - [`utils`]: Utility functions for A1 notation and data conversion
 //! - [`error`]: Error types and handling
+//! - [`export`]: Export of sheet values to JSON Lines, Markdown, text and HTML tables
+//! - [`locale`]: Locale-aware parsing of formatted values
+//! - [`logger`]: Append-only log sink writing records as rows of a sheet
+//! - [`metadata`]: Cached spreadsheet metadata such as locale and sheet properties
+//! - `metrics`: Request counters and latency histograms through the `metrics` facade (requires the `metrics` feature)
+//! - [`validation`]: Client-side validation of requests before they are sent
+//! - `vcr`: Recording and replay of HTTP interactions for tests (requires the `vcr` feature)
+//! - [`watch`]: Change watching through Google Drive push notifications
+//! - [`workflow`]: Multi-step workflows rolled back when a step fails
 
+pub mod audit;
 pub mod auth;
+pub mod batch_queue;
+pub mod cache;
+pub mod circuit_breaker;
 pub mod client;
+pub mod declarative;
+pub mod diff;
+pub mod drive;
+pub mod dry_run;
 pub mod error;
+pub mod export;
+pub mod fleet;
+pub mod health;
+pub mod kv;
+pub mod locale;
+pub mod logger;
+pub mod metadata;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod models;
 pub mod operations;
+pub mod progress;
+pub mod raw;
+pub mod report;
+pub mod rows;
+pub mod schema;
+pub mod template;
 pub mod types;
 pub mod utils;
+pub mod validation;
+#[cfg(feature = "vcr")]
+pub mod vcr;
+pub mod watch;
+pub mod workflow;
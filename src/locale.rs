@@ -0,0 +1,252 @@
+//! # Locale Module
+//!
+//! This module parses formatted values back into numbers according to the
+//! conventions of a spreadsheet locale.
+//!
+//! Values read with [`ValueRenderOption::FormattedValue`](crate::models::ValueRenderOption)
+//! are rendered the way users see them, so "1234.5" in a German spreadsheet reads
+//! back as "1.234,5". [`NumberLocale`] undoes that formatting, which makes it safe
+//! to read formatted values, process them and write them back.
+//!
+//! With the `rust_decimal` feature, [`NumberLocale::parse_decimal`] parses
+//! formatted values into exact decimals instead, for amounts that must not
+//! suffer floating-point rounding.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use gsheet_api::locale::NumberLocale;
+//!
+//! let german = NumberLocale::from_locale("de_DE");
+//! assert_eq!(german.parse_number("1.234,5").unwrap(), 1234.5);
+//! assert_eq!(german.parse_number("12,5 %").unwrap(), 0.125);
+//!
+//! let us = NumberLocale::from_locale("en_US");
+//! assert_eq!(us.parse_number("($1,000.25)").unwrap(), -1000.25);
+//! assert_eq!(us.parse_number("USD 20.50").unwrap(), 20.5);
+//! ```
+
+use crate::error::GSheetError;
+
+/// Currency symbols stripped from formatted values.
+const CURRENCY_SYMBOLS: &[char] = &[
+    '$', '€', '£', '¥', '₹', '₩', '₽', '₺', '₫', '₪', '₱', '₴', '₦', '฿', '¢', '₡', '₲', '₵',
+];
+
+/// Characters used as a minus sign in formatted values.
+const MINUS_SIGNS: &[char] = &['-', '\u{2212}'];
+
+/// Number format conventions of a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberLocale {
+    /// The character separating the integer part from the fraction.
+    pub decimal_separator: char,
+    /// The character grouping the digits of the integer part.
+    pub grouping_separator: char,
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            grouping_separator: ',',
+        }
+    }
+}
+
+impl NumberLocale {
+    /// Returns the number format conventions of a locale.
+    ///
+    /// Unknown locales fall back to US conventions.
+    ///
+    /// # Arguments
+    /// * `locale` - The locale in ISO 639-1 format, optionally with a region
+    ///   (e.g., "fr", "de_CH", "pt-BR")
+    ///
+    /// # Returns
+    /// The [`NumberLocale`] of the locale.
+    pub fn from_locale(locale: &str) -> Self {
+        let locale = locale.replace('-', "_");
+        let language = locale.split('_').next().unwrap_or_default();
+        let (decimal_separator, grouping_separator) = match (language, locale.as_str()) {
+            (_, "de_CH" | "it_CH" | "fr_CH" | "rm_CH") => ('.', '\''),
+            (_, "pt_PT") => (',', '\u{a0}'),
+            (_, "es_MX" | "es_US") => ('.', ','),
+            (
+                "fr" | "ru" | "pl" | "sv" | "fi" | "cs" | "sk" | "nb" | "no" | "uk" | "hu" | "bg"
+                | "lt" | "lv" | "et",
+                _,
+            ) => (',', '\u{a0}'),
+            (
+                "de" | "es" | "it" | "nl" | "pt" | "da" | "id" | "tr" | "el" | "ro" | "vi" | "hr"
+                | "sl" | "sr",
+                _,
+            ) => (',', '.'),
+            _ => ('.', ','),
+        };
+        Self {
+            decimal_separator,
+            grouping_separator,
+        }
+    }
+
+    /// Parses a formatted value into a number.
+    ///
+    /// Grouping separators, currency symbols, currency codes (e.g., "USD",
+    /// "CHF") and surrounding spaces are ignored. Percentages are divided by 100,
+    /// and a leading minus sign or enclosing parentheses make the number negative.
+    ///
+    /// # Arguments
+    /// * `value` - The formatted value (e.g., "1 234,5 €", "12.5%", "USD 20")
+    ///
+    /// # Returns
+    /// A `Result` containing the parsed number or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the value is not a number in this locale.
+    pub fn parse_number(&self, value: &str) -> Result<f64, GSheetError> {
+        let parsed = self.normalize(value)?;
+        let number: f64 = parsed.digits.parse().map_err(|_| invalid_number(value))?;
+        let number = if parsed.percent {
+            number / 100.0
+        } else {
+            number
+        };
+        Ok(if parsed.negative { -number } else { number })
+    }
+
+    /// Parses a formatted value into an exact decimal, avoiding the rounding of
+    /// [`parse_number`](Self::parse_number). Requires the `rust_decimal` feature.
+    ///
+    /// # Arguments
+    /// * `value` - The formatted value (e.g., "1.234,56 €")
+    ///
+    /// # Returns
+    /// A `Result` containing the parsed decimal or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the value is not a number in this
+    /// locale, or does not fit in a decimal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gsheet_api::locale::NumberLocale;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let german = NumberLocale::from_locale("de_DE");
+    /// assert_eq!(german.parse_decimal("1.234,10 €").unwrap(), Decimal::new(123410, 2));
+    /// assert_eq!(german.parse_decimal("7,5 %").unwrap(), Decimal::new(75, 3));
+    /// ```
+    #[cfg(feature = "rust_decimal")]
+    pub fn parse_decimal(&self, value: &str) -> Result<rust_decimal::Decimal, GSheetError> {
+        use std::str::FromStr;
+
+        let parsed = self.normalize(value)?;
+        let number = if parsed.digits.contains(['e', 'E']) {
+            rust_decimal::Decimal::from_scientific(&parsed.digits)
+        } else {
+            rust_decimal::Decimal::from_str(&parsed.digits)
+        }
+        .map_err(|_| invalid_number(value))?;
+        let number = if parsed.percent {
+            number / rust_decimal::Decimal::ONE_HUNDRED
+        } else {
+            number
+        };
+        Ok(if parsed.negative { -number } else { number })
+    }
+
+    /// Parses every formatted value of a grid, such as the values of a
+    /// [`ValueRange`](crate::models::ValueRange).
+    ///
+    /// # Arguments
+    /// * `rows` - The formatted values, by row
+    ///
+    /// # Returns
+    /// The numbers with the shape of the grid, `None` for empty cells and cells
+    /// that are not numbers.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gsheet_api::locale::NumberLocale;
+    ///
+    /// let rows = vec![vec!["Total".to_string(), "$1,250.00".to_string(), "15%".to_string()]];
+    /// let numbers = NumberLocale::default().parse_numbers(&rows);
+    /// assert_eq!(numbers, vec![vec![None, Some(1250.0), Some(0.15)]]);
+    /// ```
+    pub fn parse_numbers(&self, rows: &[Vec<String>]) -> Vec<Vec<Option<f64>>> {
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|value| self.parse_number(value).ok())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Strips the formatting of a value, leaving its digits in Rust syntax.
+    fn normalize(&self, value: &str) -> Result<NormalizedNumber, GSheetError> {
+        let mut text: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace() && !CURRENCY_SYMBOLS.contains(c))
+            .collect();
+        // Currency codes and symbols made of letters, such as "USD", "R$" or "kr".
+        let text_end = text.trim_end_matches(char::is_alphabetic).len();
+        text.truncate(text_end);
+        let text_start = text.len() - text.trim_start_matches(char::is_alphabetic).len();
+        text.drain(..text_start);
+
+        let mut negative = false;
+        if text.starts_with('(') && text.ends_with(')') && text.len() > 1 {
+            negative = true;
+            text = text[1..text.len() - 1].to_string();
+        }
+        if let Some(rest) = text.strip_prefix(MINUS_SIGNS) {
+            negative = !negative;
+            text = rest.to_string();
+        } else if let Some(rest) = text.strip_prefix('+') {
+            text = rest.to_string();
+        }
+        let percent = text.ends_with('%');
+        if percent {
+            text.pop();
+        }
+
+        let mut digits = String::with_capacity(text.len());
+        for c in text.chars() {
+            if c == self.grouping_separator {
+                continue;
+            } else if c == self.decimal_separator {
+                digits.push('.');
+            } else if c.is_ascii_digit() || matches!(c, 'e' | 'E' | '+' | '-') {
+                digits.push(c);
+            } else {
+                return Err(invalid_number(value));
+            }
+        }
+        if !digits.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+            return Err(invalid_number(value));
+        }
+
+        Ok(NormalizedNumber {
+            digits,
+            percent,
+            negative,
+        })
+    }
+}
+
+/// A formatted value stripped of its formatting.
+struct NormalizedNumber {
+    /// The digits, with a '.' decimal separator and no grouping.
+    digits: String,
+    /// Whether the value is a percentage.
+    percent: bool,
+    /// Whether the value is negative.
+    negative: bool,
+}
+
+/// Returns the error of a value that is not a number.
+fn invalid_number(value: &str) -> GSheetError {
+    GSheetError::UtilsError(format!("Invalid number: '{value}'"))
+}
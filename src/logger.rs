@@ -0,0 +1,538 @@
+//! # Logger Module
+//!
+//! This module provides [`SheetLogger`], an append-only log sink writing log
+//! records as rows of a sheet.
+//!
+//! ## Overview
+//!
+//! A sheet makes a lightweight audit trail that non-developers can read, sort
+//! and filter. Each [`LogRecord`] becomes a row of four columns: the timestamp
+//! in RFC 3339, the level, the message, and the fields as a JSON object.
+//!
+//! Records are buffered by a background task and appended in batches, when the
+//! flush interval elapses or enough records are pending, so logging costs one
+//! `values:append` call per batch rather than one per record. Values are
+//! appended raw, so a message starting with "=" is not evaluated as a formula.
+//!
+//! The buffer holds at most `capacity` records, counting both the records not
+//! yet received by the background task and the ones waiting to be appended.
+//! When the sheet cannot keep up, or appends fail, the buffer fills and
+//! [`SheetLogger::log`] waits for room, slowing producers down instead of
+//! growing without bound, while [`SheetLogger::try_log`] fails immediately.
+//! Records of a failed append stay buffered and are retried when the flush
+//! interval elapses, not on every new record, so an outage costs one call per
+//! interval.
+//!
+//! With the `log` feature, [`SheetLogger`] also implements `log::Log`, so it can
+//! be installed as the logger of the `log` facade. Records logged through the
+//! facade are dropped when the buffer is full, since the facade cannot wait.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::logger::{LogLevel, LogRecord};
+//! use std::time::Duration;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//! let logger = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .sheet("Log")
+//!     .logger()
+//!     .flush_interval(Duration::from_secs(5))
+//!     .min_level(LogLevel::Info)
+//!     .build()?;
+//!
+//! logger.info("Sync started").await?;
+//! logger
+//!     .log(LogRecord::new(LogLevel::Warn, "Row skipped").field("row", 42))
+//!     .await?;
+//!
+//! // Appends the pending records before the process exits.
+//! logger.flush().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use tokio::sync::{Semaphore, mpsc, oneshot};
+
+use crate::error::GSheetError;
+use crate::models::{InsertDataOption, ValueInputOption};
+use crate::operations::sheet::SheetOperations;
+
+/// The default time records are buffered before they are appended.
+pub const DEFAULT_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The default maximum number of records appended in a single call.
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 500;
+
+/// The default maximum number of buffered records.
+pub const DEFAULT_CAPACITY: usize = 10_000;
+
+/// The columns the records are appended to.
+const LOG_COLUMNS: &str = "A:D";
+
+/// The severity of a log record, ordered from the least to the most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, strum_macros::Display)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum LogLevel {
+    /// Fine-grained tracing information.
+    Trace,
+    /// Information useful when debugging.
+    Debug,
+    /// Normal operation.
+    Info,
+    /// Something unexpected that did not stop the operation.
+    Warn,
+    /// A failed operation.
+    Error,
+}
+
+/// A log record, written as one row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRecord {
+    /// When the record was created.
+    pub timestamp: DateTime<Utc>,
+    /// The severity of the record.
+    pub level: LogLevel,
+    /// The message of the record.
+    pub message: String,
+    /// Structured context of the record, by name.
+    pub fields: BTreeMap<String, String>,
+}
+
+impl LogRecord {
+    /// Creates a record timestamped now, without fields.
+    ///
+    /// # Arguments
+    /// * `level` - The severity of the record
+    /// * `message` - The message of the record
+    pub fn new(level: LogLevel, message: impl Into<String>) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            level,
+            message: message.into(),
+            fields: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a field to the record, replacing a field of the same name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the field
+    /// * `value` - The value of the field
+    ///
+    /// # Returns
+    /// The record, for method chaining.
+    pub fn field(mut self, name: &str, value: impl ToString) -> Self {
+        self.fields.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Returns the row the record is written as: the timestamp in RFC 3339 with
+    /// milliseconds, the level, the message and the fields as a JSON object, or
+    /// an empty cell without fields.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use gsheet_api::logger::{LogLevel, LogRecord};
+    ///
+    /// let mut record = LogRecord::new(LogLevel::Warn, "Row skipped").field("row", 42);
+    /// record.timestamp = Utc.with_ymd_and_hms(2026, 10, 17, 9, 30, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     record.to_row(),
+    ///     vec!["2026-10-17T09:30:00.000Z", "WARN", "Row skipped", r#"{"row":"42"}"#]
+    /// );
+    /// ```
+    pub fn to_row(&self) -> Vec<String> {
+        let fields = if self.fields.is_empty() {
+            String::new()
+        } else {
+            serde_json::to_string(&self.fields).unwrap_or_default()
+        };
+        vec![
+            self.timestamp.to_rfc3339_opts(SecondsFormat::Millis, true),
+            self.level.to_string(),
+            self.message.clone(),
+            fields,
+        ]
+    }
+}
+
+/// A message to the background task.
+enum Command {
+    /// A record to append.
+    Record(LogRecord),
+    /// A request to append every pending record now.
+    Flush(oneshot::Sender<Result<(), GSheetError>>),
+}
+
+/// Builder for creating [`SheetLogger`] instances.
+#[derive(Default)]
+pub struct SheetLoggerBuilder {
+    /// The sheet the records are appended to.
+    sheet: Option<SheetOperations>,
+    /// How long records are buffered before they are appended.
+    flush_interval: Option<Duration>,
+    /// The maximum number of records appended in a single call.
+    max_batch_size: Option<usize>,
+    /// The maximum number of buffered records.
+    capacity: Option<usize>,
+    /// The least severe level of the records kept.
+    min_level: Option<LogLevel>,
+    /// Optional timeout of each append call.
+    timeout: Option<Duration>,
+}
+
+impl SheetLoggerBuilder {
+    /// Creates a new builder for a logger appending to the sheet.
+    ///
+    /// # Arguments
+    /// * `sheet` - The sheet the records are appended to
+    ///
+    /// # Returns
+    /// A new [`SheetLoggerBuilder`] instance.
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: Some(sheet.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets how long records are buffered before they are appended.
+    ///
+    /// # Arguments
+    /// * `interval` - The flush interval, [`DEFAULT_FLUSH_INTERVAL`] by default
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn flush_interval(mut self, interval: Duration) -> Self {
+        self.flush_interval = Some(interval);
+        self
+    }
+
+    /// Sets the maximum number of records appended in a single call.
+    ///
+    /// Records are appended as soon as this many are pending, without waiting
+    /// for the end of the flush interval.
+    ///
+    /// # Arguments
+    /// * `size` - The maximum batch size, [`DEFAULT_MAX_BATCH_SIZE`] by default
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn max_batch_size(mut self, size: usize) -> Self {
+        self.max_batch_size = Some(size);
+        self
+    }
+
+    /// Sets the maximum number of buffered records, beyond which logging waits.
+    ///
+    /// # Arguments
+    /// * `capacity` - The buffer capacity, [`DEFAULT_CAPACITY`] by default
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Sets the least severe level of the records kept; less severe records
+    /// are discarded without being buffered.
+    ///
+    /// # Arguments
+    /// * `level` - The minimum level, [`LogLevel::Trace`] by default
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn min_level(mut self, level: LogLevel) -> Self {
+        self.min_level = Some(level);
+        self
+    }
+
+    /// Sets the timeout of each append call.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of an append call
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`SheetLogger`] and starts its background task.
+    ///
+    /// The task stops once every clone of the logger has been dropped and the
+    /// pending records have been appended once more; records whose final
+    /// append fails are dropped.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`SheetLogger`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the sheet is not set, the maximum
+    /// batch size or the capacity is 0, or it is called outside of a Tokio
+    /// runtime.
+    pub fn build(self) -> Result<SheetLogger, GSheetError> {
+        let sheet = self
+            .sheet
+            .ok_or_else(|| GSheetError::Other("Sheet is required".into()))?;
+        let max_batch_size = self.max_batch_size.unwrap_or(DEFAULT_MAX_BATCH_SIZE);
+        let capacity = self.capacity.unwrap_or(DEFAULT_CAPACITY);
+        if max_batch_size == 0 || capacity == 0 {
+            return Err(GSheetError::ValidationError(
+                "Maximum batch size and capacity must be at least 1".into(),
+            ));
+        }
+        let runtime = tokio::runtime::Handle::try_current().map_err(|e| {
+            GSheetError::Other(format!("Sheet logger requires a Tokio runtime: {e}"))
+        })?;
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let permits = Arc::new(Semaphore::new(capacity));
+        let worker = LogWorker {
+            sheet,
+            flush_interval: self.flush_interval.unwrap_or(DEFAULT_FLUSH_INTERVAL),
+            max_batch_size,
+            permits: permits.clone(),
+            timeout: self.timeout,
+        };
+        runtime.spawn(worker.run(receiver));
+
+        Ok(SheetLogger {
+            sender,
+            permits,
+            min_level: self.min_level.unwrap_or(LogLevel::Trace),
+        })
+    }
+}
+
+/// Append-only log sink writing records as rows of a sheet.
+///
+/// Cloning the logger is cheap, and every clone feeds the same background task.
+#[derive(Clone)]
+pub struct SheetLogger {
+    /// The channel feeding the background task.
+    sender: mpsc::UnboundedSender<Command>,
+    /// One permit per record the buffer has room for.
+    permits: Arc<Semaphore>,
+    /// The least severe level of the records kept.
+    min_level: LogLevel,
+}
+
+impl SheetLogger {
+    /// Buffers a record, waiting for room when the buffer is full.
+    ///
+    /// Records less severe than the minimum level are discarded.
+    ///
+    /// # Arguments
+    /// * `record` - The record to append
+    ///
+    /// # Errors
+    /// This method will return an error if the background task is gone.
+    pub async fn log(&self, record: LogRecord) -> Result<(), GSheetError> {
+        if record.level < self.min_level {
+            return Ok(());
+        }
+        self.permits
+            .acquire()
+            .await
+            .map_err(|_| logger_closed())?
+            .forget();
+        self.sender
+            .send(Command::Record(record))
+            .map_err(|_| logger_closed())
+    }
+
+    /// Buffers a record without waiting.
+    ///
+    /// Records less severe than the minimum level are discarded.
+    ///
+    /// # Arguments
+    /// * `record` - The record to append
+    ///
+    /// # Errors
+    /// This method will return an error if the buffer is full or the background
+    /// task is gone; the record is then dropped.
+    pub fn try_log(&self, record: LogRecord) -> Result<(), GSheetError> {
+        if record.level < self.min_level {
+            return Ok(());
+        }
+        self.permits
+            .try_acquire()
+            .map_err(|error| match error {
+                tokio::sync::TryAcquireError::NoPermits => {
+                    GSheetError::Other("Sheet logger buffer is full".into())
+                }
+                tokio::sync::TryAcquireError::Closed => logger_closed(),
+            })?
+            .forget();
+        self.sender
+            .send(Command::Record(record))
+            .map_err(|_| logger_closed())
+    }
+
+    /// Buffers an [`LogLevel::Info`] record without fields.
+    ///
+    /// # Errors
+    /// This method will return an error if the background task is gone.
+    pub async fn info(&self, message: impl Into<String>) -> Result<(), GSheetError> {
+        self.log(LogRecord::new(LogLevel::Info, message)).await
+    }
+
+    /// Buffers a [`LogLevel::Warn`] record without fields.
+    ///
+    /// # Errors
+    /// This method will return an error if the background task is gone.
+    pub async fn warn(&self, message: impl Into<String>) -> Result<(), GSheetError> {
+        self.log(LogRecord::new(LogLevel::Warn, message)).await
+    }
+
+    /// Buffers an [`LogLevel::Error`] record without fields.
+    ///
+    /// # Errors
+    /// This method will return an error if the background task is gone.
+    pub async fn error(&self, message: impl Into<String>) -> Result<(), GSheetError> {
+        self.log(LogRecord::new(LogLevel::Error, message)).await
+    }
+
+    /// Appends every record buffered so far, without waiting for the flush
+    /// interval.
+    ///
+    /// # Errors
+    /// This method will return an error if an append call fails, in which case
+    /// the records stay buffered, or if the background task is gone.
+    pub async fn flush(&self) -> Result<(), GSheetError> {
+        let (reply, response) = oneshot::channel();
+        self.sender
+            .send(Command::Flush(reply))
+            .map_err(|_| logger_closed())?;
+        response.await.map_err(|_| logger_closed())?
+    }
+}
+
+#[cfg(feature = "log")]
+impl log::Log for SheetLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        LogLevel::from(metadata.level()) >= self.min_level
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let record = LogRecord::new(record.level().into(), record.args().to_string())
+            .field("target", record.target());
+        let _ = self.try_log(record);
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(feature = "log")]
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Trace => LogLevel::Trace,
+            log::Level::Debug => LogLevel::Debug,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Error => LogLevel::Error,
+        }
+    }
+}
+
+/// Returns the error reported when the background task is gone.
+fn logger_closed() -> GSheetError {
+    GSheetError::Other("Sheet logger is closed".into())
+}
+
+/// Background task appending the buffered records.
+struct LogWorker {
+    /// The sheet the records are appended to.
+    sheet: SheetOperations,
+    /// How long records are buffered before they are appended.
+    flush_interval: Duration,
+    /// The maximum number of records appended in a single call.
+    max_batch_size: usize,
+    /// One permit per record the buffer has room for, released as records
+    /// are appended.
+    permits: Arc<Semaphore>,
+    /// Optional timeout of each append call.
+    timeout: Option<Duration>,
+}
+
+impl LogWorker {
+    /// Buffers records and appends them until the logger is dropped, then
+    /// appends the pending records one last time.
+    ///
+    /// Commands are always received, so a flush or the shutdown is never stuck
+    /// behind a full buffer; producers wait on the permits instead. After a
+    /// failed append, pending records are only retried when the interval
+    /// elapses or a flush is requested.
+    async fn run(self, mut receiver: mpsc::UnboundedReceiver<Command>) {
+        let mut pending: Vec<Vec<String>> = Vec::new();
+        let mut healthy = true;
+        let mut ticker = tokio::time::interval(self.flush_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                command = receiver.recv() => match command {
+                    Some(Command::Record(record)) => {
+                        pending.push(record.to_row());
+                        if healthy && pending.len() >= self.max_batch_size {
+                            healthy = self.flush(&mut pending).await.is_ok();
+                        }
+                    }
+                    Some(Command::Flush(reply)) => {
+                        let result = self.flush(&mut pending).await;
+                        healthy = result.is_ok();
+                        let _ = reply.send(result);
+                    }
+                    None => {
+                        let _ = self.flush(&mut pending).await;
+                        break;
+                    }
+                },
+                _ = ticker.tick() => {
+                    healthy = self.flush(&mut pending).await.is_ok();
+                }
+            }
+        }
+        self.permits.close();
+    }
+
+    /// Appends the pending rows in batches, keeping the rows of a failed batch
+    /// and the ones after it.
+    async fn flush(&self, pending: &mut Vec<Vec<String>>) -> Result<(), GSheetError> {
+        while !pending.is_empty() {
+            let size = pending.len().min(self.max_batch_size);
+            let mut append = self
+                .sheet
+                .append(pending[..size].to_vec())
+                .table_range(LOG_COLUMNS)
+                .value_input_option(ValueInputOption::Raw)
+                .insert_data_option(InsertDataOption::InsertRows);
+            if let Some(timeout) = self.timeout {
+                append = append.timeout(timeout);
+            }
+            append.execute().await?;
+            pending.drain(..size);
+            self.permits.add_permits(size);
+        }
+        Ok(())
+    }
+}
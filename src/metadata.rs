@@ -0,0 +1,158 @@
+//! # Metadata Module
+//!
+//! This module provides [`SpreadsheetMetadata`], a lightweight view of a
+//! spreadsheet holding its locale, time zone and sheet properties, without any
+//! cell data.
+//!
+//! Metadata is fetched with
+//! [`SpreadsheetOperations::metadata`](crate::operations::spreadsheet::SpreadsheetOperations::metadata).
+//! When a [`CacheProvider`](crate::cache::CacheProvider) is configured on the
+//! client, it is cached alongside value reads and invalidated by the same writes.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//! let metadata = gsheet_client.spreadsheet("spreadsheet-id")
+//!     .metadata()
+//!     .build()?
+//!     .execute()
+//!     .await?;
+//!
+//! // "1.234,5 €" in a German spreadsheet
+//! let amount = metadata.number_locale().parse_number("1.234,5 €")?;
+//! assert_eq!(amount, 1234.5);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::error::GSheetError;
+use crate::locale::NumberLocale;
+use crate::models::{SheetProperties, Spreadsheet};
+
+/// The fields requested when fetching spreadsheet metadata.
+pub(crate) const METADATA_FIELDS: &str = "spreadsheetId,properties(title,locale,timeZone),\
+    sheets.properties(sheetId,title,index,sheetType,hidden,gridProperties)";
+
+/// Locale, time zone and sheet properties of a spreadsheet.
+#[derive(Debug, Clone, Default)]
+pub struct SpreadsheetMetadata {
+    /// The unique identifier of the spreadsheet.
+    pub spreadsheet_id: String,
+    /// The title of the spreadsheet.
+    pub title: Option<String>,
+    /// The locale of the spreadsheet (e.g., "en_US", "de_DE").
+    pub locale: Option<String>,
+    /// The time zone of the spreadsheet in CLDR format (e.g., "Europe/Paris").
+    pub time_zone: Option<String>,
+    /// The properties of every sheet, in order.
+    pub sheets: Vec<SheetProperties>,
+}
+
+impl SpreadsheetMetadata {
+    /// Returns the properties of the sheet with the given title.
+    ///
+    /// # Arguments
+    /// * `title` - The title of the sheet
+    ///
+    /// # Returns
+    /// The [`SheetProperties`] of the sheet, or `None` if there is no such sheet.
+    pub fn sheet(&self, title: &str) -> Option<&SheetProperties> {
+        self.sheets
+            .iter()
+            .find(|properties| properties.title.as_deref() == Some(title))
+    }
+
+    /// Returns the number format conventions of the spreadsheet locale.
+    ///
+    /// # Returns
+    /// The [`NumberLocale`] of the spreadsheet, defaulting to US conventions when
+    /// the locale is unknown.
+    pub fn number_locale(&self) -> NumberLocale {
+        self.locale
+            .as_deref()
+            .map(NumberLocale::from_locale)
+            .unwrap_or_default()
+    }
+
+    /// Returns a resolver mapping the sheet titles of the spreadsheet to sheet IDs.
+    ///
+    /// # Returns
+    /// A [`SheetIdResolver`] for the sheets of the spreadsheet.
+    pub fn sheet_id_resolver(&self) -> SheetIdResolver {
+        SheetIdResolver::new(
+            self.sheets
+                .iter()
+                .filter_map(|properties| Some((properties.title.clone()?, properties.sheet_id?))),
+        )
+    }
+}
+
+/// Resolves sheet titles to the sheet IDs required by batch update requests.
+///
+/// A resolver is usually built from cached metadata with
+/// [`SpreadsheetMetadata::sheet_id_resolver`], and used with
+/// [`a1_to_grid_range_with_sheet`](crate::utils::a1_to_grid_range_with_sheet).
+#[derive(Debug, Clone, Default)]
+pub struct SheetIdResolver {
+    /// The sheet IDs, by sheet title.
+    ids: HashMap<String, i32>,
+}
+
+impl SheetIdResolver {
+    /// Creates a resolver from `(title, sheet_id)` pairs.
+    ///
+    /// # Arguments
+    /// * `ids` - The sheet IDs with their titles
+    ///
+    /// # Returns
+    /// A new [`SheetIdResolver`] instance.
+    pub fn new(ids: impl IntoIterator<Item = (String, i32)>) -> Self {
+        Self {
+            ids: ids.into_iter().collect(),
+        }
+    }
+
+    /// Resolves a sheet title to its sheet ID.
+    ///
+    /// # Arguments
+    /// * `title` - The unquoted title of the sheet
+    ///
+    /// # Returns
+    /// A `Result` containing the sheet ID or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if there is no sheet with this title.
+    pub fn resolve(&self, title: &str) -> Result<i32, GSheetError> {
+        self.ids
+            .get(title)
+            .copied()
+            .ok_or_else(|| GSheetError::SheetNotFound {
+                title: title.to_string(),
+            })
+    }
+}
+
+impl From<Spreadsheet> for SpreadsheetMetadata {
+    fn from(spreadsheet: Spreadsheet) -> Self {
+        let properties = spreadsheet.properties.unwrap_or_default();
+        Self {
+            spreadsheet_id: spreadsheet.spreadsheet_id.unwrap_or_default(),
+            title: properties.title,
+            locale: properties.locale,
+            time_zone: properties.time_zone,
+            sheets: spreadsheet
+                .sheets
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|sheet| sheet.properties)
+                .collect(),
+        }
+    }
+}
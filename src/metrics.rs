@@ -0,0 +1,175 @@
+//! # Metrics Module
+//!
+//! This module emits metrics of the requests sent by the client through the
+//! [`metrics`](https://docs.rs/metrics) facade, so operators can dashboard
+//! their Sheets usage with any installed recorder, such as a Prometheus
+//! exporter. It requires the `metrics` feature.
+//!
+//! ## Overview
+//!
+//! Every request sent to the API is recorded, once its response or error is
+//! received:
+//!
+//! - [`REQUESTS_TOTAL`]: a counter of requests
+//! - [`REQUEST_ERRORS_TOTAL`]: a counter of failed requests
+//! - [`REQUEST_DURATION_SECONDS`]: a histogram of request latencies
+//!
+//! Each metric is labeled with the HTTP `method`, the `endpoint` and the
+//! `status`. The endpoint is the path of the request with spreadsheet IDs,
+//! file IDs and ranges replaced by placeholders, such as
+//! `/v4/spreadsheets/{id}/values/{range}:append`, so labels stay few. The
+//! status is the HTTP status code, or `timeout`, `connect` or `transport` for
+//! requests that received no response.
+//!
+//! Requests rejected before being sent, by the circuit breaker, a read-only
+//! client or a dry run, are not recorded. The client does not retry requests
+//! itself, so retries made by callers show up as additional requests.
+//!
+//! Without an installed recorder the metrics are discarded.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! // Describe the metrics once the recorder of the application is installed.
+//! gsheet_api::metrics::describe();
+//! ```
+
+use std::time::Instant;
+
+use ::metrics::{Unit, counter, describe_counter, describe_histogram, histogram};
+
+use crate::error::GSheetError;
+
+/// The name of the counter of requests sent.
+pub const REQUESTS_TOTAL: &str = "gsheet_api_requests_total";
+/// The name of the counter of requests that failed, with an error status or no
+/// response.
+pub const REQUEST_ERRORS_TOTAL: &str = "gsheet_api_request_errors_total";
+/// The name of the histogram of request latencies, in seconds.
+pub const REQUEST_DURATION_SECONDS: &str = "gsheet_api_request_duration_seconds";
+
+/// Custom methods of the single-range values endpoints, following the range.
+const RANGE_METHODS: [&str; 2] = ["append", "clear"];
+
+/// Describes the metrics to the installed recorder, with their units.
+pub fn describe() {
+    describe_counter!(REQUESTS_TOTAL, "Requests sent to the Google APIs");
+    describe_counter!(
+        REQUEST_ERRORS_TOTAL,
+        "Requests to the Google APIs that failed"
+    );
+    describe_histogram!(
+        REQUEST_DURATION_SECONDS,
+        Unit::Seconds,
+        "Latency of requests to the Google APIs"
+    );
+}
+
+/// The metrics of a request being sent.
+pub(crate) struct RequestMetrics {
+    /// The HTTP method of the request.
+    method: String,
+    /// The path template of the request.
+    endpoint: String,
+    /// When the request was sent.
+    started: Instant,
+}
+
+impl RequestMetrics {
+    /// Starts timing a request about to be sent.
+    ///
+    /// # Arguments
+    /// * `request` - The request about to be sent
+    pub(crate) fn start(request: &reqwest::Request) -> Self {
+        Self {
+            method: request.method().to_string(),
+            endpoint: endpoint(request.url()),
+            started: Instant::now(),
+        }
+    }
+
+    /// Records the outcome of the request.
+    ///
+    /// # Arguments
+    /// * `response` - The response of the request, successful or not, or its error
+    pub(crate) fn finish(self, response: &Result<reqwest::Response, GSheetError>) {
+        let (status, failed) = match response {
+            Ok(response) => (
+                response.status().as_u16().to_string(),
+                !response.status().is_success(),
+            ),
+            Err(GSheetError::HttpRequestError(e)) if e.is_timeout() => ("timeout".into(), true),
+            Err(GSheetError::HttpRequestError(e)) if e.is_connect() => ("connect".into(), true),
+            Err(_) => ("transport".into(), true),
+        };
+        let labels = [
+            ("method", self.method),
+            ("endpoint", self.endpoint),
+            ("status", status),
+        ];
+
+        counter!(REQUESTS_TOTAL, &labels).increment(1);
+        if failed {
+            counter!(REQUEST_ERRORS_TOTAL, &labels).increment(1);
+        }
+        histogram!(REQUEST_DURATION_SECONDS, &labels).record(self.started.elapsed());
+    }
+}
+
+/// Returns the path of a URL with IDs and ranges replaced by placeholders.
+///
+/// Segments made of letters only are kept, as they name resources and
+/// methods; the segment following `values` is a range, and any other segment
+/// is an ID. A custom method (e.g., `:batchUpdate`) is kept.
+fn endpoint(url: &reqwest::Url) -> String {
+    let mut endpoint = String::new();
+    let mut after_values = false;
+    for segment in url.path().split('/').filter(|segment| !segment.is_empty()) {
+        endpoint.push('/');
+        if after_values {
+            // A1 ranges contain colons, so only known methods are split off.
+            let method = segment
+                .rsplit_once(':')
+                .filter(|(_, method)| RANGE_METHODS.contains(method));
+            endpoint.push_str("{range}");
+            if let Some((_, method)) = method {
+                endpoint.push(':');
+                endpoint.push_str(method);
+            }
+            after_values = false;
+            continue;
+        }
+
+        let (name, method) = match segment.split_once(':') {
+            Some((name, method)) => (name, Some(method)),
+            None => (segment, None),
+        };
+        if is_literal(name) {
+            endpoint.push_str(name);
+        } else {
+            endpoint.push_str("{id}");
+        }
+        if let Some(method) = method {
+            endpoint.push(':');
+            endpoint.push_str(if is_literal(method) {
+                method
+            } else {
+                "{method}"
+            });
+        }
+        after_values = name == "values" && method.is_none();
+    }
+    endpoint
+}
+
+/// Returns whether a path segment names a resource or method rather than an
+/// ID: short and made of letters only, or an API version such as `v4`.
+fn is_literal(segment: &str) -> bool {
+    let version = segment
+        .strip_prefix('v')
+        .is_some_and(|number| !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()));
+    version
+        || (!segment.is_empty()
+            && segment.len() < 20
+            && segment.bytes().all(|b| b.is_ascii_alphabetic()))
+}
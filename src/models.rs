@@ -9,12 +9,15 @@
 //! The models are organized into logical groups:
 //!
 //! - **Core Structures**: [`Spreadsheet`], [`Sheet`], [`Cell`] - Main entities
-//! - **Data Types**: [`ValueRange`], [`GridRange`] - Data containers
+//! - **Data Types**: [`ValueRange`], [`GridRange`], [`DataFilter`] - Data containers and selectors
+//! - **Typed Values**: [`CellValue`] - Cell values with times and durations
 //! - **Formatting**: [`CellFormat`], [`TextFormat`] - Cell appearance
 //! - **Conditions**: [`ConditionalFormatRule`], [`BooleanCondition`] - Conditional formatting
 //! - **Charts**: [`EmbeddedChart`], [`ChartSpec`] - Chart representations
 //! - **Filters**: [`FilterView`], [`BasicFilter`] - Data filtering
+//! - **Pivot Tables**: [`PivotTable`], [`PivotGroup`], [`PivotValue`] - Summaries of source data
 //! - **Common**: [`Color`], [`ColorStyle`] - Shared types
+//! - **Batch Updates**: [`Request`], [`Response`] - Structural changes and their replies
 //!
 //! ## Usage
 //!
@@ -29,6 +32,7 @@
 //! and provide better IDE support and compile-time validation.
 
 pub mod cell;
+pub mod cell_value;
 pub mod charts;
 pub mod common;
 pub mod conditions;
@@ -36,13 +40,18 @@ pub mod data_source;
 pub mod filters;
 pub mod formatting;
 pub mod grid;
+pub mod header;
+pub mod pivot;
 pub mod range;
+pub mod requests;
+pub mod responses;
 pub mod sheet;
 pub mod spreadsheet;
 pub mod value;
 
 // Re-export for convenience
 pub use cell::*;
+pub use cell_value::*;
 pub use charts::*;
 pub use common::*;
 pub use conditions::*;
@@ -50,7 +59,11 @@ pub use data_source::*;
 pub use filters::*;
 pub use formatting::*;
 pub use grid::*;
+pub use header::*;
+pub use pivot::*;
 pub use range::*;
+pub use requests::*;
+pub use responses::*;
 pub use sheet::*;
 pub use spreadsheet::*;
 pub use value::*;
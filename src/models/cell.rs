@@ -3,18 +3,26 @@
 //! This module contains models for representing individual cell data,
 //! formatting, and related structures in Google Sheets.
 
-use super::common::{ChipRun, Color, ColorStyle, ExtendedValue, PivotTable};
+use super::common::{ChipRun, Color, ColorStyle, ExtendedValue};
 use super::conditions::DataValidationRule;
 use super::data_source::{DataSourceFormula, DataSourceTable};
 use super::formatting::{
     Borders, HorizontalAlign, HyperlinkDisplayType, NumberFormat, Padding, TextDirection,
     TextFormat, TextFormatRun, TextRotation, VerticalAlign, WrapStrategy,
 };
+use super::pivot::PivotTable;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::ops::Add;
+use std::str::FromStr;
+
+use crate::error::GSheetError;
+use crate::utils::{col_index_to_a1, parse_a1_cell};
 
 /// Data in a cell.
 /// Data is typed and can be either a string, number, boolean, or formula.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CellData {
     /// The value the user entered in the cell.
@@ -47,7 +55,7 @@ pub struct CellData {
 
 /// The format of a cell.
 /// Cell formatting includes number formatting, background color, borders, etc.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CellFormat {
     /// The number format of the cell.
@@ -76,13 +84,296 @@ pub struct CellFormat {
     pub text_rotation: Option<TextRotation>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A cell value read from a sheet, with its position.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Cell {
-    pub address: String,
+    /// The position of the cell, serialized in A1 notation (e.g., "B3").
+    pub address: CellAddress,
+    /// The ID of the spreadsheet.
     pub sheet_id: String,
+    /// The title of the sheet.
     pub sheet_title: String,
+    /// The value of the cell, or `None` if the cell is empty.
     pub value: Option<String>,
-    pub col_index: usize,
-    pub col: String,
-    pub row_index: usize,
+    /// The column of the cell.
+    pub col_index: ColIndex,
+    /// The row of the cell.
+    pub row_index: RowIndex,
+}
+
+/// A 1-based row index, as displayed in the sheet.
+///
+/// The API addresses rows from 0 in grid ranges and from 1 in A1 notation;
+/// the explicit conversions keep the two apart.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::RowIndex;
+///
+/// let row = RowIndex::from_zero_based(2);
+/// assert_eq!(row, RowIndex::new(3));
+/// assert_eq!(row.to_string(), "3");
+/// assert_eq!(row.zero_based(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RowIndex(usize);
+
+impl RowIndex {
+    /// Creates a row index from its 1-based number.
+    pub fn new(row: usize) -> Self {
+        Self(row)
+    }
+
+    /// Creates a row index from a 0-based offset, as used by grid ranges.
+    pub fn from_zero_based(row: usize) -> Self {
+        Self(row + 1)
+    }
+
+    /// Returns the 1-based number of the row.
+    pub fn get(self) -> usize {
+        self.0
+    }
+
+    /// Returns the 0-based offset of the row, as used by grid ranges.
+    pub fn zero_based(self) -> usize {
+        self.0.saturating_sub(1)
+    }
+}
+
+impl Default for RowIndex {
+    /// Returns the first row.
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+impl From<usize> for RowIndex {
+    fn from(row: usize) -> Self {
+        Self(row)
+    }
+}
+
+impl From<RowIndex> for usize {
+    fn from(row: RowIndex) -> Self {
+        row.0
+    }
+}
+
+impl Add<usize> for RowIndex {
+    type Output = Self;
+
+    /// Returns the index `offset` rows below.
+    fn add(self, offset: usize) -> Self {
+        Self(self.0 + offset)
+    }
+}
+
+impl fmt::Display for RowIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A 1-based column index, displayed as its A1 letters.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::ColIndex;
+///
+/// let col = ColIndex::new(28);
+/// assert_eq!(col.to_string(), "AB");
+/// assert_eq!(col, ColIndex::from_zero_based(27));
+/// assert!(ColIndex::new(26) < col);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ColIndex(usize);
+
+impl ColIndex {
+    /// Creates a column index from its 1-based number (1 is column A).
+    pub fn new(col: usize) -> Self {
+        Self(col)
+    }
+
+    /// Creates a column index from a 0-based offset, as used by grid ranges.
+    pub fn from_zero_based(col: usize) -> Self {
+        Self(col + 1)
+    }
+
+    /// Returns the 1-based number of the column.
+    pub fn get(self) -> usize {
+        self.0
+    }
+
+    /// Returns the 0-based offset of the column, as used by grid ranges.
+    pub fn zero_based(self) -> usize {
+        self.0.saturating_sub(1)
+    }
+}
+
+impl Default for ColIndex {
+    /// Returns column A.
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+impl From<usize> for ColIndex {
+    fn from(col: usize) -> Self {
+        Self(col)
+    }
+}
+
+impl From<ColIndex> for usize {
+    fn from(col: ColIndex) -> Self {
+        col.0
+    }
+}
+
+impl Add<usize> for ColIndex {
+    type Output = Self;
+
+    /// Returns the index `offset` columns to the right.
+    fn add(self, offset: usize) -> Self {
+        Self(self.0 + offset)
+    }
+}
+
+impl fmt::Display for ColIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let col = col_index_to_a1(self.0).map_err(|_| fmt::Error)?;
+        f.write_str(&col)
+    }
+}
+
+/// The position of a cell, with 1-based row and column indexes.
+///
+/// Addresses are ordered row by row, then column by column, which is the
+/// reading order of a sheet. They are serialized in A1 notation.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::{CellAddress, ColIndex, RowIndex};
+///
+/// let address: CellAddress = "B3".parse().unwrap();
+/// assert_eq!(address, CellAddress::new(3, 2));
+/// assert_eq!((address.row, address.col), (RowIndex::new(3), ColIndex::new(2)));
+/// assert_eq!(address.to_string(), "B3");
+/// assert!(CellAddress::new(2, 5) < address);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CellAddress {
+    /// The row of the cell.
+    pub row: RowIndex,
+    /// The column of the cell.
+    pub col: ColIndex,
+}
+
+impl CellAddress {
+    /// Creates an address from 1-based row and column indexes.
+    pub fn new(row: impl Into<RowIndex>, col: impl Into<ColIndex>) -> Self {
+        Self {
+            row: row.into(),
+            col: col.into(),
+        }
+    }
+}
+
+impl fmt::Display for CellAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.col, self.row)
+    }
+}
+
+impl FromStr for CellAddress {
+    type Err = GSheetError;
+
+    fn from_str(a1: &str) -> Result<Self, Self::Err> {
+        let (col, row) = parse_a1_cell(a1)?;
+        Ok(Self::new(row, col))
+    }
+}
+
+impl Serialize for CellAddress {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CellAddress {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let a1 = String::deserialize(deserializer)?;
+        a1.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<&Cell> for CellAddress {
+    fn from(cell: &Cell) -> Self {
+        cell.address
+    }
+}
+
+/// Slicing of cell maps keyed by [`CellAddress`].
+///
+/// Implemented for both the `HashMap` and the ordered `BTreeMap` returned by
+/// the cell map reads. Slices are sorted in reading order.
+pub trait CellMapExt {
+    /// Returns the cells of a row, sorted by column.
+    fn row(&self, row: impl Into<RowIndex>) -> Vec<&Cell>;
+
+    /// Returns the cells of a column, sorted by row.
+    fn column(&self, col: impl Into<ColIndex>) -> Vec<&Cell>;
+
+    /// Returns the cell at an A1 notation address, if it holds a value.
+    fn get_a1(&self, a1: &str) -> Option<&Cell>;
+}
+
+impl CellMapExt for HashMap<CellAddress, Cell> {
+    fn row(&self, row: impl Into<RowIndex>) -> Vec<&Cell> {
+        let row = row.into();
+        let mut cells: Vec<&Cell> = self
+            .iter()
+            .filter(|(address, _)| address.row == row)
+            .map(|(_, cell)| cell)
+            .collect();
+        cells.sort_by_key(|cell| cell.col_index);
+        cells
+    }
+
+    fn column(&self, col: impl Into<ColIndex>) -> Vec<&Cell> {
+        let col = col.into();
+        let mut cells: Vec<&Cell> = self
+            .iter()
+            .filter(|(address, _)| address.col == col)
+            .map(|(_, cell)| cell)
+            .collect();
+        cells.sort_by_key(|cell| cell.row_index);
+        cells
+    }
+
+    fn get_a1(&self, a1: &str) -> Option<&Cell> {
+        self.get(&a1.parse().ok()?)
+    }
+}
+
+impl CellMapExt for BTreeMap<CellAddress, Cell> {
+    fn row(&self, row: impl Into<RowIndex>) -> Vec<&Cell> {
+        let row = row.into().get();
+        self.range(CellAddress::new(row, 0)..CellAddress::new(row + 1, 0))
+            .map(|(_, cell)| cell)
+            .collect()
+    }
+
+    fn column(&self, col: impl Into<ColIndex>) -> Vec<&Cell> {
+        let col = col.into();
+        self.iter()
+            .filter(|(address, _)| address.col == col)
+            .map(|(_, cell)| cell)
+            .collect()
+    }
+
+    fn get_a1(&self, a1: &str) -> Option<&Cell> {
+        self.get(&a1.parse().ok()?)
+    }
 }
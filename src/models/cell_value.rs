@@ -0,0 +1,234 @@
+//! Typed cell value models.
+//!
+//! This module contains [`CellValue`], the value of a cell interpreted with its
+//! number format, and conversions between Sheets serial numbers and `chrono`
+//! durations and times of day.
+//!
+//! Sheets stores times and durations as serial numbers counting days: 0.5 is
+//! noon as a time of day, or twelve hours as a duration. A cell holds a duration
+//! when its number format uses elapsed-time brackets, such as `[h]:mm:ss`.
+
+use chrono::{Duration, NaiveTime, Timelike};
+
+use super::cell::CellData;
+use super::common::ExtendedValue;
+use super::formatting::{NumberFormat, NumberFormatType};
+
+/// The number format pattern of durations, with hours that exceed a day.
+pub const DURATION_PATTERN: &str = "[h]:mm:ss";
+
+/// The number of milliseconds in a day.
+const MILLIS_PER_DAY: f64 = 86_400_000.0;
+
+/// Converts a duration to a serial number of days.
+///
+/// # Examples
+/// ```rust
+/// use chrono::Duration;
+/// use gsheet_api::models::{duration_to_serial, serial_to_duration};
+///
+/// assert_eq!(duration_to_serial(Duration::hours(36)), 1.5);
+/// assert_eq!(serial_to_duration(0.25), Duration::hours(6));
+/// ```
+pub fn duration_to_serial(duration: Duration) -> f64 {
+    duration.num_milliseconds() as f64 / MILLIS_PER_DAY
+}
+
+/// Converts a serial number of days to a duration, rounded to the millisecond.
+pub fn serial_to_duration(serial: f64) -> Duration {
+    Duration::milliseconds((serial * MILLIS_PER_DAY).round() as i64)
+}
+
+/// Converts a time of day to a serial fraction of a day.
+///
+/// # Examples
+/// ```rust
+/// use chrono::NaiveTime;
+/// use gsheet_api::models::{serial_to_time, time_to_serial};
+///
+/// let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+/// assert_eq!(time_to_serial(noon), 0.5);
+/// // The date part of a date-time serial is ignored.
+/// assert_eq!(serial_to_time(45_000.75), NaiveTime::from_hms_opt(18, 0, 0).unwrap());
+/// ```
+pub fn time_to_serial(time: NaiveTime) -> f64 {
+    let millis =
+        time.num_seconds_from_midnight() as f64 * 1_000.0 + (time.nanosecond() / 1_000_000) as f64;
+    millis / MILLIS_PER_DAY
+}
+
+/// Converts the fractional part of a serial number to a time of day, rounded
+/// to the millisecond.
+pub fn serial_to_time(serial: f64) -> NaiveTime {
+    let millis = (serial.rem_euclid(1.0) * MILLIS_PER_DAY).round() as i64;
+    NaiveTime::MIN + Duration::milliseconds(millis)
+}
+
+/// Parses a formatted duration such as "37:30:00", "-1:15" or "8:00:00.5".
+///
+/// # Arguments
+/// * `text` - The duration as hours, minutes and optional seconds
+///
+/// # Returns
+/// The duration, or `None` if the text is not a duration.
+///
+/// # Examples
+/// ```rust
+/// use chrono::Duration;
+/// use gsheet_api::models::parse_duration;
+///
+/// assert_eq!(parse_duration("37:30:00"), Some(Duration::minutes(37 * 60 + 30)));
+/// assert_eq!(parse_duration("-0:45"), Some(Duration::minutes(-45)));
+/// assert_eq!(parse_duration("soon"), None);
+/// ```
+pub fn parse_duration(text: &str) -> Option<Duration> {
+    let text = text.trim();
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let mut parts = text.split(':');
+    let hours: i64 = parts.next()?.parse().ok()?;
+    let minutes: i64 = parts.next()?.parse().ok()?;
+    let seconds: f64 = parts.next().map_or(Ok(0.0), str::parse).ok()?;
+    if parts.next().is_some() || !(0..60).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+    let millis = (hours * 60 + minutes) * 60_000 + (seconds * 1_000.0).round() as i64;
+    Some(Duration::milliseconds(if negative {
+        -millis
+    } else {
+        millis
+    }))
+}
+
+/// Returns whether a number format displays durations, as elapsed time.
+fn is_duration_format(format: &NumberFormat) -> bool {
+    matches!(format.type_, Some(NumberFormatType::Time))
+        && format
+            .pattern
+            .as_deref()
+            .is_some_and(|pattern| pattern.contains('['))
+}
+
+/// The value of a cell, interpreted with its number format.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellValue {
+    /// An empty cell.
+    Empty,
+    /// A text value.
+    Text(String),
+    /// A number, including dates, which keep their serial number.
+    Number(f64),
+    /// A boolean value.
+    Bool(bool),
+    /// A time of day, for numbers formatted as times.
+    Time(NaiveTime),
+    /// A duration, for numbers formatted as elapsed time (e.g., `[h]:mm`).
+    Duration(Duration),
+    /// An error, with its message.
+    Error(String),
+}
+
+impl CellValue {
+    /// Interprets the effective value of a cell with its effective number format.
+    ///
+    /// # Arguments
+    /// * `cell` - The cell data, read with its effective value and format
+    ///
+    /// # Returns
+    /// The [`CellValue`] of the cell.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chrono::Duration;
+    /// use gsheet_api::models::{CellData, CellFormat, CellValue, NumberFormat, NumberFormatType};
+    ///
+    /// let cell = CellData {
+    ///     effective_value: Some(1.5.into()),
+    ///     effective_format: Some(CellFormat {
+    ///         number_format: Some(NumberFormat {
+    ///             type_: Some(NumberFormatType::Time),
+    ///             pattern: Some("[h]:mm:ss".to_string()),
+    ///         }),
+    ///         ..Default::default()
+    ///     }),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(CellValue::from_cell_data(&cell), CellValue::Duration(Duration::hours(36)));
+    /// ```
+    pub fn from_cell_data(cell: &CellData) -> Self {
+        let Some(value) = &cell.effective_value else {
+            return CellValue::Empty;
+        };
+        let format = cell
+            .effective_format
+            .as_ref()
+            .and_then(|format| format.number_format.as_ref());
+
+        if let Some(number) = value.number_value {
+            return match format {
+                Some(format) if is_duration_format(format) => {
+                    CellValue::Duration(serial_to_duration(number))
+                }
+                Some(NumberFormat {
+                    type_: Some(NumberFormatType::Time),
+                    ..
+                }) => CellValue::Time(serial_to_time(number)),
+                _ => CellValue::Number(number),
+            };
+        }
+        if let Some(text) = &value.string_value {
+            return CellValue::Text(text.clone());
+        }
+        if let Some(boolean) = value.bool_value {
+            return CellValue::Bool(boolean);
+        }
+        if let Some(error) = &value.error_value {
+            return CellValue::Error(error.message.clone().unwrap_or_default());
+        }
+        CellValue::Empty
+    }
+
+    /// Returns the number format a cell needs to display this value, if any.
+    pub fn number_format(&self) -> Option<NumberFormat> {
+        match self {
+            CellValue::Time(_) => Some(NumberFormat {
+                type_: Some(NumberFormatType::Time),
+                pattern: Some("hh:mm:ss".to_string()),
+            }),
+            CellValue::Duration(_) => Some(NumberFormat {
+                type_: Some(NumberFormatType::Time),
+                pattern: Some(DURATION_PATTERN.to_string()),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl From<CellValue> for ExtendedValue {
+    fn from(value: CellValue) -> Self {
+        match value {
+            CellValue::Empty => ExtendedValue::default(),
+            CellValue::Text(text) => text.into(),
+            CellValue::Number(number) => number.into(),
+            CellValue::Bool(boolean) => boolean.into(),
+            CellValue::Time(time) => time.into(),
+            CellValue::Duration(duration) => duration.into(),
+            // Errors are read-only, so they are written as their message.
+            CellValue::Error(message) => message.into(),
+        }
+    }
+}
+
+impl From<Duration> for ExtendedValue {
+    fn from(duration: Duration) -> Self {
+        duration_to_serial(duration).into()
+    }
+}
+
+impl From<NaiveTime> for ExtendedValue {
+    fn from(time: NaiveTime) -> Self {
+        time_to_serial(time).into()
+    }
+}
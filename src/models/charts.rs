@@ -6,7 +6,7 @@ use super::grid::GridRange;
 use serde::{Deserialize, Serialize};
 
 /// A chart embedded in a spreadsheet.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EmbeddedChart {
     /// The ID of the chart.
@@ -21,7 +21,7 @@ pub struct EmbeddedChart {
 
 /// The specifications of a chart.
 /// This contains all the properties for a chart, including its type and data.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChartSpec {
     /// The title of the chart.
@@ -74,8 +74,75 @@ pub struct ChartSpec {
     pub scorecard_chart: Option<ScorecardChartSpec>,
 }
 
+impl ChartSpec {
+    /// Creates a basic chart from a range whose first row holds headers.
+    ///
+    /// The first column of the range is used as the domain and every other
+    /// column becomes a series named after its header.
+    pub fn basic(chart_type: BasicChartType, title: &str, range: &GridRange) -> Self {
+        let column = |index: i32| {
+            ChartData::from_range(GridRange {
+                start_column_index: Some(index),
+                end_column_index: Some(index + 1),
+                ..range.clone()
+            })
+        };
+        let start = range.start_column_index.unwrap_or_default();
+        let end = range.end_column_index.unwrap_or(start + 1);
+
+        Self {
+            title: Some(title.to_string()),
+            basic_chart: Some(BasicChartSpec {
+                chart_type: Some(chart_type),
+                legend_position: Some(BasicChartLegendPosition::BottomLegend),
+                domains: Some(vec![BasicChartDomain {
+                    domain: Some(column(start)),
+                    reversed: None,
+                }]),
+                series: Some(
+                    (start + 1..end)
+                        .map(|index| BasicChartSeries {
+                            series: Some(column(index)),
+                            ..Default::default()
+                        })
+                        .collect(),
+                ),
+                header_count: Some(1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a pie chart from a range whose first row holds headers.
+    ///
+    /// The first column holds the slice labels and the second column their values.
+    pub fn pie(title: &str, range: &GridRange) -> Self {
+        let start = range.start_column_index.unwrap_or_default();
+        let column = |index: i32| {
+            ChartData::from_range(GridRange {
+                start_row_index: Some(range.start_row_index.unwrap_or_default() + 1),
+                start_column_index: Some(index),
+                end_column_index: Some(index + 1),
+                ..range.clone()
+            })
+        };
+
+        Self {
+            title: Some(title.to_string()),
+            pie_chart: Some(PieChartSpec {
+                legend_position: Some(PieChartLegendPosition::RightLegend),
+                domain: Some(column(start)),
+                series: Some(column(start + 1)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
 /// The position of text within a chart.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TextPosition {
     /// The horizontal alignment of the text.
@@ -83,7 +150,7 @@ pub struct TextPosition {
 }
 
 /// Properties for a chart that uses a data source.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSourceChartProperties {
     /// The ID of the data source that the chart is associated with.
@@ -92,7 +159,7 @@ pub struct DataSourceChartProperties {
     pub data_execution_status: Option<DataExecutionStatus>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BasicChartSpec {
     pub chart_type: Option<BasicChartType>,
@@ -133,7 +200,7 @@ pub enum BasicChartLegendPosition {
     NoLegend,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BasicChartAxis {
     pub position: Option<BasicChartAxisPosition>,
@@ -152,7 +219,7 @@ pub enum BasicChartAxisPosition {
     RightAxis,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChartAxisViewWindowOptions {
     pub view_window_min: Option<f64>,
@@ -169,14 +236,14 @@ pub enum ViewWindowMode {
     Pretty,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BasicChartDomain {
     pub domain: Option<ChartData>,
     pub reversed: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChartData {
     pub group_rule: Option<ChartGroupRule>,
@@ -185,20 +252,32 @@ pub struct ChartData {
     pub column_reference: Option<DataSourceColumnReference>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl ChartData {
+    /// Creates chart data read from a single range.
+    pub fn from_range(range: GridRange) -> Self {
+        Self {
+            source_range: Some(ChartSourceRange {
+                sources: Some(vec![range]),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChartSourceRange {
     pub sources: Option<Vec<GridRange>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChartGroupRule {
     pub date_time_rule: Option<ChartDateTimeRule>,
     pub histogram_rule: Option<ChartHistogramRule>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChartDateTimeRule {
     pub type_: Option<ChartDateTimeRuleType>,
@@ -225,7 +304,7 @@ pub enum ChartDateTimeRuleType {
     YearMonthDay,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChartHistogramRule {
     pub min_value: Option<f64>,
@@ -245,7 +324,7 @@ pub enum ChartAggregateType {
     Sum,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BasicChartSeries {
     pub series: Option<ChartData>,
@@ -259,7 +338,7 @@ pub struct BasicChartSeries {
     pub style_overrides: Option<Vec<BasicSeriesDataPointStyleOverride>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LineStyle {
     pub width: Option<i32>,
@@ -280,7 +359,7 @@ pub enum LineDashType {
     LongDashedDotted,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataLabel {
     pub type_: Option<DataLabelType>,
@@ -312,7 +391,7 @@ pub enum DataLabelPlacement {
     OutsideEnd,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PointStyle {
     pub size: Option<f64>,
@@ -333,7 +412,7 @@ pub enum PointShape {
     XMark,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BasicSeriesDataPointStyleOverride {
     pub index: Option<i32>,
@@ -359,7 +438,7 @@ pub enum BasicChartCompareMode {
     Category,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PieChartSpec {
     pub legend_position: Option<PieChartLegendPosition>,
@@ -381,7 +460,7 @@ pub enum PieChartLegendPosition {
     LabeledLegend,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BubbleChartSpec {
     pub legend_position: Option<BubbleChartLegendPosition>,
@@ -410,21 +489,21 @@ pub enum BubbleChartLegendPosition {
     InsideLegend,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CandlestickChartSpec {
     pub domain: Option<CandlestickDomain>,
     pub data: Option<Vec<CandlestickData>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CandlestickDomain {
     pub data: Option<ChartData>,
     pub reversed: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CandlestickData {
     pub low_series: Option<CandlestickSeries>,
@@ -433,13 +512,13 @@ pub struct CandlestickData {
     pub high_series: Option<CandlestickSeries>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CandlestickSeries {
     pub data: Option<ChartData>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrgChartSpec {
     pub node_size: Option<OrgChartNodeSize>,
@@ -461,7 +540,7 @@ pub enum OrgChartNodeSize {
     Large,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HistogramChartSpec {
     pub series: Option<Vec<HistogramSeries>>,
@@ -471,7 +550,7 @@ pub struct HistogramChartSpec {
     pub outlier_percentile: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HistogramSeries {
     pub bar_color: Option<Color>,
@@ -491,7 +570,7 @@ pub enum HistogramChartLegendPosition {
     InsideLegend,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WaterfallChartSpec {
     pub domain: Option<WaterfallChartDomain>,
@@ -503,14 +582,14 @@ pub struct WaterfallChartSpec {
     pub total_data_label: Option<DataLabel>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WaterfallChartDomain {
     pub data: Option<ChartData>,
     pub reversed: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WaterfallChartSeries {
     pub data: Option<ChartData>,
@@ -522,7 +601,7 @@ pub struct WaterfallChartSeries {
     pub data_label: Option<DataLabel>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WaterfallChartColumnStyle {
     pub label: Option<String>,
@@ -530,7 +609,7 @@ pub struct WaterfallChartColumnStyle {
     pub color_style: Option<ColorStyle>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WaterfallChartCustomSubtotal {
     pub subtotal_index: Option<i32>,
@@ -546,7 +625,7 @@ pub enum WaterfallChartStackedType {
     Sequential,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TreemapChartSpec {
     pub labels: Option<ChartData>,
@@ -564,7 +643,7 @@ pub struct TreemapChartSpec {
     pub hide_tooltips: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TreemapChartColorScale {
     pub min_value_color: Option<Color>,
@@ -577,7 +656,7 @@ pub struct TreemapChartColorScale {
     pub no_data_color_style: Option<ColorStyle>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ScorecardChartSpec {
     pub key_value_data: Option<ChartData>,
@@ -590,14 +669,14 @@ pub struct ScorecardChartSpec {
     pub custom_format_options: Option<ChartCustomNumberFormatOptions>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct KeyValueFormat {
     pub text_format: Option<TextFormat>,
     pub position: Option<TextPosition>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BaselineValueFormat {
     pub comparison_type: Option<ComparisonType>,
@@ -626,7 +705,7 @@ pub enum ChartNumberFormatSource {
     Custom,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChartCustomNumberFormatOptions {
     pub prefix: Option<String>,
@@ -643,7 +722,7 @@ pub enum ChartHiddenDimensionStrategy {
     ShowAll,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EmbeddedObjectBorder {
     pub color: Option<Color>,
@@ -1,23 +1,104 @@
 use serde::{Deserialize, Serialize};
 
 /// The kinds of value that a cell in a spreadsheet can have.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Exactly one kind should be set when writing a value; unset kinds are not
+/// serialized.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExtendedValue {
     /// Represents a double value. Note: Dates, Times and DateTimes are represented as doubles in SERIAL_NUMBER format.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub number_value: Option<f64>,
     /// Represents a string value. Leading single quotes are not included.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub string_value: Option<String>,
     /// Represents a boolean value.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bool_value: Option<bool>,
     /// Represents a formula.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub formula_value: Option<String>,
     /// Represents an error. This field is read-only.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub error_value: Option<ErrorValue>,
 }
 
+impl From<f64> for ExtendedValue {
+    fn from(value: f64) -> Self {
+        Self {
+            number_value: Some(value),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<i64> for ExtendedValue {
+    fn from(value: i64) -> Self {
+        Self::from(value as f64)
+    }
+}
+
+/// Converts a decimal through its shortest `f64` representation, which the API
+/// stores losslessly for up to 15 significant digits.
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for ExtendedValue {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Self::from(value.to_string().parse::<f64>().unwrap_or_default())
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl ExtendedValue {
+    /// Returns the number value as a decimal, converted from the shortest
+    /// representation of the `f64` so that values such as 0.1 stay exact.
+    /// Requires the `decimal` feature.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gsheet_api::models::ExtendedValue;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let value = ExtendedValue::from(Decimal::new(1999, 2));
+    /// assert_eq!(value.decimal_value(), Some(Decimal::new(1999, 2)));
+    /// ```
+    pub fn decimal_value(&self) -> Option<rust_decimal::Decimal> {
+        use std::str::FromStr;
+
+        let number = self.number_value.filter(|number| number.is_finite())?;
+        let text = number.to_string();
+        rust_decimal::Decimal::from_str(&text)
+            .or_else(|_| rust_decimal::Decimal::from_scientific(&format!("{:e}", number)))
+            .ok()
+    }
+}
+
+impl From<bool> for ExtendedValue {
+    fn from(value: bool) -> Self {
+        Self {
+            bool_value: Some(value),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<String> for ExtendedValue {
+    fn from(value: String) -> Self {
+        Self {
+            string_value: Some(value),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for ExtendedValue {
+    fn from(value: &str) -> Self {
+        Self::from(value.to_string())
+    }
+}
+
 /// An error in a cell.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ErrorValue {
     /// The type of error.
@@ -55,7 +136,7 @@ pub enum ErrorType {
 
 /// Developer metadata associated with a location or object in a spreadsheet.
 /// Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DeveloperMetadata {
     /// The spreadsheet-scoped unique ID that identifies the metadata.
@@ -71,7 +152,7 @@ pub struct DeveloperMetadata {
 }
 
 /// A location where metadata may be associated in a spreadsheet.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DeveloperMetadataLocation {
     /// The type of location.
@@ -110,11 +191,35 @@ pub enum DeveloperMetadataVisibility {
     Project,
 }
 
+/// Selects the developer metadata matching all of its set fields.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeveloperMetadataLookup {
+    /// Limits the selected metadata to this type of location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_type: Option<DeveloperMetadataLocationType>,
+    /// Limits the selected metadata to this location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_location: Option<DeveloperMetadataLocation>,
+    /// Limits the selected metadata to the one with this ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_id: Option<i32>,
+    /// Limits the selected metadata to the ones with this key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_key: Option<String>,
+    /// Limits the selected metadata to the ones with this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_value: Option<String>,
+    /// Limits the selected metadata to the ones with this visibility.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<DeveloperMetadataVisibility>,
+}
+
 /// A range along a single dimension on a sheet.
 /// All indexes are zero-based.
 /// Indexes are half open: the start index is inclusive and the end index is exclusive.
 /// Missing indexes indicate the range is unbounded on that side.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DimensionRange {
     /// The sheet this dimension range is on.
@@ -128,7 +233,7 @@ pub struct DimensionRange {
 }
 
 /// Properties about a dimension.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DimensionProperties {
     /// True if this dimension is being filtered. This field is read-only.
@@ -144,7 +249,7 @@ pub struct DimensionProperties {
 }
 
 /// Data about each cell in a row.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RowData {
     /// The values in the row, one per column.
@@ -152,7 +257,7 @@ pub struct RowData {
 }
 
 /// The editors of a protected range.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Editors {
     /// The email addresses of users with edit access to the protected range.
@@ -164,7 +269,7 @@ pub struct Editors {
 }
 
 /// The position of an embedded object such as a chart.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EmbeddedObjectPosition {
     /// The sheet this is on.
@@ -176,7 +281,7 @@ pub struct EmbeddedObjectPosition {
 }
 
 /// The position of an embedded object within a sheet.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OverlayPosition {
     /// The cell the object is anchored to.
@@ -193,7 +298,7 @@ pub struct OverlayPosition {
 
 /// A coordinate in a sheet.
 /// All indexes are zero-based.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GridCoordinate {
     /// The sheet this coordinate is on.
@@ -206,7 +311,7 @@ pub struct GridCoordinate {
 
 /// The data execution status.
 /// Used by Sheets API data source objects to indicate status of data execution.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataExecutionStatus {
     /// The state of the data execution.
@@ -224,6 +329,7 @@ pub struct DataExecutionStatus {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum DataExecutionState {
     /// Default value.
+    #[serde(rename = "DATA_EXECUTION_STATE_UNSPECIFIED")]
     Unspecified,
     /// The data execution has not started.
     NotStarted,
@@ -242,6 +348,7 @@ pub enum DataExecutionState {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum DataExecutionErrorCode {
     /// Default value.
+    #[serde(rename = "DATA_EXECUTION_ERROR_CODE_UNSPECIFIED")]
     Unspecified,
     /// The data execution timed out.
     TimedOut,
@@ -284,7 +391,7 @@ pub enum DataExecutionErrorCode {
 }
 
 /// A pair mapping a theme color type to the concrete color it represents.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ThemeColorPair {
     /// The type of the theme color.
@@ -294,7 +401,7 @@ pub struct ThemeColorPair {
 }
 
 /// Settings for iterative calculation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IterativeCalculationSettings {
     /// When iterative calculation is enabled, the maximum number of calculation rounds to perform.
@@ -308,6 +415,7 @@ pub struct IterativeCalculationSettings {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RecalculationInterval {
     /// Default value. This value must not be used.
+    #[serde(rename = "RECALCULATION_INTERVAL_UNSPECIFIED")]
     Unspecified,
     /// Volatile functions are updated on every change.
     OnChange,
@@ -318,7 +426,7 @@ pub enum RecalculationInterval {
 }
 
 /// A run of rich text and associated semantic chips.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChipRun {
     /// The zero-based character index where this run starts.
@@ -328,7 +436,7 @@ pub struct ChipRun {
 }
 
 /// A chip is a UI element that represents a person or a rich link.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Chip {
     /// Properties of a person chip.
@@ -338,7 +446,7 @@ pub struct Chip {
 }
 
 /// Properties of a person chip.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PersonProperties {
     /// The email address of the person.
@@ -348,7 +456,7 @@ pub struct PersonProperties {
 }
 
 /// Properties of a rich link chip.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RichLinkProperties {
     /// The URI of the rich link.
@@ -449,7 +557,7 @@ pub struct RichLinkProperties {
 ///   return resultBuilder.join('');
 /// };
 /// // ...
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Color {
     /// The amount of red in the color as a value in the interval [0, 1].
@@ -464,8 +572,20 @@ pub struct Color {
     pub alpha: Option<f64>,
 }
 
+impl Color {
+    /// Creates an opaque color from 8-bit red, green and blue components.
+    pub fn from_rgb8(red: u8, green: u8, blue: u8) -> Self {
+        Self {
+            red: Some(f64::from(red) / 255.0),
+            green: Some(f64::from(green) / 255.0),
+            blue: Some(f64::from(blue) / 255.0),
+            alpha: None,
+        }
+    }
+}
+
 /// A color value.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ColorStyle {
     /// RGB color.
@@ -475,10 +595,11 @@ pub struct ColorStyle {
 }
 
 /// Theme color types.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ThemeColorType {
     /// Unspecified theme color.
+    #[serde(rename = "THEME_COLOR_TYPE_UNSPECIFIED")]
     Unspecified,
     /// Represents the primary text color.
     Text,
@@ -499,11 +620,3 @@ pub enum ThemeColorType {
     /// Represents the hyperlink color.
     Link,
 }
-
-/// A placeholder for pivot table functionality.
-/// This is not yet implemented.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PivotTable {
-    // Placeholder
-}
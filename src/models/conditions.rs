@@ -4,7 +4,7 @@ use super::cell::CellFormat;
 use super::common::{Color, ColorStyle};
 use super::grid::GridRange;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConditionalFormatRule {
     pub ranges: Option<Vec<GridRange>>,
@@ -12,14 +12,14 @@ pub struct ConditionalFormatRule {
     pub gradient_rule: Option<GradientRule>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BooleanRule {
     pub condition: Option<BooleanCondition>,
     pub format: Option<CellFormat>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GradientRule {
     pub minpoint: Option<InterpolationPoint>,
@@ -27,7 +27,7 @@ pub struct GradientRule {
     pub maxpoint: Option<InterpolationPoint>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BooleanCondition {
     #[serde(rename = "type")]
@@ -73,7 +73,7 @@ pub enum ConditionType {
     FilterExpression,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConditionValue {
     pub relative_date: Option<RelativeDate>,
@@ -92,7 +92,7 @@ pub enum RelativeDate {
     Tomorrow,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InterpolationPoint {
     pub color: Option<Color>,
@@ -113,7 +113,7 @@ pub enum InterpolationPointType {
     Percentile,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataValidationRule {
     pub condition: Option<BooleanCondition>,
@@ -121,3 +121,130 @@ pub struct DataValidationRule {
     pub strict: Option<bool>,
     pub show_custom_ui: Option<bool>,
 }
+
+/// A ready-made conditional format, compiled to rules over a range.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::{Color, ConditionalFormatPreset, GridRange};
+///
+/// let range = GridRange::default();
+/// let heatmap = ConditionalFormatPreset::heatmap(
+///     Color::from_rgb8(0xff, 0xff, 0xff),
+///     Color::from_rgb8(0xe6, 0x7c, 0x73),
+/// );
+/// assert_eq!(heatmap.rules(&range).len(), 1);
+///
+/// // Red below 50, yellow below 80, green from 80.
+/// let traffic_lights = ConditionalFormatPreset::traffic_lights([50.0, 80.0]);
+/// assert_eq!(traffic_lights.rules(&range).len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub enum ConditionalFormatPreset {
+    /// A color scale from the lowest to the highest value of the range.
+    Heatmap {
+        /// The color of the lowest value.
+        min_color: Color,
+        /// The color of the highest value.
+        max_color: Color,
+    },
+    /// Red, yellow and green backgrounds for values below the first threshold,
+    /// below the second threshold, and from the second threshold up.
+    TrafficLights {
+        /// The lower and upper thresholds.
+        thresholds: [f64; 2],
+    },
+}
+
+impl ConditionalFormatPreset {
+    /// Creates a color scale from the lowest to the highest value.
+    pub fn heatmap(min_color: Color, max_color: Color) -> Self {
+        Self::Heatmap {
+            min_color,
+            max_color,
+        }
+    }
+
+    /// Creates a color scale from white to a color, shading each cell by its
+    /// value like a data bar.
+    pub fn shade(color: Color) -> Self {
+        Self::heatmap(Color::from_rgb8(0xff, 0xff, 0xff), color)
+    }
+
+    /// Creates red, yellow and green backgrounds split at two thresholds.
+    pub fn traffic_lights(thresholds: [f64; 2]) -> Self {
+        Self::TrafficLights { thresholds }
+    }
+
+    /// Returns the rules of the preset over a range, in the order they should
+    /// be added: the first rule matching a cell formats it.
+    pub fn rules(&self, range: &GridRange) -> Vec<ConditionalFormatRule> {
+        let rule = |boolean_rule, gradient_rule| ConditionalFormatRule {
+            ranges: Some(vec![range.clone()]),
+            boolean_rule,
+            gradient_rule,
+        };
+        match self {
+            Self::Heatmap {
+                min_color,
+                max_color,
+            } => {
+                let point = |type_, color: &Color| InterpolationPoint {
+                    color_style: Some(ColorStyle {
+                        rgb_color: Some(color.clone()),
+                        theme_color: None,
+                    }),
+                    type_: Some(type_),
+                    ..Default::default()
+                };
+                vec![rule(
+                    None,
+                    Some(GradientRule {
+                        minpoint: Some(point(InterpolationPointType::Min, min_color)),
+                        midpoint: None,
+                        maxpoint: Some(point(InterpolationPointType::Max, max_color)),
+                    }),
+                )]
+            }
+            Self::TrafficLights {
+                thresholds: [low, high],
+            } => {
+                let light = |type_, threshold: &f64, color: Color| {
+                    rule(
+                        Some(BooleanRule {
+                            condition: Some(BooleanCondition {
+                                type_: Some(type_),
+                                values: Some(vec![ConditionValue {
+                                    relative_date: None,
+                                    user_entered_value: Some(threshold.to_string()),
+                                }]),
+                            }),
+                            format: Some(CellFormat {
+                                background_color: Some(color),
+                                ..Default::default()
+                            }),
+                        }),
+                        None,
+                    )
+                };
+                vec![
+                    light(
+                        ConditionType::NumberLess,
+                        low,
+                        Color::from_rgb8(0xf4, 0xc7, 0xc3),
+                    ),
+                    light(
+                        ConditionType::NumberLess,
+                        high,
+                        Color::from_rgb8(0xfc, 0xe8, 0xb2),
+                    ),
+                    light(
+                        ConditionType::NumberGreaterThanEq,
+                        high,
+                        Color::from_rgb8(0xb7, 0xe1, 0xcd),
+                    ),
+                ]
+            }
+        }
+    }
+}
@@ -1,10 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use super::common::DataExecutionStatus;
+use super::common::{DataExecutionStatus, GridCoordinate};
 use super::filters::{FilterSpec, SortSpec};
 use super::grid::GridRange;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSource {
     pub data_source_id: Option<String>,
@@ -13,7 +13,7 @@ pub struct DataSource {
     pub sheet_id: Option<i32>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LookerDataSourceSpec {
     pub instance_uri: Option<String>,
@@ -21,7 +21,7 @@ pub struct LookerDataSourceSpec {
     pub explore: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSourceSpec {
     pub parameters: Option<Vec<DataSourceParameter>>,
@@ -29,7 +29,7 @@ pub struct DataSourceSpec {
     pub looker: Option<LookerDataSourceSpec>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSourceParameter {
     pub name: Option<String>,
@@ -37,14 +37,14 @@ pub struct DataSourceParameter {
     pub range: Option<GridRange>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSourceColumn {
     pub reference: Option<DataSourceColumnReference>,
     pub formula: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSourceRefreshSchedule {
     pub enabled: Option<bool>,
@@ -62,13 +62,13 @@ pub enum DataSourceRefreshScope {
     AllDataSources,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSourceRefreshDailySchedule {
     pub start_time: Option<TimeOfDay>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSourceRefreshWeeklySchedule {
     pub start_time: Option<TimeOfDay>,
@@ -88,14 +88,14 @@ pub enum DayOfWeek {
     Sunday,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSourceRefreshMonthlySchedule {
     pub start_time: Option<TimeOfDay>,
     pub days_of_month: Option<Vec<i32>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TimeOfDay {
     pub hours: Option<i32>,
@@ -104,20 +104,20 @@ pub struct TimeOfDay {
     pub nanos: Option<i32>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Interval {
     pub start_time: Option<String>,
     pub end_time: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSourceColumnReference {
     pub name: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BigQueryDataSourceSpec {
     pub project_id: Option<String>,
@@ -125,13 +125,13 @@ pub struct BigQueryDataSourceSpec {
     pub table_spec: Option<BigQueryTableSpec>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BigQueryQuerySpec {
     pub raw_query: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BigQueryTableSpec {
     pub table_project_id: Option<String>,
@@ -147,7 +147,7 @@ pub enum DataSourceTableColumnSelectionType {
     SyncAll,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSourceTable {
     pub data_source_id: Option<String>,
@@ -159,9 +159,67 @@ pub struct DataSourceTable {
     pub data_execution_status: Option<DataExecutionStatus>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSourceFormula {
     pub data_source_id: Option<String>,
     pub data_execution_status: Option<DataExecutionStatus>,
 }
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataSourceObjectReference {
+    pub sheet_id: Option<String>,
+    pub chart_id: Option<i32>,
+    pub data_source_table_anchor_cell: Option<GridCoordinate>,
+    pub data_source_pivot_table_anchor_cell: Option<GridCoordinate>,
+    pub data_source_formula_cell: Option<GridCoordinate>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataSourceObjectReferences {
+    pub references: Option<Vec<DataSourceObjectReference>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshDataSourceObjectExecutionStatus {
+    pub reference: Option<DataSourceObjectReference>,
+    pub data_execution_status: Option<DataExecutionStatus>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelDataSourceRefreshStatus {
+    pub reference: Option<DataSourceObjectReference>,
+    pub refresh_cancellation_status: Option<RefreshCancellationStatus>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshCancellationStatus {
+    pub state: Option<RefreshCancellationState>,
+    pub error_code: Option<RefreshCancellationErrorCode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RefreshCancellationState {
+    #[serde(rename = "REFRESH_CANCELLATION_STATE_UNSPECIFIED")]
+    Unspecified,
+    CancelSucceeded,
+    CancelFailed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RefreshCancellationErrorCode {
+    #[serde(rename = "REFRESH_CANCELLATION_ERROR_CODE_UNSPECIFIED")]
+    Unspecified,
+    ExecutionNotFound,
+    CancelPermissionDenied,
+    QueryExecutionCompleted,
+    ConcurrentCancellation,
+    CancelOtherError,
+}
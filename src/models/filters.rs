@@ -4,7 +4,7 @@ use super::data_source::DataSourceColumnReference;
 use super::grid::GridRange;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FilterView {
     pub filter_view_id: Option<i32>,
@@ -17,7 +17,7 @@ pub struct FilterView {
     pub filter_specs: Option<Vec<FilterSpec>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SortSpec {
     pub sort_order: Option<SortOrder>,
@@ -29,7 +29,7 @@ pub struct SortSpec {
     pub data_source_column_reference: Option<DataSourceColumnReference>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FilterCriteria {
     pub hidden_values: Option<Vec<String>>,
@@ -48,7 +48,7 @@ pub enum SortOrder {
     Descending,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FilterSpec {
     pub filter_criteria: Option<FilterCriteria>,
@@ -56,7 +56,7 @@ pub struct FilterSpec {
     pub data_source_column_reference: Option<DataSourceColumnReference>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BasicFilter {
     pub range: Option<GridRange>,
@@ -16,7 +16,7 @@ pub enum NumberFormatType {
     Scientific,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NumberFormat {
     #[serde(rename = "type")]
@@ -37,7 +37,7 @@ pub enum Style {
     Double,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Borders {
     pub top: Option<Border>,
@@ -46,7 +46,7 @@ pub struct Borders {
     pub right: Option<Border>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Border {
     pub style: Option<Style>,
@@ -55,7 +55,7 @@ pub struct Border {
     pub color_style: Option<ColorStyle>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Padding {
     pub top: Option<i32>,
@@ -64,7 +64,7 @@ pub struct Padding {
     pub left: Option<i32>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TextRotation {
     pub angle: Option<i32>,
@@ -115,7 +115,7 @@ pub enum HyperlinkDisplayType {
     PlainText,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TextFormat {
     pub foreground_color: Option<Color>,
@@ -129,13 +129,13 @@ pub struct TextFormat {
     pub link: Option<Link>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Link {
     pub uri: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TextFormatRun {
     pub start_index: Option<i32>,
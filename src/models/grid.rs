@@ -1,9 +1,12 @@
+use std::collections::BTreeSet;
+
+use super::cell::{CellAddress, ColIndex, RowIndex};
 use super::common::{DimensionProperties, RowData};
 use serde::{Deserialize, Serialize};
 
 /// The data in the grid of a sheet.
 /// This contains the actual cell data and metadata for rows and columns.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GridData {
     /// The row this GridData starts on (0-based).
@@ -18,28 +21,138 @@ pub struct GridData {
     pub column_metadata: Option<Vec<DimensionProperties>>,
 }
 
+/// The rows and columns of a sheet hidden in the UI.
+///
+/// Built from the row and column metadata of grid data, read with a fields mask
+/// such as `sheets.data(rowMetadata.hiddenByUser,columnMetadata.hiddenByUser)`.
+/// Rows hidden by the user and rows hidden by the basic filter of the sheet are
+/// collected separately, and combined with [`HiddenDimensions::union`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::{CellAddress, DimensionProperties, GridData, HiddenDimensions};
+///
+/// let hidden = DimensionProperties { hidden_by_user: Some(true), ..Default::default() };
+/// let grid = GridData {
+///     start_row: Some(0),
+///     row_metadata: Some(vec![Default::default(), hidden.clone()]),
+///     column_metadata: Some(vec![Default::default(), Default::default(), hidden]),
+///     ..Default::default()
+/// };
+/// let hidden = HiddenDimensions::from_grid_data(&grid);
+/// assert!(hidden.is_row_hidden(2));
+/// assert!(hidden.is_column_hidden(3));
+/// assert!(!hidden.is_cell_hidden(&CellAddress::new(1, 1)));
+/// assert!(hidden.is_cell_hidden(&CellAddress::new(1, 3)));
+///
+/// let filtered = DimensionProperties { hidden_by_filter: Some(true), ..Default::default() };
+/// let grid = GridData {
+///     row_metadata: Some(vec![Default::default(), Default::default(), filtered]),
+///     ..grid
+/// };
+/// let visible = HiddenDimensions::from_grid_data(&grid)
+///     .union(HiddenDimensions::filtered_from_grid_data(&grid));
+/// assert!(visible.is_row_hidden(3));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HiddenDimensions {
+    /// The hidden rows.
+    pub rows: BTreeSet<RowIndex>,
+    /// The hidden columns.
+    pub columns: BTreeSet<ColIndex>,
+}
+
+impl HiddenDimensions {
+    /// Collects the rows and columns hidden by the user in grid data.
+    pub fn from_grid_data(grid: &GridData) -> Self {
+        Self::collect(grid, |properties| properties.hidden_by_user)
+    }
+
+    /// Collects the rows and columns hidden by the basic filter in grid data.
+    pub fn filtered_from_grid_data(grid: &GridData) -> Self {
+        Self::collect(grid, |properties| properties.hidden_by_filter)
+    }
+
+    /// Returns the rows and columns hidden in either set.
+    pub fn union(mut self, other: Self) -> Self {
+        self.rows.extend(other.rows);
+        self.columns.extend(other.columns);
+        self
+    }
+
+    /// Collects the rows and columns whose metadata is flagged as hidden.
+    fn collect(grid: &GridData, hidden: fn(&DimensionProperties) -> Option<bool>) -> Self {
+        Self {
+            rows: hidden_indexes(&grid.row_metadata, grid.start_row, hidden)
+                .map(RowIndex::from_zero_based)
+                .collect(),
+            columns: hidden_indexes(&grid.column_metadata, grid.start_column, hidden)
+                .map(ColIndex::from_zero_based)
+                .collect(),
+        }
+    }
+
+    /// Returns whether a 1-based row is hidden.
+    pub fn is_row_hidden(&self, row: impl Into<RowIndex>) -> bool {
+        self.rows.contains(&row.into())
+    }
+
+    /// Returns whether a 1-based column is hidden.
+    pub fn is_column_hidden(&self, col: impl Into<ColIndex>) -> bool {
+        self.columns.contains(&col.into())
+    }
+
+    /// Returns whether a cell is in a hidden row or column.
+    pub fn is_cell_hidden(&self, address: &CellAddress) -> bool {
+        self.rows.contains(&address.row) || self.columns.contains(&address.col)
+    }
+
+    /// Returns whether no row or column is hidden.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty() && self.columns.is_empty()
+    }
+}
+
+/// Returns the 0-based indexes of the dimensions flagged as hidden, from the
+/// metadata of the dimensions starting at `start`.
+fn hidden_indexes(
+    metadata: &Option<Vec<DimensionProperties>>,
+    start: Option<i32>,
+    hidden: fn(&DimensionProperties) -> Option<bool>,
+) -> impl Iterator<Item = usize> + '_ {
+    let start = start
+        .and_then(|start| usize::try_from(start).ok())
+        .unwrap_or(0);
+    metadata
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter(move |(_, properties)| hidden(properties) == Some(true))
+        .map(move |(i, _)| start + i)
+}
+
 /// A range on a sheet.
 /// All indexes are zero-based.
 /// Indexes are half open: the start index is inclusive and the end index is exclusive.
 /// Missing indexes indicate the range is unbounded on that side.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GridRange {
     /// The sheet this range is on.
     pub sheet_id: Option<i32>,
     /// The start row (inclusive) of the range, or not set if unbounded.
-    pub start_row_index: usize,
+    pub start_row_index: Option<i32>,
     /// The end row (exclusive) of the range, or not set if unbounded.
-    pub end_row_index: usize,
+    pub end_row_index: Option<i32>,
     /// The start column (inclusive) of the range, or not set if unbounded.
-    pub start_column_index: usize,
+    pub start_column_index: Option<i32>,
     /// The end column (exclusive) of the range, or not set if unbounded.
-    pub end_column_index: usize,
+    pub end_column_index: Option<i32>,
 }
 
 /// Properties of a grid.
 /// These properties define the structure and appearance of the grid.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GridProperties {
     /// The number of rows in the grid.
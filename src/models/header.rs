@@ -0,0 +1,146 @@
+//! Header row models.
+//!
+//! This module contains [`Header`], the column names of a sheet read from its
+//! header row, used to address columns by name rather than by position.
+
+use crate::error::GSheetError;
+use crate::utils::col_index_to_a1;
+
+/// The separator joining the names of a column spanning several header rows.
+pub const HEADER_SEPARATOR: &str = " / ";
+
+/// The header row of a sheet, naming its columns.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::Header;
+///
+/// let header = Header::new(1, vec!["Name".to_string(), "Email".to_string()]);
+/// assert_eq!(header.column_index_by_header("Email"), Some(1));
+/// assert_eq!(header.column_a1("Email").unwrap(), Some("B".to_string()));
+/// assert_eq!(header.column_index_by_header("Phone"), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Header {
+    /// The 1-based row holding the header, or its last row for headers spanning
+    /// several rows.
+    pub row: usize,
+    /// The column names, from the first column of the sheet.
+    pub columns: Vec<String>,
+}
+
+impl Header {
+    /// Creates a new header.
+    ///
+    /// # Arguments
+    /// * `row` - The 1-based row holding the header
+    /// * `columns` - The column names, from the first column of the sheet
+    ///
+    /// # Returns
+    /// A new [`Header`] instance.
+    pub fn new(row: usize, columns: Vec<String>) -> Self {
+        Self { row, columns }
+    }
+
+    /// Returns the 0-based position of the column with the given name.
+    ///
+    /// Names are compared after trimming surrounding whitespace. When several
+    /// columns share a name, the first one is returned.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the column
+    ///
+    /// # Returns
+    /// The position of the column in a row of values, or `None` if there is no
+    /// such column.
+    pub fn column_index_by_header(&self, name: &str) -> Option<usize> {
+        let name = name.trim();
+        self.columns.iter().position(|column| column.trim() == name)
+    }
+
+    /// Returns the A1 letter of the column with the given name (e.g., "B").
+    ///
+    /// # Arguments
+    /// * `name` - The name of the column
+    ///
+    /// # Returns
+    /// A `Result` containing the column letter, `None` if there is no such column,
+    /// or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the column is beyond the last column
+    /// of a sheet.
+    pub fn column_a1(&self, name: &str) -> Result<Option<String>, GSheetError> {
+        self.column_index_by_header(name)
+            .map(|index| col_index_to_a1(index + 1))
+            .transpose()
+    }
+
+    /// Combines header rows into a single header, for headers spanning several rows.
+    ///
+    /// The names of a column are joined with [`HEADER_SEPARATOR`] from the top row
+    /// down, skipping empty cells. A group name on an upper row applies to the
+    /// columns on its right until the next group name, as merged group cells only
+    /// hold their value in their first cell.
+    ///
+    /// # Arguments
+    /// * `first_row` - The 1-based row of the first header row
+    /// * `rows` - The header rows, from top to bottom
+    ///
+    /// # Returns
+    /// A [`Header`] whose row is the last header row.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gsheet_api::models::Header;
+    ///
+    /// let rows = vec![
+    ///     vec!["".to_string(), "Contact".to_string(), "".to_string()],
+    ///     vec!["Name".to_string(), "Email".to_string(), "Phone".to_string()],
+    /// ];
+    /// let header = Header::from_rows(1, &rows);
+    /// assert_eq!(header.row, 2);
+    /// assert_eq!(header.columns, vec!["Name", "Contact / Email", "Contact / Phone"]);
+    /// ```
+    pub fn from_rows(first_row: usize, rows: &[Vec<String>]) -> Self {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut columns = vec![Vec::new(); width];
+        for (index, row) in rows.iter().enumerate() {
+            let is_last = index + 1 == rows.len();
+            let mut group: Option<&str> = None;
+            for (col, names) in columns.iter_mut().enumerate() {
+                let name = row.get(col).map(|name| name.trim()).unwrap_or_default();
+                if !name.is_empty() {
+                    group = Some(name);
+                }
+                // Column names on the last row only apply to their own column.
+                let label = if is_last {
+                    Some(name).filter(|name| !name.is_empty())
+                } else {
+                    group
+                };
+                if let Some(label) = label {
+                    names.push(label.to_string());
+                }
+            }
+        }
+
+        Self {
+            row: first_row + rows.len().max(1) - 1,
+            columns: columns
+                .into_iter()
+                .map(|names| names.join(HEADER_SEPARATOR))
+                .collect(),
+        }
+    }
+
+    /// Returns the 1-based row of the first data row, right below the header.
+    pub fn data_start_row(&self) -> usize {
+        self.row + 1
+    }
+
+    /// Returns whether the header has no named column.
+    pub fn is_empty(&self) -> bool {
+        self.columns.iter().all(|column| column.trim().is_empty())
+    }
+}
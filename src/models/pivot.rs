@@ -0,0 +1,215 @@
+use super::common::{DataExecutionStatus, ExtendedValue};
+use super::conditions::BooleanCondition;
+use super::data_source::DataSourceColumnReference;
+use super::filters::SortOrder;
+use super::grid::GridRange;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A pivot table, stored in the top-left cell of its output.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PivotTable {
+    /// Each row grouping in the pivot table.
+    pub rows: Option<Vec<PivotGroup>>,
+    /// Each column grouping in the pivot table.
+    pub columns: Option<Vec<PivotGroup>>,
+    /// The filters by source column offset. Deprecated in favor of `filter_specs`.
+    pub criteria: Option<HashMap<String, PivotFilterCriteria>>,
+    /// The filters applied to the source columns before aggregating data.
+    pub filter_specs: Option<Vec<PivotFilterSpec>>,
+    /// A list of values to include in the pivot table.
+    pub values: Option<Vec<PivotValue>>,
+    /// Whether values should be listed horizontally (as columns) or vertically (as rows).
+    pub value_layout: Option<PivotValueLayout>,
+    /// The data execution status, for data source pivot tables.
+    pub data_execution_status: Option<DataExecutionStatus>,
+    /// The range the pivot table is reading data from.
+    pub source: Option<GridRange>,
+    /// The ID of the data source the pivot table is reading data from.
+    pub data_source_id: Option<String>,
+}
+
+/// A single grouping (either row or column) in a pivot table.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PivotGroup {
+    /// The column offset of the source range that this grouping is based on.
+    pub source_column_offset: Option<i32>,
+    /// The reference to the data source column this grouping is based on.
+    pub data_source_column_reference: Option<DataSourceColumnReference>,
+    /// True if the pivot table should include the totals for this grouping.
+    pub show_totals: Option<bool>,
+    /// Metadata about values in the grouping.
+    pub value_metadata: Option<Vec<PivotGroupValueMetadata>>,
+    /// The order the values in this group should be sorted.
+    pub sort_order: Option<SortOrder>,
+    /// The bucket of the opposite pivot group to sort by.
+    pub value_bucket: Option<PivotGroupSortValueBucket>,
+    /// True if the headings in this pivot group should be repeated.
+    pub repeat_headings: Option<bool>,
+    /// The labels to use for the row/column groups.
+    pub label: Option<String>,
+    /// The group rule to apply to this row/column group.
+    pub group_rule: Option<PivotGroupRule>,
+    /// The count limit on rows or columns to apply to this pivot group.
+    pub group_limit: Option<PivotGroupLimit>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PivotGroupValueMetadata {
+    pub value: Option<ExtendedValue>,
+    pub collapsed: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PivotGroupSortValueBucket {
+    pub values_index: Option<i32>,
+    pub buckets: Option<Vec<ExtendedValue>>,
+}
+
+/// A rule grouping the values of a pivot group, by hand, by numeric interval
+/// or by date-time part. Only one rule is set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PivotGroupRule {
+    pub manual_rule: Option<ManualRule>,
+    pub histogram_rule: Option<HistogramRule>,
+    pub date_time_rule: Option<DateTimeRule>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualRule {
+    pub groups: Option<Vec<ManualRuleGroup>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualRuleGroup {
+    pub group_name: Option<ExtendedValue>,
+    pub items: Option<Vec<ExtendedValue>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistogramRule {
+    pub interval: Option<f64>,
+    pub start: Option<f64>,
+    pub end: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DateTimeRule {
+    #[serde(rename = "type")]
+    pub rule_type: Option<DateTimeRuleType>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DateTimeRuleType {
+    #[serde(rename = "DATE_TIME_RULE_TYPE_UNSPECIFIED")]
+    Unspecified,
+    Second,
+    Minute,
+    Hour,
+    HourMinute,
+    HourMinuteAmpm,
+    DayOfWeek,
+    DayOfYear,
+    DayOfMonth,
+    DayMonth,
+    Month,
+    Quarter,
+    Year,
+    YearMonth,
+    YearQuarter,
+    YearMonthDay,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PivotGroupLimit {
+    pub count_limit: Option<i32>,
+    pub apply_order: Option<i32>,
+}
+
+/// Criteria for showing or hiding rows in a pivot table.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PivotFilterCriteria {
+    /// Values that should be included. Values not listed here are excluded.
+    pub visible_values: Option<Vec<String>>,
+    /// A condition that must be true for values to be shown.
+    pub condition: Option<BooleanCondition>,
+    /// Whether values are visible by default.
+    pub visible_by_default: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PivotFilterSpec {
+    pub filter_criteria: Option<PivotFilterCriteria>,
+    pub column_offset_index: Option<i32>,
+    pub data_source_column_reference: Option<DataSourceColumnReference>,
+}
+
+/// The definition of how a value in a pivot table should be calculated.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PivotValue {
+    /// The function to summarize the value.
+    pub summarize_function: Option<PivotValueSummarizeFunction>,
+    /// A name to use for the value.
+    pub name: Option<String>,
+    /// How the value should be displayed, relative to other values.
+    pub calculated_display_type: Option<PivotValueCalculatedDisplayType>,
+    /// The column offset of the source range that this value reads from.
+    pub source_column_offset: Option<i32>,
+    /// A custom formula to calculate the value.
+    pub formula: Option<String>,
+    /// The reference to the data source column this value reads from.
+    pub data_source_column_reference: Option<DataSourceColumnReference>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PivotValueSummarizeFunction {
+    #[serde(rename = "PIVOT_STANDARD_VALUE_FUNCTION_UNSPECIFIED")]
+    Unspecified,
+    Sum,
+    Counta,
+    Count,
+    Countunique,
+    Average,
+    Max,
+    Min,
+    Median,
+    Product,
+    Stdev,
+    Stdevp,
+    Var,
+    Varp,
+    Custom,
+    None,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PivotValueCalculatedDisplayType {
+    #[serde(rename = "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED")]
+    Unspecified,
+    PercentOfRowTotal,
+    PercentOfColumnTotal,
+    PercentOfGrandTotal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PivotValueLayout {
+    Horizontal,
+    Vertical,
+}
@@ -1,10 +1,10 @@
-use super::common::Editors;
+use super::common::{DeveloperMetadataLookup, Editors};
 use super::grid::GridRange;
 use serde::{Deserialize, Serialize};
 
 /// A named range.
 /// Named ranges are ranges that have associated names.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NamedRange {
     /// The ID of the named range.
@@ -15,9 +15,87 @@ pub struct NamedRange {
     pub range: Option<GridRange>,
 }
 
+/// Selects the data of a spreadsheet by A1 range, grid range or developer metadata.
+///
+/// Filters are accepted by the operations reading data by filter, and convert
+/// from A1 ranges, grid ranges and developer metadata lookups.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::{DataFilter, GridRange};
+///
+/// let filter = DataFilter::from("Sheet1!A1:B2");
+/// assert_eq!(
+///     serde_json::to_string(&filter).unwrap(),
+///     r#"{"a1Range":"Sheet1!A1:B2"}"#
+/// );
+///
+/// let filter = DataFilter::from(GridRange {
+///     sheet_id: Some(0),
+///     ..Default::default()
+/// });
+/// assert!(matches!(filter, DataFilter::GridRange(_)));
+///
+/// let filter = DataFilter::developer_metadata("config", "thresholds");
+/// assert_eq!(
+///     serde_json::to_string(&filter).unwrap(),
+///     r#"{"developerMetadataLookup":{"metadataKey":"config","metadataValue":"thresholds"}}"#
+/// );
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DataFilter {
+    /// Selects the data of a range in A1 notation.
+    A1Range(String),
+    /// Selects the data of a grid range.
+    GridRange(GridRange),
+    /// Selects the data of the locations the matching developer metadata is associated with.
+    DeveloperMetadataLookup(DeveloperMetadataLookup),
+}
+
+impl DataFilter {
+    /// Creates a filter selecting the locations tagged with a developer metadata
+    /// key and value.
+    ///
+    /// # Arguments
+    /// * `key` - The metadata key
+    /// * `value` - The metadata value
+    pub fn developer_metadata(key: &str, value: &str) -> Self {
+        Self::DeveloperMetadataLookup(DeveloperMetadataLookup {
+            metadata_key: Some(key.to_string()),
+            metadata_value: Some(value.to_string()),
+            ..Default::default()
+        })
+    }
+}
+
+impl From<&str> for DataFilter {
+    fn from(range: &str) -> Self {
+        Self::A1Range(range.to_string())
+    }
+}
+
+impl From<String> for DataFilter {
+    fn from(range: String) -> Self {
+        Self::A1Range(range)
+    }
+}
+
+impl From<GridRange> for DataFilter {
+    fn from(range: GridRange) -> Self {
+        Self::GridRange(range)
+    }
+}
+
+impl From<DeveloperMetadataLookup> for DataFilter {
+    fn from(lookup: DeveloperMetadataLookup) -> Self {
+        Self::DeveloperMetadataLookup(lookup)
+    }
+}
+
 /// A protected range.
 /// Protected ranges restrict editing to specific users or groups.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProtectedRange {
     /// The ID of the protected range.
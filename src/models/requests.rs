@@ -0,0 +1,677 @@
+//! Batch update request models.
+//!
+//! This module contains the requests that can be sent to the spreadsheets
+//! `batchUpdate` endpoint. Each [`Request`] applies a single change, and the
+//! requests of a batch are applied atomically in order.
+
+use super::cell::CellData;
+use super::charts::EmbeddedChart;
+use super::common::{
+    DeveloperMetadata, DimensionRange, EmbeddedObjectPosition, GridCoordinate, RowData,
+};
+use super::conditions::ConditionalFormatRule;
+use super::data_source::{DataSource, DataSourceObjectReferences};
+use super::filters::FilterView;
+use super::formatting::Border;
+use super::grid::GridRange;
+use super::range::{NamedRange, ProtectedRange};
+use super::sheet::{BandedRange, SheetProperties, Slicer, Table};
+use super::spreadsheet::SpreadsheetProperties;
+use super::value::Dimension;
+use serde::{Deserialize, Serialize};
+
+/// The body of a spreadsheets `batchUpdate` call.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchUpdateSpreadsheetRequest {
+    /// The requests to apply, in order.
+    pub requests: Vec<Request>,
+    /// Whether the updated spreadsheet should be included in the response.
+    pub include_spreadsheet_in_response: bool,
+    /// Limits the ranges included in the response spreadsheet.
+    pub response_ranges: Vec<String>,
+    /// Whether grid data should be included in the response spreadsheet.
+    pub response_include_grid_data: bool,
+}
+
+/// A single kind of update to apply to a spreadsheet.
+///
+/// Requests are serialized as an object with a single key naming the request,
+/// such as `{"addSheet": {...}}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::large_enum_variant)]
+pub enum Request {
+    /// Updates the properties of the spreadsheet.
+    UpdateSpreadsheetProperties(UpdateSpreadsheetPropertiesRequest),
+    /// Updates the properties of a sheet.
+    UpdateSheetProperties(UpdateSheetPropertiesRequest),
+    /// Adds a new sheet.
+    AddSheet(AddSheetRequest),
+    /// Deletes a sheet.
+    DeleteSheet(DeleteSheetRequest),
+    /// Duplicates a sheet.
+    DuplicateSheet(DuplicateSheetRequest),
+    /// Adds a named range.
+    AddNamedRange(AddNamedRangeRequest),
+    /// Deletes a named range.
+    DeleteNamedRange(DeleteNamedRangeRequest),
+    /// Adds a protected range.
+    AddProtectedRange(AddProtectedRangeRequest),
+    /// Deletes a protected range.
+    DeleteProtectedRange(DeleteProtectedRangeRequest),
+    /// Finds and replaces occurrences of text.
+    FindReplace(FindReplaceRequest),
+    /// Updates the cells of a range with new data.
+    UpdateCells(UpdateCellsRequest),
+    /// Updates all cells of a range to the values in the given cell.
+    RepeatCell(RepeatCellRequest),
+    /// Copies data from a source range to a destination range.
+    CopyPaste(CopyPasteRequest),
+    /// Inserts empty cells in a range, shifting the existing cells away.
+    InsertRange(InsertRangeRequest),
+    /// Deletes the cells of a range, shifting the remaining cells in.
+    DeleteRange(DeleteRangeRequest),
+    /// Moves rows or columns to another position of the sheet.
+    MoveDimension(MoveDimensionRequest),
+    /// Appends rows or columns to the end of a sheet.
+    AppendDimension(AppendDimensionRequest),
+    /// Deletes rows or columns of a sheet.
+    DeleteDimension(DeleteDimensionRequest),
+    /// Shuffles the rows of a range.
+    RandomizeRange(RandomizeRangeRequest),
+    /// Updates the borders of a range.
+    UpdateBorders(UpdateBordersRequest),
+    /// Resizes rows or columns to fit their contents.
+    AutoResizeDimensions(AutoResizeDimensionsRequest),
+    /// Adds a chart.
+    AddChart(AddChartRequest),
+    /// Deletes an embedded object, such as a chart.
+    DeleteEmbeddedObject(DeleteEmbeddedObjectRequest),
+    /// Moves or resizes an embedded object, such as a chart.
+    UpdateEmbeddedObjectPosition(UpdateEmbeddedObjectPositionRequest),
+    /// Adds a filter view.
+    AddFilterView(AddFilterViewRequest),
+    /// Duplicates a filter view.
+    DuplicateFilterView(DuplicateFilterViewRequest),
+    /// Deletes a filter view.
+    DeleteFilterView(DeleteFilterViewRequest),
+    /// Adds a conditional format rule.
+    AddConditionalFormatRule(AddConditionalFormatRuleRequest),
+    /// Updates or moves a conditional format rule.
+    UpdateConditionalFormatRule(UpdateConditionalFormatRuleRequest),
+    /// Deletes a conditional format rule.
+    DeleteConditionalFormatRule(DeleteConditionalFormatRuleRequest),
+    /// Adds a banded range.
+    AddBanding(AddBandingRequest),
+    /// Deletes a banded range.
+    DeleteBanding(DeleteBandingRequest),
+    /// Creates developer metadata.
+    CreateDeveloperMetadata(CreateDeveloperMetadataRequest),
+    /// Creates a group over a dimension range.
+    AddDimensionGroup(AddDimensionGroupRequest),
+    /// Deletes a group over a dimension range.
+    DeleteDimensionGroup(DeleteDimensionGroupRequest),
+    /// Trims the whitespace of the cells in a range.
+    TrimWhitespace(TrimWhitespaceRequest),
+    /// Removes rows that are duplicates of earlier rows in a range.
+    DeleteDuplicates(DeleteDuplicatesRequest),
+    /// Adds a slicer.
+    AddSlicer(AddSlicerRequest),
+    /// Adds a table.
+    AddTable(AddTableRequest),
+    /// Updates a table.
+    UpdateTable(UpdateTableRequest),
+    /// Deletes a table.
+    DeleteTable(DeleteTableRequest),
+    /// Adds a data source.
+    AddDataSource(AddDataSourceRequest),
+    /// Updates a data source.
+    UpdateDataSource(UpdateDataSourceRequest),
+    /// Deletes a data source.
+    DeleteDataSource(DeleteDataSourceRequest),
+    /// Refreshes data source objects.
+    RefreshDataSource(RefreshDataSourceRequest),
+}
+
+/// Updates the properties of the spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSpreadsheetPropertiesRequest {
+    /// The properties to update.
+    pub properties: SpreadsheetProperties,
+    /// The fields that should be updated, as a comma separated field mask.
+    pub fields: String,
+}
+
+/// Updates the properties of the sheet with the specified sheet ID.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSheetPropertiesRequest {
+    /// The properties to update, including the ID of the sheet.
+    pub properties: SheetProperties,
+    /// The fields that should be updated, as a comma separated field mask.
+    pub fields: String,
+}
+
+/// Adds a new sheet. When a sheet ID is not set, one is chosen by the API.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddSheetRequest {
+    /// The properties the new sheet should have.
+    pub properties: SheetProperties,
+}
+
+/// Deletes the requested sheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteSheetRequest {
+    /// The ID of the sheet to delete.
+    pub sheet_id: i32,
+}
+
+/// Duplicates the contents of a sheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateSheetRequest {
+    /// The sheet to duplicate.
+    pub source_sheet_id: i32,
+    /// The zero-based index where the new sheet should be inserted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insert_sheet_index: Option<i32>,
+    /// The ID of the new sheet, chosen by the API if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_sheet_id: Option<i32>,
+    /// The name of the new sheet, chosen by the API if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_sheet_name: Option<String>,
+}
+
+/// Adds a named range to the spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddNamedRangeRequest {
+    /// The named range to add. The ID is chosen by the API if not set.
+    pub named_range: NamedRange,
+}
+
+/// Removes the named range with the given ID from the spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteNamedRangeRequest {
+    /// The ID of the named range to delete.
+    pub named_range_id: String,
+}
+
+/// Adds a new protected range.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddProtectedRangeRequest {
+    /// The protected range to add. The ID is chosen by the API if not set.
+    pub protected_range: ProtectedRange,
+}
+
+/// Deletes the protected range with the given ID.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteProtectedRangeRequest {
+    /// The ID of the protected range to delete.
+    pub protected_range_id: i32,
+}
+
+/// Finds and replaces data in cells over a range, sheet, or all sheets.
+///
+/// Exactly one of `range`, `sheet_id` or `all_sheets` should be set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindReplaceRequest {
+    /// The value to search.
+    pub find: String,
+    /// The value to use as the replacement.
+    pub replacement: String,
+    /// True if the search is case sensitive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_case: Option<bool>,
+    /// True if the find value should match the entire cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_entire_cell: Option<bool>,
+    /// True if the find value is a regex.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_by_regex: Option<bool>,
+    /// True if the search should include cells with formulas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_formulas: Option<bool>,
+    /// The range to find/replace over.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<GridRange>,
+    /// The sheet to find/replace over.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sheet_id: Option<i32>,
+    /// True to find/replace over all sheets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_sheets: Option<bool>,
+}
+
+/// Updates the cells of a range with new data.
+///
+/// Exactly one of `start` or `range` should be set. Only the fields listed in
+/// the field mask are written, the others are left untouched.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCellsRequest {
+    /// The data to write, one row per entry.
+    pub rows: Vec<RowData>,
+    /// The fields of the cells that should be updated, as a comma separated field mask.
+    pub fields: String,
+    /// The coordinate to start writing data at.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<GridCoordinate>,
+    /// The range to write data to. Cells of the range without data are cleared.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<GridRange>,
+}
+
+/// Updates all cells of a range to the values in the given cell.
+///
+/// Only the fields listed in the field mask are written, the others are left
+/// untouched.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepeatCellRequest {
+    /// The range to repeat the cell in.
+    pub range: GridRange,
+    /// The data to write.
+    pub cell: CellData,
+    /// The fields of the cell that should be updated, as a comma separated field mask.
+    pub fields: String,
+}
+
+/// What kind of data is pasted by a [`CopyPasteRequest`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PasteType {
+    /// Values, formulas, formats and merges.
+    #[default]
+    PasteNormal,
+    /// The computed values only, without formats, formulas or merges.
+    PasteValues,
+    /// The format and data validation only.
+    PasteFormat,
+    /// Like `PasteNormal`, but without borders.
+    PasteNoBorders,
+    /// The formulas only.
+    PasteFormula,
+    /// The data validation only.
+    PasteDataValidation,
+    /// The conditional formatting rules only.
+    PasteConditionalFormatting,
+}
+
+/// How data is oriented when pasting.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PasteOrientation {
+    /// Paste normally.
+    #[default]
+    Normal,
+    /// Paste transposed, rows becoming columns.
+    Transpose,
+}
+
+/// The direction cells are shifted in when a range is inserted or deleted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ShiftDimension {
+    /// Cells are shifted down on insertion and up on deletion.
+    #[default]
+    Rows,
+    /// Cells are shifted right on insertion and left on deletion.
+    Columns,
+}
+
+/// Inserts empty cells in a range, shifting the existing cells of the range
+/// and beyond down or right.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsertRangeRequest {
+    /// The range to insert empty cells in.
+    pub range: GridRange,
+    /// The dimension the existing cells are shifted along.
+    pub shift_dimension: ShiftDimension,
+}
+
+/// Deletes the cells of a range, shifting the cells beyond it up or left.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteRangeRequest {
+    /// The range of the cells to delete.
+    pub range: GridRange,
+    /// The dimension the remaining cells are shifted along.
+    pub shift_dimension: ShiftDimension,
+}
+
+/// Moves one or more rows or columns.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveDimensionRequest {
+    /// The rows or columns to move.
+    pub source: DimensionRange,
+    /// The zero-based index the rows or columns are moved to, in the
+    /// coordinates before the move.
+    pub destination_index: i32,
+}
+
+/// Appends empty rows or columns to the end of a sheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppendDimensionRequest {
+    /// The ID of the sheet to append rows or columns to.
+    pub sheet_id: i32,
+    /// Whether rows or columns should be appended.
+    pub dimension: Dimension,
+    /// The number of rows or columns to append.
+    pub length: i32,
+}
+
+/// Deletes rows or columns, shifting the following ones back.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDimensionRequest {
+    /// The rows or columns to delete.
+    pub range: DimensionRange,
+}
+
+/// Updates the borders of a range. Borders that are not set are left
+/// unchanged; a border with the `NONE` style removes it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBordersRequest {
+    /// The range whose borders should be updated.
+    pub range: GridRange,
+    /// The border to put at the top of the range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<Border>,
+    /// The border to put at the bottom of the range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bottom: Option<Border>,
+    /// The border to put at the left of the range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left: Option<Border>,
+    /// The border to put at the right of the range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub right: Option<Border>,
+    /// The horizontal border to put between the rows of the range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inner_horizontal: Option<Border>,
+    /// The vertical border to put between the columns of the range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inner_vertical: Option<Border>,
+}
+
+/// Automatically resizes one or more rows or columns to fit their contents.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoResizeDimensionsRequest {
+    /// The rows or columns to resize.
+    pub dimensions: DimensionRange,
+}
+
+/// Randomizes the order of the rows in a range.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RandomizeRangeRequest {
+    /// The range to randomize.
+    pub range: GridRange,
+}
+
+/// Copies data from a source range to a destination range of the same
+/// spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyPasteRequest {
+    /// The range to copy from.
+    pub source: GridRange,
+    /// The range to paste to. If it covers a multiple of the height or width of
+    /// the source, the data is repeated; a single cell receives the whole source.
+    pub destination: GridRange,
+    /// What kind of data to paste.
+    pub paste_type: PasteType,
+    /// How the data is oriented when pasting.
+    pub paste_orientation: PasteOrientation,
+}
+
+/// Adds a chart to a sheet in the spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddChartRequest {
+    /// The chart to add. The ID is chosen by the API if not set.
+    pub chart: EmbeddedChart,
+}
+
+/// Deletes the embedded object with the given ID.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteEmbeddedObjectRequest {
+    /// The ID of the embedded object to delete.
+    pub object_id: i32,
+}
+
+/// Updates the position of an embedded object, such as moving or resizing a chart.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEmbeddedObjectPositionRequest {
+    /// The ID of the object to move.
+    pub object_id: i32,
+    /// The new position of the object.
+    pub new_position: EmbeddedObjectPosition,
+    /// The fields of `new_position` that should be updated, as a comma separated
+    /// field mask (e.g. "overlayPosition.anchorCell").
+    pub fields: String,
+}
+
+/// Adds a filter view.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddFilterViewRequest {
+    /// The filter to add. The ID is chosen by the API if not set.
+    pub filter: FilterView,
+}
+
+/// Duplicates a particular filter view.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateFilterViewRequest {
+    /// The ID of the filter being duplicated.
+    pub filter_id: i32,
+}
+
+/// Deletes a particular filter view.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteFilterViewRequest {
+    /// The ID of the filter to delete.
+    pub filter_id: i32,
+}
+
+/// Adds a new conditional format rule at the given index.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddConditionalFormatRuleRequest {
+    /// The rule to add.
+    pub rule: ConditionalFormatRule,
+    /// The zero-based index where the rule should be inserted.
+    pub index: i32,
+}
+
+/// Updates a conditional format rule at the given index, or moves it to another index.
+///
+/// Either `rule` or `new_index` should be set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateConditionalFormatRuleRequest {
+    /// The zero-based index of the rule that should be replaced or moved.
+    pub index: i32,
+    /// The sheet of the rule to move. Required if `new_index` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sheet_id: Option<i32>,
+    /// The rule that should replace the rule at the given index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<ConditionalFormatRule>,
+    /// The zero-based new index the rule should end up at.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_index: Option<i32>,
+}
+
+/// Deletes a conditional format rule at the given index.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteConditionalFormatRuleRequest {
+    /// The zero-based index of the rule to be deleted.
+    pub index: i32,
+    /// The sheet the rule is being deleted from.
+    pub sheet_id: i32,
+}
+
+/// Adds a new banded range to the spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddBandingRequest {
+    /// The banded range to add. The ID is chosen by the API if not set.
+    pub banded_range: BandedRange,
+}
+
+/// Removes the banded range with the given ID from the spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteBandingRequest {
+    /// The ID of the banded range to delete.
+    pub banded_range_id: i32,
+}
+
+/// Creates developer metadata.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDeveloperMetadataRequest {
+    /// The developer metadata to create.
+    pub developer_metadata: DeveloperMetadata,
+}
+
+/// Creates a group over the specified range.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddDimensionGroupRequest {
+    /// The range over which to create a group.
+    pub range: DimensionRange,
+}
+
+/// Deletes a group over the specified range by decrementing the depth of the dimensions in it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDimensionGroupRequest {
+    /// The range of the group to be deleted.
+    pub range: DimensionRange,
+}
+
+/// Trims the whitespace (such as spaces, tabs, or new lines) in every cell in the specified range.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrimWhitespaceRequest {
+    /// The range whose cells to trim.
+    pub range: GridRange,
+}
+
+/// Removes rows within this range that contain values in the specified columns
+/// that are duplicates of values in any previous row.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDuplicatesRequest {
+    /// The range to remove duplicates rows from.
+    pub range: GridRange,
+    /// The columns in the range to analyze for duplicate values.
+    /// If no columns are selected then all columns are analyzed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub comparison_columns: Vec<DimensionRange>,
+}
+
+/// Adds a slicer to a sheet in the spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddSlicerRequest {
+    /// The slicer to add. The ID is chosen by the API if not set.
+    pub slicer: Slicer,
+}
+
+/// Adds a new table to the spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddTableRequest {
+    /// The table to add. The ID is chosen by the API if not set.
+    pub table: Table,
+}
+
+/// Updates a table in the spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTableRequest {
+    /// The table to update, including its ID.
+    pub table: Table,
+    /// The fields that should be updated, as a comma separated field mask.
+    pub fields: String,
+}
+
+/// Removes the table with the given ID from the spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteTableRequest {
+    /// The ID of the table to delete.
+    pub table_id: String,
+}
+
+/// Adds a data source, such as a BigQuery table or a Looker explore.
+///
+/// A data source sheet is added along with the data source, and an execution is
+/// triggered to refresh it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddDataSourceRequest {
+    /// The data source to add.
+    pub data_source: DataSource,
+}
+
+/// Updates a data source.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDataSourceRequest {
+    /// The data source to update, including its ID.
+    pub data_source: DataSource,
+    /// The fields that should be updated, as a comma separated field mask.
+    pub fields: String,
+}
+
+/// Deletes a data source, along with its data source sheet and the objects linked to it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDataSourceRequest {
+    /// The ID of the data source to delete.
+    pub data_source_id: String,
+}
+
+/// Refreshes one or more data source objects.
+///
+/// Exactly one of `references`, `data_source_id` or `is_all` should be set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshDataSourceRequest {
+    /// References to the data source objects to refresh.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub references: Option<DataSourceObjectReferences>,
+    /// The ID of the data source whose objects should all be refreshed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_source_id: Option<String>,
+    /// Refreshes all existing data source objects in the spreadsheet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_all: Option<bool>,
+    /// Refreshes the data source objects regardless of their current state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+}
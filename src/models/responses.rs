@@ -0,0 +1,327 @@
+//! Batch update response models.
+//!
+//! This module contains the replies returned by the spreadsheets `batchUpdate`
+//! endpoint. The API returns one reply per request, in the same order as the
+//! requests, so `replies[i]` always belongs to `requests[i]`.
+
+use super::charts::EmbeddedChart;
+use super::common::{DataExecutionStatus, DeveloperMetadata, EmbeddedObjectPosition};
+use super::conditions::ConditionalFormatRule;
+use super::data_source::{
+    CancelDataSourceRefreshStatus, DataSource, RefreshDataSourceObjectExecutionStatus,
+};
+use super::filters::FilterView;
+use super::range::{NamedRange, ProtectedRange};
+use super::sheet::{BandedRange, DimensionGroup, SheetProperties, Slicer, Table};
+use super::spreadsheet::Spreadsheet;
+use serde::{Deserialize, Serialize};
+
+/// The response of a spreadsheets `batchUpdate` call.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchUpdateSpreadsheetResponse {
+    /// The spreadsheet the updates were applied to.
+    pub spreadsheet_id: String,
+    /// The reply of each request, in the same order as the requests.
+    #[serde(default)]
+    pub replies: Vec<Response>,
+    /// The spreadsheet after updates were applied, if requested.
+    pub updated_spreadsheet: Option<Spreadsheet>,
+}
+
+/// A single reply to a batch update request.
+///
+/// Requests that produce no data get an [`Response::Empty`] reply, so replies can
+/// be matched to requests by position. Replies of a kind this crate does not know
+/// are kept as raw JSON in [`Response::Other`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::large_enum_variant)]
+pub enum Response {
+    /// A reply from adding a named range.
+    AddNamedRange(AddNamedRangeResponse),
+    /// A reply from adding a sheet.
+    AddSheet(AddSheetResponse),
+    /// A reply from adding a filter view.
+    AddFilterView(AddFilterViewResponse),
+    /// A reply from duplicating a filter view.
+    DuplicateFilterView(DuplicateFilterViewResponse),
+    /// A reply from duplicating a sheet.
+    DuplicateSheet(DuplicateSheetResponse),
+    /// A reply from doing a find/replace.
+    FindReplace(FindReplaceResponse),
+    /// A reply from updating an embedded object's position.
+    UpdateEmbeddedObjectPosition(UpdateEmbeddedObjectPositionResponse),
+    /// A reply from updating a conditional format rule.
+    UpdateConditionalFormatRule(UpdateConditionalFormatRuleResponse),
+    /// A reply from deleting a conditional format rule.
+    DeleteConditionalFormatRule(DeleteConditionalFormatRuleResponse),
+    /// A reply from adding a protected range.
+    AddProtectedRange(AddProtectedRangeResponse),
+    /// A reply from adding a chart.
+    AddChart(AddChartResponse),
+    /// A reply from adding a banded range.
+    AddBanding(AddBandingResponse),
+    /// A reply from creating a developer metadata entry.
+    CreateDeveloperMetadata(CreateDeveloperMetadataResponse),
+    /// A reply from updating developer metadata entries.
+    UpdateDeveloperMetadata(UpdateDeveloperMetadataResponse),
+    /// A reply from deleting developer metadata entries.
+    DeleteDeveloperMetadata(DeleteDeveloperMetadataResponse),
+    /// A reply from adding a dimension group.
+    AddDimensionGroup(AddDimensionGroupResponse),
+    /// A reply from deleting a dimension group.
+    DeleteDimensionGroup(DeleteDimensionGroupResponse),
+    /// A reply from trimming whitespace.
+    TrimWhitespace(TrimWhitespaceResponse),
+    /// A reply from removing rows containing duplicate values.
+    DeleteDuplicates(DeleteDuplicatesResponse),
+    /// A reply from adding a slicer.
+    AddSlicer(AddSlicerResponse),
+    /// A reply from adding a data source.
+    AddDataSource(AddDataSourceResponse),
+    /// A reply from updating a data source.
+    UpdateDataSource(UpdateDataSourceResponse),
+    /// A reply from refreshing data source objects.
+    RefreshDataSource(RefreshDataSourceResponse),
+    /// A reply from cancelling data source object refreshes.
+    CancelDataSourceRefresh(CancelDataSourceRefreshResponse),
+    /// A reply from adding a table.
+    AddTable(AddTableResponse),
+    /// The reply of a request that produces no data.
+    #[serde(untagged)]
+    Empty(EmptyResponse),
+    /// A reply of a kind this crate does not model yet.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+impl Response {
+    /// Returns true if this is the reply of a request that produces no data.
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Response::Empty(_))
+    }
+}
+
+/// The empty reply of a request that produces no data.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EmptyResponse {}
+
+/// The result of adding a named range.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddNamedRangeResponse {
+    /// The named range to add.
+    pub named_range: Option<NamedRange>,
+}
+
+/// The result of adding a sheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddSheetResponse {
+    /// The properties of the newly added sheet.
+    pub properties: Option<SheetProperties>,
+}
+
+/// The result of adding a filter view.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddFilterViewResponse {
+    /// The newly added filter view.
+    pub filter: Option<FilterView>,
+}
+
+/// The result of a filter view being duplicated.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateFilterViewResponse {
+    /// The newly created filter.
+    pub filter: Option<FilterView>,
+}
+
+/// The result of duplicating a sheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateSheetResponse {
+    /// The properties of the duplicate sheet.
+    pub properties: Option<SheetProperties>,
+}
+
+/// The result of the find/replace.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindReplaceResponse {
+    /// The number of non-formula cells changed.
+    pub values_changed: Option<i32>,
+    /// The number of formula cells changed.
+    pub formulas_changed: Option<i32>,
+    /// The number of rows changed.
+    pub rows_changed: Option<i32>,
+    /// The number of sheets changed.
+    pub sheets_changed: Option<i32>,
+    /// The number of occurrences (possibly multiple within a cell) changed.
+    pub occurrences_changed: Option<i32>,
+}
+
+/// The result of updating an embedded object's position.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEmbeddedObjectPositionResponse {
+    /// The new position of the embedded object.
+    pub position: Option<EmbeddedObjectPosition>,
+}
+
+/// The result of updating a conditional format rule.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateConditionalFormatRuleResponse {
+    /// The new rule that replaced the old rule, if replacing, or the rule that was moved, if moved.
+    pub new_rule: Option<ConditionalFormatRule>,
+    /// The index of the new rule.
+    pub new_index: Option<i32>,
+    /// The old rule, if the rule was replaced.
+    pub old_rule: Option<ConditionalFormatRule>,
+    /// The old index of the rule, if the rule was moved.
+    pub old_index: Option<i32>,
+}
+
+/// The result of deleting a conditional format rule.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteConditionalFormatRuleResponse {
+    /// The rule that was deleted.
+    pub rule: Option<ConditionalFormatRule>,
+}
+
+/// The result of adding a new protected range.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddProtectedRangeResponse {
+    /// The newly added protected range.
+    pub protected_range: Option<ProtectedRange>,
+}
+
+/// The result of adding a chart to a spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddChartResponse {
+    /// The newly added chart.
+    pub chart: Option<EmbeddedChart>,
+}
+
+/// The result of adding a banded range.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddBandingResponse {
+    /// The banded range that was added.
+    pub banded_range: Option<BandedRange>,
+}
+
+/// The response from creating developer metadata.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDeveloperMetadataResponse {
+    /// The developer metadata that was created.
+    pub developer_metadata: Option<DeveloperMetadata>,
+}
+
+/// The response from updating developer metadata.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDeveloperMetadataResponse {
+    /// The updated developer metadata.
+    pub developer_metadata: Option<Vec<DeveloperMetadata>>,
+}
+
+/// The response from deleting developer metadata.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDeveloperMetadataResponse {
+    /// The metadata that was deleted.
+    pub deleted_developer_metadata: Option<Vec<DeveloperMetadata>>,
+}
+
+/// The result of adding a group.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddDimensionGroupResponse {
+    /// All groups of a dimension after adding a group to that dimension.
+    pub dimension_groups: Option<Vec<DimensionGroup>>,
+}
+
+/// The result of deleting a group.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDimensionGroupResponse {
+    /// All groups of a dimension after deleting a group from that dimension.
+    pub dimension_groups: Option<Vec<DimensionGroup>>,
+}
+
+/// The result of trimming whitespace in cells.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrimWhitespaceResponse {
+    /// The number of cells that were trimmed of whitespace.
+    pub cells_changed_count: Option<i32>,
+}
+
+/// The result of removing duplicate rows in a range.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDuplicatesResponse {
+    /// The number of duplicate rows removed.
+    pub duplicates_removed_count: Option<i32>,
+}
+
+/// The result of adding a slicer to a spreadsheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddSlicerResponse {
+    /// The newly added slicer.
+    pub slicer: Option<Slicer>,
+}
+
+/// The result of adding a data source.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddDataSourceResponse {
+    /// The data source that was created.
+    pub data_source: Option<DataSource>,
+    /// The data execution status.
+    pub data_execution_status: Option<DataExecutionStatus>,
+}
+
+/// The result of updating a data source.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDataSourceResponse {
+    /// The updated data source.
+    pub data_source: Option<DataSource>,
+    /// The data execution status.
+    pub data_execution_status: Option<DataExecutionStatus>,
+}
+
+/// The result of refreshing one or more data source objects.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshDataSourceResponse {
+    /// All the refresh status for the data source object references specified in the request.
+    pub statuses: Option<Vec<RefreshDataSourceObjectExecutionStatus>>,
+}
+
+/// The result of cancelling one or more data source object refreshes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelDataSourceRefreshResponse {
+    /// The cancellation statuses of refreshes of all data source objects specified in the request.
+    pub statuses: Option<Vec<CancelDataSourceRefreshStatus>>,
+}
+
+/// The result of adding a table.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddTableResponse {
+    /// Output only. The table that was added.
+    pub table: Option<Table>,
+}
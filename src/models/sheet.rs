@@ -1,6 +1,6 @@
 use super::charts::EmbeddedChart;
 use super::common::{Color, ColorStyle, DeveloperMetadata};
-use super::conditions::{BooleanCondition, ConditionalFormatRule};
+use super::conditions::{BooleanCondition, ConditionType, ConditionValue, ConditionalFormatRule};
 use super::data_source::DataSourceColumn;
 use super::filters::{BasicFilter, FilterCriteria, FilterView};
 use super::formatting::{HorizontalAlign, TextFormat};
@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 
 /// Represents a single sheet within a Google Sheets spreadsheet.
 /// A sheet contains data, formatting, charts, and other sheet-specific properties.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Sheet {
     /// The properties of the sheet.
@@ -44,7 +44,7 @@ pub struct Sheet {
 }
 
 /// Properties of a sheet, including its title, type, and visual properties.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SheetProperties {
     /// The ID of the sheet.
@@ -83,7 +83,7 @@ pub enum SheetType {
     DataSource,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSourceSheetProperties {
     /// The ID of the data source the sheet is connected to.
@@ -94,7 +94,7 @@ pub struct DataSourceSheetProperties {
     pub data_execution_status: Option<super::common::DataExecutionStatus>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BandedRange {
     /// The ID of the banded range.
@@ -109,7 +109,7 @@ pub struct BandedRange {
     pub column_properties: Option<BandingProperties>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BandingProperties {
     /// The color of the first row or column.
@@ -130,7 +130,7 @@ pub struct BandingProperties {
     pub footer_color_style: Option<ColorStyle>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DimensionGroup {
     /// The range over which this group exists.
@@ -141,7 +141,7 @@ pub struct DimensionGroup {
     pub collapsed: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Slicer {
     /// The ID of the slicer.
@@ -152,7 +152,7 @@ pub struct Slicer {
     pub position: Option<super::common::EmbeddedObjectPosition>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SlicerSpec {
     /// The data range that the slicer applies to.
@@ -175,7 +175,7 @@ pub struct SlicerSpec {
     pub horizontal_alignment: Option<HorizontalAlign>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Table {
     /// The ID of the table.
@@ -190,7 +190,7 @@ pub struct Table {
     pub column_properties: Option<Vec<TableColumnProperties>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TableRowsProperties {
     /// The color of the header row.
@@ -203,7 +203,7 @@ pub struct TableRowsProperties {
     pub footer_color_style: Option<ColorStyle>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TableColumnProperties {
     /// The index of the column in the table.
@@ -216,6 +216,44 @@ pub struct TableColumnProperties {
     pub data_validation_rule: Option<TableColumnDataValidationRule>,
 }
 
+impl TableColumnProperties {
+    /// Creates the properties of a table column with the given name and type.
+    ///
+    /// The column index is assigned from the position of the column when the
+    /// table is created.
+    pub fn new(column_name: &str, column_type: ColumnType) -> Self {
+        Self {
+            column_name: Some(column_name.to_string()),
+            column_type: Some(column_type),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a dropdown column offering the given options.
+    pub fn dropdown(column_name: &str, options: &[&str]) -> Self {
+        Self::new(column_name, ColumnType::Dropdown).data_validation(BooleanCondition {
+            type_: Some(ConditionType::OneOfList),
+            values: Some(
+                options
+                    .iter()
+                    .map(|option| ConditionValue {
+                        user_entered_value: Some(option.to_string()),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+        })
+    }
+
+    /// Sets the condition that data in the column must meet.
+    pub fn data_validation(mut self, condition: BooleanCondition) -> Self {
+        self.data_validation_rule = Some(TableColumnDataValidationRule {
+            condition: Some(condition),
+        });
+        self
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ColumnType {
@@ -251,7 +289,7 @@ pub enum ColumnType {
     RatingsChip,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TableColumnDataValidationRule {
     /// The condition that data in the table column must meet.
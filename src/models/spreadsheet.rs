@@ -1,12 +1,12 @@
 use super::data_source::{DataSource, DataSourceRefreshSchedule};
-use super::range::NamedRange;
+use super::range::{DataFilter, NamedRange};
 use super::sheet::Sheet;
 use super::{cell::CellFormat, common::*};
 use serde::{Deserialize, Serialize};
 
 /// Represents a Google Sheets spreadsheet.
 /// This is the top-level structure containing all sheets, properties, and metadata.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Spreadsheet {
     /// The unique identifier of the spreadsheet.
@@ -27,8 +27,19 @@ pub struct Spreadsheet {
     pub data_source_schedules: Option<Vec<DataSourceRefreshSchedule>>,
 }
 
+/// The request body of `spreadsheets.getByDataFilter`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSpreadsheetByDataFilterRequest {
+    /// The filters selecting the data to return.
+    pub data_filters: Vec<DataFilter>,
+    /// True if grid data should be returned; ignored when a fields mask is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_grid_data: Option<bool>,
+}
+
 /// Properties of a spreadsheet, such as title, locale, and calculation settings.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpreadsheetProperties {
     /// The title of the spreadsheet.
@@ -51,7 +62,7 @@ pub struct SpreadsheetProperties {
 
 /// The theme applied to a spreadsheet.
 /// Themes define the visual appearance, including fonts and colors.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpreadsheetTheme {
     /// The primary font family used in the spreadsheet.
@@ -59,3 +70,119 @@ pub struct SpreadsheetTheme {
     /// The color pairs that define the theme.
     pub theme_colors: Option<Vec<ThemeColorPair>>,
 }
+
+impl SpreadsheetTheme {
+    /// Returns the color of the given theme color type, if the theme defines it.
+    pub fn color(&self, color_type: &ThemeColorType) -> Option<&ColorStyle> {
+        self.theme_colors
+            .iter()
+            .flatten()
+            .find(|pair| pair.color_type.as_ref() == Some(color_type))
+            .and_then(|pair| pair.color.as_ref())
+    }
+}
+
+/// An 8-bit red, green and blue triple.
+type Rgb8 = (u8, u8, u8);
+
+/// Named themes that can be applied to a spreadsheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemePreset {
+    /// The default Google Sheets theme.
+    Standard,
+    /// Grayscale accents on a white background.
+    Monochrome,
+    /// Blue and teal accents.
+    Ocean,
+    /// Green and earth tone accents.
+    Forest,
+}
+
+impl ThemePreset {
+    /// Returns the complete theme, font and all color pairs, of this preset.
+    pub fn theme(self) -> SpreadsheetTheme {
+        let (font, accents, link): (&str, [Rgb8; 6], Rgb8) = match self {
+            ThemePreset::Standard => (
+                "Arial",
+                [
+                    (0x42, 0x85, 0xF4),
+                    (0xEA, 0x43, 0x35),
+                    (0xFB, 0xBC, 0x04),
+                    (0x34, 0xA8, 0x53),
+                    (0xFF, 0x6D, 0x01),
+                    (0x46, 0xBD, 0xC6),
+                ],
+                (0x11, 0x55, 0xCC),
+            ),
+            ThemePreset::Monochrome => (
+                "Roboto",
+                [
+                    (0x21, 0x21, 0x21),
+                    (0x42, 0x42, 0x42),
+                    (0x61, 0x61, 0x61),
+                    (0x75, 0x75, 0x75),
+                    (0x9E, 0x9E, 0x9E),
+                    (0xBD, 0xBD, 0xBD),
+                ],
+                (0x21, 0x21, 0x21),
+            ),
+            ThemePreset::Ocean => (
+                "Lato",
+                [
+                    (0x01, 0x57, 0x9B),
+                    (0x00, 0x83, 0x8F),
+                    (0x02, 0x88, 0xD1),
+                    (0x00, 0xAC, 0xC1),
+                    (0x4F, 0xC3, 0xF7),
+                    (0x80, 0xDE, 0xEA),
+                ],
+                (0x01, 0x57, 0x9B),
+            ),
+            ThemePreset::Forest => (
+                "Merriweather",
+                [
+                    (0x2E, 0x7D, 0x32),
+                    (0x55, 0x8B, 0x2F),
+                    (0x79, 0x55, 0x48),
+                    (0x9E, 0x9D, 0x24),
+                    (0x8D, 0x6E, 0x63),
+                    (0x66, 0xBB, 0x6A),
+                ],
+                (0x2E, 0x7D, 0x32),
+            ),
+        };
+
+        let pair = |color_type: ThemeColorType, (red, green, blue): (u8, u8, u8)| ThemeColorPair {
+            color_type: Some(color_type),
+            color: Some(ColorStyle {
+                rgb_color: Some(Color::from_rgb8(red, green, blue)),
+                theme_color: None,
+            }),
+        };
+        let accent_types = [
+            ThemeColorType::Accent1,
+            ThemeColorType::Accent2,
+            ThemeColorType::Accent3,
+            ThemeColorType::Accent4,
+            ThemeColorType::Accent5,
+            ThemeColorType::Accent6,
+        ];
+
+        let mut theme_colors = vec![
+            pair(ThemeColorType::Text, (0x00, 0x00, 0x00)),
+            pair(ThemeColorType::Background, (0xFF, 0xFF, 0xFF)),
+        ];
+        theme_colors.extend(
+            accent_types
+                .into_iter()
+                .zip(accents)
+                .map(|(t, c)| pair(t, c)),
+        );
+        theme_colors.push(pair(ThemeColorType::Link, link));
+
+        SpreadsheetTheme {
+            primary_font_family: Some(font.to_string()),
+            theme_colors: Some(theme_colors),
+        }
+    }
+}
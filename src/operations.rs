@@ -21,8 +21,9 @@
 //!
 //! ```rust,no_run
 //! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
-//! # use std::sync::{Arc, Mutex};
-//! # let auth_client = Arc::new(Mutex::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap()));
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
 //! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
 //! let spreadsheet = gsheet_client.spreadsheet("spreadsheet-id");
 //!
@@ -31,12 +32,241 @@
 //!     .get_all_value()
 //!     .execute()
 //!     .await?;
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! ## Error Handling
 //!
 //! All operations return `Result<T, GSheetError>` where `GSheetError` provides
 //! detailed information about what went wrong.
+//!
+//! ## Multi-Task Usage
+//!
+//! The client, every builder and operation, and the futures returned by their
+//! `execute()` methods are `Send + 'static`, so they can be moved into
+//! `tokio::spawn`. Clones share the same connection pool and access token.
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//! let spreadsheet = gsheet_client.spreadsheet("spreadsheet-id");
+//!
+//! let tasks: Vec<_> = ["January", "February", "March"]
+//!     .into_iter()
+//!     .map(|title| {
+//!         let operation = spreadsheet.sheet(title).get_all_value();
+//!         tokio::spawn(async move { operation.execute().await })
+//!     })
+//!     .collect();
+//!
+//! for task in tasks {
+//!     let values = task.await??;
+//! }
+//! # Ok(())
+//! # }
+//! ```
 
 pub mod sheet;
 pub mod spreadsheet;
+
+/// Compile-time check that the client, operations and their futures can be moved
+/// into spawned tasks.
+#[allow(dead_code)]
+fn assert_spawnable() {
+    use crate::batch_queue::{BatchQueue, BatchQueueBuilder};
+    use crate::client::GoogleSheetClient;
+    use crate::declarative::{ApplySpecBuilder, ApplySpecOperations};
+    use crate::diff::{DiffSpreadsheetsBuilder, DiffSpreadsheetsOperations};
+    use crate::drive::ListFilesBuilder;
+    use crate::fleet::{ForEachSpreadsheet, ForEachSpreadsheetBuilder};
+    use crate::health::{HealthCheckBuilder, HealthCheckOperations};
+    use crate::kv::{SheetKvStore, SheetKvStoreBuilder};
+    use crate::logger::{LogRecord, SheetLogger, SheetLoggerBuilder};
+    use crate::raw::{RawRequestBuilder, RawRequestOperations};
+    use crate::report::{ReportBuilder, ReportOperations};
+    use crate::rows::{FromRow, RowParseError, RowView};
+    use crate::schema::{SheetSchema, SheetSchemaBuilder};
+    use crate::template::{InstantiateTemplateBuilder, InstantiateTemplateOperations};
+    use crate::watch::{ChangeWatcher, PollChangesBuilder, WatchBuilder};
+    use crate::workflow::{Workflow, WorkflowBuilder, WorkflowContext};
+    use sheet::*;
+    use spreadsheet::*;
+
+    fn shareable<T: Send + Sync + 'static>() {}
+    fn send<T: Send>(_: &T) {}
+
+    struct Row;
+    impl FromRow for Row {
+        fn from_row(_: &RowView<'_>) -> Result<Self, RowParseError> {
+            Ok(Row)
+        }
+    }
+
+    shareable::<GoogleSheetClient>();
+    shareable::<SpreadsheetOperationsBuilder>();
+    shareable::<SpreadsheetOperations>();
+    shareable::<SheetOperations>();
+    shareable::<BatchGetValueRangeOperations>();
+    shareable::<BatchUpdateValueRangeOperations>();
+    shareable::<UpdateFromOperations>();
+    shareable::<GetAllValueOperations>();
+    shareable::<ValidateOperations>();
+    shareable::<ChunkedWriteOperations>();
+    shareable::<ChunkedReadOperations>();
+    shareable::<GetAllCellOperations>();
+    shareable::<GetHashMapCellOperations>();
+    shareable::<GetCellMapOperations>();
+    shareable::<LastRowOperations>();
+    shareable::<NextEmptyRowOperations>();
+    shareable::<UsedRangeOperations>();
+    shareable::<DimensionsOperations>();
+    shareable::<ExportHtmlOperations>();
+    shareable::<CopyValuesOperations>();
+    shareable::<CreateTableOperations>();
+    shareable::<MoveChartOperations>();
+    shareable::<AddChartOperations>();
+    shareable::<WriteCellsOperations>();
+    shareable::<InsertRangeOperations>();
+    shareable::<DeleteRangeOperations>();
+    shareable::<MoveDimensionOperations>();
+    shareable::<ShuffleRowsOperations>();
+    shareable::<SetBordersOperations>();
+    shareable::<StyleAsTableOperations>();
+    shareable::<AddConditionalFormatOperations>();
+    shareable::<ProtectRangeOperations>();
+    shareable::<GetSpreadsheetBuilder>();
+    shareable::<GetSpreadsheetOperations>();
+    shareable::<BatchUpdateBuilder>();
+    shareable::<BatchUpdateOperations>();
+    shareable::<RefreshDataSourceBuilder>();
+    shareable::<RefreshDataSourceOperations>();
+    shareable::<ResizeChartBuilder>();
+    shareable::<ResizeChartOperations>();
+    shareable::<SpreadsheetThemeBuilder>();
+    shareable::<UpdateThemeOperations>();
+    shareable::<CalculationSettingsBuilder>();
+    shareable::<UpdateCalculationSettingsOperations>();
+    shareable::<GetMetadataBuilder>();
+    shareable::<GetMetadataOperations>();
+    shareable::<ListSheetsBuilder>();
+    shareable::<ListSheetsOperations>();
+    shareable::<AddSheetBuilder>();
+    shareable::<AddSheetOperations>();
+    shareable::<RenameSheetBuilder>();
+    shareable::<RenameSheetOperations>();
+    shareable::<WatchBuilder>();
+    shareable::<BatchQueueBuilder>();
+    shareable::<BatchQueue>();
+    shareable::<PollChangesBuilder>();
+    shareable::<ReportBuilder>();
+    shareable::<ReportOperations>();
+    shareable::<InstantiateTemplateBuilder>();
+    shareable::<InstantiateTemplateOperations>();
+    shareable::<ListFilesBuilder>();
+    shareable::<WorkflowBuilder>();
+    shareable::<Workflow>();
+    shareable::<WorkflowContext>();
+    shareable::<ApplySpecBuilder>();
+    shareable::<ApplySpecOperations>();
+    shareable::<DiffSpreadsheetsBuilder>();
+    shareable::<DiffSpreadsheetsOperations>();
+    shareable::<ForEachSpreadsheetBuilder>();
+    shareable::<ForEachSpreadsheet>();
+    shareable::<HealthCheckBuilder>();
+    shareable::<HealthCheckOperations>();
+    shareable::<RawRequestBuilder>();
+    shareable::<RawRequestOperations>();
+    shareable::<TagRowOperations>();
+    shareable::<FindTaggedRowsOperations>();
+    shareable::<GetValuesByDataFilterBuilder>();
+    shareable::<GetValuesByDataFilterOperations>();
+    shareable::<SheetLoggerBuilder>();
+    shareable::<SheetLogger>();
+    shareable::<SheetKvStoreBuilder>();
+    shareable::<SheetKvStore>();
+    shareable::<AppendOperations>();
+    shareable::<GetHeaderOperations>();
+    shareable::<SetHeaderOperations>();
+    shareable::<RowsAsOperations<Row>>();
+    shareable::<SheetSchemaBuilder>();
+    shareable::<SheetSchema>();
+    shareable::<MergesOperations>();
+    shareable::<ExportCsvOperations>();
+    shareable::<ClearOperations>();
+    shareable::<ClearFormatsOperations>();
+    shareable::<ResetOperations>();
+    shareable::<ResizeOperations>();
+
+    let _ = |operation: GetAllValueOperations| send(&operation.execute());
+    let _ = |operation: ValidateOperations| send(&operation.execute());
+    let _ = |operation: ExportHtmlOperations| send(&operation.execute());
+    let _ = |operation: CopyValuesOperations| send(&operation.execute());
+    let _ = |operation: BatchUpdateValueRangeOperations| send(&operation.execute());
+    let _ = |operation: ChunkedWriteOperations| send(&operation.execute());
+    let _ = |operation: ChunkedReadOperations| send(&operation.execute());
+    let _ = |operation: GetCellMapOperations| send(&operation.execute_ordered());
+    let _ = |operation: AddChartOperations| send(&operation.execute());
+    let _ = |operation: WriteCellsOperations| send(&operation.execute());
+    let _ = |operation: InsertRangeOperations| send(&operation.execute());
+    let _ = |operation: DeleteRangeOperations| send(&operation.execute());
+    let _ = |operation: MoveDimensionOperations| send(&operation.execute());
+    let _ = |operation: ShuffleRowsOperations| send(&operation.execute());
+    let _ = |operation: SetBordersOperations| send(&operation.execute());
+    let _ = |operation: StyleAsTableOperations| send(&operation.execute());
+    let _ = |operation: AddConditionalFormatOperations| send(&operation.execute());
+    let _ = |operation: ProtectRangeOperations| send(&operation.execute());
+    let _ = |operation: GetSpreadsheetOperations| send(&operation.execute());
+    let _ = |operation: BatchUpdateOperations| send(&operation.execute());
+    let _ = |operation: RefreshDataSourceOperations| send(&operation.execute());
+    let _ = |operation: UpdateThemeOperations| send(&operation.execute());
+    let _ = |operation: GetMetadataOperations| send(&operation.execute());
+    let _ = |operation: ListSheetsOperations| send(&operation.execute());
+    let _ = |operation: AddSheetOperations| send(&operation.execute());
+    let _ = |operation: RenameSheetOperations| send(&operation.execute());
+    let _ = |spreadsheet: SpreadsheetOperations| send(&spreadsheet.ensure_sheet("", 0, 0));
+    let _ = |operation: ReportOperations| send(&operation.execute());
+    let _ = |operation: InstantiateTemplateOperations| send(&operation.execute());
+    let _ = |builder: WatchBuilder| send(&builder.start());
+    let _ = |workflow: Workflow| send(&workflow.execute());
+    let _ = |operation: ApplySpecOperations| send(&operation.plan());
+    let _ = |operation: ApplySpecOperations| send(&operation.execute());
+    let _ = |operation: DiffSpreadsheetsOperations| send(&operation.execute());
+    let _ = |run: ForEachSpreadsheet| {
+        send(&run.execute(|spreadsheet| async move {
+            spreadsheet.list_sheets().build()?.execute().await
+        }))
+    };
+    let _ = |operation: HealthCheckOperations| send(&operation.execute());
+    let _ = |operation: RawRequestOperations| send(&operation.execute());
+    let _ = |builder: ListFilesBuilder| send(&builder.stream());
+    let _ = |builder: ListFilesBuilder| send(&builder.collect());
+    let _ = |operation: TagRowOperations| send(&operation.execute());
+    let _ = |operation: FindTaggedRowsOperations| send(&operation.execute());
+    let _ = |operation: GetValuesByDataFilterOperations| send(&operation.execute());
+    let _ = |logger: SheetLogger, record: LogRecord| send(&logger.log(record));
+    let _ = |logger: SheetLogger| send(&logger.flush());
+    let _ = |store: SheetKvStore| send(&store.all());
+    let _ = |store: SheetKvStore| send(&store.set("", ""));
+    let _ = |operation: AppendOperations| send(&operation.execute());
+    let _ = |operation: GetHeaderOperations| send(&operation.execute());
+    let _ = |operation: SetHeaderOperations| send(&operation.execute());
+    let _ = |operation: RowsAsOperations<Row>| send(&operation.execute());
+    let _ = |operation: MergesOperations| send(&operation.execute());
+    let _ = |operation: ExportCsvOperations| send(&operation.execute());
+    let _ = |operation: ClearOperations| send(&operation.execute());
+    let _ = |operation: ClearFormatsOperations| send(&operation.execute());
+    let _ = |operation: ResetOperations| send(&operation.execute());
+    let _ = |operation: ResizeOperations| send(&operation.execute());
+    let _ = |watcher: ChangeWatcher| send(&watcher);
+    let _ = |watcher: ChangeWatcher| send(&watcher.stop());
+
+    #[cfg(feature = "calamine")]
+    {
+        shareable::<ImportXlsxOperations>();
+        let _ = |operation: ImportXlsxOperations| send(&operation.execute());
+    }
+}
@@ -1,28 +1,87 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
 
-use crate::auth::AuthError;
-use crate::error::GSheetError;
+use crate::cache::CacheKey;
+use crate::client::url::QueryParams;
+use crate::error::{GSheetError, decode_json};
+use crate::export::{render_csv, render_html};
+use crate::kv::SheetKvStoreBuilder;
+use crate::logger::SheetLoggerBuilder;
 use crate::models::{
-    BatchUpdateValuesResponse, BatchValueRanges, Cell, DateTimeRenderOption, Dimension,
-    ValueInputOption, ValueRange, ValueRenderOption,
+    AddBandingRequest, AddChartRequest, AddChartResponse, AddConditionalFormatRuleRequest,
+    AddProtectedRangeRequest, AddProtectedRangeResponse, AddTableRequest, AddTableResponse,
+    AppendDimensionRequest, AppendValuesResponse, AutoResizeDimensionsRequest, BandedRange,
+    BandingProperties, BasicChartType, BatchUpdateValuesResponse, BatchValueRanges, Border, Cell,
+    CellAddress, CellData, CellFormat, ChartSpec, ClearValuesResponse, ColIndex, Color, ColorStyle,
+    ConditionalFormatPreset, CopyPasteRequest, CreateDeveloperMetadataRequest,
+    CreateDeveloperMetadataResponse, DataFilter, DateTimeRenderOption, DeleteDimensionRequest,
+    DeleteRangeRequest, DeveloperMetadata, DeveloperMetadataLocation,
+    DeveloperMetadataLocationType, DeveloperMetadataLookup, DeveloperMetadataVisibility, Dimension,
+    DimensionRange, Editors, EmbeddedChart, EmbeddedObjectPosition,
+    GetSpreadsheetByDataFilterRequest, GridCoordinate, GridProperties, GridRange, Header,
+    HiddenDimensions, InsertDataOption, InsertRangeRequest, MoveDimensionRequest, OverlayPosition,
+    PasteOrientation, PasteType, ProtectedRange, RandomizeRangeRequest, RepeatCellRequest, Request,
+    Response, RowData, RowIndex, Sheet, SheetProperties, ShiftDimension, Spreadsheet, Style, Table,
+    TableColumnProperties, TableRowsProperties, TextFormat, UpdateBordersRequest,
+    UpdateCellsRequest, UpdateEmbeddedObjectPositionRequest, UpdateEmbeddedObjectPositionResponse,
+    UpdateSheetPropertiesRequest, UpdateValuesResponse, ValueInputOption, ValueRange,
+    ValueRenderOption,
 };
 use crate::operations::spreadsheet::SpreadsheetOperations;
-use crate::utils::{value_range_to_cells, value_range_to_hash_cell_map};
+use crate::progress::{
+    CancellationToken, DEFAULT_CHUNK_ROWS, Progress, ProgressCallback, values_size,
+};
+use crate::report::{ReportBuilder, ReportRecord, grey_banding};
+use crate::rows::{FromRow, RowParseError, RowView};
+use crate::schema::{SchemaViolation, SheetSchema};
+use crate::types::RangeFit;
+use crate::utils::{
+    a1_range_from_shape, a1_to_grid_range, col_index_to_a1, offset_a1, parse_a1_cell,
+    quote_sheet_title, split_sheet_range, unquote_sheet_title,
+};
+use crate::utils::{
+    cells_iter, fill_merged_values, value_range_to_cell_map, value_range_to_hash_cell_map,
+    value_range_to_ordered_cell_map,
+};
+use crate::validation::{fit_values, validate_a1_range, validate_cell_limit, validate_sheet_title};
 
 #[derive(Clone)]
 pub struct SheetOperations {
-    spreadsheet: SpreadsheetOperations,
-    sheet_title: String,
+    pub(crate) spreadsheet: SpreadsheetOperations,
+    pub(crate) sheet_title: Arc<str>,
 }
 
 impl SheetOperations {
-    pub fn new(spreadsheet: SpreadsheetOperations, sheet_title: String) -> Self {
+    pub fn new(spreadsheet: SpreadsheetOperations, sheet_title: Arc<str>) -> Self {
         Self {
             spreadsheet,
             sheet_title,
         }
     }
 
+    /// Returns these operations with a default value render option, inherited
+    /// by the reads created from them.
+    pub fn with_value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.spreadsheet = self.spreadsheet.with_value_render_option(option);
+        self
+    }
+
+    /// Returns these operations with a default date time render option,
+    /// inherited by the reads created from them.
+    pub fn with_date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.spreadsheet = self.spreadsheet.with_date_time_render_option(option);
+        self
+    }
+
+    /// Returns these operations with a default value input option, inherited by
+    /// the writes created from them.
+    pub fn with_value_input_option(mut self, option: ValueInputOption) -> Self {
+        self.spreadsheet = self.spreadsheet.with_value_input_option(option);
+        self
+    }
+
     pub fn batch_get_value_range(&self) -> BatchGetValueRangeOperations {
         BatchGetValueRangeOperations::new(self)
     }
@@ -31,6 +90,239 @@ impl SheetOperations {
         BatchUpdateValueRangeOperations::new(self)
     }
 
+    pub fn update_from(&self, cell: &str, values: Vec<Vec<String>>) -> UpdateFromOperations {
+        UpdateFromOperations::new(self, cell, values)
+    }
+
+    pub fn write_chunked(&self, cell: &str, values: Vec<Vec<String>>) -> ChunkedWriteOperations {
+        ChunkedWriteOperations::new(self, cell, values)
+    }
+
+    pub fn read_chunked(&self) -> ChunkedReadOperations {
+        ChunkedReadOperations::new(self)
+    }
+
+    /// Imports a worksheet of an Excel workbook. Requires the `calamine` feature.
+    #[cfg(feature = "calamine")]
+    pub fn import_xlsx(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        source_sheet: &str,
+    ) -> ImportXlsxOperations {
+        ImportXlsxOperations::new(self, path, source_sheet)
+    }
+
+    pub fn append(&self, values: Vec<Vec<String>>) -> AppendOperations {
+        AppendOperations::new(self, values)
+    }
+
+    pub fn append_after_header(
+        &self,
+        header_row: usize,
+        values: Vec<Vec<String>>,
+    ) -> AppendOperations {
+        AppendOperations::new(self, values).after_header(header_row)
+    }
+
+    pub fn set_header(&self, columns: &[&str]) -> SetHeaderOperations {
+        SetHeaderOperations::new(self, columns)
+    }
+
+    pub fn header(&self) -> GetHeaderOperations {
+        GetHeaderOperations::new(self)
+    }
+
+    pub fn rows_as<T: FromRow>(&self) -> RowsAsOperations<T> {
+        RowsAsOperations::new(self)
+    }
+
+    pub fn validate(&self, schema: &SheetSchema) -> ValidateOperations {
+        ValidateOperations::new(self, schema)
+    }
+
+    pub fn last_row(&self) -> LastRowOperations {
+        LastRowOperations::new(self)
+    }
+
+    pub fn next_empty_row(&self) -> NextEmptyRowOperations {
+        NextEmptyRowOperations::new(self)
+    }
+
+    pub fn used_range(&self) -> UsedRangeOperations {
+        UsedRangeOperations::new(self)
+    }
+
+    pub fn create_table(
+        &self,
+        name: &str,
+        range: &str,
+        columns: Vec<TableColumnProperties>,
+    ) -> CreateTableOperations {
+        CreateTableOperations::new(self, name, range, columns)
+    }
+
+    pub fn add_line_chart(&self, data_range: &str, title: &str) -> AddChartOperations {
+        AddChartOperations::new(
+            self,
+            data_range,
+            title,
+            QuickChart::Basic(BasicChartType::Line),
+        )
+    }
+
+    pub fn add_bar_chart(&self, data_range: &str, title: &str) -> AddChartOperations {
+        AddChartOperations::new(
+            self,
+            data_range,
+            title,
+            QuickChart::Basic(BasicChartType::Bar),
+        )
+    }
+
+    pub fn add_pie_chart(&self, data_range: &str, title: &str) -> AddChartOperations {
+        AddChartOperations::new(self, data_range, title, QuickChart::Pie)
+    }
+
+    pub fn report<R: ReportRecord>(&self, records: &[R]) -> ReportBuilder {
+        ReportBuilder::new(self, records)
+    }
+
+    pub fn move_chart(&self, chart_id: i32, anchor: &str) -> MoveChartOperations {
+        MoveChartOperations::new(self, chart_id, anchor)
+    }
+
+    /// Inserts empty cells in a range, shifting the existing cells down or
+    /// right. Fails with a `ValidationError` when the grown grid would take
+    /// the spreadsheet past the cell limit.
+    pub fn insert_range(&self, range: &str, shift: ShiftDimension) -> InsertRangeOperations {
+        InsertRangeOperations::new(self, range, shift)
+    }
+
+    /// Deletes the cells of a range, shifting the cells beyond it up or left.
+    pub fn delete_range(&self, range: &str, shift: ShiftDimension) -> DeleteRangeOperations {
+        DeleteRangeOperations::new(self, range, shift)
+    }
+
+    /// Moves rows, given as a row span ("5:7") or a range ("A5:C7"), before a
+    /// row, counted before the move.
+    pub fn move_rows(
+        &self,
+        range: &str,
+        destination: impl Into<RowIndex>,
+    ) -> MoveDimensionOperations {
+        MoveDimensionOperations::new(self, Dimension::Rows, range, destination.into().get())
+    }
+
+    /// Moves columns, given as a column span ("B:D") or a range ("B1:D5"),
+    /// before a column, counted before the move.
+    pub fn move_columns(
+        &self,
+        range: &str,
+        destination: impl Into<ColIndex>,
+    ) -> MoveDimensionOperations {
+        MoveDimensionOperations::new(self, Dimension::Columns, range, destination.into().get())
+    }
+
+    /// Shuffles the rows of a range, keeping the cells of each row together.
+    pub fn shuffle_rows(&self, range: &str) -> ShuffleRowsOperations {
+        ShuffleRowsOperations::new(self, range)
+    }
+
+    /// Sets the borders of a range, edge by edge.
+    pub fn set_borders(&self, range: &str) -> SetBordersOperations {
+        SetBordersOperations::new(self, range)
+    }
+
+    /// Styles a range as a table with its first row as header: bold, banded,
+    /// bordered, frozen header and columns resized to fit, in one batch.
+    pub fn style_as_table(&self, range: &str) -> StyleAsTableOperations {
+        StyleAsTableOperations::new(self, range)
+    }
+
+    /// Adds the rules of a conditional format preset over a range, ahead of
+    /// the existing rules of the sheet.
+    pub fn add_conditional_format(
+        &self,
+        range: &str,
+        preset: ConditionalFormatPreset,
+    ) -> AddConditionalFormatOperations {
+        AddConditionalFormatOperations::new(self, range, preset)
+    }
+
+    /// Protects a range against edits. By default, the editors are those the
+    /// API chooses: the owner and the authenticated principal.
+    pub fn protect_range(&self, range: &str) -> ProtectRangeOperations {
+        ProtectRangeOperations::new(self, range)
+    }
+
+    /// Writes cell data (values, formats, notes, validations) to a range in a
+    /// single UpdateCells request, row by row from its top-left cell.
+    pub fn write_cells(&self, range: &str, rows: Vec<Vec<CellData>>) -> WriteCellsOperations {
+        WriteCellsOperations::new(self, range, rows)
+    }
+
+    /// Copies the values of a range of this sheet to a range of another sheet,
+    /// in this spreadsheet or another one.
+    pub fn copy_values(
+        &self,
+        src_range: &str,
+        dst_spreadsheet: &SpreadsheetOperations,
+        dst_range: &str,
+    ) -> CopyValuesOperations {
+        CopyValuesOperations::new(self, src_range, dst_spreadsheet, dst_range)
+    }
+
+    pub fn export_html(&self) -> ExportHtmlOperations {
+        ExportHtmlOperations::new(self)
+    }
+
+    pub fn export_csv(&self) -> ExportCsvOperations {
+        ExportCsvOperations::new(self)
+    }
+
+    pub fn dimensions(&self) -> DimensionsOperations {
+        DimensionsOperations::new(self)
+    }
+
+    pub fn merges(&self) -> MergesOperations {
+        MergesOperations::new(self)
+    }
+
+    pub fn clear(&self) -> ClearOperations {
+        ClearOperations::new(self)
+    }
+
+    pub fn clear_formats(&self) -> ClearFormatsOperations {
+        ClearFormatsOperations::new(self)
+    }
+
+    pub fn reset(&self, rows: i32, columns: i32) -> ResetOperations {
+        ResetOperations::new(self, rows, columns)
+    }
+
+    pub fn resize(&self, rows: i32, columns: i32) -> ResizeOperations {
+        ResizeOperations::new(self, rows, columns)
+    }
+
+    pub fn tag_row(&self, row: RowIndex, key: &str, value: &str) -> TagRowOperations {
+        TagRowOperations::new(self, row, key, value)
+    }
+
+    pub fn find_tagged_rows(&self, key: &str, value: &str) -> FindTaggedRowsOperations {
+        FindTaggedRowsOperations::new(self, key, value)
+    }
+
+    /// Creates a builder for a logger appending records as rows of this sheet.
+    pub fn logger(&self) -> SheetLoggerBuilder {
+        SheetLoggerBuilder::new(self)
+    }
+
+    /// Creates a builder for a key-value store backed by columns A and B of
+    /// this sheet.
+    pub fn kv_store(&self) -> SheetKvStoreBuilder {
+        SheetKvStoreBuilder::new(self)
+    }
+
     pub fn get_all_value(&self) -> GetAllValueOperations {
         GetAllValueOperations::new(self)
     }
@@ -42,6 +334,10 @@ impl SheetOperations {
     pub fn get_hash_map_cell(&self) -> GetHashMapCellOperations {
         GetHashMapCellOperations::new(self)
     }
+
+    pub fn get_cell_map(&self) -> GetCellMapOperations {
+        GetCellMapOperations::new(self)
+    }
 }
 
 pub struct BatchGetValueRangeOperations {
@@ -50,6 +346,8 @@ pub struct BatchGetValueRangeOperations {
     major_dimension: Dimension,
     value_render_option: ValueRenderOption,
     date_time_render_option: DateTimeRenderOption,
+    timeout: Option<Duration>,
+    use_cache: bool,
 }
 
 impl BatchGetValueRangeOperations {
@@ -58,8 +356,14 @@ impl BatchGetValueRangeOperations {
             sheet: sheet.clone(),
             ranges: Vec::new(),
             major_dimension: Dimension::default(),
-            value_render_option: ValueRenderOption::default(),
-            date_time_render_option: DateTimeRenderOption::default(),
+            value_render_option: sheet.spreadsheet.value_options.value_render_option.clone(),
+            date_time_render_option: sheet
+                .spreadsheet
+                .value_options
+                .date_time_render_option
+                .clone(),
+            timeout: None,
+            use_cache: true,
         }
     }
 
@@ -78,50 +382,81 @@ impl BatchGetValueRangeOperations {
         self
     }
 
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
     pub fn range(mut self, range: &str) -> Self {
         self.ranges.push(range.to_string());
         self
     }
 
     pub async fn execute(&self) -> Result<BatchValueRanges, GSheetError> {
-        let url = format!(
-            "{}/{}/values:batchGet",
-            self.sheet.spreadsheet.gsheet_client.base_url, self.sheet.spreadsheet.spreadsheet_id
-        );
+        validate_sheet_title(&self.sheet.sheet_title)?;
+        for range in &self.ranges {
+            validate_a1_range(range)?;
+        }
 
-        let auth_client = self
-            .sheet
-            .spreadsheet
-            .gsheet_client
-            .auth_client
-            .lock()
-            .map_err(|e| GSheetError::AuthError(AuthError::Other(e.to_string())))?;
-
-        let mut request = self
-            .sheet
-            .spreadsheet
-            .gsheet_client
-            .client
-            .get(&url)
-            .bearer_auth(auth_client.get_token())
-            .query(&[("majorDimension", self.major_dimension.to_string())])
-            .query(&[("valueRenderOption", self.value_render_option.to_string())])
-            .query(&[(
-                "dateTimeRenderOption",
-                self.date_time_render_option.to_string(),
-            )]);
+        let gsheet_client = &self.sheet.spreadsheet.gsheet_client;
+        let spreadsheet_id = &self.sheet.spreadsheet.spreadsheet_id;
+        let ranges: Vec<String> = self
+            .ranges
+            .iter()
+            .map(|range| format!("{}!{}", quote_sheet_title(&self.sheet.sheet_title), range))
+            .collect();
+        let cache_keys: Vec<CacheKey> = ranges
+            .iter()
+            .map(|range| {
+                CacheKey::new(
+                    spreadsheet_id,
+                    range,
+                    &self.major_dimension,
+                    &self.value_render_option,
+                    &self.date_time_render_option,
+                )
+            })
+            .collect();
 
-        for range in &self.ranges {
-            request = request.query(&[("ranges", format!("{}!{}", self.sheet.sheet_title, range))]);
+        if let Some(cache) = gsheet_client.cache.as_ref().filter(|_| self.use_cache) {
+            let cached: Option<Vec<ValueRange>> =
+                cache_keys.iter().map(|key| cache.get(key)).collect();
+            if let Some(value_ranges) = cached {
+                return Ok(BatchValueRanges {
+                    spreadsheet_id: spreadsheet_id.to_string(),
+                    value_ranges,
+                });
+            }
         }
 
-        let response = request.send().await?;
+        let url = gsheet_client.spreadsheet_url(spreadsheet_id, "/values:batchGet");
+
+        let query = QueryParams::new()
+            .major_dimension(&self.major_dimension)
+            .value_render_option(&self.value_render_option)
+            .date_time_render_option(&self.date_time_render_option)
+            .ranges(&ranges);
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
+
+        let response = gsheet_client.send(request).await?;
 
         if response.status().is_success() {
-            let value_range: BatchValueRanges = response.json().await?;
+            let value_range: BatchValueRanges = decode_json(response).await?;
+            if let Some(cache) = &gsheet_client.cache {
+                for (key, value) in cache_keys.into_iter().zip(&value_range.value_ranges) {
+                    cache.insert(key, value.clone());
+                }
+            }
             Ok(value_range)
         } else {
-            Err(GSheetError::from(response.error_for_status().unwrap_err()))
+            Err(GSheetError::from_response(response, spreadsheet_id).await)
         }
     }
 }
@@ -133,6 +468,8 @@ pub struct BatchUpdateValueRangeOperations {
     include_values_in_response: bool,
     response_value_render_option: ValueRenderOption,
     response_date_time_render_option: DateTimeRenderOption,
+    range_fit: RangeFit,
+    timeout: Option<Duration>,
 }
 
 impl BatchUpdateValueRangeOperations {
@@ -140,10 +477,20 @@ impl BatchUpdateValueRangeOperations {
         Self {
             sheet: sheet.clone(),
             value_ranges: Vec::new(),
-            value_input_option: ValueInputOption::default(),
+            value_input_option: sheet.spreadsheet.value_options.value_input_option.clone(),
             include_values_in_response: false,
-            response_value_render_option: ValueRenderOption::default(),
-            response_date_time_render_option: DateTimeRenderOption::default(),
+            response_value_render_option: sheet
+                .spreadsheet
+                .value_options
+                .value_render_option
+                .clone(),
+            response_date_time_render_option: sheet
+                .spreadsheet
+                .value_options
+                .date_time_render_option
+                .clone(),
+            range_fit: RangeFit::default(),
+            timeout: None,
         }
     }
 
@@ -167,71 +514,128 @@ impl BatchUpdateValueRangeOperations {
         self
     }
 
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn range_fit(mut self, range_fit: RangeFit) -> Self {
+        self.range_fit = range_fit;
+        self
+    }
+
     pub fn add_value_range(mut self, range: &str, value: Vec<Vec<String>>) -> Self {
         self.value_ranges.push(ValueRange {
-            range: Some(format!("{}!{}", self.sheet.sheet_title, range)),
+            range: Some(range.to_string()),
             values: Some(value),
             major_dimension: Some(Dimension::default()),
         });
         self
     }
 
+    pub fn add_values_at(self, cell: &str, value: Vec<Vec<String>>) -> Self {
+        let rows = value.len();
+        let cols = value.iter().map(Vec::len).max().unwrap_or(0);
+        // An invalid cell or empty values are reported by validation on execute.
+        let range = a1_range_from_shape(cell, rows, cols).unwrap_or_else(|_| cell.to_string());
+        self.add_value_range(&range, value)
+    }
+
     pub async fn execute(&self) -> Result<BatchUpdateValuesResponse, GSheetError> {
-        let url = format!(
-            "{}/{}/values:batchUpdate",
-            self.sheet.spreadsheet.gsheet_client.base_url, self.sheet.spreadsheet.spreadsheet_id
-        );
+        validate_sheet_title(&self.sheet.sheet_title)?;
+        if self.value_ranges.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "At least one value range must be added".into(),
+            ));
+        }
+        let data = self
+            .value_ranges
+            .iter()
+            .map(|value_range| {
+                let major_dimension = value_range.major_dimension.clone().unwrap_or_default();
+                let (range, values) = fit_values(
+                    value_range.range.as_deref().unwrap_or_default(),
+                    value_range.values.clone().unwrap_or_default(),
+                    &major_dimension,
+                    self.range_fit,
+                )?;
+                Ok(ValueRange {
+                    range: Some(format!(
+                        "{}!{}",
+                        quote_sheet_title(&self.sheet.sheet_title),
+                        range
+                    )),
+                    major_dimension: Some(major_dimension),
+                    values: Some(values),
+                })
+            })
+            .collect::<Result<Vec<ValueRange>, GSheetError>>()?;
 
-        let auth_client = self
-            .sheet
-            .spreadsheet
-            .gsheet_client
-            .auth_client
-            .lock()
-            .map_err(|e| GSheetError::AuthError(AuthError::Other(e.to_string())))?;
+        let url = self.sheet.spreadsheet.gsheet_client.spreadsheet_url(
+            &self.sheet.spreadsheet.spreadsheet_id,
+            "/values:batchUpdate",
+        );
 
         let body = serde_json::json!({
             "valueInputOption": self.value_input_option,
-            "data": self.value_ranges,
+            "data": data,
             "includeValuesInResponse": self.include_values_in_response,
             "responseValueRenderOption": self.response_value_render_option.to_string(),
             "responseDateTimeRenderOption": self.response_date_time_render_option.to_string(),
         });
 
-        let response = self
-            .sheet
-            .spreadsheet
-            .gsheet_client
-            .client
-            .post(&url)
-            .bearer_auth(auth_client.get_token())
-            .json(&body)
-            .send()
-            .await?;
+        let gsheet_client = &self.sheet.spreadsheet.gsheet_client;
+        let request = gsheet_client
+            .request(reqwest::Method::POST, &url, self.timeout)
+            .await?
+            .json(&body);
+        let response = gsheet_client.send(request).await?;
 
         if response.status().is_success() {
-            let result: BatchUpdateValuesResponse = response.json().await?;
+            self.sheet
+                .spreadsheet
+                .gsheet_client
+                .invalidate_cache(&self.sheet.spreadsheet.spreadsheet_id);
+            let result: BatchUpdateValuesResponse = decode_json(response).await?;
             Ok(result)
         } else {
-            Err(GSheetError::from(response.error_for_status().unwrap_err()))
+            Err(GSheetError::from_response(response, &self.sheet.spreadsheet.spreadsheet_id).await)
         }
     }
 }
 
-pub struct GetAllValueOperations {
+pub struct UpdateFromOperations {
     sheet: SheetOperations,
+    cell: String,
+    values: Vec<Vec<String>>,
     major_dimension: Dimension,
-    value_render_option: ValueRenderOption,
-    date_time_render_option: DateTimeRenderOption,
+    value_input_option: ValueInputOption,
+    include_values_in_response: bool,
+    response_value_render_option: ValueRenderOption,
+    response_date_time_render_option: DateTimeRenderOption,
+    timeout: Option<Duration>,
 }
 
-impl GetAllValueOperations {
-    pub fn new(sheet: &SheetOperations) -> Self {
+impl UpdateFromOperations {
+    pub fn new(sheet: &SheetOperations, cell: &str, values: Vec<Vec<String>>) -> Self {
         Self {
             sheet: sheet.clone(),
+            cell: cell.to_string(),
+            values,
             major_dimension: Dimension::default(),
-            value_render_option: ValueRenderOption::default(),
-            date_time_render_option: DateTimeRenderOption::default(),
+            value_input_option: sheet.spreadsheet.value_options.value_input_option.clone(),
+            include_values_in_response: false,
+            response_value_render_option: sheet
+                .spreadsheet
+                .value_options
+                .value_render_option
+                .clone(),
+            response_date_time_render_option: sheet
+                .spreadsheet
+                .value_options
+                .date_time_render_option
+                .clone(),
+            timeout: None,
         }
     }
 
@@ -240,127 +644,376 @@ impl GetAllValueOperations {
         self
     }
 
-    pub fn value_render_option(mut self, option: ValueRenderOption) -> Self {
-        self.value_render_option = option;
+    pub fn value_input_option(mut self, option: ValueInputOption) -> Self {
+        self.value_input_option = option;
         self
     }
 
-    pub fn date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
-        self.date_time_render_option = option;
+    pub fn include_values_in_response(mut self, include: bool) -> Self {
+        self.include_values_in_response = include;
         self
     }
 
-    pub async fn execute(&self) -> Result<ValueRange, GSheetError> {
-        let url = format!(
-            "{}/{}/values/{}",
-            self.sheet.spreadsheet.gsheet_client.base_url,
-            self.sheet.spreadsheet.spreadsheet_id,
-            self.sheet.sheet_title
-        );
+    pub fn response_value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.response_value_render_option = option;
+        self
+    }
+
+    pub fn response_date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.response_date_time_render_option = option;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<UpdateValuesResponse, GSheetError> {
+        validate_sheet_title(&self.sheet.sheet_title)?;
+        let (range, values) = fit_values(
+            &self.cell,
+            self.values.clone(),
+            &self.major_dimension,
+            RangeFit::Expand,
+        )?;
+        let range = format!("{}!{}", quote_sheet_title(&self.sheet.sheet_title), range);
+
+        let gsheet_client = &self.sheet.spreadsheet.gsheet_client;
+        let url = gsheet_client.values_url(&self.sheet.spreadsheet.spreadsheet_id, &range, "");
+
+        let body = ValueRange {
+            range: Some(range),
+            major_dimension: Some(self.major_dimension.clone()),
+            values: Some(values),
+        };
 
-        let auth_client = self
-            .sheet
-            .spreadsheet
-            .gsheet_client
-            .auth_client
-            .lock()
-            .map_err(|e| GSheetError::AuthError(AuthError::Other(e.to_string())))?;
-
-        let request = self
-            .sheet
-            .spreadsheet
-            .gsheet_client
-            .client
-            .get(&url)
-            .bearer_auth(auth_client.get_token())
-            .query(&[("majorDimension", self.major_dimension.to_string())])
-            .query(&[("valueRenderOption", self.value_render_option.to_string())])
-            .query(&[(
-                "dateTimeRenderOption",
-                self.date_time_render_option.to_string(),
-            )]);
-
-        let response = request.send().await?;
+        let query = QueryParams::new()
+            .value_input_option(&self.value_input_option)
+            .response_values(
+                self.include_values_in_response,
+                &self.response_value_render_option,
+                &self.response_date_time_render_option,
+            );
+        let request = gsheet_client
+            .request(reqwest::Method::PUT, &query.url(&url), self.timeout)
+            .await?
+            .json(&body);
+        let response = gsheet_client.send(request).await?;
 
         if response.status().is_success() {
-            let value_range: ValueRange = response.json().await?;
-            Ok(value_range)
+            gsheet_client.invalidate_cache(&self.sheet.spreadsheet.spreadsheet_id);
+            let result: UpdateValuesResponse = decode_json(response).await?;
+            Ok(result)
         } else {
-            Err(GSheetError::from(response.error_for_status().unwrap_err()))
+            Err(GSheetError::from_response(response, &self.sheet.spreadsheet.spreadsheet_id).await)
         }
     }
 }
 
-pub struct GetAllCellOperations {
+/// Writes rows in chunks of consecutive rows, one request per chunk.
+///
+/// Chunks are written in order, and the progress callback is invoked after each
+/// one. A failed chunk or a cancellation stops the operation; the chunks before
+/// it stay written.
+pub struct ChunkedWriteOperations {
     sheet: SheetOperations,
-    major_dimension: Dimension,
-    value_render_option: ValueRenderOption,
-    date_time_render_option: DateTimeRenderOption,
+    cell: String,
+    values: Vec<Vec<String>>,
+    chunk_rows: usize,
+    value_input_option: ValueInputOption,
+    timeout: Option<Duration>,
+    on_progress: Option<ProgressCallback>,
+    cancellation: Option<CancellationToken>,
 }
-impl GetAllCellOperations {
-    pub fn new(sheet: &SheetOperations) -> Self {
+
+impl ChunkedWriteOperations {
+    pub fn new(sheet: &SheetOperations, cell: &str, values: Vec<Vec<String>>) -> Self {
         Self {
             sheet: sheet.clone(),
-            major_dimension: Dimension::default(),
-            value_render_option: ValueRenderOption::default(),
-            date_time_render_option: DateTimeRenderOption::default(),
+            cell: cell.to_string(),
+            values,
+            chunk_rows: DEFAULT_CHUNK_ROWS,
+            value_input_option: sheet.spreadsheet.value_options.value_input_option.clone(),
+            timeout: None,
+            on_progress: None,
+            cancellation: None,
         }
     }
 
-    pub fn major_dimension(mut self, dimension: Dimension) -> Self {
-        self.major_dimension = dimension;
+    pub fn chunk_rows(mut self, rows: usize) -> Self {
+        self.chunk_rows = rows.max(1);
         self
     }
 
-    pub fn value_render_option(mut self, option: ValueRenderOption) -> Self {
-        self.value_render_option = option;
+    pub fn value_input_option(mut self, option: ValueInputOption) -> Self {
+        self.value_input_option = option;
         self
     }
 
-    pub fn date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
-        self.date_time_render_option = option;
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
         self
     }
 
-    pub async fn execute(&self) -> Result<Vec<Cell>, GSheetError> {
-        let value_range = GetAllValueOperations::new(&self.sheet)
-            .major_dimension(self.major_dimension.clone())
-            .value_render_option(self.value_render_option.clone())
-            .date_time_render_option(self.date_time_render_option.clone())
-            .execute()
-            .await?;
+    pub fn on_progress(mut self, callback: ProgressCallback) -> Self {
+        self.on_progress = Some(callback);
+        self
+    }
 
-        if value_range.range.is_none() {
-            return Err(GSheetError::ResponseParseError("No range found".into()));
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    pub async fn execute(&self) -> Result<Vec<UpdateValuesResponse>, GSheetError> {
+        parse_a1_cell(&self.cell)?;
+        let total_rows = self.values.len();
+        let total_chunks = total_rows.div_ceil(self.chunk_rows);
+        let mut progress = Progress {
+            total_chunks,
+            total_rows,
+            ..Default::default()
+        };
+
+        let mut responses = Vec::with_capacity(total_chunks);
+        for (index, chunk) in self.values.chunks(self.chunk_rows).enumerate() {
+            if self.is_cancelled() {
+                return Err(GSheetError::Cancelled {
+                    completed_rows: progress.rows_done,
+                });
+            }
+            let cell = offset_a1(&self.cell, (index * self.chunk_rows) as isize, 0)?;
+            let mut operation = UpdateFromOperations::new(&self.sheet, &cell, chunk.to_vec())
+                .value_input_option(self.value_input_option.clone());
+            if let Some(timeout) = self.timeout {
+                operation = operation.timeout(timeout);
+            }
+            responses.push(operation.execute().await?);
+
+            progress.chunk = index + 1;
+            progress.rows_done += chunk.len();
+            progress.bytes += values_size(chunk);
+            if let Some(callback) = &self.on_progress {
+                callback(&progress);
+            }
         }
 
-        let cells = value_range_to_cells(
-            &self.sheet.spreadsheet.spreadsheet_id,
-            &self.sheet.sheet_title,
-            &value_range,
-        )?;
-        Ok(cells)
+        Ok(responses)
     }
 }
 
-pub struct GetHashMapCellOperations {
+/// Imports the values of a worksheet of an Excel workbook (xlsx, xlsm, xlsb, xls
+/// or ods), written in chunks of consecutive rows like [`ChunkedWriteOperations`].
+/// Requires the `calamine` feature.
+///
+/// Cells keep their position in the worksheet relative to the target cell, "A1"
+/// by default. Values are written as user-entered, with text forced to stay text,
+/// and formulas are imported as their cached results. With `formats` enabled,
+/// dates, times and durations are written as text Sheets recognizes, so they get
+/// a date, time or duration format; otherwise they are written as serial numbers.
+#[cfg(feature = "calamine")]
+pub struct ImportXlsxOperations {
     sheet: SheetOperations,
-    major_dimension: Dimension,
+    path: std::path::PathBuf,
+    source_sheet: String,
+    cell: String,
+    formats: bool,
+    chunk_rows: usize,
+    timeout: Option<Duration>,
+    on_progress: Option<ProgressCallback>,
+}
+
+#[cfg(feature = "calamine")]
+impl ImportXlsxOperations {
+    pub fn new(
+        sheet: &SheetOperations,
+        path: impl AsRef<std::path::Path>,
+        source_sheet: &str,
+    ) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            path: path.as_ref().to_path_buf(),
+            source_sheet: source_sheet.to_string(),
+            cell: "A1".to_string(),
+            formats: false,
+            chunk_rows: DEFAULT_CHUNK_ROWS,
+            timeout: None,
+            on_progress: None,
+        }
+    }
+
+    /// Sets the cell receiving the A1 cell of the worksheet.
+    pub fn cell(mut self, cell: &str) -> Self {
+        self.cell = cell.to_string();
+        self
+    }
+
+    /// Sets whether dates, times and durations keep a matching number format.
+    pub fn formats(mut self, formats: bool) -> Self {
+        self.formats = formats;
+        self
+    }
+
+    pub fn chunk_rows(mut self, rows: usize) -> Self {
+        self.chunk_rows = rows.max(1);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn on_progress(mut self, callback: ProgressCallback) -> Self {
+        self.on_progress = Some(callback);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<Vec<UpdateValuesResponse>, GSheetError> {
+        parse_a1_cell(&self.cell)?;
+        let path = self.path.clone();
+        let source_sheet = self.source_sheet.clone();
+        // Workbooks are read with blocking file I/O, kept off the async workers.
+        let range = tokio::task::spawn_blocking(move || read_worksheet(&path, &source_sheet))
+            .await
+            .map_err(|e| GSheetError::Other(format!("Failed to read workbook: {}", e)))??;
+        let Some(start) = range.start() else {
+            return Ok(Vec::new());
+        };
+        let values: Vec<Vec<String>> = range
+            .rows()
+            .map(|row| {
+                let mut values: Vec<String> = row
+                    .iter()
+                    .map(|data| xlsx_cell_text(data, self.formats))
+                    .collect();
+                while values.last().is_some_and(String::is_empty) {
+                    values.pop();
+                }
+                values
+            })
+            .collect();
+
+        let cell = offset_a1(&self.cell, start.0 as isize, start.1 as isize)?;
+        let mut operation = ChunkedWriteOperations::new(&self.sheet, &cell, values)
+            .chunk_rows(self.chunk_rows)
+            .value_input_option(ValueInputOption::UserEntered);
+        if let Some(timeout) = self.timeout {
+            operation = operation.timeout(timeout);
+        }
+        if let Some(callback) = &self.on_progress {
+            operation = operation.on_progress(callback.clone());
+        }
+        operation.execute().await
+    }
+}
+
+/// Reads the used range of a worksheet.
+#[cfg(feature = "calamine")]
+fn read_worksheet(
+    path: &std::path::Path,
+    source_sheet: &str,
+) -> Result<calamine::Range<calamine::Data>, GSheetError> {
+    use calamine::Reader;
+
+    let mut workbook = calamine::open_workbook_auto(path).map_err(|e| {
+        GSheetError::Other(format!("Failed to open workbook {}: {}", path.display(), e))
+    })?;
+    workbook.worksheet_range(source_sheet).map_err(|e| {
+        GSheetError::Other(format!(
+            "Failed to read worksheet '{}' of {}: {}",
+            source_sheet,
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// Returns the user-entered text of a workbook cell.
+#[cfg(feature = "calamine")]
+fn xlsx_cell_text(data: &calamine::Data, formats: bool) -> String {
+    use calamine::Data;
+
+    match data {
+        Data::Empty => String::new(),
+        Data::Int(value) => value.to_string(),
+        Data::Float(value) => value.to_string(),
+        Data::Bool(value) => value.to_string().to_uppercase(),
+        // The apostrophe keeps text that looks like a number or formula as text.
+        Data::String(text) if text.is_empty() => String::new(),
+        Data::String(text) => format!("'{}", text),
+        Data::DateTime(date_time) if formats && date_time.is_duration() => date_time
+            .as_duration()
+            .map(|duration| {
+                let seconds = duration.num_seconds();
+                format!(
+                    "{}{}:{:02}:{:02}",
+                    if seconds < 0 { "-" } else { "" },
+                    seconds.abs() / 3600,
+                    seconds.abs() / 60 % 60,
+                    seconds.abs() % 60
+                )
+            })
+            .unwrap_or_else(|| date_time.as_f64().to_string()),
+        Data::DateTime(date_time) if formats => date_time
+            .as_datetime()
+            .map(|value| {
+                if date_time.as_f64() < 1.0 {
+                    value.format("%H:%M:%S").to_string()
+                } else if date_time.as_f64().fract() == 0.0 {
+                    value.format("%Y-%m-%d").to_string()
+                } else {
+                    value.format("%Y-%m-%d %H:%M:%S").to_string()
+                }
+            })
+            .unwrap_or_else(|| date_time.as_f64().to_string()),
+        Data::DateTime(date_time) => date_time.as_f64().to_string(),
+        Data::DateTimeIso(text) | Data::DurationIso(text) => text.clone(),
+        Data::Error(error) => error.to_string(),
+    }
+}
+
+/// Reads the whole grid of a sheet in chunks of consecutive rows, one request
+/// per chunk.
+///
+/// The number of rows comes from the grid properties of the sheet, and the
+/// progress callback is invoked after each chunk. Reads bypass the cache.
+pub struct ChunkedReadOperations {
+    sheet: SheetOperations,
+    chunk_rows: usize,
     value_render_option: ValueRenderOption,
     date_time_render_option: DateTimeRenderOption,
+    timeout: Option<Duration>,
+    on_progress: Option<ProgressCallback>,
+    cancellation: Option<CancellationToken>,
 }
-impl GetHashMapCellOperations {
+
+impl ChunkedReadOperations {
     pub fn new(sheet: &SheetOperations) -> Self {
         Self {
             sheet: sheet.clone(),
-            major_dimension: Dimension::default(),
-            value_render_option: ValueRenderOption::default(),
-            date_time_render_option: DateTimeRenderOption::default(),
+            chunk_rows: DEFAULT_CHUNK_ROWS,
+            value_render_option: sheet.spreadsheet.value_options.value_render_option.clone(),
+            date_time_render_option: sheet
+                .spreadsheet
+                .value_options
+                .date_time_render_option
+                .clone(),
+            timeout: None,
+            on_progress: None,
+            cancellation: None,
         }
     }
 
-    pub fn major_dimension(mut self, dimension: Dimension) -> Self {
-        self.major_dimension = dimension;
+    pub fn chunk_rows(mut self, rows: usize) -> Self {
+        self.chunk_rows = rows.max(1);
         self
     }
 
@@ -374,23 +1027,3212 @@ impl GetHashMapCellOperations {
         self
     }
 
-    pub async fn execute(&self) -> Result<HashMap<String, HashMap<usize, Cell>>, GSheetError> {
-        let value_range = GetAllValueOperations::new(&self.sheet)
-            .major_dimension(self.major_dimension.clone())
-            .value_render_option(self.value_render_option.clone())
-            .date_time_render_option(self.date_time_render_option.clone())
-            .execute()
-            .await?;
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
 
-        if value_range.range.is_none() {
-            return Err(GSheetError::ResponseParseError("No range found".into()));
-        }
+    pub fn on_progress(mut self, callback: ProgressCallback) -> Self {
+        self.on_progress = Some(callback);
+        self
+    }
 
-        let hash_map = value_range_to_hash_cell_map(
-            &self.sheet.spreadsheet.spreadsheet_id,
-            &self.sheet.sheet_title,
-            &value_range,
-        )?;
-        Ok(hash_map)
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    pub async fn execute(&self) -> Result<ValueRange, GSheetError> {
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+        let total_rows = properties
+            .grid_properties
+            .and_then(|grid| grid.row_count)
+            .unwrap_or_default()
+            .max(0) as usize;
+        let total_chunks = total_rows.div_ceil(self.chunk_rows);
+        let mut progress = Progress {
+            total_chunks,
+            total_rows,
+            ..Default::default()
+        };
+
+        let mut values: Vec<Vec<String>> = Vec::new();
+        for index in 0..total_chunks {
+            if self.is_cancelled() {
+                return Err(GSheetError::Cancelled {
+                    completed_rows: progress.rows_done,
+                });
+            }
+            let first_row = index * self.chunk_rows;
+            let last_row = (first_row + self.chunk_rows).min(total_rows);
+            let mut operation = BatchGetValueRangeOperations::new(&self.sheet)
+                .range(&format!("{}:{}", first_row + 1, last_row))
+                .value_render_option(self.value_render_option.clone())
+                .date_time_render_option(self.date_time_render_option.clone())
+                .use_cache(false);
+            if let Some(timeout) = self.timeout {
+                operation = operation.timeout(timeout);
+            }
+            let rows = operation
+                .execute()
+                .await?
+                .value_ranges
+                .into_iter()
+                .next()
+                .and_then(|value_range| value_range.values)
+                .unwrap_or_default();
+
+            progress.chunk = index + 1;
+            progress.rows_done = last_row;
+            progress.bytes += values_size(&rows);
+            if !rows.is_empty() {
+                // Rows of a chunk start at its first row, even when it is empty.
+                values.resize(first_row, Vec::new());
+                values.extend(rows);
+            }
+            if let Some(callback) = &self.on_progress {
+                callback(&progress);
+            }
+        }
+
+        let cols = values.iter().map(Vec::len).max().unwrap_or(0);
+        let range = if values.is_empty() || cols == 0 {
+            quote_sheet_title(&self.sheet.sheet_title)
+        } else {
+            format!(
+                "{}!{}",
+                quote_sheet_title(&self.sheet.sheet_title),
+                a1_range_from_shape("A1", values.len(), cols)?
+            )
+        };
+
+        Ok(ValueRange {
+            range: Some(range),
+            major_dimension: Some(Dimension::Rows),
+            values: Some(values),
+        })
+    }
+}
+
+/// Appends rows after the last row of a table.
+///
+/// The API searches the table range for a table, that is a block of consecutive
+/// non-empty rows, and writes the values below its last row. Without a table
+/// range the whole sheet is searched from its first cell.
+pub struct AppendOperations {
+    sheet: SheetOperations,
+    values: Vec<Vec<String>>,
+    table_range: Option<String>,
+    major_dimension: Dimension,
+    value_input_option: ValueInputOption,
+    insert_data_option: InsertDataOption,
+    include_values_in_response: bool,
+    response_value_render_option: ValueRenderOption,
+    response_date_time_render_option: DateTimeRenderOption,
+    timeout: Option<Duration>,
+}
+
+impl AppendOperations {
+    pub fn new(sheet: &SheetOperations, values: Vec<Vec<String>>) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            values,
+            table_range: None,
+            major_dimension: Dimension::default(),
+            value_input_option: sheet.spreadsheet.value_options.value_input_option.clone(),
+            insert_data_option: InsertDataOption::default(),
+            include_values_in_response: false,
+            response_value_render_option: sheet
+                .spreadsheet
+                .value_options
+                .value_render_option
+                .clone(),
+            response_date_time_render_option: sheet
+                .spreadsheet
+                .value_options
+                .date_time_render_option
+                .clone(),
+            timeout: None,
+        }
+    }
+
+    /// Sets the range searched for the table, without sheet title (e.g., "A:D").
+    ///
+    /// The values are appended after the first table found in the range, starting
+    /// in its first column, even if the table extends beyond the range.
+    pub fn table_range(mut self, range: &str) -> Self {
+        self.table_range = Some(range.to_string());
+        self
+    }
+
+    /// Anchors the append under the table whose header is on the given 1-based row,
+    /// ignoring tables above it.
+    pub fn after_header(self, header_row: usize) -> Self {
+        self.table_range(&format!("{header_row}:{header_row}"))
+    }
+
+    pub fn insert_data_option(mut self, option: InsertDataOption) -> Self {
+        self.insert_data_option = option;
+        self
+    }
+
+    pub fn major_dimension(mut self, dimension: Dimension) -> Self {
+        self.major_dimension = dimension;
+        self
+    }
+
+    pub fn value_input_option(mut self, option: ValueInputOption) -> Self {
+        self.value_input_option = option;
+        self
+    }
+
+    pub fn include_values_in_response(mut self, include: bool) -> Self {
+        self.include_values_in_response = include;
+        self
+    }
+
+    pub fn response_value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.response_value_render_option = option;
+        self
+    }
+
+    pub fn response_date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.response_date_time_render_option = option;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<AppendValuesResponse, GSheetError> {
+        validate_sheet_title(&self.sheet.sheet_title)?;
+        if self.values.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "At least one row must be appended".into(),
+            ));
+        }
+        let range = match &self.table_range {
+            Some(table_range) => {
+                validate_a1_range(table_range)?;
+                format!(
+                    "{}!{}",
+                    quote_sheet_title(&self.sheet.sheet_title),
+                    table_range
+                )
+            }
+            None => quote_sheet_title(&self.sheet.sheet_title),
+        };
+
+        let gsheet_client = &self.sheet.spreadsheet.gsheet_client;
+        let url =
+            gsheet_client.values_url(&self.sheet.spreadsheet.spreadsheet_id, &range, ":append");
+
+        let body = ValueRange {
+            range: Some(range),
+            major_dimension: Some(self.major_dimension.clone()),
+            values: Some(self.values.clone()),
+        };
+
+        let query = QueryParams::new()
+            .value_input_option(&self.value_input_option)
+            .insert_data_option(&self.insert_data_option)
+            .response_values(
+                self.include_values_in_response,
+                &self.response_value_render_option,
+                &self.response_date_time_render_option,
+            );
+        let request = gsheet_client
+            .request(reqwest::Method::POST, &query.url(&url), self.timeout)
+            .await?
+            .json(&body);
+        let response = gsheet_client.send(request).await?;
+
+        if response.status().is_success() {
+            gsheet_client.invalidate_cache(&self.sheet.spreadsheet.spreadsheet_id);
+            let result: AppendValuesResponse = decode_json(response).await?;
+            Ok(result)
+        } else {
+            Err(GSheetError::from_response(response, &self.sheet.spreadsheet.spreadsheet_id).await)
+        }
+    }
+}
+
+pub struct SetHeaderOperations {
+    sheet: SheetOperations,
+    columns: Vec<String>,
+    row: usize,
+    timeout: Option<Duration>,
+}
+
+impl SetHeaderOperations {
+    pub fn new(sheet: &SheetOperations, columns: &[&str]) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+            row: 1,
+            timeout: None,
+        }
+    }
+
+    pub fn row(mut self, row: usize) -> Self {
+        self.row = row;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<UpdateValuesResponse, GSheetError> {
+        if self.columns.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "Header must have at least one column".into(),
+            ));
+        }
+        // Header names are written as is, so a name like "2024" stays text.
+        let mut update = UpdateFromOperations::new(
+            &self.sheet,
+            &format!("A{}", self.row),
+            vec![self.columns.clone()],
+        )
+        .value_input_option(ValueInputOption::Raw);
+        if let Some(timeout) = self.timeout {
+            update = update.timeout(timeout);
+        }
+        update.execute().await
+    }
+}
+
+pub struct GetHeaderOperations {
+    sheet: SheetOperations,
+    row: usize,
+    frozen_rows: bool,
+    timeout: Option<Duration>,
+    use_cache: bool,
+}
+
+impl GetHeaderOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            row: 1,
+            frozen_rows: false,
+            timeout: None,
+            use_cache: true,
+        }
+    }
+
+    pub fn row(mut self, row: usize) -> Self {
+        self.row = row;
+        self
+    }
+
+    /// Treats the frozen rows of the sheet as the header, combining their names
+    /// when the header spans several rows. Sheets without frozen rows fall back
+    /// to the header row.
+    pub fn frozen_rows(mut self, frozen_rows: bool) -> Self {
+        self.frozen_rows = frozen_rows;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
+    pub async fn execute(&self) -> Result<Header, GSheetError> {
+        let (first_row, last_row) =
+            header_rows(&self.sheet, self.row, self.frozen_rows, self.timeout).await?;
+
+        let mut get = BatchGetValueRangeOperations::new(&self.sheet)
+            .range(&format!("{first_row}:{last_row}"))
+            .use_cache(self.use_cache);
+        if let Some(timeout) = self.timeout {
+            get = get.timeout(timeout);
+        }
+        let mut rows = get
+            .execute()
+            .await?
+            .value_ranges
+            .into_iter()
+            .next()
+            .and_then(|value_range| value_range.values)
+            .unwrap_or_default();
+        rows.resize(last_row - first_row + 1, Vec::new());
+
+        Ok(Header::from_rows(first_row, &rows))
+    }
+}
+
+/// Returns the first and last 1-based rows of a header, which are the frozen
+/// rows when requested and the sheet has some, and the header row otherwise.
+async fn header_rows(
+    sheet: &SheetOperations,
+    row: usize,
+    frozen_rows: bool,
+    timeout: Option<Duration>,
+) -> Result<(usize, usize), GSheetError> {
+    if !frozen_rows {
+        return Ok((row, row));
+    }
+    let properties = fetch_sheet_properties(sheet, timeout).await?;
+    let frozen = properties
+        .grid_properties
+        .and_then(|grid| grid.frozen_row_count)
+        .unwrap_or(0);
+    match usize::try_from(frozen) {
+        Ok(frozen) if frozen > 0 => Ok((1, frozen)),
+        _ => Ok((row, row)),
+    }
+}
+
+pub struct RowsAsOperations<T> {
+    sheet: SheetOperations,
+    header_row: usize,
+    frozen_rows: bool,
+    skip_hidden: bool,
+    skip_filtered: bool,
+    value_render_option: ValueRenderOption,
+    timeout: Option<Duration>,
+    use_cache: bool,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: FromRow> RowsAsOperations<T> {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            header_row: 1,
+            frozen_rows: false,
+            skip_hidden: false,
+            skip_filtered: false,
+            value_render_option: sheet.spreadsheet.value_options.value_render_option.clone(),
+            timeout: None,
+            use_cache: true,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn header_row(mut self, row: usize) -> Self {
+        self.header_row = row;
+        self
+    }
+
+    /// Treats the frozen rows of the sheet as the header, like
+    /// [`GetHeaderOperations::frozen_rows`].
+    pub fn frozen_rows(mut self, frozen_rows: bool) -> Self {
+        self.frozen_rows = frozen_rows;
+        self
+    }
+
+    /// Sets whether the rows hidden by the user are skipped and the columns
+    /// hidden by the user read as empty, at the cost of a request for the row
+    /// and column metadata of the sheet.
+    pub fn skip_hidden(mut self, skip_hidden: bool) -> Self {
+        self.skip_hidden = skip_hidden;
+        self
+    }
+
+    /// Sets whether the rows hidden by the basic filter of the sheet are skipped,
+    /// reproducing the filtered view, at the cost of a request for the row and
+    /// column metadata of the sheet.
+    pub fn skip_filtered(mut self, skip_filtered: bool) -> Self {
+        self.skip_filtered = skip_filtered;
+        self
+    }
+
+    pub fn value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.value_render_option = option;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
+    /// Reads the sheet and converts every non-empty row below the header.
+    ///
+    /// Rows failing to convert yield a [`RowParseError`] locating the offending
+    /// cell; the request itself only fails on API errors.
+    pub async fn execute(&self) -> Result<Vec<Result<T, RowParseError>>, GSheetError> {
+        let (first_row, last_row) =
+            header_rows(&self.sheet, self.header_row, self.frozen_rows, self.timeout).await?;
+
+        let mut get = GetAllValueOperations::new(&self.sheet)
+            .value_render_option(self.value_render_option.clone())
+            .use_cache(self.use_cache);
+        if let Some(timeout) = self.timeout {
+            get = get.timeout(timeout);
+        }
+        let mut rows = get.execute().await?.values.unwrap_or_default();
+
+        let hidden = hidden_dimensions(
+            &self.sheet,
+            self.skip_hidden,
+            self.skip_filtered,
+            self.timeout,
+        )
+        .await?;
+        for row in &mut rows {
+            for (j, value) in row.iter_mut().enumerate() {
+                if hidden.is_column_hidden(j + 1) {
+                    value.clear();
+                }
+            }
+        }
+
+        let mut header_rows = rows
+            .get(first_row.saturating_sub(1)..last_row.min(rows.len()))
+            .unwrap_or_default()
+            .to_vec();
+        header_rows.resize(last_row - first_row + 1, Vec::new());
+        let header = Header::from_rows(first_row, &header_rows);
+
+        Ok(rows
+            .iter()
+            .enumerate()
+            .skip(last_row)
+            .filter(|(index, _)| !hidden.is_row_hidden(index + 1))
+            .filter(|(_, values)| values.iter().any(|value| !value.trim().is_empty()))
+            .map(|(index, values)| T::from_row(&RowView::new(index + 1, &header, values)))
+            .collect())
+    }
+}
+
+pub struct ValidateOperations {
+    sheet: SheetOperations,
+    schema: SheetSchema,
+    timeout: Option<Duration>,
+    use_cache: bool,
+}
+
+impl ValidateOperations {
+    pub fn new(sheet: &SheetOperations, schema: &SheetSchema) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            schema: schema.clone(),
+            timeout: None,
+            use_cache: true,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
+    /// Reads the sheet and returns every cell or column violating the schema.
+    pub async fn execute(&self) -> Result<Vec<SchemaViolation>, GSheetError> {
+        let mut get = GetAllValueOperations::new(&self.sheet).use_cache(self.use_cache);
+        if let Some(timeout) = self.timeout {
+            get = get.timeout(timeout);
+        }
+        let rows = get.execute().await?.values.unwrap_or_default();
+        Ok(self.schema.validate_rows(&rows))
+    }
+}
+
+pub struct GetAllValueOperations {
+    sheet: SheetOperations,
+    major_dimension: Dimension,
+    value_render_option: ValueRenderOption,
+    date_time_render_option: DateTimeRenderOption,
+    timeout: Option<Duration>,
+    use_cache: bool,
+}
+
+impl GetAllValueOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            major_dimension: Dimension::default(),
+            value_render_option: sheet.spreadsheet.value_options.value_render_option.clone(),
+            date_time_render_option: sheet
+                .spreadsheet
+                .value_options
+                .date_time_render_option
+                .clone(),
+            timeout: None,
+            use_cache: true,
+        }
+    }
+
+    pub fn major_dimension(mut self, dimension: Dimension) -> Self {
+        self.major_dimension = dimension;
+        self
+    }
+
+    pub fn value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.value_render_option = option;
+        self
+    }
+
+    pub fn date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.date_time_render_option = option;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
+    pub async fn execute(&self) -> Result<ValueRange, GSheetError> {
+        validate_sheet_title(&self.sheet.sheet_title)?;
+
+        let gsheet_client = &self.sheet.spreadsheet.gsheet_client;
+        let cache_key = CacheKey::new(
+            &self.sheet.spreadsheet.spreadsheet_id,
+            &self.sheet.sheet_title,
+            &self.major_dimension,
+            &self.value_render_option,
+            &self.date_time_render_option,
+        );
+
+        if let Some(value_range) = gsheet_client
+            .cache
+            .as_ref()
+            .filter(|_| self.use_cache)
+            .and_then(|cache| cache.get(&cache_key))
+        {
+            return Ok(value_range);
+        }
+
+        let url = gsheet_client.values_url(
+            &self.sheet.spreadsheet.spreadsheet_id,
+            &quote_sheet_title(&self.sheet.sheet_title),
+            "",
+        );
+
+        let query = QueryParams::new()
+            .major_dimension(&self.major_dimension)
+            .value_render_option(&self.value_render_option)
+            .date_time_render_option(&self.date_time_render_option);
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
+
+        let response = gsheet_client.send(request).await?;
+
+        if response.status().is_success() {
+            let value_range: ValueRange = decode_json(response).await?;
+            if let Some(cache) = &gsheet_client.cache {
+                cache.insert(cache_key, value_range.clone());
+            }
+            Ok(value_range)
+        } else {
+            Err(GSheetError::from_response(response, &self.sheet.spreadsheet.spreadsheet_id).await)
+        }
+    }
+}
+
+pub struct GetAllCellOperations {
+    sheet: SheetOperations,
+    major_dimension: Dimension,
+    value_render_option: ValueRenderOption,
+    date_time_render_option: DateTimeRenderOption,
+    timeout: Option<Duration>,
+    use_cache: bool,
+    include_empty: bool,
+    fill_merges: bool,
+    skip_hidden: bool,
+    skip_filtered: bool,
+}
+impl GetAllCellOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            major_dimension: Dimension::default(),
+            value_render_option: sheet.spreadsheet.value_options.value_render_option.clone(),
+            date_time_render_option: sheet
+                .spreadsheet
+                .value_options
+                .date_time_render_option
+                .clone(),
+            timeout: None,
+            use_cache: true,
+            include_empty: false,
+            fill_merges: false,
+            skip_hidden: false,
+            skip_filtered: false,
+        }
+    }
+
+    pub fn major_dimension(mut self, dimension: Dimension) -> Self {
+        self.major_dimension = dimension;
+        self
+    }
+
+    pub fn value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.value_render_option = option;
+        self
+    }
+
+    pub fn date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.date_time_render_option = option;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
+    pub fn include_empty(mut self, include_empty: bool) -> Self {
+        self.include_empty = include_empty;
+        self
+    }
+
+    /// Sets whether the value of each merged region is given to every cell of
+    /// the region, at the cost of a request for the merges of the sheet.
+    pub fn fill_merges(mut self, fill_merges: bool) -> Self {
+        self.fill_merges = fill_merges;
+        self
+    }
+
+    /// Sets whether the cells of the rows and columns hidden by the user are
+    /// left out, so the result matches the UI, at the cost of a request for the
+    /// row and column metadata of the sheet.
+    pub fn skip_hidden(mut self, skip_hidden: bool) -> Self {
+        self.skip_hidden = skip_hidden;
+        self
+    }
+
+    /// Sets whether the cells of the rows hidden by the basic filter of the sheet
+    /// are left out, reproducing the filtered view, at the cost of a request for
+    /// the row and column metadata of the sheet.
+    pub fn skip_filtered(mut self, skip_filtered: bool) -> Self {
+        self.skip_filtered = skip_filtered;
+        self
+    }
+
+    pub async fn execute(&self) -> Result<Vec<Cell>, GSheetError> {
+        let mut value_range = GetAllValueOperations {
+            timeout: self.timeout,
+            use_cache: self.use_cache,
+            ..GetAllValueOperations::new(&self.sheet)
+                .major_dimension(self.major_dimension.clone())
+                .value_render_option(self.value_render_option.clone())
+                .date_time_render_option(self.date_time_render_option.clone())
+        }
+        .execute()
+        .await?;
+
+        if value_range.range.is_none() && !value_range.is_empty() {
+            return Err(GSheetError::ResponseParseError("No range found".into()));
+        }
+        if self.fill_merges {
+            let merges = fetch_sheet_merges(&self.sheet, self.timeout).await?;
+            fill_merged_values(&mut value_range, &merges)?;
+        }
+        let hidden = hidden_dimensions(
+            &self.sheet,
+            self.skip_hidden,
+            self.skip_filtered,
+            self.timeout,
+        )
+        .await?;
+
+        cells_iter(
+            &self.sheet.spreadsheet.spreadsheet_id,
+            &self.sheet.sheet_title,
+            &value_range,
+            self.include_empty,
+        )?
+        .filter(|cell| {
+            cell.as_ref()
+                .map_or(true, |cell| !hidden.is_cell_hidden(&cell.address))
+        })
+        .collect()
+    }
+}
+
+pub struct GetHashMapCellOperations {
+    sheet: SheetOperations,
+    major_dimension: Dimension,
+    value_render_option: ValueRenderOption,
+    date_time_render_option: DateTimeRenderOption,
+    timeout: Option<Duration>,
+    use_cache: bool,
+    fill_merges: bool,
+    skip_hidden: bool,
+    skip_filtered: bool,
+}
+impl GetHashMapCellOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            major_dimension: Dimension::default(),
+            value_render_option: sheet.spreadsheet.value_options.value_render_option.clone(),
+            date_time_render_option: sheet
+                .spreadsheet
+                .value_options
+                .date_time_render_option
+                .clone(),
+            timeout: None,
+            use_cache: true,
+            fill_merges: false,
+            skip_hidden: false,
+            skip_filtered: false,
+        }
+    }
+
+    pub fn major_dimension(mut self, dimension: Dimension) -> Self {
+        self.major_dimension = dimension;
+        self
+    }
+
+    pub fn value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.value_render_option = option;
+        self
+    }
+
+    pub fn date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.date_time_render_option = option;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
+    /// Sets whether the value of each merged region is given to every cell of
+    /// the region, at the cost of a request for the merges of the sheet.
+    pub fn fill_merges(mut self, fill_merges: bool) -> Self {
+        self.fill_merges = fill_merges;
+        self
+    }
+
+    /// Sets whether the cells of the rows and columns hidden by the user are
+    /// left out, so the result matches the UI, at the cost of a request for the
+    /// row and column metadata of the sheet.
+    pub fn skip_hidden(mut self, skip_hidden: bool) -> Self {
+        self.skip_hidden = skip_hidden;
+        self
+    }
+
+    /// Sets whether the cells of the rows hidden by the basic filter of the sheet
+    /// are left out, reproducing the filtered view, at the cost of a request for
+    /// the row and column metadata of the sheet.
+    pub fn skip_filtered(mut self, skip_filtered: bool) -> Self {
+        self.skip_filtered = skip_filtered;
+        self
+    }
+
+    pub async fn execute(&self) -> Result<HashMap<String, HashMap<usize, Cell>>, GSheetError> {
+        let mut value_range = GetAllValueOperations {
+            timeout: self.timeout,
+            use_cache: self.use_cache,
+            ..GetAllValueOperations::new(&self.sheet)
+                .major_dimension(self.major_dimension.clone())
+                .value_render_option(self.value_render_option.clone())
+                .date_time_render_option(self.date_time_render_option.clone())
+        }
+        .execute()
+        .await?;
+
+        if value_range.range.is_none() && !value_range.is_empty() {
+            return Err(GSheetError::ResponseParseError("No range found".into()));
+        }
+        if self.fill_merges {
+            let merges = fetch_sheet_merges(&self.sheet, self.timeout).await?;
+            fill_merged_values(&mut value_range, &merges)?;
+        }
+
+        let hidden = hidden_dimensions(
+            &self.sheet,
+            self.skip_hidden,
+            self.skip_filtered,
+            self.timeout,
+        )
+        .await?;
+
+        let mut hash_map = value_range_to_hash_cell_map(
+            &self.sheet.spreadsheet.spreadsheet_id,
+            &self.sheet.sheet_title,
+            &value_range,
+        )?;
+        if !hidden.is_empty() {
+            hash_map.retain(|_, column| {
+                column.retain(|_, cell| !hidden.is_cell_hidden(&cell.address));
+                !column.is_empty()
+            });
+        }
+        Ok(hash_map)
+    }
+}
+
+pub struct GetCellMapOperations {
+    sheet: SheetOperations,
+    value_render_option: ValueRenderOption,
+    date_time_render_option: DateTimeRenderOption,
+    timeout: Option<Duration>,
+    use_cache: bool,
+    fill_merges: bool,
+    skip_hidden: bool,
+    skip_filtered: bool,
+}
+
+impl GetCellMapOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            value_render_option: sheet.spreadsheet.value_options.value_render_option.clone(),
+            date_time_render_option: sheet
+                .spreadsheet
+                .value_options
+                .date_time_render_option
+                .clone(),
+            timeout: None,
+            use_cache: true,
+            fill_merges: false,
+            skip_hidden: false,
+            skip_filtered: false,
+        }
+    }
+
+    pub fn value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.value_render_option = option;
+        self
+    }
+
+    pub fn date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.date_time_render_option = option;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
+    /// Sets whether the value of each merged region is given to every cell of
+    /// the region, at the cost of a request for the merges of the sheet.
+    pub fn fill_merges(mut self, fill_merges: bool) -> Self {
+        self.fill_merges = fill_merges;
+        self
+    }
+
+    /// Sets whether the cells of the rows and columns hidden by the user are
+    /// left out, so the result matches the UI, at the cost of a request for the
+    /// row and column metadata of the sheet.
+    pub fn skip_hidden(mut self, skip_hidden: bool) -> Self {
+        self.skip_hidden = skip_hidden;
+        self
+    }
+
+    /// Sets whether the cells of the rows hidden by the basic filter of the sheet
+    /// are left out, reproducing the filtered view, at the cost of a request for
+    /// the row and column metadata of the sheet.
+    pub fn skip_filtered(mut self, skip_filtered: bool) -> Self {
+        self.skip_filtered = skip_filtered;
+        self
+    }
+
+    pub async fn execute(&self) -> Result<HashMap<CellAddress, Cell>, GSheetError> {
+        let (value_range, hidden) = self.read().await?;
+        let mut cells = value_range_to_cell_map(
+            &self.sheet.spreadsheet.spreadsheet_id,
+            &self.sheet.sheet_title,
+            &value_range,
+        )?;
+        cells.retain(|address, _| !hidden.is_cell_hidden(address));
+        Ok(cells)
+    }
+
+    pub async fn execute_ordered(&self) -> Result<BTreeMap<CellAddress, Cell>, GSheetError> {
+        let (value_range, hidden) = self.read().await?;
+        let mut cells = value_range_to_ordered_cell_map(
+            &self.sheet.spreadsheet.spreadsheet_id,
+            &self.sheet.sheet_title,
+            &value_range,
+        )?;
+        cells.retain(|address, _| !hidden.is_cell_hidden(address));
+        Ok(cells)
+    }
+
+    /// Reads the whole sheet, row by row, filling the merged regions if asked,
+    /// with the rows and columns to leave out.
+    async fn read(&self) -> Result<(ValueRange, HiddenDimensions), GSheetError> {
+        let mut value_range = GetAllValueOperations {
+            timeout: self.timeout,
+            use_cache: self.use_cache,
+            ..GetAllValueOperations::new(&self.sheet)
+                .value_render_option(self.value_render_option.clone())
+                .date_time_render_option(self.date_time_render_option.clone())
+        }
+        .execute()
+        .await?;
+
+        if value_range.range.is_none() && !value_range.is_empty() {
+            return Err(GSheetError::ResponseParseError("No range found".into()));
+        }
+        if self.fill_merges {
+            let merges = fetch_sheet_merges(&self.sheet, self.timeout).await?;
+            fill_merged_values(&mut value_range, &merges)?;
+        }
+        let hidden = hidden_dimensions(
+            &self.sheet,
+            self.skip_hidden,
+            self.skip_filtered,
+            self.timeout,
+        )
+        .await?;
+        Ok((value_range, hidden))
+    }
+}
+
+/// Reads the values used to locate data in a sheet.
+///
+/// When a key column is given only that column is read, otherwise the whole sheet
+/// is read. Either way the API trims trailing empty rows and columns, so only the
+/// used part of the sheet is transferred.
+async fn read_used_values(
+    sheet: &SheetOperations,
+    column: Option<&str>,
+    timeout: Option<Duration>,
+    use_cache: bool,
+) -> Result<Vec<Vec<String>>, GSheetError> {
+    let value_range = match column {
+        Some(column) => {
+            let mut batch = BatchGetValueRangeOperations {
+                timeout,
+                use_cache,
+                ..BatchGetValueRangeOperations::new(sheet).range(&format!("{column}:{column}"))
+            }
+            .execute()
+            .await?;
+            batch.value_ranges.pop().unwrap_or_default()
+        }
+        None => {
+            GetAllValueOperations {
+                timeout,
+                use_cache,
+                ..GetAllValueOperations::new(sheet)
+            }
+            .execute()
+            .await?
+        }
+    };
+
+    Ok(value_range.values.unwrap_or_default())
+}
+
+/// Returns the 1-based index of the last row holding a non-empty value.
+fn last_used_row(values: &[Vec<String>]) -> Option<usize> {
+    values
+        .iter()
+        .rposition(|row| row.iter().any(|value| !value.is_empty()))
+        .map(|i| i + 1)
+}
+
+/// Returns the 1-based index of the last column holding a non-empty value.
+fn last_used_column(values: &[Vec<String>]) -> Option<usize> {
+    values
+        .iter()
+        .filter_map(|row| row.iter().rposition(|value| !value.is_empty()))
+        .max()
+        .map(|j| j + 1)
+}
+
+pub struct LastRowOperations {
+    sheet: SheetOperations,
+    column: Option<String>,
+    timeout: Option<Duration>,
+    use_cache: bool,
+}
+
+impl LastRowOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            column: None,
+            timeout: None,
+            use_cache: true,
+        }
+    }
+
+    pub fn column(mut self, column: &str) -> Self {
+        self.column = Some(column.to_string());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
+    pub async fn execute(&self) -> Result<Option<usize>, GSheetError> {
+        let values = read_used_values(
+            &self.sheet,
+            self.column.as_deref(),
+            self.timeout,
+            self.use_cache,
+        )
+        .await?;
+        Ok(last_used_row(&values))
+    }
+}
+
+pub struct NextEmptyRowOperations {
+    last_row: LastRowOperations,
+}
+
+impl NextEmptyRowOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            last_row: LastRowOperations::new(sheet),
+        }
+    }
+
+    pub fn column(mut self, column: &str) -> Self {
+        self.last_row = self.last_row.column(column);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.last_row = self.last_row.timeout(timeout);
+        self
+    }
+
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.last_row = self.last_row.use_cache(use_cache);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<usize, GSheetError> {
+        Ok(self.last_row.execute().await?.map_or(1, |row| row + 1))
+    }
+}
+
+pub struct UsedRangeOperations {
+    sheet: SheetOperations,
+    timeout: Option<Duration>,
+    use_cache: bool,
+}
+
+impl UsedRangeOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            timeout: None,
+            use_cache: true,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
+    pub async fn execute(&self) -> Result<Option<String>, GSheetError> {
+        let values = read_used_values(&self.sheet, None, self.timeout, self.use_cache).await?;
+
+        let Some(last_row) = last_used_row(&values) else {
+            return Ok(None);
+        };
+        let first_row = values
+            .iter()
+            .position(|row| row.iter().any(|value| !value.is_empty()))
+            .map_or(1, |i| i + 1);
+
+        let used_columns = || {
+            values
+                .iter()
+                .flat_map(|row| row.iter().enumerate())
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(j, _)| j + 1)
+        };
+        let first_col = used_columns().min().unwrap_or(1);
+        let last_col = used_columns().max().unwrap_or(1);
+
+        Ok(Some(format!(
+            "{}{}:{}{}",
+            col_index_to_a1(first_col)?,
+            first_row,
+            col_index_to_a1(last_col)?,
+            last_row
+        )))
+    }
+}
+
+pub struct DimensionsOperations {
+    sheet: SheetOperations,
+    timeout: Option<Duration>,
+}
+
+impl DimensionsOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            timeout: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<GridProperties, GSheetError> {
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+        Ok(properties.grid_properties.unwrap_or_default())
+    }
+}
+
+pub struct MergesOperations {
+    sheet: SheetOperations,
+    timeout: Option<Duration>,
+}
+
+impl MergesOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            timeout: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the merged ranges of the sheet, in the order of the API.
+    pub async fn execute(&self) -> Result<Vec<GridRange>, GSheetError> {
+        fetch_sheet_merges(&self.sheet, self.timeout).await
+    }
+}
+
+/// Clears the values of the whole sheet, keeping its formats, validation rules
+/// and size.
+pub struct ClearOperations {
+    sheet: SheetOperations,
+    timeout: Option<Duration>,
+}
+
+impl ClearOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            timeout: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<ClearValuesResponse, GSheetError> {
+        validate_sheet_title(&self.sheet.sheet_title)?;
+
+        let gsheet_client = &self.sheet.spreadsheet.gsheet_client;
+        let url = gsheet_client.values_url(
+            &self.sheet.spreadsheet.spreadsheet_id,
+            &quote_sheet_title(&self.sheet.sheet_title),
+            ":clear",
+        );
+        let request = gsheet_client
+            .request(reqwest::Method::POST, &url, self.timeout)
+            .await?
+            .json(&serde_json::json!({}));
+        let response = gsheet_client.send(request).await?;
+
+        if response.status().is_success() {
+            gsheet_client.invalidate_cache(&self.sheet.spreadsheet.spreadsheet_id);
+            let result: ClearValuesResponse = decode_json(response).await?;
+            Ok(result)
+        } else {
+            Err(GSheetError::from_response(response, &self.sheet.spreadsheet.spreadsheet_id).await)
+        }
+    }
+}
+
+/// Clears the formats of the whole sheet, keeping its values.
+pub struct ClearFormatsOperations {
+    sheet: SheetOperations,
+    timeout: Option<Duration>,
+}
+
+impl ClearFormatsOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            timeout: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+        let request = Request::UpdateCells(UpdateCellsRequest {
+            rows: Vec::new(),
+            fields: "userEnteredFormat".to_string(),
+            start: None,
+            range: Some(GridRange {
+                sheet_id: properties.sheet_id,
+                ..Default::default()
+            }),
+        });
+        send_request(&self.sheet, request, self.timeout).await
+    }
+}
+
+/// Resets a sheet to an empty grid of the given size, in one batch update.
+///
+/// The values, formats, validation rules and notes of every cell are cleared,
+/// the frozen rows and columns are unfrozen, and the grid is resized, so ETL
+/// jobs can rebuild a staging sheet without deleting it, which would break the
+/// references of other sheets to it.
+///
+/// Like [`ResizeOperations`], growing the grid past the cell limit of the
+/// spreadsheet fails before any change.
+pub struct ResetOperations {
+    sheet: SheetOperations,
+    rows: i32,
+    columns: i32,
+    timeout: Option<Duration>,
+}
+
+impl ResetOperations {
+    pub fn new(sheet: &SheetOperations, rows: i32, columns: i32) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            rows,
+            columns,
+            timeout: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        if self.rows < 1 || self.columns < 1 {
+            return Err(GSheetError::ValidationError(format!(
+                "A sheet needs at least one row and one column, not {} by {}",
+                self.rows, self.columns
+            )));
+        }
+
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+        let sheet_id = properties.sheet_id;
+        let grid = properties.grid_properties.unwrap_or_default();
+        if i64::from(self.rows) * i64::from(self.columns)
+            > i64::from(grid.row_count.unwrap_or_default())
+                * i64::from(grid.column_count.unwrap_or_default())
+        {
+            check_cell_limit(&self.sheet, sheet_id, self.rows, self.columns, self.timeout).await?;
+        }
+
+        let requests = vec![
+            Request::UpdateCells(UpdateCellsRequest {
+                rows: Vec::new(),
+                fields: "*".to_string(),
+                start: None,
+                range: Some(GridRange {
+                    sheet_id,
+                    ..Default::default()
+                }),
+            }),
+            Request::UpdateSheetProperties(UpdateSheetPropertiesRequest {
+                properties: SheetProperties {
+                    sheet_id,
+                    grid_properties: Some(GridProperties {
+                        row_count: Some(self.rows),
+                        column_count: Some(self.columns),
+                        frozen_row_count: Some(0),
+                        frozen_column_count: Some(0),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                fields: "gridProperties(rowCount,columnCount,frozenRowCount,frozenColumnCount)"
+                    .to_string(),
+            }),
+        ];
+
+        let mut batch_update = self.sheet.spreadsheet.batch_update();
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        batch_update.requests(requests).build()?.execute().await?;
+        Ok(())
+    }
+}
+
+/// Resizes the grid of a sheet to a number of rows and columns, in one batch
+/// update.
+///
+/// Missing rows and columns are appended at the end of the grid, and extra ones
+/// deleted from the end. Unless `truncate` is enabled, shrinking the grid over
+/// cells holding values fails before any change, naming the last used cell.
+/// Frozen rows and columns beyond the new size are unfrozen, as the API keeps
+/// at least one row and one column unfrozen.
+///
+/// Growing the grid past the cell limit of the spreadsheet fails before any
+/// change, from the cached metadata when the client has a cache.
+pub struct ResizeOperations {
+    sheet: SheetOperations,
+    rows: i32,
+    columns: i32,
+    truncate: bool,
+    timeout: Option<Duration>,
+}
+
+impl ResizeOperations {
+    pub fn new(sheet: &SheetOperations, rows: i32, columns: i32) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            rows,
+            columns,
+            truncate: false,
+            timeout: None,
+        }
+    }
+
+    /// Sets whether values beyond the new size are deleted with their rows and
+    /// columns, instead of failing.
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        if self.rows < 1 || self.columns < 1 {
+            return Err(GSheetError::ValidationError(format!(
+                "A sheet needs at least one row and one column, not {} by {}",
+                self.rows, self.columns
+            )));
+        }
+
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+        let sheet_id = properties.sheet_id.unwrap_or_default();
+        let grid = properties.grid_properties.unwrap_or_default();
+        let current_rows = grid.row_count.unwrap_or_default();
+        let current_columns = grid.column_count.unwrap_or_default();
+
+        if !self.truncate && (self.rows < current_rows || self.columns < current_columns) {
+            let values = read_used_values(&self.sheet, None, self.timeout, false).await?;
+            let last_row = last_used_row(&values).unwrap_or_default();
+            let last_col = last_used_column(&values).unwrap_or_default();
+            if last_row > self.rows as usize || last_col > self.columns as usize {
+                return Err(GSheetError::ValidationError(format!(
+                    "Resizing sheet '{}' to {} rows by {} columns would delete values up to {}{}; \
+                     enable truncate to delete them",
+                    self.sheet.sheet_title,
+                    self.rows,
+                    self.columns,
+                    col_index_to_a1(last_col.max(1))?,
+                    last_row.max(1)
+                )));
+            }
+        }
+
+        if i64::from(self.rows) * i64::from(self.columns)
+            > i64::from(current_rows) * i64::from(current_columns)
+        {
+            check_cell_limit(
+                &self.sheet,
+                Some(sheet_id),
+                self.rows,
+                self.columns,
+                self.timeout,
+            )
+            .await?;
+        }
+
+        let mut requests = Vec::new();
+        let frozen_rows = grid.frozen_row_count.unwrap_or_default();
+        let frozen_columns = grid.frozen_column_count.unwrap_or_default();
+        if frozen_rows >= self.rows || frozen_columns >= self.columns {
+            requests.push(Request::UpdateSheetProperties(
+                UpdateSheetPropertiesRequest {
+                    properties: SheetProperties {
+                        sheet_id: Some(sheet_id),
+                        grid_properties: Some(GridProperties {
+                            frozen_row_count: Some(frozen_rows.min(self.rows - 1)),
+                            frozen_column_count: Some(frozen_columns.min(self.columns - 1)),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    fields: "gridProperties(frozenRowCount,frozenColumnCount)".to_string(),
+                },
+            ));
+        }
+        for (dimension, current, target) in [
+            (Dimension::Rows, current_rows, self.rows),
+            (Dimension::Columns, current_columns, self.columns),
+        ] {
+            if target > current {
+                requests.push(Request::AppendDimension(AppendDimensionRequest {
+                    sheet_id,
+                    dimension,
+                    length: target - current,
+                }));
+            } else if target < current {
+                requests.push(Request::DeleteDimension(DeleteDimensionRequest {
+                    range: DimensionRange {
+                        sheet_id: Some(sheet_id),
+                        dimension: Some(dimension.to_string()),
+                        start_index: Some(target),
+                        end_index: Some(current),
+                    },
+                }));
+            }
+        }
+        if requests.is_empty() {
+            return Ok(());
+        }
+
+        let mut batch_update = self.sheet.spreadsheet.batch_update();
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        batch_update.requests(requests).build()?.execute().await?;
+        Ok(())
+    }
+}
+
+/// Copies the values of a range to a sheet-qualified destination range (e.g.,
+/// "Archive!A1"), whose top-left cell receives the top-left value.
+///
+/// Within a spreadsheet, the copy is a single `CopyPasteRequest` applied by the
+/// API. Between spreadsheets, the values are read and written again as
+/// user-entered values. Computed values are copied by default; with `formulas`
+/// enabled, formulas are copied instead, their relative references shifting to
+/// the destination.
+pub struct CopyValuesOperations {
+    sheet: SheetOperations,
+    src_range: String,
+    dst_spreadsheet: SpreadsheetOperations,
+    dst_range: String,
+    formulas: bool,
+    timeout: Option<Duration>,
+}
+
+impl CopyValuesOperations {
+    pub fn new(
+        sheet: &SheetOperations,
+        src_range: &str,
+        dst_spreadsheet: &SpreadsheetOperations,
+        dst_range: &str,
+    ) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            src_range: src_range.to_string(),
+            dst_spreadsheet: dst_spreadsheet.clone(),
+            dst_range: dst_range.to_string(),
+            formulas: false,
+            timeout: None,
+        }
+    }
+
+    /// Sets whether formulas are copied instead of their computed values.
+    pub fn formulas(mut self, formulas: bool) -> Self {
+        self.formulas = formulas;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        validate_a1_range(&self.src_range)?;
+        let (dst_title, dst_cells) = split_sheet_range(&self.dst_range).map_err(|_| {
+            GSheetError::ValidationError(format!(
+                "Destination range '{}' must include a sheet title",
+                self.dst_range
+            ))
+        })?;
+        let dst_title = unquote_sheet_title(dst_title);
+        let destination = a1_to_grid_range(dst_cells)?;
+
+        if self.dst_spreadsheet.spreadsheet_id == self.sheet.spreadsheet.spreadsheet_id {
+            self.copy_paste(&dst_title, destination).await
+        } else {
+            self.read_write(&dst_title, &destination).await
+        }
+    }
+
+    /// Copies within the spreadsheet with a `CopyPasteRequest`.
+    async fn copy_paste(&self, dst_title: &str, destination: GridRange) -> Result<(), GSheetError> {
+        let mut list_sheets = self.sheet.spreadsheet.list_sheets();
+        if let Some(timeout) = self.timeout {
+            list_sheets = list_sheets.timeout(timeout);
+        }
+        let sheets = list_sheets.build()?.execute().await?;
+        let sheet_id = |title: &str| {
+            sheets
+                .iter()
+                .find(|properties| properties.title.as_deref() == Some(title))
+                .and_then(|properties| properties.sheet_id)
+                .ok_or_else(|| GSheetError::SheetNotFound {
+                    title: title.to_string(),
+                })
+        };
+
+        let source = GridRange {
+            sheet_id: Some(sheet_id(&self.sheet.sheet_title)?),
+            ..a1_to_grid_range(&self.src_range)?
+        };
+        let destination = GridRange {
+            sheet_id: Some(sheet_id(dst_title)?),
+            ..destination
+        };
+        let paste_type = if self.formulas {
+            PasteType::PasteFormula
+        } else {
+            PasteType::PasteValues
+        };
+
+        let mut batch_update = self.sheet.spreadsheet.batch_update();
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        batch_update
+            .request(Request::CopyPaste(CopyPasteRequest {
+                source,
+                destination,
+                paste_type,
+                paste_orientation: PasteOrientation::Normal,
+            }))
+            .build()?
+            .execute()
+            .await?;
+        Ok(())
+    }
+
+    /// Copies between spreadsheets by reading and writing the values.
+    async fn read_write(
+        &self,
+        dst_title: &str,
+        destination: &GridRange,
+    ) -> Result<(), GSheetError> {
+        let render_option = if self.formulas {
+            ValueRenderOption::Formula
+        } else {
+            ValueRenderOption::FormattedValue
+        };
+        let mut read = BatchGetValueRangeOperations::new(&self.sheet)
+            .range(&self.src_range)
+            .value_render_option(render_option)
+            .use_cache(false);
+        if let Some(timeout) = self.timeout {
+            read = read.timeout(timeout);
+        }
+        let values = read
+            .execute()
+            .await?
+            .value_ranges
+            .into_iter()
+            .next()
+            .and_then(|value_range| value_range.values)
+            .unwrap_or_default();
+        if values.is_empty() {
+            return Ok(());
+        }
+
+        let cell = format!(
+            "{}{}",
+            col_index_to_a1(destination.start_column_index.unwrap_or_default() as usize + 1)?,
+            destination.start_row_index.unwrap_or_default() + 1
+        );
+        let mut write = self
+            .dst_spreadsheet
+            .sheet(dst_title)
+            .update_from(&cell, values)
+            .value_input_option(ValueInputOption::UserEntered);
+        if let Some(timeout) = self.timeout {
+            write = write.timeout(timeout);
+        }
+        write.execute().await?;
+        Ok(())
+    }
+}
+
+/// The fields of the grid data rendered as HTML.
+const HTML_GRID_FIELDS: &str = "sheets.data.rowData.values(formattedValue,hyperlink,\
+    effectiveFormat(backgroundColor,backgroundColorStyle,horizontalAlignment,verticalAlignment,textFormat))";
+
+pub struct ExportHtmlOperations {
+    sheet: SheetOperations,
+    range: Option<String>,
+    timeout: Option<Duration>,
+}
+
+impl ExportHtmlOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            range: None,
+            timeout: None,
+        }
+    }
+
+    /// Restricts the export to a range of the sheet (e.g., "A1:F20"), instead of
+    /// the whole sheet.
+    pub fn range(mut self, range: &str) -> Self {
+        self.range = Some(range.to_string());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Reads the grid data with its formatted values and effective formats, and
+    /// renders it with [`render_html`].
+    pub async fn execute(&self) -> Result<String, GSheetError> {
+        if let Some(range) = &self.range {
+            validate_a1_range(range)?;
+        }
+
+        let gsheet_client = &self.sheet.spreadsheet.gsheet_client;
+        let spreadsheet_id = &self.sheet.spreadsheet.spreadsheet_id;
+        let url = gsheet_client.spreadsheet_url(spreadsheet_id, "");
+        let range = match &self.range {
+            Some(range) => format!("{}!{}", quote_sheet_title(&self.sheet.sheet_title), range),
+            None => quote_sheet_title(&self.sheet.sheet_title),
+        };
+
+        let query = QueryParams::new().ranges([range]).fields(HTML_GRID_FIELDS);
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
+        let response = gsheet_client.send(request).await?;
+
+        if !response.status().is_success() {
+            return Err(GSheetError::from_response(response, spreadsheet_id).await);
+        }
+
+        let spreadsheet: Spreadsheet = decode_json(response).await?;
+        let grid = spreadsheet
+            .sheets
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|sheet| sheet.data.unwrap_or_default())
+            .next()
+            .unwrap_or_default();
+        Ok(render_html(&grid))
+    }
+}
+
+pub struct ExportCsvOperations {
+    sheet: SheetOperations,
+    visible_only: bool,
+    value_render_option: ValueRenderOption,
+    timeout: Option<Duration>,
+    use_cache: bool,
+}
+
+impl ExportCsvOperations {
+    pub fn new(sheet: &SheetOperations) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            visible_only: false,
+            value_render_option: sheet.spreadsheet.value_options.value_render_option.clone(),
+            timeout: None,
+            use_cache: true,
+        }
+    }
+
+    /// Sets whether the rows and columns hidden by the user or by the basic
+    /// filter of the sheet are left out, so the export reproduces the view of
+    /// the sheet in the UI.
+    pub fn visible_only(mut self, visible_only: bool) -> Self {
+        self.visible_only = visible_only;
+        self
+    }
+
+    pub fn value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.value_render_option = option;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
+    /// Reads the values of the sheet and renders them with [`render_csv`].
+    pub async fn execute(&self) -> Result<String, GSheetError> {
+        let value_range = GetAllValueOperations {
+            timeout: self.timeout,
+            use_cache: self.use_cache,
+            ..GetAllValueOperations::new(&self.sheet)
+                .value_render_option(self.value_render_option.clone())
+        }
+        .execute()
+        .await?;
+        let hidden = hidden_dimensions(
+            &self.sheet,
+            self.visible_only,
+            self.visible_only,
+            self.timeout,
+        )
+        .await?;
+
+        let rows = value_range
+            .to_rows()
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !hidden.is_row_hidden(i + 1))
+            .map(|(_, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(j, _)| !hidden.is_column_hidden(j + 1))
+                    .map(|(_, value)| value.clone())
+                    .collect()
+            })
+            .collect();
+        Ok(render_csv(&ValueRange {
+            values: Some(rows),
+            ..Default::default()
+        }))
+    }
+}
+
+/// Returns the sheet with the given title from a fields-masked response.
+fn find_sheet(spreadsheet: Spreadsheet, title: &str) -> Result<Sheet, GSheetError> {
+    spreadsheet
+        .sheets
+        .unwrap_or_default()
+        .into_iter()
+        .find(|sheet| {
+            sheet
+                .properties
+                .as_ref()
+                .and_then(|properties| properties.title.as_deref())
+                == Some(title)
+        })
+        .ok_or_else(|| GSheetError::SheetNotFound {
+            title: title.to_string(),
+        })
+}
+
+/// Fetches the merged ranges of a sheet, with a fields mask limiting the response
+/// to the titles and merges of the sheets.
+pub(crate) async fn fetch_sheet_merges(
+    sheet: &SheetOperations,
+    timeout: Option<Duration>,
+) -> Result<Vec<GridRange>, GSheetError> {
+    let gsheet_client = &sheet.spreadsheet.gsheet_client;
+    let url = gsheet_client.spreadsheet_url(&sheet.spreadsheet.spreadsheet_id, "");
+
+    let query = QueryParams::new()
+        .ranges([quote_sheet_title(&sheet.sheet_title)])
+        .fields("sheets(properties.title,merges)");
+    let request = gsheet_client
+        .request(reqwest::Method::GET, &query.url(&url), timeout)
+        .await?;
+    let response = gsheet_client.send(request).await?;
+
+    if !response.status().is_success() {
+        return Err(GSheetError::from_response(response, &sheet.spreadsheet.spreadsheet_id).await);
+    }
+
+    let spreadsheet: Spreadsheet = decode_json(response).await?;
+    Ok(find_sheet(spreadsheet, &sheet.sheet_title)?
+        .merges
+        .unwrap_or_default())
+}
+
+/// The fields of the grid data locating the hidden rows and columns.
+const HIDDEN_DIMENSION_FIELDS: &str = "sheets(properties.title,data(startRow,startColumn,\
+    rowMetadata(hiddenByUser,hiddenByFilter),columnMetadata(hiddenByUser,hiddenByFilter)))";
+
+/// Fetches the rows and columns of a sheet hidden by the user, by the basic
+/// filter, or both, or none when neither is skipped.
+pub(crate) async fn hidden_dimensions(
+    sheet: &SheetOperations,
+    skip_hidden: bool,
+    skip_filtered: bool,
+    timeout: Option<Duration>,
+) -> Result<HiddenDimensions, GSheetError> {
+    if !skip_hidden && !skip_filtered {
+        return Ok(HiddenDimensions::default());
+    }
+
+    let gsheet_client = &sheet.spreadsheet.gsheet_client;
+    let url = gsheet_client.spreadsheet_url(&sheet.spreadsheet.spreadsheet_id, "");
+
+    let query = QueryParams::new()
+        .ranges([quote_sheet_title(&sheet.sheet_title)])
+        .fields(HIDDEN_DIMENSION_FIELDS);
+    let request = gsheet_client
+        .request(reqwest::Method::GET, &query.url(&url), timeout)
+        .await?;
+    let response = gsheet_client.send(request).await?;
+
+    if !response.status().is_success() {
+        return Err(GSheetError::from_response(response, &sheet.spreadsheet.spreadsheet_id).await);
+    }
+
+    let spreadsheet: Spreadsheet = decode_json(response).await?;
+    let grids = find_sheet(spreadsheet, &sheet.sheet_title)?
+        .data
+        .unwrap_or_default();
+
+    let mut hidden = HiddenDimensions::default();
+    for grid in &grids {
+        if skip_hidden {
+            hidden = hidden.union(HiddenDimensions::from_grid_data(grid));
+        }
+        if skip_filtered {
+            hidden = hidden.union(HiddenDimensions::filtered_from_grid_data(grid));
+        }
+    }
+    Ok(hidden)
+}
+
+/// Fetches the properties of a sheet, with a fields mask limiting the response to
+/// the sheet IDs, titles and grid properties of the spreadsheet.
+pub(crate) async fn fetch_sheet_properties(
+    sheet: &SheetOperations,
+    timeout: Option<Duration>,
+) -> Result<SheetProperties, GSheetError> {
+    let gsheet_client = &sheet.spreadsheet.gsheet_client;
+    let url = gsheet_client.spreadsheet_url(&sheet.spreadsheet.spreadsheet_id, "");
+
+    let query = QueryParams::new().fields("sheets.properties(sheetId,title,gridProperties)");
+    let request = gsheet_client
+        .request(reqwest::Method::GET, &query.url(&url), timeout)
+        .await?;
+    let response = gsheet_client.send(request).await?;
+
+    if !response.status().is_success() {
+        return Err(GSheetError::from_response(response, &sheet.spreadsheet.spreadsheet_id).await);
+    }
+
+    let spreadsheet: Spreadsheet = decode_json(response).await?;
+    spreadsheet
+        .sheets
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|sheet| sheet.properties)
+        .find(|properties| properties.title.as_deref() == Some(&*sheet.sheet_title))
+        .ok_or_else(|| GSheetError::SheetNotFound {
+            title: sheet.sheet_title.to_string(),
+        })
+}
+
+pub struct CreateTableOperations {
+    sheet: SheetOperations,
+    name: String,
+    range: String,
+    columns: Vec<TableColumnProperties>,
+    rows_properties: Option<TableRowsProperties>,
+    timeout: Option<Duration>,
+}
+
+impl CreateTableOperations {
+    pub fn new(
+        sheet: &SheetOperations,
+        name: &str,
+        range: &str,
+        columns: Vec<TableColumnProperties>,
+    ) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            name: name.to_string(),
+            range: range.to_string(),
+            columns,
+            rows_properties: None,
+            timeout: None,
+        }
+    }
+
+    pub fn rows_properties(mut self, rows_properties: TableRowsProperties) -> Self {
+        self.rows_properties = Some(rows_properties);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<Table, GSheetError> {
+        validate_a1_range(&self.range)?;
+        let mut range = a1_to_grid_range(&self.range)?;
+        let width = range.end_column_index.unwrap_or_default()
+            - range.start_column_index.unwrap_or_default();
+        if self.columns.len() > width as usize {
+            return Err(GSheetError::ValidationError(format!(
+                "Table '{}' has {} columns but range '{}' only spans {}",
+                self.name,
+                self.columns.len(),
+                self.range,
+                width
+            )));
+        }
+
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+        range.sheet_id = properties.sheet_id;
+
+        let column_properties = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| TableColumnProperties {
+                column_index: Some(i as i32),
+                ..column.clone()
+            })
+            .collect();
+
+        let table = Table {
+            name: Some(self.name.clone()),
+            range: Some(range),
+            rows_properties: self.rows_properties.clone(),
+            column_properties: Some(column_properties),
+            ..Default::default()
+        };
+
+        let mut batch_update = self.sheet.spreadsheet.batch_update();
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        let response = batch_update
+            .request(Request::AddTable(AddTableRequest { table }))
+            .build()?
+            .execute()
+            .await?;
+
+        match response.replies.into_iter().next() {
+            Some(Response::AddTable(AddTableResponse { table: Some(table) })) => Ok(table),
+            _ => Err(GSheetError::ResponseParseError(
+                "No table found in AddTable reply".into(),
+            )),
+        }
+    }
+}
+
+pub struct MoveChartOperations {
+    sheet: SheetOperations,
+    chart_id: i32,
+    anchor: String,
+    offset: Option<(i32, i32)>,
+    timeout: Option<Duration>,
+}
+
+impl MoveChartOperations {
+    pub fn new(sheet: &SheetOperations, chart_id: i32, anchor: &str) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            chart_id,
+            anchor: anchor.to_string(),
+            offset: None,
+            timeout: None,
+        }
+    }
+
+    pub fn offset(mut self, x_pixels: i32, y_pixels: i32) -> Self {
+        self.offset = Some((x_pixels, y_pixels));
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<EmbeddedObjectPosition, GSheetError> {
+        let (col, row) = parse_a1_cell(&self.anchor)?;
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+
+        let mut fields = String::from("overlayPosition.anchorCell");
+        let (offset_x_pixels, offset_y_pixels) = match self.offset {
+            Some((x, y)) => {
+                fields.push_str(",overlayPosition.offsetXPixels,overlayPosition.offsetYPixels");
+                (Some(x), Some(y))
+            }
+            None => (None, None),
+        };
+
+        let new_position = EmbeddedObjectPosition {
+            overlay_position: Some(OverlayPosition {
+                anchor_cell: Some(GridCoordinate {
+                    sheet_id: properties.sheet_id,
+                    row_index: Some(row as i32 - 1),
+                    column_index: Some(col as i32 - 1),
+                }),
+                offset_x_pixels,
+                offset_y_pixels,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let mut batch_update = self.sheet.spreadsheet.batch_update();
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        let response = batch_update
+            .request(Request::UpdateEmbeddedObjectPosition(
+                UpdateEmbeddedObjectPositionRequest {
+                    object_id: self.chart_id,
+                    new_position,
+                    fields,
+                },
+            ))
+            .build()?
+            .execute()
+            .await?;
+
+        match response.replies.into_iter().next() {
+            Some(Response::UpdateEmbeddedObjectPosition(
+                UpdateEmbeddedObjectPositionResponse {
+                    position: Some(position),
+                },
+            )) => Ok(position),
+            _ => Err(GSheetError::ResponseParseError(
+                "No position found in UpdateEmbeddedObjectPosition reply".into(),
+            )),
+        }
+    }
+}
+
+/// The writable fields of [`CellData`], with their field mask names.
+const WRITABLE_CELL_FIELDS: [&str; 7] = [
+    "userEnteredValue",
+    "userEnteredFormat",
+    "note",
+    "textFormatRuns",
+    "dataValidation",
+    "pivotTable",
+    "chipRuns",
+];
+
+/// Returns the field mask covering the writable fields set in any cell.
+fn cell_data_fields(rows: &[Vec<CellData>]) -> String {
+    let cells = || rows.iter().flatten();
+    let set = [
+        cells().any(|cell| cell.user_entered_value.is_some()),
+        cells().any(|cell| cell.user_entered_format.is_some()),
+        cells().any(|cell| cell.note.is_some()),
+        cells().any(|cell| cell.text_format_runs.is_some()),
+        cells().any(|cell| cell.data_validation.is_some()),
+        cells().any(|cell| cell.pivot_table.is_some()),
+        cells().any(|cell| cell.chip_runs.is_some()),
+    ];
+    WRITABLE_CELL_FIELDS
+        .iter()
+        .zip(set)
+        .filter(|(_, set)| *set)
+        .map(|(field, _)| *field)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub struct WriteCellsOperations {
+    sheet: SheetOperations,
+    range: String,
+    rows: Vec<Vec<CellData>>,
+    fields: Option<String>,
+    timeout: Option<Duration>,
+}
+
+impl WriteCellsOperations {
+    pub fn new(sheet: &SheetOperations, range: &str, rows: Vec<Vec<CellData>>) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            range: range.to_string(),
+            rows,
+            fields: None,
+            timeout: None,
+        }
+    }
+
+    /// Sets the field mask of the write (e.g., "userEnteredValue,note").
+    ///
+    /// The fields of the mask are written for every cell of the range: cells
+    /// without data, or without a value for a field, have that field cleared,
+    /// and fields outside the mask are left untouched. By default, the mask
+    /// covers the writable fields set in any of the cells.
+    pub fn fields(mut self, fields: &str) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        validate_a1_range(&self.range)?;
+        let mut range = a1_to_grid_range(&self.range)?;
+        let fields = match &self.fields {
+            Some(fields) => fields.clone(),
+            None => cell_data_fields(&self.rows),
+        };
+        if fields.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "No writable cell field is set; set one or give a field mask".into(),
+            ));
+        }
+
+        // Data beyond the range would be rejected by the API.
+        let span = |start: Option<i32>, end: Option<i32>| {
+            end.map(|end| (end - start.unwrap_or_default()) as usize)
+        };
+        let rows = span(range.start_row_index, range.end_row_index);
+        let cols = span(range.start_column_index, range.end_column_index);
+        let widest = self.rows.iter().map(Vec::len).max().unwrap_or_default();
+        if rows.is_some_and(|rows| self.rows.len() > rows) || cols.is_some_and(|cols| widest > cols)
+        {
+            return Err(GSheetError::ValidationError(format!(
+                "{} rows of up to {} cells do not fit in range '{}'",
+                self.rows.len(),
+                widest,
+                self.range
+            )));
+        }
+
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+        range.sheet_id = properties.sheet_id;
+
+        let request = Request::UpdateCells(UpdateCellsRequest {
+            rows: self
+                .rows
+                .iter()
+                .map(|row| RowData {
+                    values: Some(row.clone()),
+                })
+                .collect(),
+            fields,
+            start: None,
+            range: Some(range),
+        });
+        send_request(&self.sheet, request, self.timeout).await
+    }
+}
+
+/// Resolves an A1 range of a sheet to a grid range carrying the sheet ID.
+async fn sheet_grid_range(
+    sheet: &SheetOperations,
+    range: &str,
+    timeout: Option<Duration>,
+) -> Result<GridRange, GSheetError> {
+    validate_a1_range(range)?;
+    let mut grid_range = a1_to_grid_range(range)?;
+    grid_range.sheet_id = fetch_sheet_properties(sheet, timeout).await?.sheet_id;
+    Ok(grid_range)
+}
+
+/// Checks that growing the grid of a sheet to the given size keeps the
+/// spreadsheet within the cell limit, from its (possibly cached) metadata.
+async fn check_cell_limit(
+    sheet: &SheetOperations,
+    sheet_id: Option<i32>,
+    rows: i32,
+    columns: i32,
+    timeout: Option<Duration>,
+) -> Result<(), GSheetError> {
+    let mut metadata = sheet.spreadsheet.metadata();
+    if let Some(timeout) = timeout {
+        metadata = metadata.timeout(timeout);
+    }
+    let metadata = metadata.build()?.execute().await?;
+    validate_cell_limit(&metadata.sheets, sheet_id, rows, columns)
+}
+
+/// Sends a single batch update request, with an optional timeout.
+async fn send_request(
+    sheet: &SheetOperations,
+    request: Request,
+    timeout: Option<Duration>,
+) -> Result<(), GSheetError> {
+    let mut batch_update = sheet.spreadsheet.batch_update();
+    if let Some(timeout) = timeout {
+        batch_update = batch_update.timeout(timeout);
+    }
+    batch_update.request(request).build()?.execute().await?;
+    Ok(())
+}
+
+pub struct InsertRangeOperations {
+    sheet: SheetOperations,
+    range: String,
+    shift: ShiftDimension,
+    timeout: Option<Duration>,
+}
+
+impl InsertRangeOperations {
+    pub fn new(sheet: &SheetOperations, range: &str, shift: ShiftDimension) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            range: range.to_string(),
+            shift,
+            timeout: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        validate_a1_range(&self.range)?;
+        let mut range = a1_to_grid_range(&self.range)?;
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+        range.sheet_id = properties.sheet_id;
+
+        // The shifted cells push the grid out by the height or width of the range.
+        let grid = properties.grid_properties.unwrap_or_default();
+        let (rows, columns) = (
+            grid.row_count.unwrap_or_default(),
+            grid.column_count.unwrap_or_default(),
+        );
+        let (rows, columns) = match self.shift {
+            ShiftDimension::Rows => {
+                let start = range.start_row_index.unwrap_or(0);
+                let end = range.end_row_index.unwrap_or(rows);
+                (rows.saturating_add((end - start).max(0)), columns)
+            }
+            ShiftDimension::Columns => {
+                let start = range.start_column_index.unwrap_or(0);
+                let end = range.end_column_index.unwrap_or(columns);
+                (rows, columns.saturating_add((end - start).max(0)))
+            }
+        };
+        check_cell_limit(
+            &self.sheet,
+            properties.sheet_id,
+            rows,
+            columns,
+            self.timeout,
+        )
+        .await?;
+
+        let request = Request::InsertRange(InsertRangeRequest {
+            range,
+            shift_dimension: self.shift,
+        });
+        send_request(&self.sheet, request, self.timeout).await
+    }
+}
+
+pub struct DeleteRangeOperations {
+    sheet: SheetOperations,
+    range: String,
+    shift: ShiftDimension,
+    timeout: Option<Duration>,
+}
+
+impl DeleteRangeOperations {
+    pub fn new(sheet: &SheetOperations, range: &str, shift: ShiftDimension) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            range: range.to_string(),
+            shift,
+            timeout: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        let range = sheet_grid_range(&self.sheet, &self.range, self.timeout).await?;
+        let request = Request::DeleteRange(DeleteRangeRequest {
+            range,
+            shift_dimension: self.shift,
+        });
+        send_request(&self.sheet, request, self.timeout).await
+    }
+}
+
+pub struct ShuffleRowsOperations {
+    sheet: SheetOperations,
+    range: String,
+    timeout: Option<Duration>,
+}
+
+impl ShuffleRowsOperations {
+    pub fn new(sheet: &SheetOperations, range: &str) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            range: range.to_string(),
+            timeout: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        let range = sheet_grid_range(&self.sheet, &self.range, self.timeout).await?;
+        let request = Request::RandomizeRange(RandomizeRangeRequest { range });
+        send_request(&self.sheet, request, self.timeout).await
+    }
+}
+
+/// Creates a border of a style and color.
+fn border(style: Style, color: Color) -> Border {
+    Border {
+        style: Some(style),
+        color_style: Some(ColorStyle {
+            rgb_color: Some(color),
+            theme_color: None,
+        }),
+        ..Default::default()
+    }
+}
+
+pub struct SetBordersOperations {
+    sheet: SheetOperations,
+    range: String,
+    borders: UpdateBordersRequest,
+    timeout: Option<Duration>,
+}
+
+impl SetBordersOperations {
+    pub fn new(sheet: &SheetOperations, range: &str) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            range: range.to_string(),
+            borders: UpdateBordersRequest::default(),
+            timeout: None,
+        }
+    }
+
+    /// Sets the four outer edges of the range.
+    pub fn outer(self, style: Style, color: Color) -> Self {
+        self.top(style.clone(), color.clone())
+            .bottom(style.clone(), color.clone())
+            .left(style.clone(), color.clone())
+            .right(style, color)
+    }
+
+    pub fn top(mut self, style: Style, color: Color) -> Self {
+        self.borders.top = Some(border(style, color));
+        self
+    }
+
+    pub fn bottom(mut self, style: Style, color: Color) -> Self {
+        self.borders.bottom = Some(border(style, color));
+        self
+    }
+
+    pub fn left(mut self, style: Style, color: Color) -> Self {
+        self.borders.left = Some(border(style, color));
+        self
+    }
+
+    pub fn right(mut self, style: Style, color: Color) -> Self {
+        self.borders.right = Some(border(style, color));
+        self
+    }
+
+    /// Sets the horizontal borders between the rows of the range.
+    pub fn inner_horizontal(mut self, style: Style, color: Color) -> Self {
+        self.borders.inner_horizontal = Some(border(style, color));
+        self
+    }
+
+    /// Sets the vertical borders between the columns of the range.
+    pub fn inner_vertical(mut self, style: Style, color: Color) -> Self {
+        self.borders.inner_vertical = Some(border(style, color));
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        let borders = &self.borders;
+        let edges = [
+            &borders.top,
+            &borders.bottom,
+            &borders.left,
+            &borders.right,
+            &borders.inner_horizontal,
+            &borders.inner_vertical,
+        ];
+        if edges.iter().all(|edge| edge.is_none()) {
+            return Err(GSheetError::ValidationError(format!(
+                "No border set for range '{}'",
+                self.range
+            )));
+        }
+
+        let range = sheet_grid_range(&self.sheet, &self.range, self.timeout).await?;
+        let request = Request::UpdateBorders(UpdateBordersRequest {
+            range,
+            ..borders.clone()
+        });
+        send_request(&self.sheet, request, self.timeout).await
+    }
+}
+
+pub struct StyleAsTableOperations {
+    sheet: SheetOperations,
+    range: String,
+    bold_header: bool,
+    banding: Option<BandingProperties>,
+    borders: bool,
+    freeze_header: bool,
+    auto_resize: bool,
+    timeout: Option<Duration>,
+}
+
+impl StyleAsTableOperations {
+    pub fn new(sheet: &SheetOperations, range: &str) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            range: range.to_string(),
+            bold_header: true,
+            banding: Some(grey_banding()),
+            borders: true,
+            freeze_header: true,
+            auto_resize: true,
+            timeout: None,
+        }
+    }
+
+    pub fn bold_header(mut self, bold: bool) -> Self {
+        self.bold_header = bold;
+        self
+    }
+
+    /// Sets whether the rows are banded with the neutral grey palette.
+    /// Banding cannot overlap existing banding.
+    pub fn banded(mut self, banded: bool) -> Self {
+        self.banding = banded.then(grey_banding);
+        self
+    }
+
+    /// Bands the rows with the given header and band colors.
+    pub fn banding(mut self, properties: BandingProperties) -> Self {
+        self.banding = Some(properties);
+        self
+    }
+
+    pub fn borders(mut self, borders: bool) -> Self {
+        self.borders = borders;
+        self
+    }
+
+    /// Sets whether the rows down to the header are frozen.
+    pub fn freeze_header(mut self, freeze: bool) -> Self {
+        self.freeze_header = freeze;
+        self
+    }
+
+    /// Sets whether the columns of the range are resized to fit their contents.
+    pub fn auto_resize(mut self, auto_resize: bool) -> Self {
+        self.auto_resize = auto_resize;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        let range = sheet_grid_range(&self.sheet, &self.range, self.timeout).await?;
+        let sheet_id = range.sheet_id;
+        let header_end_row = range.start_row_index.unwrap_or_default() + 1;
+        let mut requests = Vec::new();
+
+        if self.bold_header {
+            requests.push(Request::RepeatCell(RepeatCellRequest {
+                range: GridRange {
+                    end_row_index: Some(header_end_row),
+                    ..range.clone()
+                },
+                cell: CellData {
+                    user_entered_format: Some(CellFormat {
+                        text_format: Some(TextFormat {
+                            bold: Some(true),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                fields: "userEnteredFormat.textFormat.bold".to_string(),
+            }));
+        }
+
+        if let Some(banding) = &self.banding {
+            requests.push(Request::AddBanding(AddBandingRequest {
+                banded_range: BandedRange {
+                    range: Some(range.clone()),
+                    row_properties: Some(banding.clone()),
+                    ..Default::default()
+                },
+            }));
+        }
+
+        if self.borders {
+            let outer = border(Style::Solid, Color::from_rgb8(0x99, 0x99, 0x99));
+            let inner = border(Style::Solid, Color::from_rgb8(0xd9, 0xd9, 0xd9));
+            requests.push(Request::UpdateBorders(UpdateBordersRequest {
+                range: range.clone(),
+                top: Some(outer.clone()),
+                bottom: Some(outer.clone()),
+                left: Some(outer.clone()),
+                right: Some(outer),
+                inner_horizontal: Some(inner.clone()),
+                inner_vertical: Some(inner),
+            }));
+        }
+
+        if self.freeze_header {
+            requests.push(Request::UpdateSheetProperties(
+                UpdateSheetPropertiesRequest {
+                    properties: SheetProperties {
+                        sheet_id,
+                        grid_properties: Some(GridProperties {
+                            frozen_row_count: Some(header_end_row),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    fields: "gridProperties.frozenRowCount".to_string(),
+                },
+            ));
+        }
+
+        if self.auto_resize {
+            requests.push(Request::AutoResizeDimensions(AutoResizeDimensionsRequest {
+                dimensions: DimensionRange {
+                    sheet_id,
+                    dimension: Some(Dimension::Columns.to_string()),
+                    start_index: range.start_column_index,
+                    end_index: range.end_column_index,
+                },
+            }));
+        }
+
+        if requests.is_empty() {
+            return Err(GSheetError::ValidationError(format!(
+                "No table style enabled for range '{}'",
+                self.range
+            )));
+        }
+
+        let mut batch_update = self.sheet.spreadsheet.batch_update();
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        batch_update.requests(requests).build()?.execute().await?;
+        Ok(())
+    }
+}
+
+pub struct AddConditionalFormatOperations {
+    sheet: SheetOperations,
+    range: String,
+    preset: ConditionalFormatPreset,
+    timeout: Option<Duration>,
+}
+
+impl AddConditionalFormatOperations {
+    pub fn new(sheet: &SheetOperations, range: &str, preset: ConditionalFormatPreset) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            range: range.to_string(),
+            preset,
+            timeout: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        let range = sheet_grid_range(&self.sheet, &self.range, self.timeout).await?;
+        let requests: Vec<Request> = self
+            .preset
+            .rules(&range)
+            .into_iter()
+            .enumerate()
+            .map(|(index, rule)| {
+                Request::AddConditionalFormatRule(AddConditionalFormatRuleRequest {
+                    rule,
+                    index: index as i32,
+                })
+            })
+            .collect();
+
+        let mut batch_update = self.sheet.spreadsheet.batch_update();
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        batch_update.requests(requests).build()?.execute().await?;
+        Ok(())
+    }
+}
+
+pub struct ProtectRangeOperations {
+    sheet: SheetOperations,
+    range: String,
+    description: Option<String>,
+    only_owner: bool,
+    editors: Vec<String>,
+    warning_only: bool,
+    timeout: Option<Duration>,
+}
+
+impl ProtectRangeOperations {
+    pub fn new(sheet: &SheetOperations, range: &str) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            range: range.to_string(),
+            description: None,
+            only_owner: false,
+            editors: Vec::new(),
+            warning_only: false,
+            timeout: None,
+        }
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Restricts editing to the authenticated principal, such as the service
+    /// account, and the editors added with [`editor`](Self::editor).
+    pub fn only_owner(mut self) -> Self {
+        self.only_owner = true;
+        self
+    }
+
+    /// Adds a user, by email address, allowed to edit the range.
+    pub fn editor(mut self, email: &str) -> Self {
+        self.editors.push(email.to_string());
+        self
+    }
+
+    /// Shows a warning when the range is edited instead of preventing edits.
+    /// Warning-only protections cannot restrict editors.
+    pub fn warning_only(mut self) -> Self {
+        self.warning_only = true;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<ProtectedRange, GSheetError> {
+        if self.warning_only && (self.only_owner || !self.editors.is_empty()) {
+            return Err(GSheetError::ValidationError(
+                "A warning-only protection cannot restrict editors".into(),
+            ));
+        }
+
+        let mut users = self.editors.clone();
+        if self.only_owner {
+            let email = self
+                .sheet
+                .spreadsheet
+                .gsheet_client
+                .auth_client
+                .email()
+                .ok_or_else(|| {
+                    GSheetError::ValidationError(
+                        "The authentication provider has no email to restrict editors to".into(),
+                    )
+                })?;
+            users.insert(0, email);
+        }
+        let editors = (!users.is_empty()).then_some(Editors {
+            users: Some(users),
+            groups: None,
+            domain_users_can_edit: Some(false),
+        });
+
+        let range = sheet_grid_range(&self.sheet, &self.range, self.timeout).await?;
+        let mut batch_update = self.sheet.spreadsheet.batch_update();
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        let response = batch_update
+            .request(Request::AddProtectedRange(AddProtectedRangeRequest {
+                protected_range: ProtectedRange {
+                    range: Some(range),
+                    description: self.description.clone(),
+                    warning_only: Some(self.warning_only),
+                    editors,
+                    ..Default::default()
+                },
+            }))
+            .build()?
+            .execute()
+            .await?;
+
+        match response.replies.into_iter().next() {
+            Some(Response::AddProtectedRange(AddProtectedRangeResponse {
+                protected_range: Some(protected_range),
+            })) => Ok(protected_range),
+            _ => Err(GSheetError::ResponseParseError(
+                "No protected range found in AddProtectedRange reply".into(),
+            )),
+        }
+    }
+}
+
+/// Returns the 1-based, inclusive span of rows or columns covered by a row
+/// span ("5:7"), a column span ("B:D") or a range ("A5:C7").
+fn dimension_span(range: &str, dimension: &Dimension) -> Result<(usize, usize), GSheetError> {
+    let (_, span) = split_sheet_range(range).unwrap_or(("", range));
+    let (start, end) = span.split_once(':').unwrap_or((span, span));
+    let bound = |a1: &str| -> Result<usize, GSheetError> {
+        let a1 = a1.trim_start_matches('$');
+        let (col, row) = match dimension {
+            Dimension::Rows if a1.chars().all(|c| c.is_ascii_digit()) => {
+                parse_a1_cell(&format!("A{a1}"))?
+            }
+            Dimension::Columns if a1.chars().all(|c| c.is_ascii_alphabetic()) => {
+                parse_a1_cell(&format!("{a1}1"))?
+            }
+            _ => parse_a1_cell(a1)?,
+        };
+        Ok(if matches!(dimension, Dimension::Columns) {
+            col
+        } else {
+            row
+        })
+    };
+    let (start, end) = (bound(start)?, bound(end)?);
+    if start > end {
+        return Err(GSheetError::ValidationError(format!(
+            "Range '{}' ends before it starts",
+            range
+        )));
+    }
+    Ok((start, end))
+}
+
+pub struct MoveDimensionOperations {
+    sheet: SheetOperations,
+    dimension: Dimension,
+    range: String,
+    destination: usize,
+    timeout: Option<Duration>,
+}
+
+impl MoveDimensionOperations {
+    pub fn new(
+        sheet: &SheetOperations,
+        dimension: Dimension,
+        range: &str,
+        destination: usize,
+    ) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            dimension,
+            range: range.to_string(),
+            destination,
+            timeout: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        let (start, end) = dimension_span(&self.range, &self.dimension)?;
+        if self.destination == 0 {
+            return Err(GSheetError::ValidationError(
+                "The destination of a move is 1-based".into(),
+            ));
+        }
+        if start < self.destination && self.destination <= end {
+            return Err(GSheetError::ValidationError(format!(
+                "Cannot move '{}' inside itself",
+                self.range
+            )));
+        }
+
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+        let request = Request::MoveDimension(MoveDimensionRequest {
+            source: DimensionRange {
+                sheet_id: properties.sheet_id,
+                dimension: Some(self.dimension.to_string()),
+                start_index: Some(start as i32 - 1),
+                end_index: Some(end as i32),
+            },
+            destination_index: self.destination as i32 - 1,
+        });
+        send_request(&self.sheet, request, self.timeout).await
+    }
+}
+
+/// The kind of chart created by the one-liner chart helpers.
+enum QuickChart {
+    Basic(BasicChartType),
+    Pie,
+}
+
+pub struct AddChartOperations {
+    sheet: SheetOperations,
+    data_range: String,
+    title: String,
+    kind: QuickChart,
+    anchor: Option<String>,
+    timeout: Option<Duration>,
+}
+
+impl AddChartOperations {
+    fn new(sheet: &SheetOperations, data_range: &str, title: &str, kind: QuickChart) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            data_range: data_range.to_string(),
+            title: title.to_string(),
+            kind,
+            anchor: None,
+            timeout: None,
+        }
+    }
+
+    pub fn anchor(mut self, cell: &str) -> Self {
+        self.anchor = Some(cell.to_string());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<EmbeddedChart, GSheetError> {
+        validate_a1_range(&self.data_range)?;
+        let mut range = a1_to_grid_range(&self.data_range)?;
+        let rows =
+            range.end_row_index.unwrap_or_default() - range.start_row_index.unwrap_or_default();
+        let cols = range.end_column_index.unwrap_or_default()
+            - range.start_column_index.unwrap_or_default();
+        if rows < 2 || cols < 2 {
+            return Err(GSheetError::ValidationError(format!(
+                "Chart range '{}' needs a header row, a data row, a domain column and a series column",
+                self.data_range
+            )));
+        }
+        if matches!(self.kind, QuickChart::Pie) && cols != 2 {
+            return Err(GSheetError::ValidationError(format!(
+                "Pie chart range '{}' must have exactly a label and a value column",
+                self.data_range
+            )));
+        }
+
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+        range.sheet_id = properties.sheet_id;
+
+        // Without an explicit anchor, place the chart right of the data.
+        let (row_index, column_index) = match &self.anchor {
+            Some(cell) => {
+                let (col, row) = parse_a1_cell(cell)?;
+                (row as i32 - 1, col as i32 - 1)
+            }
+            None => (
+                range.start_row_index.unwrap_or_default(),
+                range.end_column_index.unwrap_or_default() + 1,
+            ),
+        };
+
+        let spec = match &self.kind {
+            QuickChart::Basic(chart_type) => {
+                ChartSpec::basic(chart_type.clone(), &self.title, &range)
+            }
+            QuickChart::Pie => ChartSpec::pie(&self.title, &range),
+        };
+        let chart = EmbeddedChart {
+            spec: Some(spec),
+            position: Some(EmbeddedObjectPosition {
+                overlay_position: Some(OverlayPosition {
+                    anchor_cell: Some(GridCoordinate {
+                        sheet_id: properties.sheet_id,
+                        row_index: Some(row_index),
+                        column_index: Some(column_index),
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let mut batch_update = self.sheet.spreadsheet.batch_update();
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        let response = batch_update
+            .request(Request::AddChart(AddChartRequest { chart }))
+            .build()?
+            .execute()
+            .await?;
+
+        match response.replies.into_iter().next() {
+            Some(Response::AddChart(AddChartResponse { chart: Some(chart) })) => Ok(chart),
+            _ => Err(GSheetError::ResponseParseError(
+                "No chart found in AddChart reply".into(),
+            )),
+        }
+    }
+}
+
+/// Attaches developer metadata to a row, giving it an identity that follows the
+/// row when rows are inserted or deleted above it.
+///
+/// The metadata is visible to any project with access to the document, and
+/// rows can later be located by it with [`FindTaggedRowsOperations`].
+pub struct TagRowOperations {
+    sheet: SheetOperations,
+    row: RowIndex,
+    key: String,
+    value: String,
+    timeout: Option<Duration>,
+}
+
+impl TagRowOperations {
+    pub fn new(sheet: &SheetOperations, row: RowIndex, key: &str, value: &str) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            row,
+            key: key.to_string(),
+            value: value.to_string(),
+            timeout: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<DeveloperMetadata, GSheetError> {
+        if self.row.get() == 0 {
+            return Err(GSheetError::ValidationError(
+                "Row numbers start at 1".into(),
+            ));
+        }
+        if self.key.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "Metadata key must not be empty".into(),
+            ));
+        }
+
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+        let start = self.row.zero_based() as i32;
+        let developer_metadata = DeveloperMetadata {
+            metadata_key: Some(self.key.clone()),
+            metadata_value: Some(self.value.clone()),
+            location: Some(DeveloperMetadataLocation {
+                dimension_range: Some(DimensionRange {
+                    sheet_id: properties.sheet_id,
+                    dimension: Some(Dimension::Rows.to_string()),
+                    start_index: Some(start),
+                    end_index: Some(start + 1),
+                }),
+                ..Default::default()
+            }),
+            visibility: Some(DeveloperMetadataVisibility::Document),
+            ..Default::default()
+        };
+
+        let mut batch_update = self.sheet.spreadsheet.batch_update();
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        let response = batch_update
+            .request(Request::CreateDeveloperMetadata(
+                CreateDeveloperMetadataRequest { developer_metadata },
+            ))
+            .build()?
+            .execute()
+            .await?;
+
+        match response.replies.into_iter().next() {
+            Some(Response::CreateDeveloperMetadata(CreateDeveloperMetadataResponse {
+                developer_metadata: Some(developer_metadata),
+            })) => Ok(developer_metadata),
+            _ => Err(GSheetError::ResponseParseError(
+                "No developer metadata found in CreateDeveloperMetadata reply".into(),
+            )),
+        }
+    }
+}
+
+/// Locates the rows of the sheet tagged with a metadata key and value by
+/// [`TagRowOperations`], wherever they have moved since.
+///
+/// The rows are found with a single `getByDataFilter` request, and returned in
+/// ascending order; a row tagged twice with the same pair is returned once.
+pub struct FindTaggedRowsOperations {
+    sheet: SheetOperations,
+    key: String,
+    value: String,
+    timeout: Option<Duration>,
+}
+
+impl FindTaggedRowsOperations {
+    pub fn new(sheet: &SheetOperations, key: &str, value: &str) -> Self {
+        Self {
+            sheet: sheet.clone(),
+            key: key.to_string(),
+            value: value.to_string(),
+            timeout: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<Vec<RowIndex>, GSheetError> {
+        let filter = DataFilter::DeveloperMetadataLookup(DeveloperMetadataLookup {
+            location_type: Some(DeveloperMetadataLocationType::Row),
+            metadata_key: Some(self.key.clone()),
+            metadata_value: Some(self.value.clone()),
+            ..Default::default()
+        });
+        let spreadsheet = get_by_data_filter(
+            &self.sheet.spreadsheet,
+            vec![filter],
+            "sheets(properties.title,data.startRow)",
+            self.timeout,
+        )
+        .await?;
+
+        let rows: BTreeSet<RowIndex> = spreadsheet
+            .sheets
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|sheet| {
+                sheet
+                    .properties
+                    .as_ref()
+                    .and_then(|properties| properties.title.as_deref())
+                    == Some(&*self.sheet.sheet_title)
+            })
+            .flat_map(|sheet| sheet.data.unwrap_or_default())
+            .map(|grid| RowIndex::from_zero_based(grid.start_row.unwrap_or_default() as usize))
+            .collect();
+        Ok(rows.into_iter().collect())
+    }
+}
+
+/// Reads the parts of a spreadsheet selected by data filters, limited to the
+/// given fields mask.
+async fn get_by_data_filter(
+    spreadsheet: &SpreadsheetOperations,
+    data_filters: Vec<DataFilter>,
+    fields: &str,
+    timeout: Option<Duration>,
+) -> Result<Spreadsheet, GSheetError> {
+    let gsheet_client = &spreadsheet.gsheet_client;
+    let url = gsheet_client.spreadsheet_url(&spreadsheet.spreadsheet_id, ":getByDataFilter");
+    let query = QueryParams::new().fields(fields);
+    let request = gsheet_client
+        .request(reqwest::Method::POST, &query.url(&url), timeout)
+        .await?
+        .json(&GetSpreadsheetByDataFilterRequest {
+            data_filters,
+            include_grid_data: None,
+        });
+    let response = gsheet_client.send_read(request).await?;
+
+    if response.status().is_success() {
+        decode_json(response).await
+    } else {
+        Err(GSheetError::from_response(response, &spreadsheet.spreadsheet_id).await)
     }
 }
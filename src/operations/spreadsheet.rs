@@ -3,31 +3,47 @@
 //! This module provides operations that work with entire Google Sheets spreadsheets,
 //! such as retrieving spreadsheet metadata, properties, and accessing individual sheets.
 
-use super::sheet::SheetOperations;
-use crate::auth::AuthError;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::sheet::{SheetOperations, fetch_sheet_properties};
+use crate::batch_queue::BatchQueueBuilder;
 use crate::client::GoogleSheetClient;
-use crate::error::GSheetError;
-use crate::models::Spreadsheet;
+use crate::client::url::QueryParams;
+use crate::declarative::{ApplySpecBuilder, SpreadsheetSpec};
+use crate::error::{GSheetError, decode_json};
+use crate::metadata::{METADATA_FIELDS, SpreadsheetMetadata};
+use crate::models::{
+    AddSheetRequest, AddSheetResponse, BatchGetValuesByDataFilterRequest,
+    BatchGetValuesByDataFilterResponse, BatchUpdateSpreadsheetRequest,
+    BatchUpdateSpreadsheetResponse, BatchValueRanges, Color, ColorStyle, DataExecutionState,
+    DataExecutionStatus, DataFilter, DateTimeRenderOption, Dimension, EmbeddedObjectPosition,
+    GridProperties, IterativeCalculationSettings, OverlayPosition, RecalculationInterval,
+    RefreshDataSourceObjectExecutionStatus, RefreshDataSourceRequest, Request, Response,
+    SheetProperties, Spreadsheet, SpreadsheetProperties, SpreadsheetTheme, ThemeColorPair,
+    ThemeColorType, ThemePreset, UpdateEmbeddedObjectPositionRequest,
+    UpdateEmbeddedObjectPositionResponse, UpdateSheetPropertiesRequest,
+    UpdateSpreadsheetPropertiesRequest, ValueInputOption, ValueRenderOption,
+};
+use crate::types::{TitleCollision, ValueOptions};
+use crate::validation::{
+    validate_cell_limit, validate_range, validate_request, validate_sheet_title,
+};
+use crate::watch::{PollChangesBuilder, WatchBuilder};
+use crate::workflow::WorkflowBuilder;
 
 /// Builder for creating [`SpreadsheetOperations`] instances.
 ///
 /// This builder provides a fluent interface for configuring spreadsheet operations
 /// with the necessary Google Sheets client and spreadsheet identifier.
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct SpreadsheetOperationsBuilder {
     /// The Google Sheets client for making API requests.
     gsheet_client: Option<GoogleSheetClient>,
     /// The unique identifier of the spreadsheet to operate on.
     spreadsheet_id: Option<String>,
-}
-
-impl Default for SpreadsheetOperationsBuilder {
-    fn default() -> Self {
-        Self {
-            gsheet_client: None,
-            spreadsheet_id: None,
-        }
-    }
+    /// The default value options of the operations.
+    value_options: ValueOptions,
 }
 
 impl SpreadsheetOperationsBuilder {
@@ -43,6 +59,7 @@ impl SpreadsheetOperationsBuilder {
         Self {
             gsheet_client: Some(gsheet_client),
             spreadsheet_id: Some(spreadsheet_id.to_string()),
+            value_options: ValueOptions::default(),
         }
     }
 
@@ -70,6 +87,19 @@ impl SpreadsheetOperationsBuilder {
         self
     }
 
+    /// Sets the default value options, inherited by every operation unless
+    /// overridden.
+    ///
+    /// # Arguments
+    /// * `options` - The default render and input options
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn value_options(mut self, options: ValueOptions) -> Self {
+        self.value_options = options;
+        self
+    }
+
     /// Builds the [`SpreadsheetOperations`] instance.
     ///
     /// # Returns
@@ -79,17 +109,18 @@ impl SpreadsheetOperationsBuilder {
     /// This method will return an error if either the client or spreadsheet ID is not set.
     pub fn build(self) -> Result<SpreadsheetOperations, GSheetError> {
         let gsheet_client = self.gsheet_client.ok_or_else(|| {
-            GSheetError::Other(format!(
-                "GoogleSheetClient is required to build SpreadsheetOperations"
-            ))
+            GSheetError::Other(
+                "GoogleSheetClient is required to build SpreadsheetOperations".into(),
+            )
         })?;
         let spreadsheet_id = self.spreadsheet_id.ok_or_else(|| {
-            GSheetError::Other(format!(
-                "spreadsheet_id is required to build SpreadsheetOperations"
-            ))
+            GSheetError::Other("spreadsheet_id is required to build SpreadsheetOperations".into())
         })?;
 
-        Ok(SpreadsheetOperations::new(gsheet_client, spreadsheet_id))
+        Ok(SpreadsheetOperations {
+            value_options: self.value_options,
+            ..SpreadsheetOperations::new(gsheet_client, spreadsheet_id.into())
+        })
     }
 }
 
@@ -97,12 +128,17 @@ impl SpreadsheetOperationsBuilder {
 ///
 /// This struct provides methods for performing operations on an entire Google Sheets
 /// spreadsheet, such as retrieving metadata or accessing individual sheets within it.
+///
+/// Like the client, it only holds reference-counted handles, so operations and
+/// tasks can clone it freely.
 #[derive(Clone)]
 pub struct SpreadsheetOperations {
     /// The Google Sheets client for making API requests.
     pub gsheet_client: GoogleSheetClient,
     /// The unique identifier of the spreadsheet.
-    pub spreadsheet_id: String,
+    pub spreadsheet_id: Arc<str>,
+    /// The default value options of the operations.
+    pub value_options: ValueOptions,
 }
 
 impl SpreadsheetOperations {
@@ -114,13 +150,53 @@ impl SpreadsheetOperations {
     ///
     /// # Returns
     /// A new [`SpreadsheetOperations`] instance.
-    pub fn new(gsheet_client: GoogleSheetClient, spreadsheet_id: String) -> Self {
+    pub fn new(gsheet_client: GoogleSheetClient, spreadsheet_id: Arc<str>) -> Self {
         Self {
             gsheet_client,
             spreadsheet_id,
+            value_options: ValueOptions::default(),
         }
     }
 
+    /// Returns these operations with a default value render option, inherited
+    /// by the reads created from them unless overridden.
+    ///
+    /// # Arguments
+    /// * `option` - The default value render option
+    ///
+    /// # Returns
+    /// The [`SpreadsheetOperations`] with the new default.
+    pub fn with_value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.value_options.value_render_option = option;
+        self
+    }
+
+    /// Returns these operations with a default date time render option,
+    /// inherited by the reads created from them unless overridden.
+    ///
+    /// # Arguments
+    /// * `option` - The default date time render option
+    ///
+    /// # Returns
+    /// The [`SpreadsheetOperations`] with the new default.
+    pub fn with_date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.value_options.date_time_render_option = option;
+        self
+    }
+
+    /// Returns these operations with a default value input option, inherited by
+    /// the writes created from them unless overridden.
+    ///
+    /// # Arguments
+    /// * `option` - The default value input option
+    ///
+    /// # Returns
+    /// The [`SpreadsheetOperations`] with the new default.
+    pub fn with_value_input_option(mut self, option: ValueInputOption) -> Self {
+        self.value_options.value_input_option = option;
+        self
+    }
+
     /// Creates a builder for constructing [`SpreadsheetOperations`].
     ///
     /// # Arguments
@@ -144,7 +220,7 @@ impl SpreadsheetOperations {
     /// # Returns
     /// A [`SheetOperations`] instance for the specified sheet.
     pub fn sheet(&self, title: &str) -> SheetOperations {
-        SheetOperations::new(self.clone(), title.to_string())
+        SheetOperations::new(self.clone(), title.into())
     }
 
     /// Creates a builder for getting spreadsheet metadata.
@@ -154,12 +230,252 @@ impl SpreadsheetOperations {
     pub fn get(&self) -> GetSpreadsheetBuilder {
         GetSpreadsheetBuilder::new(self)
     }
+
+    /// Creates a builder for getting the metadata of this spreadsheet.
+    ///
+    /// Metadata holds the locale, time zone and sheet properties, and is served
+    /// from the client cache when one is configured.
+    ///
+    /// # Returns
+    /// A [`GetMetadataBuilder`] for configuring the metadata retrieval.
+    pub fn metadata(&self) -> GetMetadataBuilder {
+        GetMetadataBuilder::new(self)
+    }
+
+    /// Creates a builder for a workflow of steps on this spreadsheet, rolled
+    /// back when a step fails.
+    ///
+    /// # Returns
+    /// A new [`WorkflowBuilder`] instance.
+    pub fn workflow(&self) -> WorkflowBuilder {
+        WorkflowBuilder::new(self)
+    }
+
+    /// Creates a builder for bringing this spreadsheet to the state described
+    /// by a spec, sending only the batch update requests that are needed.
+    ///
+    /// # Arguments
+    /// * `spec` - The desired state of the spreadsheet
+    ///
+    /// # Returns
+    /// A new [`ApplySpecBuilder`] instance.
+    pub fn apply(&self, spec: &SpreadsheetSpec) -> ApplySpecBuilder {
+        ApplySpecBuilder::new(self, spec)
+    }
+
+    /// Creates a builder for listing the properties of the sheets of this
+    /// spreadsheet, without downloading the whole spreadsheet resource.
+    ///
+    /// # Returns
+    /// A [`ListSheetsBuilder`] for configuring the listing.
+    pub fn list_sheets(&self) -> ListSheetsBuilder {
+        ListSheetsBuilder::new(self)
+    }
+
+    /// Creates a builder for adding a sheet to this spreadsheet.
+    ///
+    /// # Arguments
+    /// * `title` - The title of the new sheet
+    ///
+    /// # Returns
+    /// An [`AddSheetBuilder`] for configuring the addition.
+    pub fn add_sheet(&self, title: &str) -> AddSheetBuilder {
+        AddSheetBuilder::new(self, title)
+    }
+
+    /// Creates a builder for renaming a sheet of this spreadsheet.
+    ///
+    /// # Arguments
+    /// * `title` - The current title of the sheet
+    /// * `new_title` - The new title of the sheet
+    ///
+    /// # Returns
+    /// A [`RenameSheetBuilder`] for configuring the rename.
+    pub fn rename_sheet(&self, title: &str, new_title: &str) -> RenameSheetBuilder {
+        RenameSheetBuilder::new(self, title, new_title)
+    }
+
+    /// Checks whether this spreadsheet has a sheet with the given title.
+    ///
+    /// # Arguments
+    /// * `title` - The title of the sheet
+    ///
+    /// # Returns
+    /// A `Result` containing whether the sheet exists or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the sheets cannot be listed.
+    pub async fn sheet_exists(&self, title: &str) -> Result<bool, GSheetError> {
+        let sheets = self.list_sheets().build()?.execute().await?;
+        Ok(sheets
+            .iter()
+            .any(|properties| properties.title.as_deref() == Some(title)))
+    }
+
+    /// Returns the sheet with the given title, adding it first if it is missing.
+    ///
+    /// A sheet added concurrently by another writer between the existence check
+    /// and the addition is not an error: the existing sheet is returned.
+    ///
+    /// # Arguments
+    /// * `title` - The title of the sheet
+    /// * `rows` - The number of rows of the sheet, when it is added
+    /// * `columns` - The number of columns of the sheet, when it is added
+    ///
+    /// # Returns
+    /// A `Result` containing the [`SheetOperations`] of the sheet or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the title is invalid, or if the
+    /// sheets cannot be listed or the sheet cannot be added.
+    pub async fn ensure_sheet(
+        &self,
+        title: &str,
+        rows: i32,
+        columns: i32,
+    ) -> Result<SheetOperations, GSheetError> {
+        if self.sheet_exists(title).await? {
+            return Ok(self.sheet(title));
+        }
+
+        let operation = self.add_sheet(title).rows(rows).columns(columns).build()?;
+        match operation.execute().await {
+            Ok(_) | Err(GSheetError::SheetAlreadyExists { .. }) => Ok(self.sheet(title)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Creates a builder for applying batch updates to this spreadsheet.
+    ///
+    /// # Returns
+    /// A [`BatchUpdateBuilder`] for configuring the batch update.
+    pub fn batch_update(&self) -> BatchUpdateBuilder {
+        BatchUpdateBuilder::new(self)
+    }
+
+    /// Creates a builder for a queue coalescing single-range reads and writes
+    /// into batch calls.
+    ///
+    /// # Returns
+    /// A [`BatchQueueBuilder`] for configuring the queue.
+    pub fn batch_queue(&self) -> BatchQueueBuilder {
+        BatchQueueBuilder::new(self)
+    }
+
+    /// Creates a builder for watching this spreadsheet for changes.
+    ///
+    /// # Arguments
+    /// * `address` - The HTTPS webhook address Drive delivers notifications to
+    ///
+    /// # Returns
+    /// A [`WatchBuilder`] for configuring the watch.
+    pub fn watch(&self, address: &str) -> WatchBuilder {
+        WatchBuilder::new(self, address)
+    }
+
+    /// Creates a builder for detecting changes to this spreadsheet by polling.
+    ///
+    /// # Arguments
+    /// * `interval` - The time between two polls
+    ///
+    /// # Returns
+    /// A [`PollChangesBuilder`] for configuring the polling.
+    pub fn poll_changes(&self, interval: Duration) -> PollChangesBuilder {
+        PollChangesBuilder::new(self, interval)
+    }
+
+    /// Creates a builder for refreshing the objects of a data source.
+    ///
+    /// # Arguments
+    /// * `data_source_id` - The ID of the BigQuery or Looker data source
+    ///
+    /// # Returns
+    /// A [`RefreshDataSourceBuilder`] for configuring the refresh.
+    pub fn refresh_data_source(&self, data_source_id: &str) -> RefreshDataSourceBuilder {
+        RefreshDataSourceBuilder::new(self, data_source_id)
+    }
+
+    /// Creates a builder for resizing a chart, keeping its anchor cell.
+    ///
+    /// Charts are moved with [`SheetOperations::move_chart`], since their anchor
+    /// cell belongs to a sheet.
+    ///
+    /// # Arguments
+    /// * `chart_id` - The ID of the chart
+    /// * `width` - The new width, in pixels
+    /// * `height` - The new height, in pixels
+    ///
+    /// # Returns
+    /// A [`ResizeChartBuilder`] for configuring the resize.
+    pub fn resize_chart(&self, chart_id: i32, width: u32, height: u32) -> ResizeChartBuilder {
+        ResizeChartBuilder::new(self, chart_id, width, height)
+    }
+
+    /// Creates a builder for reading the values of the ranges selected by data
+    /// filters: A1 ranges, grid ranges or developer metadata lookups.
+    ///
+    /// # Returns
+    /// A [`GetValuesByDataFilterBuilder`] for configuring the read.
+    pub fn get_values_by_data_filter(&self) -> GetValuesByDataFilterBuilder {
+        GetValuesByDataFilterBuilder::new(self)
+    }
+
+    /// Creates a builder for reading the values of the ranges tagged with a
+    /// developer metadata key and value, so ranges can be addressed by name
+    /// instead of by A1 coordinates that shift when rows are inserted.
+    ///
+    /// # Arguments
+    /// * `key` - The metadata key of the ranges
+    /// * `value` - The metadata value of the ranges
+    ///
+    /// # Returns
+    /// A [`GetValuesByDataFilterBuilder`] for configuring the read, whose
+    /// `build()` fails with a validation error when the key is empty.
+    pub fn get_values_by_metadata(&self, key: &str, value: &str) -> GetValuesByDataFilterBuilder {
+        GetValuesByDataFilterBuilder::new(self).filter(DataFilter::developer_metadata(key, value))
+    }
+
+    /// Creates a builder for changing the theme of this spreadsheet.
+    ///
+    /// # Returns
+    /// A [`SpreadsheetThemeBuilder`] for configuring the new theme.
+    pub fn update_theme(&self) -> SpreadsheetThemeBuilder {
+        SpreadsheetThemeBuilder::new(self)
+    }
+
+    /// Creates a builder for changing how often volatile functions are recalculated.
+    ///
+    /// # Arguments
+    /// * `interval` - The recalculation interval (e.g., [`RecalculationInterval::Minute`])
+    ///
+    /// # Returns
+    /// A [`CalculationSettingsBuilder`] for configuring the update.
+    pub fn set_recalculation(&self, interval: RecalculationInterval) -> CalculationSettingsBuilder {
+        CalculationSettingsBuilder::new(self).recalculation(interval)
+    }
+
+    /// Creates a builder for enabling iterative calculation of circular references.
+    ///
+    /// # Arguments
+    /// * `max_iterations` - The maximum number of calculation rounds
+    /// * `threshold` - The convergence threshold below which calculation stops
+    ///
+    /// # Returns
+    /// A [`CalculationSettingsBuilder`] for configuring the update.
+    pub fn set_iterative_calculation(
+        &self,
+        max_iterations: u32,
+        threshold: f64,
+    ) -> CalculationSettingsBuilder {
+        CalculationSettingsBuilder::new(self).iterative_calculation(max_iterations, threshold)
+    }
 }
 
 /// Builder for configuring spreadsheet retrieval operations.
 ///
 /// This builder allows you to configure various options for retrieving spreadsheet
 /// metadata and data, such as specific ranges and data inclusion options.
+#[derive(Default)]
 pub struct GetSpreadsheetBuilder {
     /// The spreadsheet operations instance.
     spreadsheet: Option<SpreadsheetOperations>,
@@ -169,16 +485,8 @@ pub struct GetSpreadsheetBuilder {
     include_grid_data: bool,
     /// Whether to exclude tables in banded ranges.
     exclude_tables_in_banded_ranges: bool,
-}
-impl Default for GetSpreadsheetBuilder {
-    fn default() -> Self {
-        Self {
-            spreadsheet: None,
-            ranges: vec![],
-            include_grid_data: false,
-            exclude_tables_in_banded_ranges: false,
-        }
-    }
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
 }
 
 impl GetSpreadsheetBuilder {
@@ -195,6 +503,7 @@ impl GetSpreadsheetBuilder {
             ranges: vec![],
             include_grid_data: false,
             exclude_tables_in_banded_ranges: false,
+            timeout: None,
         }
     }
 
@@ -237,6 +546,18 @@ impl GetSpreadsheetBuilder {
         self
     }
 
+    /// Sets the timeout for this operation, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of the request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Builds the [`GetSpreadsheetOperations`] instance.
     ///
     /// # Returns
@@ -246,9 +567,9 @@ impl GetSpreadsheetBuilder {
     /// This method will return an error if the spreadsheet is not set.
     pub fn build(self) -> Result<GetSpreadsheetOperations, GSheetError> {
         let spreadsheet = self.spreadsheet.ok_or_else(|| {
-            GSheetError::Other(format!(
-                "SpreadsheetOperations is required to build GetSpreadsheetOperations"
-            ))
+            GSheetError::Other(
+                "SpreadsheetOperations is required to build GetSpreadsheetOperations".into(),
+            )
         })?;
 
         Ok(GetSpreadsheetOperations {
@@ -256,6 +577,7 @@ impl GetSpreadsheetBuilder {
             ranges: self.ranges,
             include_grid_data: self.include_grid_data,
             exclude_tables_in_banded_ranges: self.exclude_tables_in_banded_ranges,
+            timeout: self.timeout,
         })
     }
 }
@@ -273,6 +595,8 @@ pub struct GetSpreadsheetOperations {
     include_grid_data: bool,
     /// Whether to exclude tables in banded ranges.
     exclude_tables_in_banded_ranges: bool,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
 }
 
 impl GetSpreadsheetOperations {
@@ -284,7 +608,7 @@ impl GetSpreadsheetOperations {
     /// # Returns
     /// A [`SheetOperations`] instance for the specified sheet.
     pub async fn sheet(&self, title: &str) -> SheetOperations {
-        SheetOperations::new(self.spreadsheet.clone(), title.to_string())
+        SheetOperations::new(self.spreadsheet.clone(), title.into())
     }
 
     /// Executes the spreadsheet retrieval operation.
@@ -302,52 +626,1993 @@ impl GetSpreadsheetOperations {
     /// - The response cannot be parsed
     /// - The spreadsheet does not exist or is not accessible
     pub async fn execute(&self) -> Result<Spreadsheet, GSheetError> {
-        let url = format!(
-            "{}/{}",
-            self.spreadsheet.gsheet_client.base_url, self.spreadsheet.spreadsheet_id
-        );
-
-        let mut auth_client = self
+        let url = self
             .spreadsheet
             .gsheet_client
-            .auth_client
-            .lock()
-            .map_err(|e| GSheetError::AuthError(AuthError::Other(e.to_string())))?;
+            .spreadsheet_url(&self.spreadsheet.spreadsheet_id, "");
 
-        auth_client.ensure_valid_token().await?;
-
-        let mut request = self
+        let query = QueryParams::new()
+            .ranges(&self.ranges)
+            .flag("includeGridData", self.include_grid_data)
+            .flag(
+                "excludeTablesInBandedRanges",
+                self.exclude_tables_in_banded_ranges,
+            );
+        let request = self
             .spreadsheet
             .gsheet_client
-            .client
-            .get(&url)
-            .bearer_auth(auth_client.get_token());
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
 
-        println!("Ranges: {}", self.ranges.join(", "));
+        let response = self.spreadsheet.gsheet_client.send(request).await?;
 
-        if !self.ranges.is_empty() {
-            for range in &self.ranges {
-                request = request.query(&[("ranges", range)]);
-            }
+        if response.status().is_success() {
+            let spreadsheet: Spreadsheet = decode_json(response).await?;
+            Ok(spreadsheet)
+        } else {
+            Err(GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await)
         }
+    }
+}
 
-        if self.include_grid_data {
-            request = request.query(&[("includeGridData", "true")]);
-        }
+/// Builder for configuring spreadsheet batch update operations.
+///
+/// A batch update applies a list of [`Request`]s atomically: either every request
+/// is applied or none is. The replies are returned in the same order as the
+/// requests.
+#[derive(Default)]
+pub struct BatchUpdateBuilder {
+    /// The spreadsheet operations instance.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// The requests to apply, in order.
+    requests: Vec<Request>,
+    /// Whether to include the updated spreadsheet in the response.
+    include_spreadsheet_in_response: bool,
+    /// Ranges to include in the updated spreadsheet.
+    response_ranges: Vec<String>,
+    /// Whether to include grid data in the updated spreadsheet.
+    response_include_grid_data: bool,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
 
-        if self.exclude_tables_in_banded_ranges {
-            request = request.query(&[("excludeTablesInBandedRanges", "true")]);
+impl BatchUpdateBuilder {
+    /// Creates a new builder for the specified spreadsheet.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    ///
+    /// # Returns
+    /// A new [`BatchUpdateBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            ..Default::default()
         }
+    }
+
+    /// Adds a request to the batch.
+    ///
+    /// # Arguments
+    /// * `request` - The request to apply
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn request(mut self, request: Request) -> Self {
+        self.requests.push(request);
+        self
+    }
 
-        let response = request.send().await?;
+    /// Adds several requests to the batch, keeping their order.
+    ///
+    /// # Arguments
+    /// * `requests` - The requests to apply
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn requests(mut self, requests: impl IntoIterator<Item = Request>) -> Self {
+        self.requests.extend(requests);
+        self
+    }
 
-        if response.status().is_success() {
-            let spreadsheet: Spreadsheet = response.json().await?;
-            Ok(spreadsheet)
-        } else {
-            Err(GSheetError::HttpRequestError(
-                response.error_for_status().unwrap_err(),
-            ))
+    /// Sets whether the updated spreadsheet is included in the response.
+    ///
+    /// # Arguments
+    /// * `include` - Whether to include the updated spreadsheet
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn include_spreadsheet_in_response(mut self, include: bool) -> Self {
+        self.include_spreadsheet_in_response = include;
+        self
+    }
+
+    /// Adds a range to include in the updated spreadsheet.
+    ///
+    /// Only meaningful when the updated spreadsheet is included in the response.
+    ///
+    /// # Arguments
+    /// * `range` - The A1 notation range (e.g., "Sheet1!A1:B10")
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn response_range(mut self, range: &str) -> Self {
+        self.response_ranges.push(range.to_string());
+        self
+    }
+
+    /// Sets whether grid data is included in the updated spreadsheet.
+    ///
+    /// # Arguments
+    /// * `include` - Whether to include grid data
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn response_include_grid_data(mut self, include: bool) -> Self {
+        self.response_include_grid_data = include;
+        self
+    }
+
+    /// Sets the timeout for this operation, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of the request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`BatchUpdateOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`BatchUpdateOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set.
+    pub fn build(self) -> Result<BatchUpdateOperations, GSheetError> {
+        let spreadsheet = self.spreadsheet.ok_or_else(|| {
+            GSheetError::Other(
+                "SpreadsheetOperations is required to build BatchUpdateOperations".into(),
+            )
+        })?;
+
+        Ok(BatchUpdateOperations {
+            spreadsheet,
+            body: BatchUpdateSpreadsheetRequest {
+                requests: self.requests,
+                include_spreadsheet_in_response: self.include_spreadsheet_in_response,
+                response_ranges: self.response_ranges,
+                response_include_grid_data: self.response_include_grid_data,
+            },
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation for applying batch updates to a spreadsheet.
+pub struct BatchUpdateOperations {
+    /// The spreadsheet operations instance.
+    spreadsheet: SpreadsheetOperations,
+    /// The request body to send.
+    body: BatchUpdateSpreadsheetRequest,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl BatchUpdateOperations {
+    /// Returns the request body that will be sent.
+    pub fn body(&self) -> &BatchUpdateSpreadsheetRequest {
+        &self.body
+    }
+
+    /// Executes the batch update operation.
+    ///
+    /// On success, cached value reads of the spreadsheet are invalidated.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`BatchUpdateSpreadsheetResponse`], with one typed
+    /// reply per request in request order, or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - Authentication fails
+    /// - No request was added, or a request fails client-side validation
+    /// - A duplicated sheet would bring the spreadsheet over its cell limit
+    /// - The HTTP request fails
+    /// - Any of the requests is invalid, in which case none is applied
+    /// - The response cannot be parsed
+    pub async fn execute(&self) -> Result<BatchUpdateSpreadsheetResponse, GSheetError> {
+        if self.body.requests.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "At least one request must be added".into(),
+            ));
+        }
+        for request in &self.body.requests {
+            validate_request(request)?;
+        }
+        self.check_duplicated_cells().await?;
+
+        let gsheet_client = &self.spreadsheet.gsheet_client;
+        let url = gsheet_client.spreadsheet_url(&self.spreadsheet.spreadsheet_id, ":batchUpdate");
+
+        let request = gsheet_client
+            .request(reqwest::Method::POST, &url, self.timeout)
+            .await?
+            .json(&self.body);
+        let response = gsheet_client.send(request).await?;
+
+        if response.status().is_success() {
+            gsheet_client.invalidate_cache(&self.spreadsheet.spreadsheet_id);
+            let result: BatchUpdateSpreadsheetResponse = decode_json(response).await?;
+            Ok(result)
+        } else {
+            Err(GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await)
+        }
+    }
+
+    /// Checks that the sheets duplicated by the batch keep the spreadsheet
+    /// within the cell limit. Each copy has the grid of its source, so the
+    /// copies are added one by one to the (possibly cached) metadata.
+    async fn check_duplicated_cells(&self) -> Result<(), GSheetError> {
+        let duplicates: Vec<i32> = self
+            .body
+            .requests
+            .iter()
+            .filter_map(|request| match request {
+                Request::DuplicateSheet(request) => Some(request.source_sheet_id),
+                _ => None,
+            })
+            .collect();
+        if duplicates.is_empty() {
+            return Ok(());
+        }
+
+        let mut metadata = self.spreadsheet.metadata();
+        if let Some(timeout) = self.timeout {
+            metadata = metadata.timeout(timeout);
+        }
+        let mut sheets = metadata.build()?.execute().await?.sheets;
+        for source_sheet_id in duplicates {
+            let Some(source) = sheets
+                .iter()
+                .find(|properties| properties.sheet_id == Some(source_sheet_id))
+            else {
+                // Left for the API to reject.
+                continue;
+            };
+            let grid = source.grid_properties.clone().unwrap_or_default();
+            validate_cell_limit(
+                &sheets,
+                None,
+                grid.row_count.unwrap_or_default(),
+                grid.column_count.unwrap_or_default(),
+            )?;
+            sheets.push(SheetProperties {
+                sheet_id: None,
+                grid_properties: Some(grid),
+                ..Default::default()
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Default upper bound on the time spent waiting for a data source refresh.
+const DEFAULT_REFRESH_MAX_WAIT: Duration = Duration::from_secs(300);
+
+/// Builder for configuring data source refresh operations.
+///
+/// Refreshing a data source starts a data execution for every object linked to
+/// it. By default the operation returns as soon as the executions are started;
+/// [`wait_for_completion`](Self::wait_for_completion) makes it poll the data
+/// source sheets until their executions finish.
+#[derive(Default)]
+pub struct RefreshDataSourceBuilder {
+    /// The spreadsheet operations instance.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// The ID of the data source to refresh.
+    data_source_id: String,
+    /// Whether to refresh regardless of the current state of the objects.
+    force: bool,
+    /// The time between two status polls, if waiting for completion.
+    poll_interval: Option<Duration>,
+    /// The maximum time spent waiting for completion.
+    max_wait: Option<Duration>,
+    /// Timeout override for each request.
+    timeout: Option<Duration>,
+}
+
+impl RefreshDataSourceBuilder {
+    /// Creates a new builder for the specified spreadsheet and data source.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    /// * `data_source_id` - The ID of the data source to refresh
+    ///
+    /// # Returns
+    /// A new [`RefreshDataSourceBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations, data_source_id: &str) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            data_source_id: data_source_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether the objects are refreshed regardless of their current state.
+    ///
+    /// Without forcing, objects whose execution is still running are skipped.
+    /// Forcing cancels the running execution and starts a new one.
+    ///
+    /// # Arguments
+    /// * `force` - Whether to force the refresh
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Waits for the data source sheets to finish refreshing before returning.
+    ///
+    /// The execution status of each refreshed data source sheet is polled until
+    /// it succeeds or fails. Other objects (charts, tables, formulas) keep the
+    /// status returned when the refresh was started.
+    ///
+    /// # Arguments
+    /// * `poll_interval` - The time between two status polls
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn wait_for_completion(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = Some(poll_interval);
+        self
+    }
+
+    /// Sets the maximum time spent waiting for completion.
+    ///
+    /// Defaults to five minutes. Only meaningful together with
+    /// [`wait_for_completion`](Self::wait_for_completion).
+    ///
+    /// # Arguments
+    /// * `max_wait` - The maximum waiting time
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+
+    /// Sets the timeout for each request, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of a single request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`RefreshDataSourceOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`RefreshDataSourceOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set or the
+    /// data source ID is empty.
+    pub fn build(self) -> Result<RefreshDataSourceOperations, GSheetError> {
+        let spreadsheet = self.spreadsheet.ok_or_else(|| {
+            GSheetError::Other(
+                "SpreadsheetOperations is required to build RefreshDataSourceOperations".into(),
+            )
+        })?;
+        if self.data_source_id.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "Data source ID must not be empty".into(),
+            ));
+        }
+
+        Ok(RefreshDataSourceOperations {
+            spreadsheet,
+            data_source_id: self.data_source_id,
+            force: self.force,
+            poll_interval: self.poll_interval,
+            max_wait: self.max_wait.unwrap_or(DEFAULT_REFRESH_MAX_WAIT),
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation for refreshing the objects of a data source.
+pub struct RefreshDataSourceOperations {
+    /// The spreadsheet operations instance.
+    spreadsheet: SpreadsheetOperations,
+    /// The ID of the data source to refresh.
+    data_source_id: String,
+    /// Whether to refresh regardless of the current state of the objects.
+    force: bool,
+    /// The time between two status polls, if waiting for completion.
+    poll_interval: Option<Duration>,
+    /// The maximum time spent waiting for completion.
+    max_wait: Duration,
+    /// Timeout override for each request.
+    timeout: Option<Duration>,
+}
+
+impl RefreshDataSourceOperations {
+    /// Executes the data source refresh operation.
+    ///
+    /// # Returns
+    /// A `Result` containing the execution status of every refreshed object or a
+    /// [`GSheetError`]. When waiting for completion, the statuses of data source
+    /// sheets are the final ones.
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - Authentication fails
+    /// - The HTTP request fails
+    /// - The data source does not exist
+    /// - The response cannot be parsed
+    /// - The refresh does not complete within the maximum waiting time
+    pub async fn execute(
+        &self,
+    ) -> Result<Vec<RefreshDataSourceObjectExecutionStatus>, GSheetError> {
+        let mut batch_update = self
+            .spreadsheet
+            .batch_update()
+            .request(Request::RefreshDataSource(RefreshDataSourceRequest {
+                data_source_id: Some(self.data_source_id.clone()),
+                force: Some(self.force),
+                ..Default::default()
+            }));
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        let response = batch_update.build()?.execute().await?;
+
+        let mut statuses = match response.replies.into_iter().next() {
+            Some(Response::RefreshDataSource(reply)) => reply.statuses.unwrap_or_default(),
+            _ => {
+                return Err(GSheetError::Other(
+                    "Missing refreshDataSource reply in batch update response".into(),
+                ));
+            }
+        };
+
+        let Some(poll_interval) = self.poll_interval else {
+            return Ok(statuses);
+        };
+
+        let deadline = tokio::time::Instant::now() + self.max_wait;
+        while statuses.iter().any(is_pending_sheet) {
+            if tokio::time::Instant::now() + poll_interval > deadline {
+                return Err(GSheetError::Other(format!(
+                    "Data source '{}' did not finish refreshing within {:?}",
+                    self.data_source_id, self.max_wait
+                )));
+            }
+            tokio::time::sleep(poll_interval).await;
+
+            let sheet_statuses = self.fetch_sheet_statuses().await?;
+            for status in statuses.iter_mut() {
+                let sheet_id = status
+                    .reference
+                    .as_ref()
+                    .and_then(|reference| reference.sheet_id.as_deref());
+                if let Some(current) =
+                    sheet_id.and_then(|id| sheet_statuses.iter().find(|(sheet, _)| sheet == id))
+                {
+                    status.data_execution_status = Some(current.1.clone());
+                }
+            }
+        }
+
+        Ok(statuses)
+    }
+
+    /// Fetches the execution status of every data source sheet of the data source.
+    ///
+    /// # Returns
+    /// A `Result` containing `(sheet_id, status)` pairs or a [`GSheetError`].
+    async fn fetch_sheet_statuses(
+        &self,
+    ) -> Result<Vec<(String, DataExecutionStatus)>, GSheetError> {
+        let gsheet_client = &self.spreadsheet.gsheet_client;
+        let url = gsheet_client.spreadsheet_url(&self.spreadsheet.spreadsheet_id, "");
+
+        let query = QueryParams::new().fields(
+            "sheets.properties(sheetId,dataSourceSheetProperties(dataSourceId,dataExecutionStatus))",
+        );
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
+        let response = gsheet_client.send(request).await?;
+
+        if !response.status().is_success() {
+            return Err(
+                GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await,
+            );
+        }
+
+        let spreadsheet: Spreadsheet = decode_json(response).await?;
+        Ok(spreadsheet
+            .sheets
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|sheet| sheet.properties)
+            .filter_map(|properties| {
+                let data_source = properties.data_source_sheet_properties?;
+                if data_source.data_source_id.as_deref() != Some(self.data_source_id.as_str()) {
+                    return None;
+                }
+                Some((
+                    properties.sheet_id?.to_string(),
+                    data_source.data_execution_status?,
+                ))
+            })
+            .collect())
+    }
+}
+
+/// Returns whether a status refers to a data source sheet whose execution is not finished.
+fn is_pending_sheet(status: &RefreshDataSourceObjectExecutionStatus) -> bool {
+    let is_sheet = status
+        .reference
+        .as_ref()
+        .is_some_and(|reference| reference.sheet_id.is_some());
+    let state = status
+        .data_execution_status
+        .as_ref()
+        .and_then(|status| status.state.as_ref());
+    is_sheet
+        && matches!(
+            state,
+            Some(
+                DataExecutionState::NotStarted
+                    | DataExecutionState::Running
+                    | DataExecutionState::Cancelling
+            )
+        )
+}
+
+/// Builder for configuring chart resize operations.
+#[derive(Default)]
+pub struct ResizeChartBuilder {
+    /// The spreadsheet operations instance.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// The ID of the chart to resize.
+    chart_id: i32,
+    /// The new width, in pixels.
+    width: u32,
+    /// The new height, in pixels.
+    height: u32,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl ResizeChartBuilder {
+    /// Creates a new builder for the specified spreadsheet and chart.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    /// * `chart_id` - The ID of the chart to resize
+    /// * `width` - The new width, in pixels
+    /// * `height` - The new height, in pixels
+    ///
+    /// # Returns
+    /// A new [`ResizeChartBuilder`] instance.
+    pub fn new(
+        spreadsheet: &SpreadsheetOperations,
+        chart_id: i32,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            chart_id,
+            width,
+            height,
+            timeout: None,
+        }
+    }
+
+    /// Sets the timeout for this operation, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of the request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`ResizeChartOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`ResizeChartOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set, or if the
+    /// width or height is zero or does not fit in an `i32`.
+    pub fn build(self) -> Result<ResizeChartOperations, GSheetError> {
+        let spreadsheet = self.spreadsheet.ok_or_else(|| {
+            GSheetError::Other(
+                "SpreadsheetOperations is required to build ResizeChartOperations".into(),
+            )
+        })?;
+        let pixels = |value: u32| i32::try_from(value).ok().filter(|value| *value > 0);
+        let (Some(width), Some(height)) = (pixels(self.width), pixels(self.height)) else {
+            return Err(GSheetError::ValidationError(format!(
+                "Invalid chart size {}x{}",
+                self.width, self.height
+            )));
+        };
+
+        Ok(ResizeChartOperations {
+            spreadsheet,
+            chart_id: self.chart_id,
+            width,
+            height,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation for resizing a chart.
+pub struct ResizeChartOperations {
+    /// The spreadsheet operations instance.
+    spreadsheet: SpreadsheetOperations,
+    /// The ID of the chart to resize.
+    chart_id: i32,
+    /// The new width, in pixels.
+    width: i32,
+    /// The new height, in pixels.
+    height: i32,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl ResizeChartOperations {
+    /// Executes the chart resize operation.
+    ///
+    /// # Returns
+    /// A `Result` containing the new [`EmbeddedObjectPosition`] of the chart or a
+    /// [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - Authentication fails
+    /// - The HTTP request fails
+    /// - The chart does not exist
+    /// - The response cannot be parsed
+    pub async fn execute(&self) -> Result<EmbeddedObjectPosition, GSheetError> {
+        let request = Request::UpdateEmbeddedObjectPosition(UpdateEmbeddedObjectPositionRequest {
+            object_id: self.chart_id,
+            new_position: EmbeddedObjectPosition {
+                overlay_position: Some(OverlayPosition {
+                    width_pixels: Some(self.width),
+                    height_pixels: Some(self.height),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            fields: "overlayPosition.widthPixels,overlayPosition.heightPixels".into(),
+        });
+
+        let mut batch_update = self.spreadsheet.batch_update().request(request);
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        let response = batch_update.build()?.execute().await?;
+
+        match response.replies.into_iter().next() {
+            Some(Response::UpdateEmbeddedObjectPosition(
+                UpdateEmbeddedObjectPositionResponse {
+                    position: Some(position),
+                },
+            )) => Ok(position),
+            _ => Err(GSheetError::ResponseParseError(
+                "No position found in UpdateEmbeddedObjectPosition reply".into(),
+            )),
+        }
+    }
+}
+
+/// The number of theme color types, excluding the unspecified one.
+const THEME_COLOR_COUNT: usize = 9;
+
+/// Builder for configuring spreadsheet theme updates.
+///
+/// Only the parts of the theme that are set are changed. The API requires every
+/// theme color pair whenever theme colors are updated, so colors that are not set
+/// are filled in from the current theme.
+#[derive(Default)]
+pub struct SpreadsheetThemeBuilder {
+    /// The spreadsheet operations instance.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// The new primary font family.
+    primary_font_family: Option<String>,
+    /// The new theme color pairs.
+    theme_colors: Vec<ThemeColorPair>,
+    /// Timeout override for each request.
+    timeout: Option<Duration>,
+}
+
+impl SpreadsheetThemeBuilder {
+    /// Creates a new builder for the specified spreadsheet.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    ///
+    /// # Returns
+    /// A new [`SpreadsheetThemeBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Applies the font and every color of a named preset.
+    ///
+    /// Font and colors set afterwards override the ones of the preset.
+    ///
+    /// # Arguments
+    /// * `preset` - The preset to apply
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn preset(mut self, preset: ThemePreset) -> Self {
+        let theme = preset.theme();
+        self.primary_font_family = theme.primary_font_family;
+        self.theme_colors = theme.theme_colors.unwrap_or_default();
+        self
+    }
+
+    /// Sets the primary font family.
+    ///
+    /// # Arguments
+    /// * `font_family` - The font family (e.g., "Roboto")
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn primary_font_family(mut self, font_family: &str) -> Self {
+        self.primary_font_family = Some(font_family.to_string());
+        self
+    }
+
+    /// Sets the color of a theme color type.
+    ///
+    /// # Arguments
+    /// * `color_type` - The theme color type to change
+    /// * `color` - The new color
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn color(mut self, color_type: ThemeColorType, color: Color) -> Self {
+        self.theme_colors
+            .retain(|pair| pair.color_type.as_ref() != Some(&color_type));
+        self.theme_colors.push(ThemeColorPair {
+            color_type: Some(color_type),
+            color: Some(ColorStyle {
+                rgb_color: Some(color),
+                theme_color: None,
+            }),
+        });
+        self
+    }
+
+    /// Sets the timeout for each request, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of a single request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`UpdateThemeOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`UpdateThemeOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set, if nothing
+    /// is changed, or if a color uses the unspecified theme color type.
+    pub fn build(self) -> Result<UpdateThemeOperations, GSheetError> {
+        let spreadsheet = self.spreadsheet.ok_or_else(|| {
+            GSheetError::Other(
+                "SpreadsheetOperations is required to build UpdateThemeOperations".into(),
+            )
+        })?;
+        if self.primary_font_family.is_none() && self.theme_colors.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "A font family or a theme color must be set".into(),
+            ));
+        }
+        if self
+            .theme_colors
+            .iter()
+            .any(|pair| pair.color_type == Some(ThemeColorType::Unspecified))
+        {
+            return Err(GSheetError::ValidationError(
+                "Theme colors must have a specified color type".into(),
+            ));
+        }
+
+        Ok(UpdateThemeOperations {
+            spreadsheet,
+            primary_font_family: self.primary_font_family,
+            theme_colors: self.theme_colors,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation for updating the theme of a spreadsheet.
+pub struct UpdateThemeOperations {
+    /// The spreadsheet operations instance.
+    spreadsheet: SpreadsheetOperations,
+    /// The new primary font family.
+    primary_font_family: Option<String>,
+    /// The new theme color pairs.
+    theme_colors: Vec<ThemeColorPair>,
+    /// Timeout override for each request.
+    timeout: Option<Duration>,
+}
+
+impl UpdateThemeOperations {
+    /// Executes the theme update operation.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`SpreadsheetTheme`] that was sent, or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - Authentication fails
+    /// - The HTTP request fails
+    /// - The font family or a color is rejected by the API
+    /// - The response cannot be parsed
+    pub async fn execute(&self) -> Result<SpreadsheetTheme, GSheetError> {
+        let mut fields = Vec::new();
+        if self.primary_font_family.is_some() {
+            fields.push("spreadsheetTheme.primaryFontFamily");
+        }
+
+        let mut theme_colors = self.theme_colors.clone();
+        if !theme_colors.is_empty() {
+            fields.push("spreadsheetTheme.themeColors");
+            if theme_colors.len() < THEME_COLOR_COUNT {
+                let current = self.fetch_theme().await?;
+                for pair in current.theme_colors.unwrap_or_default() {
+                    if !theme_colors
+                        .iter()
+                        .any(|set| set.color_type == pair.color_type)
+                    {
+                        theme_colors.push(pair);
+                    }
+                }
+            }
+        }
+
+        let theme = SpreadsheetTheme {
+            primary_font_family: self.primary_font_family.clone(),
+            theme_colors: (!theme_colors.is_empty()).then_some(theme_colors),
+        };
+
+        let mut batch_update =
+            self.spreadsheet
+                .batch_update()
+                .request(Request::UpdateSpreadsheetProperties(
+                    UpdateSpreadsheetPropertiesRequest {
+                        properties: SpreadsheetProperties {
+                            spreadsheet_theme: Some(theme.clone()),
+                            ..Default::default()
+                        },
+                        fields: fields.join(","),
+                    },
+                ));
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        batch_update.build()?.execute().await?;
+
+        Ok(theme)
+    }
+
+    /// Fetches the current theme of the spreadsheet.
+    ///
+    /// # Returns
+    /// A `Result` containing the current [`SpreadsheetTheme`] or a [`GSheetError`].
+    async fn fetch_theme(&self) -> Result<SpreadsheetTheme, GSheetError> {
+        let gsheet_client = &self.spreadsheet.gsheet_client;
+        let url = gsheet_client.spreadsheet_url(&self.spreadsheet.spreadsheet_id, "");
+
+        let query = QueryParams::new().fields("properties.spreadsheetTheme");
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
+        let response = gsheet_client.send(request).await?;
+
+        if !response.status().is_success() {
+            return Err(
+                GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await,
+            );
+        }
+
+        let spreadsheet: Spreadsheet = decode_json(response).await?;
+        Ok(spreadsheet
+            .properties
+            .and_then(|properties| properties.spreadsheet_theme)
+            .unwrap_or_default())
+    }
+}
+
+/// Builder for configuring the calculation settings of a spreadsheet.
+///
+/// Only the settings that are set are changed.
+#[derive(Default)]
+pub struct CalculationSettingsBuilder {
+    /// The spreadsheet operations instance.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// The new recalculation interval.
+    recalculation: Option<RecalculationInterval>,
+    /// The new iterative calculation settings; `Some(None)` disables it.
+    iterative_calculation: Option<Option<(u32, f64)>>,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl CalculationSettingsBuilder {
+    /// Creates a new builder for the specified spreadsheet.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    ///
+    /// # Returns
+    /// A new [`CalculationSettingsBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets how often volatile functions such as `NOW()` are recalculated.
+    ///
+    /// # Arguments
+    /// * `interval` - The recalculation interval
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn recalculation(mut self, interval: RecalculationInterval) -> Self {
+        self.recalculation = Some(interval);
+        self
+    }
+
+    /// Enables iterative calculation of circular references.
+    ///
+    /// # Arguments
+    /// * `max_iterations` - The maximum number of calculation rounds
+    /// * `threshold` - The convergence threshold below which calculation stops
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn iterative_calculation(mut self, max_iterations: u32, threshold: f64) -> Self {
+        self.iterative_calculation = Some(Some((max_iterations, threshold)));
+        self
+    }
+
+    /// Disables iterative calculation, so circular references become errors.
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn disable_iterative_calculation(mut self) -> Self {
+        self.iterative_calculation = Some(None);
+        self
+    }
+
+    /// Sets the timeout for this operation, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of the request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`UpdateCalculationSettingsOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`UpdateCalculationSettingsOperations`]
+    /// or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set, nothing is
+    /// changed, the recalculation interval is unspecified, or the iterative
+    /// calculation settings are out of range.
+    pub fn build(self) -> Result<UpdateCalculationSettingsOperations, GSheetError> {
+        let spreadsheet = self.spreadsheet.ok_or_else(|| {
+            GSheetError::Other(
+                "SpreadsheetOperations is required to build UpdateCalculationSettingsOperations"
+                    .into(),
+            )
+        })?;
+
+        let mut properties = SpreadsheetProperties::default();
+        let mut fields = Vec::new();
+
+        if let Some(interval) = self.recalculation {
+            if matches!(interval, RecalculationInterval::Unspecified) {
+                return Err(GSheetError::ValidationError(
+                    "Recalculation interval must be specified".into(),
+                ));
+            }
+            properties.auto_recalc = Some(interval);
+            fields.push("autoRecalc");
+        }
+
+        if let Some(iterative_calculation) = self.iterative_calculation {
+            if let Some((max_iterations, threshold)) = iterative_calculation {
+                let max_iterations = i32::try_from(max_iterations)
+                    .ok()
+                    .filter(|max_iterations| *max_iterations > 0)
+                    .ok_or_else(|| {
+                        GSheetError::ValidationError(format!(
+                            "Invalid maximum number of iterations: {max_iterations}"
+                        ))
+                    })?;
+                if !threshold.is_finite() || threshold < 0.0 {
+                    return Err(GSheetError::ValidationError(format!(
+                        "Invalid convergence threshold: {threshold}"
+                    )));
+                }
+                properties.iterative_calculation_settings = Some(IterativeCalculationSettings {
+                    max_iterations: Some(max_iterations),
+                    convergence_threshold: Some(threshold),
+                });
+            }
+            fields.push("iterativeCalculationSettings");
+        }
+
+        if fields.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "At least one calculation setting must be set".into(),
+            ));
+        }
+
+        Ok(UpdateCalculationSettingsOperations {
+            spreadsheet,
+            request: UpdateSpreadsheetPropertiesRequest {
+                properties,
+                fields: fields.join(","),
+            },
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation for updating the calculation settings of a spreadsheet.
+pub struct UpdateCalculationSettingsOperations {
+    /// The spreadsheet operations instance.
+    spreadsheet: SpreadsheetOperations,
+    /// The properties update to send.
+    request: UpdateSpreadsheetPropertiesRequest,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl UpdateCalculationSettingsOperations {
+    /// Executes the calculation settings update operation.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - Authentication fails
+    /// - The HTTP request fails
+    /// - The response cannot be parsed
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        let mut batch_update = self
+            .spreadsheet
+            .batch_update()
+            .request(Request::UpdateSpreadsheetProperties(self.request.clone()));
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        batch_update.build()?.execute().await?;
+        Ok(())
+    }
+}
+
+/// Builder for configuring spreadsheet metadata retrieval.
+pub struct GetMetadataBuilder {
+    /// The spreadsheet operations instance.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// Whether the client cache may be used.
+    use_cache: bool,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl Default for GetMetadataBuilder {
+    fn default() -> Self {
+        Self {
+            spreadsheet: None,
+            use_cache: true,
+            timeout: None,
+        }
+    }
+}
+
+impl GetMetadataBuilder {
+    /// Creates a new builder for the specified spreadsheet.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    ///
+    /// # Returns
+    /// A new [`GetMetadataBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether the metadata may be served from the client cache.
+    ///
+    /// Fresh metadata is still stored in the cache when this is disabled.
+    ///
+    /// # Arguments
+    /// * `use_cache` - Whether to look the metadata up in the cache first
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
+    /// Sets the timeout for this operation, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of the request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`GetMetadataOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`GetMetadataOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set.
+    pub fn build(self) -> Result<GetMetadataOperations, GSheetError> {
+        let spreadsheet = self.spreadsheet.ok_or_else(|| {
+            GSheetError::Other(
+                "SpreadsheetOperations is required to build GetMetadataOperations".into(),
+            )
+        })?;
+
+        Ok(GetMetadataOperations {
+            spreadsheet,
+            use_cache: self.use_cache,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation for retrieving spreadsheet metadata.
+pub struct GetMetadataOperations {
+    /// The spreadsheet operations instance.
+    spreadsheet: SpreadsheetOperations,
+    /// Whether the client cache may be used.
+    use_cache: bool,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl GetMetadataOperations {
+    /// Executes the metadata retrieval operation.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`SpreadsheetMetadata`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - Authentication fails
+    /// - The HTTP request fails
+    /// - The response cannot be parsed
+    /// - The spreadsheet does not exist or is not accessible
+    pub async fn execute(&self) -> Result<SpreadsheetMetadata, GSheetError> {
+        let gsheet_client = &self.spreadsheet.gsheet_client;
+        let cache = gsheet_client.cache.as_ref();
+
+        if self.use_cache
+            && let Some(metadata) =
+                cache.and_then(|cache| cache.get_metadata(&self.spreadsheet.spreadsheet_id))
+        {
+            return Ok(metadata);
+        }
+
+        let url = gsheet_client.spreadsheet_url(&self.spreadsheet.spreadsheet_id, "");
+        let query = QueryParams::new().fields(METADATA_FIELDS);
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
+        let response = gsheet_client.send(request).await?;
+
+        if !response.status().is_success() {
+            return Err(
+                GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await,
+            );
+        }
+
+        let spreadsheet: Spreadsheet = decode_json(response).await?;
+        let mut metadata = SpreadsheetMetadata::from(spreadsheet);
+        if metadata.spreadsheet_id.is_empty() {
+            metadata.spreadsheet_id = self.spreadsheet.spreadsheet_id.to_string();
+        }
+        if let Some(cache) = cache {
+            cache.insert_metadata(metadata.clone());
+        }
+        Ok(metadata)
+    }
+}
+
+/// Builder for reading the values of the ranges selected by data filters.
+#[derive(Clone, Default)]
+pub struct GetValuesByDataFilterBuilder {
+    /// The spreadsheet operations instance.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// The filters selecting the ranges.
+    data_filters: Vec<DataFilter>,
+    /// How values are rendered.
+    value_render_option: ValueRenderOption,
+    /// How dates and times are rendered.
+    date_time_render_option: DateTimeRenderOption,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl GetValuesByDataFilterBuilder {
+    /// Creates a new builder for the specified spreadsheet.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    ///
+    /// # Returns
+    /// A new [`GetValuesByDataFilterBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            data_filters: Vec::new(),
+            value_render_option: spreadsheet.value_options.value_render_option.clone(),
+            date_time_render_option: spreadsheet.value_options.date_time_render_option.clone(),
+            timeout: None,
+        }
+    }
+
+    /// Adds a filter selecting ranges to read.
+    ///
+    /// # Arguments
+    /// * `filter` - An A1 range, a grid range or a developer metadata lookup
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn filter(mut self, filter: impl Into<DataFilter>) -> Self {
+        self.data_filters.push(filter.into());
+        self
+    }
+
+    /// Adds several filters selecting ranges to read.
+    ///
+    /// # Arguments
+    /// * `filters` - A1 ranges, grid ranges or developer metadata lookups
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn filters<F: Into<DataFilter>>(mut self, filters: impl IntoIterator<Item = F>) -> Self {
+        self.data_filters
+            .extend(filters.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets how values are rendered, overriding the spreadsheet default.
+    ///
+    /// # Arguments
+    /// * `option` - The value render option
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.value_render_option = option;
+        self
+    }
+
+    /// Sets how dates and times are rendered, overriding the spreadsheet default.
+    ///
+    /// # Arguments
+    /// * `option` - The date time render option
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.date_time_render_option = option;
+        self
+    }
+
+    /// Sets the timeout for this operation, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of the request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`GetValuesByDataFilterOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`GetValuesByDataFilterOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set, no filter
+    /// was added, an A1 range filter is malformed, or a developer metadata
+    /// lookup has an empty key.
+    pub fn build(self) -> Result<GetValuesByDataFilterOperations, GSheetError> {
+        let spreadsheet = self.spreadsheet.ok_or_else(|| {
+            GSheetError::Other(
+                "SpreadsheetOperations is required to build GetValuesByDataFilterOperations".into(),
+            )
+        })?;
+        if self.data_filters.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "At least one data filter is required".into(),
+            ));
+        }
+        for filter in &self.data_filters {
+            match filter {
+                DataFilter::A1Range(range) => validate_range(range)?,
+                DataFilter::DeveloperMetadataLookup(lookup)
+                    if lookup.metadata_key.as_deref() == Some("") =>
+                {
+                    return Err(GSheetError::ValidationError(
+                        "Metadata key must not be empty".into(),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(GetValuesByDataFilterOperations {
+            spreadsheet,
+            data_filters: self.data_filters,
+            value_render_option: self.value_render_option,
+            date_time_render_option: self.date_time_render_option,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation for reading the values of the ranges selected by data filters.
+pub struct GetValuesByDataFilterOperations {
+    /// The spreadsheet operations instance.
+    spreadsheet: SpreadsheetOperations,
+    /// The filters selecting the ranges.
+    data_filters: Vec<DataFilter>,
+    /// How values are rendered.
+    value_render_option: ValueRenderOption,
+    /// How dates and times are rendered.
+    date_time_render_option: DateTimeRenderOption,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl GetValuesByDataFilterOperations {
+    /// Executes the read.
+    ///
+    /// # Returns
+    /// A `Result` containing the values of every range matched by the filters,
+    /// in the order returned by the API, or a [`GSheetError`]. No match is an
+    /// empty [`BatchValueRanges`], not an error.
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - Authentication fails
+    /// - The HTTP request fails
+    /// - The response cannot be parsed
+    pub async fn execute(&self) -> Result<BatchValueRanges, GSheetError> {
+        let gsheet_client = &self.spreadsheet.gsheet_client;
+        let url = gsheet_client.spreadsheet_url(
+            &self.spreadsheet.spreadsheet_id,
+            "/values:batchGetByDataFilter",
+        );
+        let body = BatchGetValuesByDataFilterRequest {
+            data_filters: self.data_filters.clone(),
+            major_dimension: Some(Dimension::Rows),
+            value_render_option: Some(self.value_render_option.clone()),
+            date_time_render_option: Some(self.date_time_render_option.clone()),
+        };
+        let request = gsheet_client
+            .request(reqwest::Method::POST, &url, self.timeout)
+            .await?
+            .json(&body);
+        let response = gsheet_client.send_read(request).await?;
+
+        if !response.status().is_success() {
+            return Err(
+                GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await,
+            );
+        }
+
+        let response: BatchGetValuesByDataFilterResponse = decode_json(response).await?;
+        Ok(BatchValueRanges {
+            spreadsheet_id: response
+                .spreadsheet_id
+                .unwrap_or_else(|| self.spreadsheet.spreadsheet_id.to_string()),
+            value_ranges: response
+                .value_ranges
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|matched| matched.value_range)
+                .collect(),
+        })
+    }
+}
+
+/// Builder for listing the sheets of a spreadsheet.
+#[derive(Clone, Default)]
+pub struct ListSheetsBuilder {
+    /// The spreadsheet operations instance.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl ListSheetsBuilder {
+    /// Creates a new builder for the specified spreadsheet.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    ///
+    /// # Returns
+    /// A new [`ListSheetsBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the timeout for this operation, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of the request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`ListSheetsOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`ListSheetsOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set.
+    pub fn build(self) -> Result<ListSheetsOperations, GSheetError> {
+        let spreadsheet = self.spreadsheet.ok_or_else(|| {
+            GSheetError::Other(
+                "SpreadsheetOperations is required to build ListSheetsOperations".into(),
+            )
+        })?;
+
+        Ok(ListSheetsOperations {
+            spreadsheet,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation for listing the sheets of a spreadsheet.
+pub struct ListSheetsOperations {
+    /// The spreadsheet operations instance.
+    spreadsheet: SpreadsheetOperations,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl ListSheetsOperations {
+    /// Executes the listing.
+    ///
+    /// Only the `sheets.properties` fields are requested, so neither cell data
+    /// nor other sheet resources such as charts are downloaded.
+    ///
+    /// # Returns
+    /// A `Result` containing the properties of every sheet, in tab order, or a
+    /// [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - Authentication fails
+    /// - The HTTP request fails
+    /// - The response cannot be parsed
+    /// - The spreadsheet does not exist or is not accessible
+    pub async fn execute(&self) -> Result<Vec<SheetProperties>, GSheetError> {
+        let gsheet_client = &self.spreadsheet.gsheet_client;
+        let url = gsheet_client.spreadsheet_url(&self.spreadsheet.spreadsheet_id, "");
+        let query = QueryParams::new().fields("sheets.properties");
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.timeout)
+            .await?;
+        let response = gsheet_client.send(request).await?;
+
+        if !response.status().is_success() {
+            return Err(
+                GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await,
+            );
+        }
+
+        let spreadsheet: Spreadsheet = decode_json(response).await?;
+        let mut sheets: Vec<SheetProperties> = spreadsheet
+            .sheets
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|sheet| sheet.properties)
+            .collect();
+        sheets.sort_by_key(|properties| properties.index.unwrap_or_default());
+        Ok(sheets)
+    }
+}
+
+/// The maximum number of titles tried when deduplicating a taken title, in case
+/// other writers keep taking the chosen titles.
+const MAX_TITLE_ATTEMPTS: usize = 5;
+
+/// Returns the first title of the form "title (n)", with n starting at 2, that is
+/// not taken. Sheet titles are compared case-insensitively, like the API does.
+fn deduplicated_title(title: &str, sheets: &[SheetProperties]) -> String {
+    let taken: Vec<String> = sheets
+        .iter()
+        .filter_map(|properties| properties.title.as_deref())
+        .map(str::to_lowercase)
+        .collect();
+    (2..)
+        .map(|n| format!("{} ({})", title, n))
+        .find(|candidate| !taken.contains(&candidate.to_lowercase()))
+        .unwrap_or_else(|| title.to_string())
+}
+
+/// Applies a title through `attempt`, deduplicating it on collision if allowed.
+async fn with_title_collision<T, F, Fut>(
+    spreadsheet: &SpreadsheetOperations,
+    title: &str,
+    on_collision: TitleCollision,
+    timeout: Option<Duration>,
+    attempt: F,
+) -> Result<T, GSheetError>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<T, GSheetError>>,
+{
+    let mut candidate = title.to_string();
+    for _ in 0..MAX_TITLE_ATTEMPTS {
+        match attempt(candidate.clone()).await {
+            Err(GSheetError::SheetAlreadyExists { .. })
+                if on_collision == TitleCollision::Deduplicate =>
+            {
+                let mut list = spreadsheet.list_sheets();
+                if let Some(timeout) = timeout {
+                    list = list.timeout(timeout);
+                }
+                let sheets = list.build()?.execute().await?;
+                candidate = deduplicated_title(title, &sheets);
+            }
+            Err(GSheetError::SheetAlreadyExists { .. }) => {
+                return Err(GSheetError::SheetAlreadyExists { title: candidate });
+            }
+            result => return result,
+        }
+    }
+    Err(GSheetError::SheetAlreadyExists {
+        title: title.to_string(),
+    })
+}
+
+/// The number of rows of a sheet added without an explicit size.
+const DEFAULT_SHEET_ROWS: i32 = 1000;
+
+/// The number of columns of a sheet added without an explicit size.
+const DEFAULT_SHEET_COLUMNS: i32 = 26;
+
+/// Builder for adding a sheet to a spreadsheet.
+#[derive(Clone, Default)]
+pub struct AddSheetBuilder {
+    /// The spreadsheet operations instance.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// The requested title of the sheet.
+    title: String,
+    /// The number of rows of the sheet.
+    rows: Option<i32>,
+    /// The number of columns of the sheet.
+    columns: Option<i32>,
+    /// The zero-based position of the sheet among the tabs.
+    index: Option<i32>,
+    /// The policy applied when the title is taken.
+    on_collision: TitleCollision,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl AddSheetBuilder {
+    /// Creates a new builder adding a sheet with the given title.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    /// * `title` - The title of the new sheet
+    ///
+    /// # Returns
+    /// A new [`AddSheetBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations, title: &str) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the number of rows of the new sheet.
+    ///
+    /// # Arguments
+    /// * `rows` - The number of rows
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn rows(mut self, rows: i32) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+
+    /// Sets the number of columns of the new sheet.
+    ///
+    /// # Arguments
+    /// * `columns` - The number of columns
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn columns(mut self, columns: i32) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Sets the zero-based position of the new sheet among the tabs.
+    ///
+    /// # Arguments
+    /// * `index` - The position of the sheet
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn index(mut self, index: i32) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Sets the policy applied when a sheet with the title already exists.
+    ///
+    /// # Arguments
+    /// * `on_collision` - The collision policy, [`TitleCollision::Fail`] by default
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn on_collision(mut self, on_collision: TitleCollision) -> Self {
+        self.on_collision = on_collision;
+        self
+    }
+
+    /// Sets the timeout for this operation, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of each request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`AddSheetOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`AddSheetOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set or if the
+    /// title is invalid.
+    pub fn build(self) -> Result<AddSheetOperations, GSheetError> {
+        let spreadsheet = self.spreadsheet.ok_or_else(|| {
+            GSheetError::Other(
+                "SpreadsheetOperations is required to build AddSheetOperations".into(),
+            )
+        })?;
+        validate_sheet_title(&self.title)?;
+
+        Ok(AddSheetOperations {
+            spreadsheet,
+            title: self.title,
+            rows: self.rows,
+            columns: self.columns,
+            index: self.index,
+            on_collision: self.on_collision,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation for adding a sheet to a spreadsheet.
+pub struct AddSheetOperations {
+    /// The spreadsheet operations instance.
+    spreadsheet: SpreadsheetOperations,
+    /// The requested title of the sheet.
+    title: String,
+    /// The number of rows of the sheet.
+    rows: Option<i32>,
+    /// The number of columns of the sheet.
+    columns: Option<i32>,
+    /// The zero-based position of the sheet among the tabs.
+    index: Option<i32>,
+    /// The policy applied when the title is taken.
+    on_collision: TitleCollision,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl AddSheetOperations {
+    /// Executes the sheet addition.
+    ///
+    /// # Returns
+    /// A `Result` containing the properties of the new sheet, whose title is the
+    /// one actually used, or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - The new sheet would bring the spreadsheet over its cell limit
+    /// - The title is taken and the collision policy is [`TitleCollision::Fail`]
+    /// - Authentication fails
+    /// - The HTTP request fails
+    /// - The response cannot be parsed
+    pub async fn execute(&self) -> Result<SheetProperties, GSheetError> {
+        let mut metadata = self.spreadsheet.metadata();
+        if let Some(timeout) = self.timeout {
+            metadata = metadata.timeout(timeout);
+        }
+        let metadata = metadata.build()?.execute().await?;
+        validate_cell_limit(
+            &metadata.sheets,
+            None,
+            self.rows.unwrap_or(DEFAULT_SHEET_ROWS),
+            self.columns.unwrap_or(DEFAULT_SHEET_COLUMNS),
+        )?;
+
+        with_title_collision(
+            &self.spreadsheet,
+            &self.title,
+            self.on_collision,
+            self.timeout,
+            |title| self.add(title),
+        )
+        .await
+    }
+
+    /// Adds the sheet with the given title.
+    async fn add(&self, title: String) -> Result<SheetProperties, GSheetError> {
+        let grid_properties =
+            (self.rows.is_some() || self.columns.is_some()).then(|| GridProperties {
+                row_count: self.rows,
+                column_count: self.columns,
+                ..Default::default()
+            });
+        let request = Request::AddSheet(AddSheetRequest {
+            properties: SheetProperties {
+                title: Some(title),
+                index: self.index,
+                grid_properties,
+                ..Default::default()
+            },
+        });
+
+        let mut batch_update = self.spreadsheet.batch_update().request(request);
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        let response = batch_update.build()?.execute().await?;
+
+        match response.replies.into_iter().next() {
+            Some(Response::AddSheet(AddSheetResponse {
+                properties: Some(properties),
+            })) => Ok(properties),
+            _ => Err(GSheetError::ResponseParseError(
+                "No properties found in AddSheet reply".into(),
+            )),
+        }
+    }
+}
+
+/// Builder for renaming a sheet of a spreadsheet.
+#[derive(Clone, Default)]
+pub struct RenameSheetBuilder {
+    /// The spreadsheet operations instance.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// The current title of the sheet.
+    title: String,
+    /// The requested new title of the sheet.
+    new_title: String,
+    /// The policy applied when the new title is taken.
+    on_collision: TitleCollision,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl RenameSheetBuilder {
+    /// Creates a new builder renaming a sheet.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    /// * `title` - The current title of the sheet
+    /// * `new_title` - The new title of the sheet
+    ///
+    /// # Returns
+    /// A new [`RenameSheetBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations, title: &str, new_title: &str) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            title: title.to_string(),
+            new_title: new_title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the policy applied when another sheet already has the new title.
+    ///
+    /// # Arguments
+    /// * `on_collision` - The collision policy, [`TitleCollision::Fail`] by default
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn on_collision(mut self, on_collision: TitleCollision) -> Self {
+        self.on_collision = on_collision;
+        self
+    }
+
+    /// Sets the timeout for this operation, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of each request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`RenameSheetOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`RenameSheetOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set or if the
+    /// new title is invalid.
+    pub fn build(self) -> Result<RenameSheetOperations, GSheetError> {
+        let spreadsheet = self.spreadsheet.ok_or_else(|| {
+            GSheetError::Other(
+                "SpreadsheetOperations is required to build RenameSheetOperations".into(),
+            )
+        })?;
+        validate_sheet_title(&self.new_title)?;
+
+        Ok(RenameSheetOperations {
+            spreadsheet,
+            title: self.title,
+            new_title: self.new_title,
+            on_collision: self.on_collision,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation for renaming a sheet of a spreadsheet.
+pub struct RenameSheetOperations {
+    /// The spreadsheet operations instance.
+    spreadsheet: SpreadsheetOperations,
+    /// The current title of the sheet.
+    title: String,
+    /// The requested new title of the sheet.
+    new_title: String,
+    /// The policy applied when the new title is taken.
+    on_collision: TitleCollision,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl RenameSheetOperations {
+    /// Executes the rename.
+    ///
+    /// # Returns
+    /// A `Result` containing the title actually given to the sheet or a
+    /// [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - The sheet does not exist
+    /// - The new title is taken and the collision policy is [`TitleCollision::Fail`]
+    /// - Authentication fails
+    /// - The HTTP request fails
+    pub async fn execute(&self) -> Result<String, GSheetError> {
+        if self.title == self.new_title {
+            return Ok(self.new_title.clone());
+        }
+
+        let sheet_id = fetch_sheet_properties(&self.spreadsheet.sheet(&self.title), self.timeout)
+            .await?
+            .sheet_id;
+        with_title_collision(
+            &self.spreadsheet,
+            &self.new_title,
+            self.on_collision,
+            self.timeout,
+            |title| self.rename(sheet_id, title),
+        )
+        .await
+    }
+
+    /// Gives the sheet with the given ID the given title.
+    async fn rename(&self, sheet_id: Option<i32>, title: String) -> Result<String, GSheetError> {
+        let request = Request::UpdateSheetProperties(UpdateSheetPropertiesRequest {
+            properties: SheetProperties {
+                sheet_id,
+                title: Some(title.clone()),
+                ..Default::default()
+            },
+            fields: "title".into(),
+        });
+
+        let mut batch_update = self.spreadsheet.batch_update().request(request);
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
         }
+        batch_update.build()?.execute().await?;
+        Ok(title)
     }
 }
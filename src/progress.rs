@@ -0,0 +1,138 @@
+//! # Progress Module
+//!
+//! This module provides progress reporting and cancellation for long-running
+//! bulk operations, such as chunked writes and reads of large sheets.
+//!
+//! ## Overview
+//!
+//! Chunked operations accept a [`ProgressCallback`] through their `on_progress`
+//! setter. The callback is invoked once after each chunk completes, with a
+//! [`Progress`] snapshot of the rows and bytes processed so far, so command line
+//! tools can render progress bars for multi-minute jobs.
+//!
+//! The callback runs on the task executing the operation and should return
+//! quickly. To consume progress elsewhere, forward the snapshots to a channel:
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::progress::Progress;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//! # let rows: Vec<Vec<String>> = Vec::new();
+//!
+//! let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<Progress>();
+//! tokio::spawn(async move {
+//!     while let Some(progress) = receiver.recv().await {
+//!         println!("{:.0}% ({} rows)", progress.fraction() * 100.0, progress.rows_done);
+//!     }
+//! });
+//!
+//! gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .sheet("Import")
+//!     .write_chunked("A1", rows)
+//!     .chunk_rows(5_000)
+//!     .on_progress(Arc::new(move |progress| {
+//!         let _ = sender.send(progress.clone());
+//!     }))
+//!     .execute()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Cancellation
+//!
+//! Chunked operations also accept a [`CancellationToken`] through their
+//! `cancellation` setter. Once the token is cancelled, no further request is
+//! issued: the chunk in flight completes, and the operation returns
+//! [`GSheetError::Cancelled`](crate::error::GSheetError::Cancelled) with the
+//! number of rows processed so far. Dropping the future of an operation also
+//! stops it, but may abort a chunk in flight.
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::error::GSheetError;
+//! use gsheet_api::progress::CancellationToken;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//! # let rows: Vec<Vec<String>> = Vec::new();
+//!
+//! let token = CancellationToken::new();
+//! let shutdown = token.clone();
+//! tokio::spawn(async move {
+//!     tokio::signal::ctrl_c().await.ok();
+//!     shutdown.cancel();
+//! });
+//!
+//! let result = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .sheet("Import")
+//!     .write_chunked("A1", rows)
+//!     .cancellation(token)
+//!     .execute()
+//!     .await;
+//! if let Err(GSheetError::Cancelled { completed_rows }) = result {
+//!     println!("Stopped after {} rows", completed_rows);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+
+pub use tokio_util::sync::CancellationToken;
+
+/// The default number of rows per chunk of chunked operations.
+pub const DEFAULT_CHUNK_ROWS: usize = 1_000;
+
+/// A callback invoked after each chunk of a bulk operation.
+pub type ProgressCallback = Arc<dyn Fn(&Progress) + Send + Sync>;
+
+/// A snapshot of the progress of a chunked operation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Progress {
+    /// The 1-based index of the chunk that just completed.
+    pub chunk: usize,
+    /// The total number of chunks of the operation.
+    pub total_chunks: usize,
+    /// The number of rows processed so far.
+    pub rows_done: usize,
+    /// The total number of rows of the operation.
+    pub total_rows: usize,
+    /// The size of the values transferred so far, as serialized JSON.
+    pub bytes: u64,
+}
+
+impl Progress {
+    /// Returns the completed fraction of the operation, between 0 and 1.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gsheet_api::progress::Progress;
+    ///
+    /// let progress = Progress { rows_done: 250, total_rows: 1000, ..Default::default() };
+    /// assert_eq!(progress.fraction(), 0.25);
+    /// ```
+    pub fn fraction(&self) -> f64 {
+        if self.total_rows == 0 {
+            1.0
+        } else {
+            self.rows_done as f64 / self.total_rows as f64
+        }
+    }
+
+    /// Returns whether every chunk has completed.
+    pub fn is_done(&self) -> bool {
+        self.chunk >= self.total_chunks
+    }
+}
+
+/// Returns the size of values once serialized as JSON.
+pub(crate) fn values_size(values: &[Vec<String>]) -> u64 {
+    serde_json::to_vec(values).map_or(0, |bytes| bytes.len() as u64)
+}
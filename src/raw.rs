@@ -0,0 +1,254 @@
+//! # Raw Module
+//!
+//! This module sends arbitrary requests to the Sheets API, as an escape hatch
+//! for features the typed operations don't cover yet.
+//!
+//! ## Overview
+//!
+//! [`GoogleSheetClient::raw_request`](crate::client::GoogleSheetClient::raw_request)
+//! creates a [`RawRequestBuilder`] for an HTTP method and a path relative to
+//! the Sheets base URL, such as `{spreadsheetId}:batchUpdate` or
+//! `{spreadsheetId}/values/{range}`. An absolute `https://` URL can be given
+//! instead, to reach another Google API with the same credentials. Since the
+//! credentials are attached to it, the URL must be on a `googleapis.com` host
+//! or on the host of a configured base URL. The path is sent as is, so ranges
+//! in it must already be percent-encoded.
+//!
+//! The request goes through the same pipeline as the typed operations: the
+//! token is refreshed, timeouts, quota headers, the circuit breaker, read-only
+//! and dry-run modes, the audit sink, metrics and cassettes all apply. Writes
+//! invalidate the cached reads of the spreadsheet named by the path. The
+//! response body is returned as a [`serde_json::Value`], and failed responses
+//! are mapped to the same errors as the typed operations.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//! use serde_json::json;
+//!
+//! let response = gsheet_client
+//!     .raw_request(reqwest::Method::POST, "spreadsheet-id:batchUpdate")
+//!     .body(json!({
+//!         "requests": [{ "autoResizeDimensions": {
+//!             "dimensions": { "sheetId": 0, "dimension": "COLUMNS" }
+//!         }}]
+//!     }))
+//!     .build()?
+//!     .execute()
+//!     .await?;
+//! println!("{}", response["replies"]);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::client::GoogleSheetClient;
+use crate::client::url::QueryParams;
+use crate::error::{GSheetError, decode_json};
+
+/// Builder for configuring a raw request.
+#[derive(Clone, Default)]
+pub struct RawRequestBuilder {
+    /// The Google Sheets client.
+    gsheet_client: Option<GoogleSheetClient>,
+    /// The HTTP method of the request.
+    method: reqwest::Method,
+    /// The path of the request, relative to the Sheets base URL, or an absolute URL.
+    path: String,
+    /// The query parameters of the request, in order.
+    query: QueryParams,
+    /// The JSON body of the request, if any.
+    body: Option<Value>,
+    /// Timeout override for the request.
+    timeout: Option<Duration>,
+}
+
+impl RawRequestBuilder {
+    /// Creates a new builder for a raw request.
+    ///
+    /// # Arguments
+    /// * `gsheet_client` - The Google Sheets client
+    /// * `method` - The HTTP method of the request
+    /// * `path` - The path relative to the Sheets base URL, or an absolute URL
+    ///
+    /// # Returns
+    /// A new [`RawRequestBuilder`] instance.
+    pub fn new(gsheet_client: &GoogleSheetClient, method: reqwest::Method, path: &str) -> Self {
+        Self {
+            gsheet_client: Some(gsheet_client.clone()),
+            method,
+            path: path.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a query parameter. Parameters can be repeated, such as `ranges`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the parameter
+    /// * `value` - The value of the parameter, encoded when sent
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn query(mut self, name: &str, value: &str) -> Self {
+        self.query = self.query.push(name, value);
+        self
+    }
+
+    /// Sets the JSON body of the request.
+    ///
+    /// # Arguments
+    /// * `body` - The body of the request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn body(mut self, body: Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Sets the timeout of the request, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of the request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`RawRequestOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`RawRequestOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the client is not set, or if an
+    /// absolute URL is not an `https` URL on a `googleapis.com` host or on the
+    /// host of a configured base URL.
+    pub fn build(self) -> Result<RawRequestOperations, GSheetError> {
+        let gsheet_client = self.gsheet_client.ok_or_else(|| {
+            GSheetError::Other("GoogleSheetClient is required to build RawRequestOperations".into())
+        })?;
+
+        let absolute = self.path.contains("://");
+        if absolute && !self.path.starts_with("https://") {
+            return Err(GSheetError::ValidationError(format!(
+                "Raw requests must use https, got '{}'",
+                self.path
+            )));
+        }
+        if absolute && !is_google_api_url(&gsheet_client, &self.path) {
+            return Err(GSheetError::ValidationError(format!(
+                "Raw requests carry the client credentials and can only reach \
+                 googleapis.com or the configured base URLs, got '{}'",
+                self.path
+            )));
+        }
+        let path = self.path.trim_start_matches('/');
+        let (url, spreadsheet_id) = if absolute {
+            (self.path.clone(), String::new())
+        } else if path.is_empty() {
+            (gsheet_client.base_url.to_string(), String::new())
+        } else {
+            let spreadsheet_id = path.split(['/', ':', '?']).next().unwrap_or_default();
+            (
+                format!("{}/{}", gsheet_client.base_url, path),
+                spreadsheet_id.to_string(),
+            )
+        };
+
+        Ok(RawRequestOperations {
+            gsheet_client,
+            method: self.method,
+            url,
+            spreadsheet_id,
+            query: self.query,
+            body: self.body,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Whether an absolute URL is on a Google API host, or on the host of one of
+/// the base URLs of the client.
+fn is_google_api_url(gsheet_client: &GoogleSheetClient, url: &str) -> bool {
+    let Ok(url) = reqwest::Url::parse(url) else {
+        return false;
+    };
+    let on_google_api = url
+        .host_str()
+        .is_some_and(|host| host == "googleapis.com" || host.ends_with(".googleapis.com"));
+    on_google_api
+        || [&gsheet_client.base_url, &gsheet_client.drive_base_url]
+            .into_iter()
+            .filter_map(|base_url| reqwest::Url::parse(base_url).ok())
+            .any(|base_url| base_url.origin() == url.origin())
+}
+
+/// Operations for sending a raw request.
+#[derive(Clone)]
+pub struct RawRequestOperations {
+    /// The Google Sheets client.
+    gsheet_client: GoogleSheetClient,
+    /// The HTTP method of the request.
+    method: reqwest::Method,
+    /// The full URL of the request.
+    url: String,
+    /// The spreadsheet named by the path, or empty.
+    spreadsheet_id: String,
+    /// The query parameters of the request, in order.
+    query: QueryParams,
+    /// The JSON body of the request, if any.
+    body: Option<Value>,
+    /// Timeout override for the request.
+    timeout: Option<Duration>,
+}
+
+impl RawRequestOperations {
+    /// Executes the raw request.
+    ///
+    /// # Returns
+    /// A `Result` containing the JSON body of the response, [`Value::Null`] if
+    /// it is empty, or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the request fails, if the API
+    /// returns an error status or if the body of the response is not JSON.
+    pub async fn execute(&self) -> Result<Value, GSheetError> {
+        let gsheet_client = &self.gsheet_client;
+
+        let mut request = gsheet_client
+            .request(
+                self.method.clone(),
+                &self.query.url(&self.url),
+                self.timeout,
+            )
+            .await?;
+        if let Some(body) = &self.body {
+            request = request.json(body);
+        }
+        let response = gsheet_client.send(request).await?;
+
+        if !response.status().is_success() {
+            return Err(GSheetError::from_response(response, &self.spreadsheet_id).await);
+        }
+        if self.method != reqwest::Method::GET && !self.spreadsheet_id.is_empty() {
+            gsheet_client.invalidate_cache(&self.spreadsheet_id);
+        }
+        if response.content_length() == Some(0) {
+            return Ok(Value::Null);
+        }
+        decode_json(response).await
+    }
+}
@@ -0,0 +1,593 @@
+//! # Report Module
+//!
+//! This module provides [`ReportBuilder`], which turns typed records into a
+//! formatted table in a single spreadsheet transaction.
+//!
+//! ## Overview
+//!
+//! Records implement [`ReportRecord`] to describe their column headers and
+//! values. A report writes the header and one row per record, then optionally
+//! makes the header bold, applies number formats by column, freezes the header,
+//! bands the rows and adds a chart next to the table.
+//!
+//! Everything is compiled into a single `batchUpdate` call, preceded by a lookup
+//! of the sheet ID, so the report is applied atomically: either the whole table
+//! with its formats is written, or nothing is. The grid of the sheet is extended
+//! when the table does not fit in it.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::models::{BasicChartType, ExtendedValue, NumberFormat, NumberFormatType};
+//! use gsheet_api::report::ReportRecord;
+//!
+//! struct Sale {
+//!     region: String,
+//!     revenue: f64,
+//! }
+//!
+//! impl ReportRecord for Sale {
+//!     fn headers() -> Vec<String> {
+//!         vec!["Region".to_string(), "Revenue".to_string()]
+//!     }
+//!
+//!     fn values(&self) -> Vec<ExtendedValue> {
+//!         vec![self.region.as_str().into(), self.revenue.into()]
+//!     }
+//! }
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! let sales = vec![
+//!     Sale { region: "North".to_string(), revenue: 1250.5 },
+//!     Sale { region: "South".to_string(), revenue: 980.0 },
+//! ];
+//!
+//! let report = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .sheet("Sales")
+//!     .report(&sales)
+//!     .number_format("Revenue", NumberFormat {
+//!         type_: Some(NumberFormatType::Currency),
+//!         pattern: Some("$#,##0.00".to_string()),
+//!     })
+//!     .banded(true)
+//!     .chart(BasicChartType::Column, "Revenue by region")
+//!     .build()?
+//!     .execute()
+//!     .await?;
+//!
+//! println!("Report written to {}", report.range);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use crate::error::GSheetError;
+use crate::models::{
+    AddBandingRequest, AddChartRequest, BandedRange, BandingProperties, BasicChartType, CellData,
+    CellFormat, ChartSpec, Color, EmbeddedChart, EmbeddedObjectPosition, ExtendedValue,
+    GridCoordinate, GridProperties, GridRange, NumberFormat, OverlayPosition, RepeatCellRequest,
+    Request, Response, RowData, SheetProperties, TextFormat, UpdateCellsRequest,
+    UpdateSheetPropertiesRequest,
+};
+use crate::operations::sheet::{SheetOperations, fetch_sheet_properties};
+use crate::utils::{grid_range_to_a1, parse_a1_cell};
+
+/// A record that can be written as a row of a report.
+pub trait ReportRecord {
+    /// Returns the column headers, in column order.
+    fn headers() -> Vec<String>;
+
+    /// Returns the values of the record, in the same order as the headers.
+    fn values(&self) -> Vec<ExtendedValue>;
+}
+
+/// The chart added next to a report.
+#[derive(Debug, Clone)]
+enum ReportChart {
+    /// A basic chart, with the first column as domain and the others as series.
+    Basic(BasicChartType, String),
+    /// A pie chart, with the first column as labels and the second as values.
+    Pie(String),
+}
+
+/// The neutral grey palette of banded tables.
+pub(crate) fn grey_banding() -> BandingProperties {
+    BandingProperties {
+        header_color: Some(Color::from_rgb8(0xd9, 0xd9, 0xd9)),
+        first_band_color: Some(Color::from_rgb8(0xff, 0xff, 0xff)),
+        second_band_color: Some(Color::from_rgb8(0xf3, 0xf3, 0xf3)),
+        ..Default::default()
+    }
+}
+
+/// Builder for configuring a report.
+#[derive(Default)]
+pub struct ReportBuilder {
+    /// The sheet operations instance.
+    sheet: Option<SheetOperations>,
+    /// The column headers.
+    headers: Vec<String>,
+    /// The values of the records, one row per record.
+    rows: Vec<Vec<ExtendedValue>>,
+    /// The top-left cell of the table.
+    start_cell: Option<String>,
+    /// Number formats applied to data cells, by column header.
+    number_formats: Vec<(String, NumberFormat)>,
+    /// Whether the header is bold.
+    bold_header: bool,
+    /// Whether the rows down to the header are frozen.
+    freeze_header: bool,
+    /// The colors of the row banding, if any.
+    banding: Option<BandingProperties>,
+    /// The chart added next to the table, if any.
+    chart: Option<ReportChart>,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl ReportBuilder {
+    /// Creates a new builder writing the given records to a sheet.
+    ///
+    /// The header is bold and frozen by default.
+    ///
+    /// # Arguments
+    /// * `sheet` - The sheet operations instance
+    /// * `records` - The records to write, one row per record
+    ///
+    /// # Returns
+    /// A new [`ReportBuilder`] instance.
+    pub fn new<R: ReportRecord>(sheet: &SheetOperations, records: &[R]) -> Self {
+        Self {
+            sheet: Some(sheet.clone()),
+            headers: R::headers(),
+            rows: records.iter().map(ReportRecord::values).collect(),
+            bold_header: true,
+            freeze_header: true,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the top-left cell of the table.
+    ///
+    /// # Arguments
+    /// * `cell` - The A1 notation cell (e.g., "B2"), defaulting to "A1"
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn start_cell(mut self, cell: &str) -> Self {
+        self.start_cell = Some(cell.to_string());
+        self
+    }
+
+    /// Sets the number format of the data cells of a column.
+    ///
+    /// # Arguments
+    /// * `header` - The header of the column
+    /// * `format` - The number format to apply
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn number_format(mut self, header: &str, format: NumberFormat) -> Self {
+        self.number_formats.push((header.to_string(), format));
+        self
+    }
+
+    /// Sets whether the header is bold.
+    ///
+    /// # Arguments
+    /// * `bold` - Whether to make the header bold
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn bold_header(mut self, bold: bool) -> Self {
+        self.bold_header = bold;
+        self
+    }
+
+    /// Sets whether the rows down to the header are frozen.
+    ///
+    /// # Arguments
+    /// * `freeze` - Whether to freeze the header
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn freeze_header(mut self, freeze: bool) -> Self {
+        self.freeze_header = freeze;
+        self
+    }
+
+    /// Sets whether the rows of the table are banded with alternating colors.
+    ///
+    /// Uses a neutral grey palette; see [`banding`](Self::banding) for custom colors.
+    ///
+    /// # Arguments
+    /// * `banded` - Whether to band the rows
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn banded(mut self, banded: bool) -> Self {
+        self.banding = banded.then(grey_banding);
+        self
+    }
+
+    /// Bands the rows of the table with the given colors.
+    ///
+    /// # Arguments
+    /// * `properties` - The header and band colors
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn banding(mut self, properties: BandingProperties) -> Self {
+        self.banding = Some(properties);
+        self
+    }
+
+    /// Adds a basic chart right of the table, with the first column as domain and
+    /// every other column as a series.
+    ///
+    /// # Arguments
+    /// * `chart_type` - The type of the chart
+    /// * `title` - The title of the chart
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn chart(mut self, chart_type: BasicChartType, title: &str) -> Self {
+        self.chart = Some(ReportChart::Basic(chart_type, title.to_string()));
+        self
+    }
+
+    /// Adds a pie chart right of the table, with the first column as labels and
+    /// the second column as values.
+    ///
+    /// # Arguments
+    /// * `title` - The title of the chart
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn pie_chart(mut self, title: &str) -> Self {
+        self.chart = Some(ReportChart::Pie(title.to_string()));
+        self
+    }
+
+    /// Sets the timeout of each request, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of a single request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`ReportOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`ReportOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - The sheet is not set
+    /// - There is no header, or a record has more values than headers
+    /// - The start cell is malformed
+    /// - A number format names an unknown column
+    /// - A chart is requested without data rows, or a pie chart on more than two columns
+    pub fn build(self) -> Result<ReportOperations, GSheetError> {
+        let sheet = self.sheet.ok_or_else(|| {
+            GSheetError::Other("SheetOperations is required to build ReportOperations".into())
+        })?;
+        if self.headers.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "A report needs at least one column".into(),
+            ));
+        }
+        if let Some(index) = self
+            .rows
+            .iter()
+            .position(|row| row.len() > self.headers.len())
+        {
+            return Err(GSheetError::ValidationError(format!(
+                "Record {} has more values than the {} headers",
+                index,
+                self.headers.len()
+            )));
+        }
+
+        let (col, row) = parse_a1_cell(self.start_cell.as_deref().unwrap_or("A1"))?;
+        let column_formats = self
+            .number_formats
+            .into_iter()
+            .map(|(header, format)| {
+                let index = self
+                    .headers
+                    .iter()
+                    .position(|column| column.trim() == header.trim())
+                    .ok_or_else(|| {
+                        GSheetError::ValidationError(format!("No report column named {}", header))
+                    })?;
+                Ok((index, format))
+            })
+            .collect::<Result<Vec<_>, GSheetError>>()?;
+
+        if let Some(chart) = &self.chart {
+            if self.rows.is_empty() || self.headers.len() < 2 {
+                return Err(GSheetError::ValidationError(
+                    "A report chart needs a data row and at least two columns".into(),
+                ));
+            }
+            if matches!(chart, ReportChart::Pie(_)) && self.headers.len() != 2 {
+                return Err(GSheetError::ValidationError(
+                    "A report pie chart needs exactly a label and a value column".into(),
+                ));
+            }
+        }
+
+        Ok(ReportOperations {
+            sheet,
+            headers: self.headers,
+            rows: self.rows,
+            start: (row - 1, col - 1),
+            column_formats,
+            bold_header: self.bold_header,
+            freeze_header: self.freeze_header,
+            banding: self.banding,
+            chart: self.chart,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation writing a report to a sheet.
+pub struct ReportOperations {
+    /// The sheet operations instance.
+    sheet: SheetOperations,
+    /// The column headers.
+    headers: Vec<String>,
+    /// The values of the records, one row per record.
+    rows: Vec<Vec<ExtendedValue>>,
+    /// The zero-based row and column of the top-left cell.
+    start: (usize, usize),
+    /// Number formats applied to data cells, by zero-based column offset.
+    column_formats: Vec<(usize, NumberFormat)>,
+    /// Whether the header is bold.
+    bold_header: bool,
+    /// Whether the rows down to the header are frozen.
+    freeze_header: bool,
+    /// The colors of the row banding, if any.
+    banding: Option<BandingProperties>,
+    /// The chart added next to the table, if any.
+    chart: Option<ReportChart>,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl ReportOperations {
+    /// Returns the batch update requests writing the report to the given sheet.
+    ///
+    /// # Arguments
+    /// * `properties` - The properties of the sheet, with its ID and grid size
+    ///
+    /// # Returns
+    /// The requests, in the order they are applied.
+    pub fn requests(&self, properties: &SheetProperties) -> Vec<Request> {
+        let sheet_id = properties.sheet_id;
+        let (start_row, start_col) = (self.start.0 as i32, self.start.1 as i32);
+        let end_row = start_row + 1 + self.rows.len() as i32;
+        let end_col = start_col + self.headers.len() as i32;
+        let range = |start_row: i32, end_row: i32, start_col: i32, end_col: i32| GridRange {
+            sheet_id,
+            start_row_index: Some(start_row),
+            end_row_index: Some(end_row),
+            start_column_index: Some(start_col),
+            end_column_index: Some(end_col),
+        };
+        let mut requests = Vec::new();
+
+        // Grow the grid first, as cells outside of it cannot be written.
+        let grid = properties.grid_properties.clone().unwrap_or_default();
+        let mut grid_update = GridProperties::default();
+        let mut fields = Vec::new();
+        if grid.row_count.is_some_and(|count| count < end_row) {
+            grid_update.row_count = Some(end_row);
+            fields.push("gridProperties.rowCount");
+        }
+        if grid.column_count.is_some_and(|count| count < end_col) {
+            grid_update.column_count = Some(end_col);
+            fields.push("gridProperties.columnCount");
+        }
+        if self.freeze_header {
+            grid_update.frozen_row_count = Some(start_row + 1);
+            fields.push("gridProperties.frozenRowCount");
+        }
+        if !fields.is_empty() {
+            requests.push(Request::UpdateSheetProperties(
+                UpdateSheetPropertiesRequest {
+                    properties: SheetProperties {
+                        sheet_id,
+                        grid_properties: Some(grid_update),
+                        ..Default::default()
+                    },
+                    fields: fields.join(","),
+                },
+            ));
+        }
+
+        let row_data = |values: Vec<ExtendedValue>| RowData {
+            values: Some(
+                values
+                    .into_iter()
+                    .map(|value| CellData {
+                        user_entered_value: Some(value),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+        };
+        let header = self
+            .headers
+            .iter()
+            .map(|header| ExtendedValue::from(header.as_str()))
+            .collect();
+        requests.push(Request::UpdateCells(UpdateCellsRequest {
+            rows: std::iter::once(row_data(header))
+                .chain(self.rows.iter().cloned().map(row_data))
+                .collect(),
+            fields: "userEnteredValue".to_string(),
+            start: Some(GridCoordinate {
+                sheet_id,
+                row_index: Some(start_row),
+                column_index: Some(start_col),
+            }),
+            range: None,
+        }));
+
+        if self.bold_header {
+            requests.push(Request::RepeatCell(RepeatCellRequest {
+                range: range(start_row, start_row + 1, start_col, end_col),
+                cell: CellData {
+                    user_entered_format: Some(CellFormat {
+                        text_format: Some(TextFormat {
+                            bold: Some(true),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                fields: "userEnteredFormat.textFormat.bold".to_string(),
+            }));
+        }
+
+        if !self.rows.is_empty() {
+            for (offset, format) in &self.column_formats {
+                let col = start_col + *offset as i32;
+                requests.push(Request::RepeatCell(RepeatCellRequest {
+                    range: range(start_row + 1, end_row, col, col + 1),
+                    cell: CellData {
+                        user_entered_format: Some(CellFormat {
+                            number_format: Some(format.clone()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    fields: "userEnteredFormat.numberFormat".to_string(),
+                }));
+            }
+        }
+
+        if let Some(banding) = &self.banding {
+            requests.push(Request::AddBanding(AddBandingRequest {
+                banded_range: BandedRange {
+                    range: Some(range(start_row, end_row, start_col, end_col)),
+                    row_properties: Some(banding.clone()),
+                    ..Default::default()
+                },
+            }));
+        }
+
+        if let Some(chart) = &self.chart {
+            let table = range(start_row, end_row, start_col, end_col);
+            let spec = match chart {
+                ReportChart::Basic(chart_type, title) => {
+                    ChartSpec::basic(chart_type.clone(), title, &table)
+                }
+                ReportChart::Pie(title) => ChartSpec::pie(title, &table),
+            };
+            requests.push(Request::AddChart(AddChartRequest {
+                chart: EmbeddedChart {
+                    spec: Some(spec),
+                    position: Some(EmbeddedObjectPosition {
+                        overlay_position: Some(OverlayPosition {
+                            anchor_cell: Some(GridCoordinate {
+                                sheet_id,
+                                row_index: Some(start_row),
+                                column_index: Some(end_col + 1),
+                            }),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            }));
+        }
+
+        requests
+    }
+
+    /// Executes the report operation.
+    ///
+    /// The sheet properties are fetched first, then the whole report is written
+    /// in a single batch update. Banding cannot overlap existing banding, so
+    /// rewriting a banded report over itself fails until the old banding is
+    /// deleted.
+    ///
+    /// # Returns
+    /// A `Result` containing the written [`Report`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - Authentication fails
+    /// - The sheet does not exist
+    /// - The HTTP request fails
+    /// - Any of the requests is rejected, in which case nothing is written
+    pub async fn execute(&self) -> Result<Report, GSheetError> {
+        let properties = fetch_sheet_properties(&self.sheet, self.timeout).await?;
+
+        let mut batch_update = self.sheet.spreadsheet.batch_update();
+        if let Some(timeout) = self.timeout {
+            batch_update = batch_update.timeout(timeout);
+        }
+        let response = batch_update
+            .requests(self.requests(&properties))
+            .build()?
+            .execute()
+            .await?;
+
+        let (start_row, start_col) = (self.start.0 as i32, self.start.1 as i32);
+        let range = grid_range_to_a1(
+            &GridRange {
+                sheet_id: None,
+                start_row_index: Some(start_row),
+                end_row_index: Some(start_row + 1 + self.rows.len() as i32),
+                start_column_index: Some(start_col),
+                end_column_index: Some(start_col + self.headers.len() as i32),
+            },
+            &self.sheet.sheet_title,
+        )?;
+
+        let mut report = Report {
+            range,
+            ..Default::default()
+        };
+        for reply in response.replies {
+            match reply {
+                Response::AddBanding(reply) => {
+                    report.banded_range_id =
+                        reply.banded_range.and_then(|range| range.banded_range_id);
+                }
+                Response::AddChart(reply) => report.chart = reply.chart,
+                _ => {}
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// A report written to a sheet.
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    /// The A1 notation range of the table, header included.
+    pub range: String,
+    /// The ID of the banded range, if the rows were banded.
+    pub banded_range_id: Option<i32>,
+    /// The added chart, if any.
+    pub chart: Option<EmbeddedChart>,
+}
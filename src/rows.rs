@@ -0,0 +1,285 @@
+//! # Rows Module
+//!
+//! This module provides typed conversion of sheet rows, with errors identifying
+//! the offending row and column.
+//!
+//! ## Overview
+//!
+//! Types implement [`FromRow`] to build themselves from a [`RowView`], which
+//! reads cells by header name and parses them with [`FromStr`]. Reading a sheet
+//! with [`SheetOperations::rows_as`](crate::operations::sheet::SheetOperations::rows_as)
+//! converts every data row independently: a row that fails to convert yields a
+//! [`RowParseError`] with its row number, column name and column letter, while
+//! the other rows are still returned. This suits sheets edited by hand, where a
+//! stray value in one row should not fail the whole read.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::rows::{FromRow, RowParseError, RowView};
+//!
+//! struct Order {
+//!     customer: String,
+//!     quantity: u32,
+//!     discount: Option<f64>,
+//! }
+//!
+//! impl FromRow for Order {
+//!     fn from_row(row: &RowView<'_>) -> Result<Self, RowParseError> {
+//!         Ok(Order {
+//!             customer: row.get("Customer")?,
+//!             quantity: row.get("Quantity")?,
+//!             discount: row.get_opt("Discount")?,
+//!         })
+//!     }
+//! }
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! let rows = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .sheet("Orders")
+//!     .rows_as::<Order>()
+//!     .execute()
+//!     .await?;
+//!
+//! for row in rows {
+//!     match row {
+//!         Ok(order) => println!("{} x{}", order.customer, order.quantity),
+//!         Err(error) => eprintln!("Skipping {}", error),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::models::Header;
+
+/// A type that can be built from a row of a sheet.
+pub trait FromRow: Sized {
+    /// Builds a value from a row.
+    ///
+    /// # Arguments
+    /// * `row` - The row, with its cells addressable by header name
+    ///
+    /// # Returns
+    /// A `Result` containing the value or a [`RowParseError`].
+    fn from_row(row: &RowView<'_>) -> Result<Self, RowParseError>;
+}
+
+/// The error of a row that could not be converted.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("row {row}, column '{column}': {message}")]
+pub struct RowParseError {
+    /// The 1-based row of the sheet.
+    pub row: usize,
+    /// The name of the offending column.
+    pub column: String,
+    /// The A1 letter of the offending column, if the column exists.
+    pub column_a1: Option<String>,
+    /// The offending cell value, if the cell is not empty.
+    pub value: Option<String>,
+    /// What went wrong.
+    pub message: String,
+}
+
+impl RowParseError {
+    /// Returns the A1 address of the offending cell (e.g., "C5"), if the column
+    /// exists.
+    pub fn cell(&self) -> Option<String> {
+        self.column_a1
+            .as_ref()
+            .map(|column| format!("{}{}", column, self.row))
+    }
+}
+
+/// A row of a sheet, with its cells addressable by header name.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::Header;
+/// use gsheet_api::rows::RowView;
+///
+/// let header = Header::new(1, vec!["Name".to_string(), "Age".to_string()]);
+/// let values = vec!["Ada".to_string(), "thirty".to_string()];
+/// let row = RowView::new(2, &header, &values);
+///
+/// assert_eq!(row.get::<String>("Name").unwrap(), "Ada");
+/// let error = row.get::<u32>("Age").unwrap_err();
+/// assert_eq!(error.cell(), Some("B2".to_string()));
+/// assert_eq!(error.value.as_deref(), Some("thirty"));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RowView<'a> {
+    /// The 1-based row of the sheet.
+    row: usize,
+    /// The header naming the columns.
+    header: &'a Header,
+    /// The values of the row, from the first column of the sheet.
+    values: &'a [String],
+}
+
+impl<'a> RowView<'a> {
+    /// Creates a view over the values of a row.
+    ///
+    /// # Arguments
+    /// * `row` - The 1-based row of the sheet
+    /// * `header` - The header naming the columns
+    /// * `values` - The values of the row, from the first column of the sheet
+    ///
+    /// # Returns
+    /// A new [`RowView`] instance.
+    pub fn new(row: usize, header: &'a Header, values: &'a [String]) -> Self {
+        Self {
+            row,
+            header,
+            values,
+        }
+    }
+
+    /// Returns the 1-based row of the sheet.
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Returns the values of the row, from the first column of the sheet.
+    pub fn values(&self) -> &'a [String] {
+        self.values
+    }
+
+    /// Returns the trimmed value of a cell, or `None` if the column does not
+    /// exist or the cell is empty.
+    ///
+    /// # Arguments
+    /// * `column` - The header name of the column
+    pub fn raw(&self, column: &str) -> Option<&'a str> {
+        let index = self.header.column_index_by_header(column)?;
+        self.values
+            .get(index)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+
+    /// Parses a required cell.
+    ///
+    /// # Arguments
+    /// * `column` - The header name of the column
+    ///
+    /// # Returns
+    /// A `Result` containing the parsed value or a [`RowParseError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the column does not exist, if the cell
+    /// is empty, or if its value cannot be parsed.
+    pub fn get<T>(&self, column: &str) -> Result<T, RowParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.get_opt(column)?
+            .ok_or_else(|| self.error(column, None, "missing value".to_string()))
+    }
+
+    /// Parses an optional cell, an empty cell giving `None`.
+    ///
+    /// # Arguments
+    /// * `column` - The header name of the column
+    ///
+    /// # Returns
+    /// A `Result` containing the parsed value, `None` for an empty cell, or a
+    /// [`RowParseError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the column does not exist or if the
+    /// value of the cell cannot be parsed.
+    pub fn get_opt<T>(&self, column: &str) -> Result<Option<T>, RowParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        if self.header.column_index_by_header(column).is_none() {
+            return Err(self.error(column, None, "no such column".to_string()));
+        }
+        self.raw(column)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|e: T::Err| self.error(column, Some(value), e.to_string()))
+            })
+            .transpose()
+    }
+
+    /// Parses a required cell holding a formatted number into an exact decimal.
+    /// Requires the `decimal` feature.
+    ///
+    /// Unlike [`get`](Self::get), currency symbols, grouping separators and
+    /// percent signs are accepted, following the conventions of the locale.
+    ///
+    /// # Arguments
+    /// * `column` - The header name of the column
+    /// * `locale` - The number conventions of the spreadsheet
+    ///
+    /// # Returns
+    /// A `Result` containing the parsed decimal or a [`RowParseError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the column does not exist, if the cell
+    /// is empty, or if its value is not a number in the locale.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gsheet_api::locale::NumberLocale;
+    /// use gsheet_api::models::Header;
+    /// use gsheet_api::rows::RowView;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let header = Header::new(1, vec!["Amount".to_string()]);
+    /// let values = vec!["$1,234.10".to_string()];
+    /// let row = RowView::new(2, &header, &values);
+    /// let amount = row.get_decimal("Amount", &NumberLocale::default()).unwrap();
+    /// assert_eq!(amount, Decimal::new(123410, 2));
+    /// ```
+    #[cfg(feature = "rust_decimal")]
+    pub fn get_decimal(
+        &self,
+        column: &str,
+        locale: &crate::locale::NumberLocale,
+    ) -> Result<rust_decimal::Decimal, RowParseError> {
+        if self.header.column_index_by_header(column).is_none() {
+            return Err(self.error(column, None, "no such column".to_string()));
+        }
+        let value = self
+            .raw(column)
+            .ok_or_else(|| self.error(column, None, "missing value".to_string()))?;
+        locale
+            .parse_decimal(value)
+            .map_err(|e| self.error(column, Some(value), e.to_string()))
+    }
+
+    /// Builds an error for a cell of the row.
+    ///
+    /// # Arguments
+    /// * `column` - The header name of the column
+    /// * `value` - The offending value, if any
+    /// * `message` - What went wrong
+    ///
+    /// # Returns
+    /// A [`RowParseError`] locating the cell.
+    pub fn error(&self, column: &str, value: Option<&str>, message: String) -> RowParseError {
+        RowParseError {
+            row: self.row,
+            column: column.to_string(),
+            column_a1: self.header.column_a1(column).ok().flatten(),
+            value: value.map(str::to_string),
+            message,
+        }
+    }
+}
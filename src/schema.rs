@@ -0,0 +1,501 @@
+//! # Schema Module
+//!
+//! This module provides [`SheetSchema`], which describes the expected columns of
+//! a sheet and reports the cells that do not conform to it.
+//!
+//! ## Overview
+//!
+//! A schema lists columns by header name, each with a [`ColumnType`] and
+//! optional constraints: a required flag, a regular expression the values must
+//! fully match, or a list of allowed values. Validating a sheet with
+//! [`SheetOperations::validate`](crate::operations::sheet::SheetOperations::validate)
+//! reads it once and returns every [`SchemaViolation`] with the A1 address of
+//! the offending cell, rather than stopping at the first one, so data-intake
+//! sheets filled by hand can be corrected in one pass.
+//!
+//! Cells are validated as displayed, so values such as numbers must be written
+//! without currency symbols or units to match their type.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::schema::{ColumnSchema, ColumnType, SheetSchema};
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! let schema = SheetSchema::builder()
+//!     .column(ColumnSchema::new("Email", ColumnType::Text).required(true).pattern(r"[^@\s]+@[^@\s]+"))
+//!     .column(ColumnSchema::new("Seats", ColumnType::Integer).required(true))
+//!     .column(ColumnSchema::new("Plan", ColumnType::Text).one_of(["Free", "Pro"]))
+//!     .build()?;
+//!
+//! let violations = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .sheet("Signups")
+//!     .validate(&schema)
+//!     .execute()
+//!     .await?;
+//!
+//! for violation in violations {
+//!     println!("{}", violation);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use regex::Regex;
+
+use crate::error::GSheetError;
+use crate::models::Header;
+
+/// The type of the values of a column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnType {
+    /// Any text.
+    Text,
+    /// A whole number, such as "42" or "-7".
+    Integer,
+    /// A number, such as "3.5" or "1,250.75".
+    Number,
+    /// A boolean, "TRUE" or "FALSE" in any case.
+    Boolean,
+    /// A date in the given `chrono` format (e.g., "%Y-%m-%d").
+    Date(String),
+}
+
+impl ColumnType {
+    /// Returns whether a non-empty value has this type.
+    fn accepts(&self, value: &str) -> bool {
+        match self {
+            ColumnType::Text => true,
+            ColumnType::Integer => value.replace(',', "").parse::<i64>().is_ok(),
+            ColumnType::Number => value.replace(',', "").parse::<f64>().is_ok(),
+            ColumnType::Boolean => {
+                value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false")
+            }
+            ColumnType::Date(format) => chrono::NaiveDate::parse_from_str(value, format).is_ok(),
+        }
+    }
+}
+
+impl fmt::Display for ColumnType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnType::Text => write!(f, "text"),
+            ColumnType::Integer => write!(f, "integer"),
+            ColumnType::Number => write!(f, "number"),
+            ColumnType::Boolean => write!(f, "boolean"),
+            ColumnType::Date(format) => write!(f, "date ({})", format),
+        }
+    }
+}
+
+/// The expected content of a column.
+#[derive(Debug, Clone)]
+pub struct ColumnSchema {
+    /// The header name of the column.
+    name: String,
+    /// The type of the values.
+    column_type: ColumnType,
+    /// Whether every data row must have a value.
+    required: bool,
+    /// The regular expression values must fully match, if any.
+    pattern: Option<String>,
+    /// The allowed values, if restricted.
+    allowed: Option<Vec<String>>,
+}
+
+impl ColumnSchema {
+    /// Creates an optional column without constraints.
+    ///
+    /// # Arguments
+    /// * `name` - The header name of the column
+    /// * `column_type` - The type of the values
+    ///
+    /// # Returns
+    /// A new [`ColumnSchema`] instance.
+    pub fn new(name: &str, column_type: ColumnType) -> Self {
+        Self {
+            name: name.to_string(),
+            column_type,
+            required: false,
+            pattern: None,
+            allowed: None,
+        }
+    }
+
+    /// Sets whether every data row must have a value in the column.
+    ///
+    /// # Arguments
+    /// * `required` - Whether empty cells are violations
+    ///
+    /// # Returns
+    /// The column schema for method chaining.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Sets a regular expression values must fully match.
+    ///
+    /// # Arguments
+    /// * `pattern` - The regular expression, compiled when the schema is built
+    ///
+    /// # Returns
+    /// The column schema for method chaining.
+    pub fn pattern(mut self, pattern: &str) -> Self {
+        self.pattern = Some(pattern.to_string());
+        self
+    }
+
+    /// Restricts the values to a list, compared case-sensitively.
+    ///
+    /// # Arguments
+    /// * `values` - The allowed values
+    ///
+    /// # Returns
+    /// The column schema for method chaining.
+    pub fn one_of<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+/// Builder for configuring a [`SheetSchema`].
+#[derive(Debug, Clone)]
+pub struct SheetSchemaBuilder {
+    /// The expected columns.
+    columns: Vec<ColumnSchema>,
+    /// The 1-based row of the header.
+    header_row: usize,
+    /// Whether columns that are not in the schema are allowed.
+    allow_extra_columns: bool,
+}
+
+impl Default for SheetSchemaBuilder {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+            header_row: 1,
+            allow_extra_columns: true,
+        }
+    }
+}
+
+impl SheetSchemaBuilder {
+    /// Adds an expected column.
+    ///
+    /// # Arguments
+    /// * `column` - The column schema
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn column(mut self, column: ColumnSchema) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Sets the 1-based row of the header, the first row by default.
+    ///
+    /// # Arguments
+    /// * `row` - The header row
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn header_row(mut self, row: usize) -> Self {
+        self.header_row = row;
+        self
+    }
+
+    /// Sets whether the sheet may have columns that are not in the schema,
+    /// which is the default.
+    ///
+    /// # Arguments
+    /// * `allow` - Whether extra columns are allowed
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn allow_extra_columns(mut self, allow: bool) -> Self {
+        self.allow_extra_columns = allow;
+        self
+    }
+
+    /// Builds the [`SheetSchema`], compiling the patterns of its columns.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`SheetSchema`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return a [`GSheetError::ValidationError`] if the header
+    /// row is 0, if two columns share a name, or if a pattern is invalid.
+    pub fn build(self) -> Result<SheetSchema, GSheetError> {
+        if self.header_row == 0 {
+            return Err(GSheetError::ValidationError(
+                "Header row must be 1 or greater".into(),
+            ));
+        }
+
+        let mut columns: Vec<CompiledColumn> = Vec::with_capacity(self.columns.len());
+        for column in self.columns {
+            if columns
+                .iter()
+                .any(|compiled| compiled.schema.name.trim() == column.name.trim())
+            {
+                return Err(GSheetError::ValidationError(format!(
+                    "Duplicate schema column '{}'",
+                    column.name
+                )));
+            }
+            let pattern = column
+                .pattern
+                .as_deref()
+                .map(|pattern| Regex::new(&format!("^(?:{})$", pattern)))
+                .transpose()
+                .map_err(|e| {
+                    GSheetError::ValidationError(format!(
+                        "Invalid pattern for column '{}': {}",
+                        column.name, e
+                    ))
+                })?;
+            columns.push(CompiledColumn {
+                schema: column,
+                pattern,
+            });
+        }
+
+        Ok(SheetSchema {
+            columns,
+            header_row: self.header_row,
+            allow_extra_columns: self.allow_extra_columns,
+        })
+    }
+}
+
+/// A column schema with its compiled pattern.
+#[derive(Debug, Clone)]
+struct CompiledColumn {
+    /// The column schema.
+    schema: ColumnSchema,
+    /// The compiled pattern, anchored to match whole values.
+    pattern: Option<Regex>,
+}
+
+/// The expected columns of a sheet.
+#[derive(Debug, Clone)]
+pub struct SheetSchema {
+    /// The expected columns.
+    columns: Vec<CompiledColumn>,
+    /// The 1-based row of the header.
+    header_row: usize,
+    /// Whether columns that are not in the schema are allowed.
+    allow_extra_columns: bool,
+}
+
+impl SheetSchema {
+    /// Creates a builder for a schema.
+    ///
+    /// # Returns
+    /// A new [`SheetSchemaBuilder`] instance.
+    pub fn builder() -> SheetSchemaBuilder {
+        SheetSchemaBuilder::default()
+    }
+
+    /// Returns the 1-based row of the header.
+    pub fn header_row(&self) -> usize {
+        self.header_row
+    }
+
+    /// Validates the values of a sheet, read from its first row.
+    ///
+    /// Rows whose cells are all empty are skipped.
+    ///
+    /// # Arguments
+    /// * `rows` - The values of the sheet, from the first row and column
+    ///
+    /// # Returns
+    /// The violations, header violations first, then by row and schema column.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gsheet_api::schema::{ColumnSchema, ColumnType, SheetSchema, ViolationKind};
+    ///
+    /// let schema = SheetSchema::builder()
+    ///     .column(ColumnSchema::new("Name", ColumnType::Text).required(true))
+    ///     .column(ColumnSchema::new("Age", ColumnType::Integer))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let rows = vec![
+    ///     vec!["Name".to_string(), "Age".to_string()],
+    ///     vec!["Ada".to_string(), "36".to_string()],
+    ///     vec!["".to_string(), "thirty".to_string()],
+    /// ];
+    /// let violations = schema.validate_rows(&rows);
+    /// assert_eq!(violations.len(), 2);
+    /// assert_eq!(violations[0].cell.as_deref(), Some("A3"));
+    /// assert_eq!(violations[0].kind, ViolationKind::Missing);
+    /// assert_eq!(violations[1].cell.as_deref(), Some("B3"));
+    /// ```
+    pub fn validate_rows(&self, rows: &[Vec<String>]) -> Vec<SchemaViolation> {
+        let header = Header::new(
+            self.header_row,
+            rows.get(self.header_row - 1).cloned().unwrap_or_default(),
+        );
+        let mut violations = Vec::new();
+
+        let mut located = Vec::with_capacity(self.columns.len());
+        for column in &self.columns {
+            let name = &column.schema.name;
+            match header.column_index_by_header(name) {
+                Some(index) => located.push((column, index, header.column_a1(name).ok().flatten())),
+                None => violations.push(SchemaViolation {
+                    row: self.header_row,
+                    column: name.clone(),
+                    cell: None,
+                    value: None,
+                    kind: ViolationKind::MissingColumn,
+                }),
+            }
+        }
+        if !self.allow_extra_columns {
+            for (index, name) in header.columns.iter().enumerate() {
+                let name = name.trim();
+                if name.is_empty() || located.iter().any(|(_, located, _)| *located == index) {
+                    continue;
+                }
+                violations.push(SchemaViolation {
+                    row: self.header_row,
+                    column: name.to_string(),
+                    cell: header
+                        .column_a1(name)
+                        .ok()
+                        .flatten()
+                        .map(|column| format!("{}{}", column, self.header_row)),
+                    value: Some(name.to_string()),
+                    kind: ViolationKind::UnexpectedColumn,
+                });
+            }
+        }
+
+        for (index, values) in rows.iter().enumerate().skip(self.header_row) {
+            if values.iter().all(|value| value.trim().is_empty()) {
+                continue;
+            }
+            let row = index + 1;
+            for (column, col_index, column_a1) in &located {
+                let value = values
+                    .get(*col_index)
+                    .map(|value| value.trim())
+                    .unwrap_or_default();
+                if let Some(kind) = column.check(value) {
+                    violations.push(SchemaViolation {
+                        row,
+                        column: column.schema.name.clone(),
+                        cell: column_a1
+                            .as_ref()
+                            .map(|column| format!("{}{}", column, row)),
+                        value: Some(value.to_string()).filter(|value| !value.is_empty()),
+                        kind,
+                    });
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl CompiledColumn {
+    /// Returns the violation of a trimmed cell value, if any.
+    fn check(&self, value: &str) -> Option<ViolationKind> {
+        if value.is_empty() {
+            return self.schema.required.then_some(ViolationKind::Missing);
+        }
+        if !self.schema.column_type.accepts(value) {
+            return Some(ViolationKind::InvalidType(self.schema.column_type.clone()));
+        }
+        if let Some(pattern) = &self.pattern
+            && !pattern.is_match(value)
+        {
+            return Some(ViolationKind::PatternMismatch(
+                self.schema.pattern.clone().unwrap_or_default(),
+            ));
+        }
+        if let Some(allowed) = &self.schema.allowed
+            && !allowed.iter().any(|allowed| allowed == value)
+        {
+            return Some(ViolationKind::NotAllowed);
+        }
+        None
+    }
+}
+
+/// What is wrong with a cell or column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// A schema column is missing from the header.
+    MissingColumn,
+    /// The header has a column that is not in the schema.
+    UnexpectedColumn,
+    /// A required cell is empty.
+    Missing,
+    /// The value does not have the type of its column.
+    InvalidType(ColumnType),
+    /// The value does not match the pattern of its column.
+    PatternMismatch(String),
+    /// The value is not one of the allowed values of its column.
+    NotAllowed,
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViolationKind::MissingColumn => write!(f, "column is missing"),
+            ViolationKind::UnexpectedColumn => write!(f, "column is not in the schema"),
+            ViolationKind::Missing => write!(f, "value is required"),
+            ViolationKind::InvalidType(column_type) => write!(f, "expected {}", column_type),
+            ViolationKind::PatternMismatch(pattern) => {
+                write!(f, "does not match pattern '{}'", pattern)
+            }
+            ViolationKind::NotAllowed => write!(f, "value is not allowed"),
+        }
+    }
+}
+
+/// A cell or column that does not conform to a [`SheetSchema`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// The 1-based row, which is the header row for column violations.
+    pub row: usize,
+    /// The header name of the column.
+    pub column: String,
+    /// The A1 address of the offending cell (e.g., "C5"), absent for missing
+    /// columns.
+    pub cell: Option<String>,
+    /// The offending value, absent for empty cells.
+    pub value: Option<String>,
+    /// What is wrong.
+    pub kind: ViolationKind,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.cell {
+            Some(cell) => write!(f, "{} ({}): {}", cell, self.column, self.kind)?,
+            None => write!(f, "row {} ({}): {}", self.row, self.column, self.kind)?,
+        }
+        if let Some(value) = &self.value {
+            write!(f, ", got '{}'", value)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,350 @@
+//! # Template Module
+//!
+//! This module provides report generation from template spreadsheets.
+//!
+//! ## Overview
+//!
+//! A template is an ordinary spreadsheet holding `{{placeholders}}` in its cells,
+//! for example `Sales report for {{client}}`. Instantiating it copies the
+//! spreadsheet through the Drive API, then replaces every placeholder of the copy
+//! with its value in a single batch update covering all sheets. The template
+//! itself is never modified.
+//!
+//! Placeholders are matched case-sensitively, including inside formulas.
+//! Placeholders without a value are left untouched.
+//!
+//! ## Requirements
+//!
+//! Copying a file needs a Drive scope, such as
+//! [`DRIVE_SCOPE`](crate::auth::service_account::DRIVE_SCOPE), and the service
+//! account must be able to read the template.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! let report = gsheet_client
+//!     .instantiate_template(
+//!         "template-id",
+//!         "Sales report - ACME",
+//!         [("client", "ACME"), ("quarter", "Q3 2026")],
+//!     )
+//!     .parent_folder("reports-folder-id")
+//!     .build()?
+//!     .execute()
+//!     .await?;
+//!
+//! let values = report.spreadsheet.sheet("Summary").get_all_value().execute().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::client::GoogleSheetClient;
+use crate::client::url::QueryParams;
+use crate::drive::DriveFile;
+use crate::error::{GSheetError, decode_json};
+use crate::models::{FindReplaceRequest, Request, Response};
+use crate::operations::spreadsheet::SpreadsheetOperations;
+
+/// Formats a placeholder name as it appears in a template (e.g., "{{client}}").
+///
+/// # Arguments
+/// * `name` - The name of the placeholder
+///
+/// # Returns
+/// The placeholder text.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::template::placeholder;
+///
+/// assert_eq!(placeholder("client"), "{{client}}");
+/// ```
+pub fn placeholder(name: &str) -> String {
+    format!("{{{{{}}}}}", name)
+}
+
+/// Builder for configuring template instantiation.
+#[derive(Default)]
+pub struct InstantiateTemplateBuilder {
+    /// The Google Sheets client.
+    gsheet_client: Option<GoogleSheetClient>,
+    /// The ID of the template spreadsheet.
+    template_id: String,
+    /// The title of the new spreadsheet.
+    title: String,
+    /// The placeholder values, by placeholder name.
+    vars: Vec<(String, String)>,
+    /// The Drive folders holding the new spreadsheet.
+    parents: Vec<String>,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl InstantiateTemplateBuilder {
+    /// Creates a new builder for the specified template.
+    ///
+    /// # Arguments
+    /// * `gsheet_client` - The Google Sheets client
+    /// * `template_id` - The ID of the template spreadsheet
+    /// * `title` - The title of the new spreadsheet
+    ///
+    /// # Returns
+    /// A new [`InstantiateTemplateBuilder`] instance.
+    pub fn new(gsheet_client: &GoogleSheetClient, template_id: &str, title: &str) -> Self {
+        Self {
+            gsheet_client: Some(gsheet_client.clone()),
+            template_id: template_id.to_string(),
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the value of a placeholder.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the placeholder, without braces (e.g., "client")
+    /// * `value` - The text replacing the placeholder
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn var(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.vars.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the values of several placeholders.
+    ///
+    /// # Arguments
+    /// * `vars` - The placeholder values, by placeholder name
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn vars<K, V>(mut self, vars: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.vars.extend(
+            vars.into_iter()
+                .map(|(name, value)| (name.into(), value.into())),
+        );
+        self
+    }
+
+    /// Adds a Drive folder holding the new spreadsheet.
+    ///
+    /// Without a folder, Drive places the copy next to the template.
+    ///
+    /// # Arguments
+    /// * `folder_id` - The ID of the Drive folder
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn parent_folder(mut self, folder_id: &str) -> Self {
+        self.parents.push(folder_id.to_string());
+        self
+    }
+
+    /// Sets the timeout of each request, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of a single request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`InstantiateTemplateOperations`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`InstantiateTemplateOperations`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - The client is not set
+    /// - The template ID or the title is empty
+    /// - A placeholder name is empty or set more than once
+    pub fn build(self) -> Result<InstantiateTemplateOperations, GSheetError> {
+        let gsheet_client = self.gsheet_client.ok_or_else(|| {
+            GSheetError::Other(
+                "GoogleSheetClient is required to build InstantiateTemplateOperations".into(),
+            )
+        })?;
+        if self.template_id.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "Template ID must not be empty".into(),
+            ));
+        }
+        if self.title.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "Spreadsheet title must not be empty".into(),
+            ));
+        }
+        for (index, (name, _)) in self.vars.iter().enumerate() {
+            if name.is_empty() {
+                return Err(GSheetError::ValidationError(
+                    "Placeholder names must not be empty".into(),
+                ));
+            }
+            if self.vars[..index].iter().any(|(other, _)| other == name) {
+                return Err(GSheetError::ValidationError(format!(
+                    "Placeholder {} is set more than once",
+                    placeholder(name)
+                )));
+            }
+        }
+
+        Ok(InstantiateTemplateOperations {
+            gsheet_client,
+            template_id: self.template_id,
+            title: self.title,
+            vars: self.vars,
+            parents: self.parents,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Operation copying a template spreadsheet and filling in its placeholders.
+pub struct InstantiateTemplateOperations {
+    /// The Google Sheets client.
+    gsheet_client: GoogleSheetClient,
+    /// The ID of the template spreadsheet.
+    template_id: String,
+    /// The title of the new spreadsheet.
+    title: String,
+    /// The placeholder values, by placeholder name.
+    vars: Vec<(String, String)>,
+    /// The Drive folders holding the new spreadsheet.
+    parents: Vec<String>,
+    /// Timeout override for this operation.
+    timeout: Option<Duration>,
+}
+
+impl InstantiateTemplateOperations {
+    /// Executes the instantiation.
+    ///
+    /// If filling in the placeholders fails, the copy is left in Drive and the
+    /// error is returned; it can be deleted or retried by the caller.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`TemplateInstance`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - Authentication fails
+    /// - The template does not exist or cannot be read
+    /// - The HTTP request fails
+    /// - The response cannot be parsed
+    pub async fn execute(&self) -> Result<TemplateInstance, GSheetError> {
+        let spreadsheet_id = self.copy().await?;
+        let spreadsheet = self.gsheet_client.spreadsheet(&spreadsheet_id);
+
+        let mut occurrences = HashMap::new();
+        if !self.vars.is_empty() {
+            let mut batch =
+                spreadsheet
+                    .batch_update()
+                    .requests(self.vars.iter().map(|(name, value)| {
+                        Request::FindReplace(FindReplaceRequest {
+                            find: placeholder(name),
+                            replacement: value.clone(),
+                            match_case: Some(true),
+                            include_formulas: Some(true),
+                            all_sheets: Some(true),
+                            ..Default::default()
+                        })
+                    }));
+            if let Some(timeout) = self.timeout {
+                batch = batch.timeout(timeout);
+            }
+            let response = batch.build()?.execute().await?;
+
+            for ((name, _), reply) in self.vars.iter().zip(response.replies) {
+                let changed = match reply {
+                    Response::FindReplace(reply) => reply.occurrences_changed.unwrap_or(0),
+                    _ => 0,
+                };
+                occurrences.insert(name.clone(), changed.max(0) as usize);
+            }
+        }
+
+        Ok(TemplateInstance {
+            spreadsheet_id,
+            spreadsheet,
+            occurrences,
+        })
+    }
+
+    /// Copies the template through the Drive API.
+    ///
+    /// # Returns
+    /// A `Result` containing the ID of the copy or a [`GSheetError`].
+    async fn copy(&self) -> Result<String, GSheetError> {
+        let url = self
+            .gsheet_client
+            .drive_file_url(&self.template_id, "/copy");
+
+        let mut body = serde_json::json!({ "name": self.title });
+        if !self.parents.is_empty() {
+            body["parents"] = serde_json::json!(self.parents);
+        }
+
+        let query = QueryParams::new()
+            .flag("supportsAllDrives", true)
+            .fields("id");
+        let request = self
+            .gsheet_client
+            .request(reqwest::Method::POST, &query.url(&url), self.timeout)
+            .await?
+            .json(&body);
+        let response = self.gsheet_client.send(request).await?;
+
+        if response.status().is_success() {
+            let file: DriveFile = decode_json(response).await?;
+            Ok(file.id)
+        } else {
+            Err(GSheetError::from_response(response, &self.template_id).await)
+        }
+    }
+}
+
+/// A spreadsheet created from a template.
+#[derive(Clone)]
+pub struct TemplateInstance {
+    /// The ID of the new spreadsheet.
+    pub spreadsheet_id: String,
+    /// Operations on the new spreadsheet.
+    pub spreadsheet: SpreadsheetOperations,
+    /// The number of replaced occurrences, by placeholder name.
+    pub occurrences: HashMap<String, usize>,
+}
+
+impl TemplateInstance {
+    /// Returns the placeholders that were not found in the template.
+    ///
+    /// # Returns
+    /// The names of the placeholders with no replaced occurrence.
+    pub fn unused_placeholders(&self) -> Vec<&str> {
+        let mut unused: Vec<&str> = self
+            .occurrences
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        unused.sort_unstable();
+        unused
+    }
+}
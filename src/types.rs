@@ -0,0 +1,51 @@
+//! # Types Module
+//!
+//! This module contains client-side option types that configure how operations
+//! behave, as opposed to the [`models`](crate::models) that mirror the API.
+
+use crate::models::{DateTimeRenderOption, ValueInputOption, ValueRenderOption};
+
+/// Policy applied when the shape of written values disagrees with the target range.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RangeFit {
+    /// Fail with a validation error when the values overflow the range. Values
+    /// smaller than the range are written as is, and the remaining cells are left
+    /// untouched by the API.
+    #[default]
+    Reject,
+    /// Fail with a validation error unless the values exactly fill the bounded
+    /// sides of the range.
+    Exact,
+    /// Resize the range from its start cell to the shape of the values, growing
+    /// or shrinking it as needed.
+    Expand,
+    /// Drop the values that fall outside the range. Values smaller than the range
+    /// are written as is.
+    Truncate,
+}
+
+/// Policy applied when a sheet is added or renamed to a title that is taken.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TitleCollision {
+    /// Fail with [`GSheetError::SheetAlreadyExists`](crate::error::GSheetError::SheetAlreadyExists).
+    #[default]
+    Fail,
+    /// Use the first free title with a numeric suffix, such as "Report (2)".
+    Deduplicate,
+}
+
+/// Default value options of the operations of a spreadsheet.
+///
+/// Operations created from a [`SpreadsheetOperations`](crate::operations::spreadsheet::SpreadsheetOperations)
+/// or [`SheetOperations`](crate::operations::sheet::SheetOperations) start from
+/// these options, and their own setters override them. Writes render the values
+/// they return with the read options.
+#[derive(Debug, Clone, Default)]
+pub struct ValueOptions {
+    /// How read values are rendered.
+    pub value_render_option: ValueRenderOption,
+    /// How read dates and times are rendered.
+    pub date_time_render_option: DateTimeRenderOption,
+    /// How written values are interpreted.
+    pub value_input_option: ValueInputOption,
+}
@@ -29,16 +29,24 @@
 //! representations used by the Google Sheets API, such as converting ValueRange
 //! responses to Cell structures or HashMap representations.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::error::GSheetError;
-use crate::models::{Cell, GridRange, ValueRange};
+use crate::metadata::SheetIdResolver;
+use crate::models::{Cell, CellAddress, GridRange, ValueRange};
+
+/// The largest column index supported in A1 notation, column "ZZZ".
+pub const MAX_COLUMN: usize = 18_278;
+
+/// The largest row number that fits the `i32` indexes used by the API.
+pub const MAX_ROW: usize = i32::MAX as usize;
 
 /// Parses an A1 notation cell reference into column and row indices.
 ///
 /// This function converts a cell reference like "A1", "B2", "AA10" into
-/// zero-based column and row indices. Column letters are converted to numbers
-/// where A=1, B=2, ..., Z=26, AA=27, etc.
+/// 1-based column and row indices. Column letters are converted to numbers
+/// where A=1, B=2, ..., Z=26, AA=27, etc. Column letters are case-insensitive
+/// but must not mix cases, and absolute references such as "$B$3" are accepted.
 ///
 /// # Arguments
 /// * `a1` - The A1 notation cell reference (e.g., "A1", "B2", "AA10")
@@ -57,40 +65,72 @@ use crate::models::{Cell, GridRange, ValueRange};
 /// let (col, row) = parse_a1_cell("B3").unwrap();
 /// assert_eq!(col, 2);
 /// assert_eq!(row, 3);
+///
+/// assert_eq!(parse_a1_cell("$ZZZ$10").unwrap(), (18_278, 10));
+/// assert!(parse_a1_cell("AAAA1").is_err());
+/// assert!(parse_a1_cell("A99999999999999999999").is_err());
 /// ```
 ///
 /// # Errors
-/// This function will return an error if:
+/// This function will return an error, naming the offending input, if:
 /// - The input contains invalid characters
-/// - The cell reference is malformed
-/// - Column or row indices are zero or negative
+/// - The column letters or the row number are missing
+/// - The column letters mix upper and lower case
+/// - The column is beyond [`MAX_COLUMN`] or the row is zero or beyond [`MAX_ROW`]
 pub fn parse_a1_cell(a1: &str) -> Result<(usize, usize), GSheetError> {
-    let mut col = 0;
-    let mut row = 0;
-    let mut col_part = true;
-
-    for c in a1.chars() {
-        if c.is_ascii_alphabetic() && col_part {
-            col = col * 26 + (c.to_ascii_uppercase() as usize - 'A' as usize + 1);
-        } else if c.is_ascii_digit() {
-            col_part = false;
-            row = row * 10 + (c as usize - '0' as usize);
-        } else {
-            return Err(GSheetError::UtilsError("Invalid character".into()));
-        }
+    let invalid =
+        |reason: String| GSheetError::UtilsError(format!("Invalid A1 cell '{a1}': {reason}"));
+
+    let rest = a1.strip_prefix('$').unwrap_or(a1);
+    let letters_len = rest
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(rest.len());
+    let (letters, rest) = rest.split_at(letters_len);
+    let digits = rest.strip_prefix('$').unwrap_or(rest);
+
+    if letters.is_empty() {
+        return Err(invalid("missing column letters".into()));
+    }
+    if letters.chars().any(|c| c.is_ascii_uppercase())
+        && letters.chars().any(|c| c.is_ascii_lowercase())
+    {
+        return Err(invalid(format!("mixed-case column '{letters}'")));
+    }
+    if digits.is_empty() {
+        return Err(invalid("missing row number".into()));
+    }
+    if let Some(c) = digits.chars().find(|c| !c.is_ascii_digit()) {
+        return Err(invalid(format!("unexpected character '{c}'")));
     }
 
-    if col > 0 && row > 0 {
-        Ok((col, row))
-    } else {
-        Err(GSheetError::UtilsError("Invalid A1 notation".into()))
+    let col = letters
+        .chars()
+        .try_fold(0usize, |col, c| {
+            col.checked_mul(26)?
+                .checked_add(c.to_ascii_uppercase() as usize - 'A' as usize + 1)
+        })
+        .filter(|col| *col <= MAX_COLUMN)
+        .ok_or_else(|| invalid(format!("column '{letters}' is beyond ZZZ")))?;
+
+    let row = digits
+        .chars()
+        .try_fold(0usize, |row, c| {
+            row.checked_mul(10)?.checked_add(c as usize - '0' as usize)
+        })
+        .filter(|row| *row <= MAX_ROW)
+        .ok_or_else(|| invalid(format!("row '{digits}' is too large")))?;
+    if row == 0 {
+        return Err(invalid("rows start at 1".into()));
     }
+
+    Ok((col, row))
 }
 
 /// Converts an A1 notation range to a GridRange structure.
 ///
 /// This function parses A1 notation ranges like "A1:B10" or "Sheet1!A1:B10"
-/// and converts them to the internal GridRange representation used by the API.
+/// and converts them to the GridRange representation used by the API, with
+/// zero-based, half-open indexes. The sheet ID is left unset.
 ///
 /// # Arguments
 /// * `a1` - The A1 notation range (e.g., "A1:B10", "Sheet1!A1:Z100")
@@ -103,9 +143,9 @@ pub fn parse_a1_cell(a1: &str) -> Result<(usize, usize), GSheetError> {
 /// use gsheet_api::utils::a1_to_grid_range;
 ///
 /// let range = a1_to_grid_range("A1:B10").unwrap();
-/// assert_eq!(range.start_row_index, Some(1));
+/// assert_eq!(range.start_row_index, Some(0));
 /// assert_eq!(range.end_row_index, Some(10));
-/// assert_eq!(range.start_column_index, Some(1));
+/// assert_eq!(range.start_column_index, Some(0));
 /// assert_eq!(range.end_column_index, Some(2));
 /// ```
 ///
@@ -115,9 +155,25 @@ pub fn parse_a1_cell(a1: &str) -> Result<(usize, usize), GSheetError> {
 /// - The cell references are malformed
 /// - Sheet name parsing fails (if present)
 pub fn a1_to_grid_range(a1: &str) -> Result<GridRange, GSheetError> {
+    let ((start_col, start_row), (end_col, end_row)) = a1_range_bounds(a1)?;
+
+    Ok(GridRange {
+        sheet_id: None,
+        start_row_index: Some(start_row as i32 - 1),
+        end_row_index: Some(end_row as i32),
+        start_column_index: Some(start_col as i32 - 1),
+        end_column_index: Some(end_col as i32),
+    })
+}
+
+/// The 1-based `(column, row)` start and end cells of a range, both inclusive.
+type A1Bounds = ((usize, usize), (usize, usize));
+
+/// Parses an A1 notation range into its start and end cells.
+fn a1_range_bounds(a1: &str) -> Result<A1Bounds, GSheetError> {
     let mut range_part: &str = a1.trim();
 
-    if range_part.contains("!") == true {
+    if range_part.contains("!") {
         let (_, range_part_str) = split_sheet_range(range_part)?;
         range_part = range_part_str;
     }
@@ -130,22 +186,14 @@ pub fn a1_to_grid_range(a1: &str) -> Result<GridRange, GSheetError> {
         _ => return Err(GSheetError::UtilsError("Invalid range".into())),
     };
 
-    let (start_col, start_row) = parse_a1_cell(start)?;
-    let (end_col, end_row) = parse_a1_cell(end)?;
-
-    Ok(GridRange {
-        sheet_id: None,
-        start_row_index: start_row,
-        end_row_index: end_row,
-        start_column_index: start_col,
-        end_column_index: end_col,
-    })
+    Ok((parse_a1_cell(start)?, parse_a1_cell(end)?))
 }
 
 /// Splits a sheet-qualified range into sheet name and range components.
 ///
 /// This function takes a range like "Sheet1!A1:B10" and splits it into
-/// the sheet name ("Sheet1") and the range part ("A1:B10").
+/// the sheet name ("Sheet1") and the range part ("A1:B10"). The sheet name is
+/// returned as written, quotes included; see [`unquote_sheet_title`].
 ///
 /// # Arguments
 /// * `a1` - The sheet-qualified A1 notation range
@@ -156,18 +204,208 @@ pub fn a1_to_grid_range(a1: &str) -> Result<GridRange, GSheetError> {
 /// # Errors
 /// This function will return an error if the range doesn't contain a "!" separator.
 pub fn split_sheet_range(a1: &str) -> Result<(&str, &str), GSheetError> {
-    let range_part: &str = a1.trim();
+    // Sheet titles may contain '!', cell references never do.
+    a1.trim()
+        .rsplit_once('!')
+        .ok_or_else(|| GSheetError::UtilsError("Invalid range".into()))
+}
 
-    if range_part.contains("!") == true {
-        let parts: Vec<&str> = range_part.split('!').collect();
-        if parts.len() != 2 {
-            return Err(GSheetError::UtilsError("Invalid range".into()));
-        }
+/// Quotes a sheet title for use in A1 notation, if needed.
+///
+/// Titles made of letters, digits and underscores are returned as is, unless
+/// they could be mistaken for a cell reference. Other titles are enclosed in
+/// single quotes, with inner single quotes doubled.
+///
+/// # Arguments
+/// * `title` - The title of the sheet
+///
+/// # Returns
+/// The title, quoted if needed.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::utils::quote_sheet_title;
+///
+/// assert_eq!(quote_sheet_title("Sheet1"), "Sheet1");
+/// assert_eq!(quote_sheet_title("Q1 Sales"), "'Q1 Sales'");
+/// assert_eq!(quote_sheet_title("Bob's"), "'Bob''s'");
+/// assert_eq!(quote_sheet_title("AB12"), "'AB12'");
+/// ```
+pub fn quote_sheet_title(title: &str) -> String {
+    // Up to three column letters followed by a row number, such as "AB12".
+    let letters = title.chars().take_while(char::is_ascii_alphabetic).count();
+    let looks_like_cell = (1..=3).contains(&letters)
+        && title.len() > letters
+        && title[letters..].chars().all(|c| c.is_ascii_digit());
+
+    let plain = !title.is_empty()
+        && title.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !title.starts_with(|c: char| c.is_ascii_digit())
+        && !looks_like_cell;
 
-        return Ok((parts[0], parts[1]));
+    if plain {
+        title.to_string()
+    } else {
+        format!("'{}'", title.replace('\'', "''"))
     }
+}
 
-    Err(GSheetError::UtilsError("Invalid range".into()))
+/// Removes the quotes added by [`quote_sheet_title`].
+///
+/// # Arguments
+/// * `title` - The sheet title as written in A1 notation
+///
+/// # Returns
+/// The title of the sheet.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::utils::unquote_sheet_title;
+///
+/// assert_eq!(unquote_sheet_title("'Bob''s'"), "Bob's");
+/// assert_eq!(unquote_sheet_title("Sheet1"), "Sheet1");
+/// ```
+pub fn unquote_sheet_title(title: &str) -> String {
+    match title
+        .strip_prefix('\'')
+        .and_then(|title| title.strip_suffix('\''))
+    {
+        Some(inner) => inner.replace("''", "'"),
+        None => title.to_string(),
+    }
+}
+
+/// Converts a GridRange to A1 notation, prefixed with the sheet title.
+///
+/// Unset indexes are unbounded: a range without row indexes spans whole
+/// columns ("A:C"), a range without column indexes spans whole rows ("2:5"),
+/// and a range without any index spans the whole sheet. The sheet ID of the
+/// range is ignored.
+///
+/// # Arguments
+/// * `range` - The range, with zero-based, half-open indexes
+/// * `sheet_title` - The title of the sheet the range is on
+///
+/// # Returns
+/// A `Result` containing the A1 notation range or a [`GSheetError`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::GridRange;
+/// use gsheet_api::utils::{a1_to_grid_range, grid_range_to_a1};
+///
+/// let range = a1_to_grid_range("B2:D10").unwrap();
+/// assert_eq!(grid_range_to_a1(&range, "Q1 Sales").unwrap(), "'Q1 Sales'!B2:D10");
+///
+/// let columns = GridRange {
+///     start_column_index: Some(0),
+///     end_column_index: Some(3),
+///     ..Default::default()
+/// };
+/// assert_eq!(grid_range_to_a1(&columns, "Sheet1").unwrap(), "Sheet1!A:C");
+/// ```
+///
+/// # Errors
+/// This function will return an error if an index is negative, if an end index
+/// is not after its start index, or if the range cannot be expressed in A1
+/// notation (e.g., a start row without an end on a bounded column span).
+pub fn grid_range_to_a1(range: &GridRange, sheet_title: &str) -> Result<String, GSheetError> {
+    let invalid = || GSheetError::UtilsError(format!("Cannot express {range:?} in A1 notation"));
+    let index = |value: Option<i32>| -> Result<Option<usize>, GSheetError> {
+        value
+            .map(|value| usize::try_from(value).map_err(|_| invalid()))
+            .transpose()
+    };
+
+    let start_row = index(range.start_row_index)?;
+    let end_row = index(range.end_row_index)?;
+    let start_col = index(range.start_column_index)?;
+    let end_col = index(range.end_column_index)?;
+
+    if matches!((start_row, end_row), (Some(start), Some(end)) if end <= start)
+        || matches!((start_col, end_col), (Some(start), Some(end)) if end <= start)
+    {
+        return Err(invalid());
+    }
+
+    let title = quote_sheet_title(sheet_title);
+    let rows_bounded = start_row.is_some() || end_row.is_some();
+    let cols_bounded = start_col.is_some() || end_col.is_some();
+
+    let cells = match (rows_bounded, cols_bounded) {
+        (false, false) => return Ok(title),
+        (true, false) => {
+            let end = end_row.ok_or_else(invalid)?;
+            format!("{}:{}", start_row.unwrap_or_default() + 1, end)
+        }
+        (false, true) => {
+            let end = end_col.ok_or_else(invalid)?;
+            format!(
+                "{}:{}",
+                col_index_to_a1(start_col.unwrap_or_default() + 1)?,
+                col_index_to_a1(end)?
+            )
+        }
+        (true, true) => {
+            let start = format!(
+                "{}{}",
+                col_index_to_a1(start_col.unwrap_or_default() + 1)?,
+                start_row.unwrap_or_default() + 1
+            );
+            let end_col = col_index_to_a1(end_col.ok_or_else(invalid)?)?;
+            match end_row {
+                Some(end_row) => {
+                    let end = format!("{end_col}{end_row}");
+                    if start == end {
+                        start
+                    } else {
+                        format!("{start}:{end}")
+                    }
+                }
+                // An open-ended row span, such as "A2:C".
+                None => format!("{start}:{end_col}"),
+            }
+        }
+    };
+
+    Ok(format!("{title}!{cells}"))
+}
+
+/// Converts a sheet-qualified A1 notation range to a GridRange with its sheet ID.
+///
+/// # Arguments
+/// * `a1` - The A1 notation range, including the sheet title (e.g., "'Q1 Sales'!A1:B10")
+/// * `resolver` - The resolver mapping sheet titles to sheet IDs
+///
+/// # Returns
+/// A `Result` containing a [`GridRange`] with `sheet_id` set, or a [`GSheetError`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::metadata::SheetIdResolver;
+/// use gsheet_api::utils::a1_to_grid_range_with_sheet;
+///
+/// let resolver = SheetIdResolver::new([("Q1 Sales".to_string(), 42)]);
+/// let range = a1_to_grid_range_with_sheet("'Q1 Sales'!A1:B10", &resolver).unwrap();
+/// assert_eq!(range.sheet_id, Some(42));
+/// assert_eq!(range.end_row_index, Some(10));
+/// ```
+///
+/// # Errors
+/// This function will return an error if the range has no sheet title, if the
+/// range is malformed, or if the sheet title cannot be resolved.
+pub fn a1_to_grid_range_with_sheet(
+    a1: &str,
+    resolver: &SheetIdResolver,
+) -> Result<GridRange, GSheetError> {
+    let (sheet_title, _) = split_sheet_range(a1)
+        .map_err(|_| GSheetError::UtilsError(format!("Range '{a1}' has no sheet title")))?;
+    let sheet_id = resolver.resolve(&unquote_sheet_title(sheet_title))?;
+
+    Ok(GridRange {
+        sheet_id: Some(sheet_id),
+        ..a1_to_grid_range(a1)?
+    })
 }
 
 /// Converts a column index to A1 notation column letters.
@@ -214,10 +452,245 @@ pub fn col_index_to_a1(col_index: usize) -> Result<String, GSheetError> {
     Ok(col_str)
 }
 
+/// Computes the A1 range starting at a cell and spanning the given shape.
+///
+/// # Arguments
+/// * `start` - The A1 notation of the top-left cell (e.g., "B2")
+/// * `rows` - The number of rows the range spans
+/// * `cols` - The number of columns the range spans
+///
+/// # Returns
+/// A `Result` containing the A1 notation range or a [`GSheetError`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::utils::a1_range_from_shape;
+///
+/// let range = a1_range_from_shape("B2", 16, 4).unwrap();
+/// assert_eq!(range, "B2:E17");
+/// ```
+///
+/// # Errors
+/// This function will return an error if the start cell is malformed or if the
+/// shape is empty.
+pub fn a1_range_from_shape(start: &str, rows: usize, cols: usize) -> Result<String, GSheetError> {
+    if rows == 0 || cols == 0 {
+        return Err(GSheetError::UtilsError(format!(
+            "Cannot compute a range of {rows}x{cols} cells"
+        )));
+    }
+
+    let (start_col, start_row) = parse_a1_cell(start.trim())?;
+    Ok(format!(
+        "{}{}:{}{}",
+        col_index_to_a1(start_col)?,
+        start_row,
+        col_index_to_a1(start_col + cols - 1)?,
+        start_row + rows - 1
+    ))
+}
+
+/// Moves a cell reference by a number of rows and columns.
+///
+/// # Arguments
+/// * `cell` - The A1 notation cell reference (e.g., "B3"), optionally
+///   prefixed with a sheet title
+/// * `rows` - The number of rows to move down, or up when negative
+/// * `cols` - The number of columns to move right, or left when negative
+///
+/// # Returns
+/// A `Result` containing the moved cell reference, with the same sheet prefix,
+/// or a [`GSheetError`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::utils::offset_a1;
+///
+/// assert_eq!(offset_a1("B3", 2, 1).unwrap(), "C5");
+/// assert_eq!(offset_a1("Sheet1!B3", -2, -1).unwrap(), "Sheet1!A1");
+/// assert!(offset_a1("A1", -1, 0).is_err());
+/// ```
+///
+/// # Errors
+/// This function will return an error if the cell reference is malformed or if
+/// the moved cell falls outside the sheet.
+pub fn offset_a1(cell: &str, rows: isize, cols: isize) -> Result<String, GSheetError> {
+    let (prefix, cell_part) = split_prefix(cell);
+    let (col, row) = parse_a1_cell(cell_part)?;
+
+    let moved = |index: usize, delta: isize| {
+        index
+            .checked_add_signed(delta)
+            .filter(|index| *index > 0)
+            .ok_or_else(|| {
+                GSheetError::UtilsError(format!(
+                    "Offsetting '{cell}' by {rows} rows and {cols} columns leaves the sheet"
+                ))
+            })
+    };
+
+    Ok(format!(
+        "{prefix}{}{}",
+        col_index_to_a1(moved(col, cols)?)?,
+        moved(row, rows)?
+    ))
+}
+
+/// Computes the smallest range containing two ranges.
+///
+/// # Arguments
+/// * `a` - The first A1 notation range
+/// * `b` - The second A1 notation range
+///
+/// # Returns
+/// A `Result` containing the bounding range, prefixed with the sheet title of
+/// the inputs if any, or a [`GSheetError`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::utils::range_union;
+///
+/// assert_eq!(range_union("A1:B2", "D5").unwrap(), "A1:D5");
+/// assert_eq!(range_union("Data!C3:D4", "Data!A1").unwrap(), "Data!A1:D4");
+/// ```
+///
+/// # Errors
+/// This function will return an error if a range is malformed or if the ranges
+/// are on different sheets.
+pub fn range_union(a: &str, b: &str) -> Result<String, GSheetError> {
+    let prefix = common_prefix(a, b).ok_or_else(|| {
+        GSheetError::UtilsError(format!("Ranges '{a}' and '{b}' are on different sheets"))
+    })?;
+    let ((a_start_col, a_start_row), (a_end_col, a_end_row)) = a1_range_bounds(a)?;
+    let ((b_start_col, b_start_row), (b_end_col, b_end_row)) = a1_range_bounds(b)?;
+
+    bounds_to_a1(
+        prefix,
+        (a_start_col.min(b_start_col), a_start_row.min(b_start_row)),
+        (a_end_col.max(b_end_col), a_end_row.max(b_end_row)),
+    )
+}
+
+/// Computes the overlap of two ranges.
+///
+/// # Arguments
+/// * `a` - The first A1 notation range
+/// * `b` - The second A1 notation range
+///
+/// # Returns
+/// A `Result` containing the overlapping range, or `None` if the ranges do not
+/// overlap or are on different sheets, or a [`GSheetError`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::utils::range_intersect;
+///
+/// assert_eq!(range_intersect("A1:C3", "B2:D4").unwrap(), Some("B2:C3".to_string()));
+/// assert_eq!(range_intersect("A1:B2", "C3:D4").unwrap(), None);
+/// ```
+///
+/// # Errors
+/// This function will return an error if a range is malformed.
+pub fn range_intersect(a: &str, b: &str) -> Result<Option<String>, GSheetError> {
+    let ((a_start_col, a_start_row), (a_end_col, a_end_row)) = a1_range_bounds(a)?;
+    let ((b_start_col, b_start_row), (b_end_col, b_end_row)) = a1_range_bounds(b)?;
+    let Some(prefix) = common_prefix(a, b) else {
+        return Ok(None);
+    };
+
+    let start = (a_start_col.max(b_start_col), a_start_row.max(b_start_row));
+    let end = (a_end_col.min(b_end_col), a_end_row.min(b_end_row));
+    if start.0 > end.0 || start.1 > end.1 {
+        return Ok(None);
+    }
+
+    bounds_to_a1(prefix, start, end).map(Some)
+}
+
+/// Checks whether a range lies entirely within another range.
+///
+/// # Arguments
+/// * `outer` - The A1 notation range that may contain the other
+/// * `inner` - The A1 notation range or cell that may be contained
+///
+/// # Returns
+/// A `Result` containing whether `inner` is within `outer`, or a [`GSheetError`].
+/// Ranges on different sheets never contain each other.
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::utils::range_contains;
+///
+/// assert!(range_contains("A1:D10", "B2:C3").unwrap());
+/// assert!(!range_contains("A1:D10", "C9:E11").unwrap());
+/// ```
+///
+/// # Errors
+/// This function will return an error if a range is malformed.
+pub fn range_contains(outer: &str, inner: &str) -> Result<bool, GSheetError> {
+    let ((outer_start_col, outer_start_row), (outer_end_col, outer_end_row)) =
+        a1_range_bounds(outer)?;
+    let ((inner_start_col, inner_start_row), (inner_end_col, inner_end_row)) =
+        a1_range_bounds(inner)?;
+    if common_prefix(outer, inner).is_none() {
+        return Ok(false);
+    }
+
+    Ok(outer_start_col <= inner_start_col
+        && outer_start_row <= inner_start_row
+        && inner_end_col <= outer_end_col
+        && inner_end_row <= outer_end_row)
+}
+
+/// Splits a range into its sheet prefix, "!" included, and its cell part.
+fn split_prefix(a1: &str) -> (&str, &str) {
+    let a1 = a1.trim();
+    match a1.rfind('!') {
+        Some(index) => a1.split_at(index + 1),
+        None => ("", a1),
+    }
+}
+
+/// Returns the sheet prefix shared by two ranges, or `None` if they are on
+/// different sheets. A range without a prefix is on the sheet of the other.
+fn common_prefix<'a>(a: &'a str, b: &'a str) -> Option<&'a str> {
+    let (a_prefix, _) = split_prefix(a);
+    let (b_prefix, _) = split_prefix(b);
+
+    match (a_prefix, b_prefix) {
+        ("", prefix) | (prefix, "") => Some(prefix),
+        (a_prefix, b_prefix) => {
+            let title = |prefix: &str| unquote_sheet_title(&prefix[..prefix.len() - 1]);
+            (title(a_prefix) == title(b_prefix)).then_some(a_prefix)
+        }
+    }
+}
+
+/// Formats 1-based, inclusive range bounds in A1 notation.
+fn bounds_to_a1(
+    prefix: &str,
+    (start_col, start_row): (usize, usize),
+    (end_col, end_row): (usize, usize),
+) -> Result<String, GSheetError> {
+    let start = format!("{}{}", col_index_to_a1(start_col)?, start_row);
+    if (start_col, start_row) == (end_col, end_row) {
+        return Ok(format!("{prefix}{start}"));
+    }
+    Ok(format!(
+        "{prefix}{start}:{}{}",
+        col_index_to_a1(end_col)?,
+        end_row
+    ))
+}
+
 /// Converts a ValueRange response to a vector of Cell structures.
 ///
 /// This function takes the raw API response from Google Sheets and converts it
 /// into a more convenient vector of Cell structures with proper addressing.
+/// Only cells holding a non-empty value are returned; use [`cells_iter`] to
+/// include the empty cells of the range. Values read column by column (with a
+/// `COLUMNS` major dimension) are placed at the same addresses as values read
+/// row by row.
 ///
 /// # Arguments
 /// * `sheet_id` - The ID of the spreadsheet
@@ -226,45 +699,111 @@ pub fn col_index_to_a1(col_index: usize) -> Result<String, GSheetError> {
 ///
 /// # Returns
 /// A `Result` containing a vector of [`Cell`] structures or a [`GSheetError`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::{Dimension, ValueRange};
+/// use gsheet_api::utils::value_range_to_cells;
+///
+/// // A1=1, B1=2, A2=3, read row by row and column by column.
+/// let rows = ValueRange {
+///     range: Some("Sheet1!A1:B2".to_string()),
+///     major_dimension: Some(Dimension::Rows),
+///     values: Some(vec![vec!["1".into(), "2".into()], vec!["3".into()]]),
+/// };
+/// let columns = ValueRange {
+///     major_dimension: Some(Dimension::Columns),
+///     values: Some(vec![vec!["1".into(), "3".into()], vec!["2".into()]]),
+///     ..rows.clone()
+/// };
+///
+/// for value_range in [&rows, &columns] {
+///     let cells = value_range_to_cells("id", "Sheet1", value_range).unwrap();
+///     let cells: Vec<_> = cells
+///         .iter()
+///         .map(|cell| (cell.address.to_string(), cell.value.as_deref().unwrap()))
+///         .collect();
+///     let expected = [("A1", "1"), ("B1", "2"), ("A2", "3")].map(|(a1, v)| (a1.to_string(), v));
+///     assert_eq!(cells, expected);
+/// }
+/// ```
 pub fn value_range_to_cells(
     sheet_id: &str,
     sheet_title: &str,
     value_range: &ValueRange,
 ) -> Result<Vec<Cell>, GSheetError> {
+    cells_iter(sheet_id, sheet_title, value_range, false)?.collect()
+}
+
+/// Iterates over the cells of a ValueRange response without collecting them.
+///
+/// Cells are produced in row order, whatever the major dimension of the values.
+/// With `include_empty`, every position of the range is produced, empty or not;
+/// otherwise only cells holding a non-empty value are, which keeps the work
+/// proportional to the data. A ValueRange holding no values, such as the read
+/// of an empty sheet, produces no cells either way.
+///
+/// # Arguments
+/// * `sheet_id` - The ID of the spreadsheet
+/// * `sheet_title` - The title of the sheet
+/// * `value_range` - The ValueRange response from the API
+/// * `include_empty` - Whether to produce the empty cells of the range
+///
+/// # Returns
+/// A `Result` containing an iterator of [`Cell`] results or a [`GSheetError`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::ValueRange;
+/// use gsheet_api::utils::cells_iter;
+///
+/// let value_range = ValueRange {
+///     range: Some("Sheet1!A1:Z1000".to_string()),
+///     values: Some(vec![vec!["Name".into(), "Age".into()]]),
+///     ..Default::default()
+/// };
+/// assert_eq!(cells_iter("id", "Sheet1", &value_range, false).unwrap().count(), 2);
+/// assert_eq!(cells_iter("id", "Sheet1", &value_range, true).unwrap().count(), 26_000);
+///
+/// // An empty sheet, and a single-cell range with nothing in it.
+/// for range in ["Sheet1!A1:Z1000", "Sheet1!A1"] {
+///     let empty = ValueRange {
+///         range: Some(range.to_string()),
+///         ..Default::default()
+///     };
+///     assert_eq!(cells_iter("id", "Sheet1", &empty, true).unwrap().count(), 0);
+/// }
+/// ```
+///
+/// # Errors
+/// This function will return an error if the range of the ValueRange is missing
+/// or malformed.
+pub fn cells_iter<'a>(
+    sheet_id: &'a str,
+    sheet_title: &'a str,
+    value_range: &'a ValueRange,
+    include_empty: bool,
+) -> Result<Box<dyn Iterator<Item = Result<Cell, GSheetError>> + 'a>, GSheetError> {
+    if value_range.is_empty() {
+        return Ok(Box::new(std::iter::empty()));
+    }
+    if !include_empty {
+        return Ok(Box::new(filled_cells(sheet_id, sheet_title, value_range)?));
+    }
+
     let range = value_range
         .range
         .as_ref()
         .ok_or_else(|| GSheetError::UtilsError("ValueRange.range is None".into()))?;
-    let grid_range = a1_to_grid_range(range)?;
-
-    let all_values = vec![vec![]];
-    let all_values = value_range.values.as_ref().unwrap_or(&all_values);
-
-    let mut cells = Vec::new();
-    for row_index in grid_range.start_row_index..=grid_range.end_row_index {
-        for col_index in grid_range.start_column_index..=grid_range.end_column_index {
-            let i = row_index - grid_range.start_row_index;
-            let j = col_index - grid_range.start_column_index;
-
-            let _cell_value = all_values.get(i).and_then(|r| r.get(j)).cloned();
-
-            let col = col_index_to_a1(col_index)?;
-
-            let address = format!("{}{}", col, row_index);
-            let cell = Cell {
-                sheet_id: sheet_id.to_string(),
-                sheet_title: sheet_title.to_string(),
-                address,
-                value: _cell_value,
-                col,
-                col_index,
-                row_index,
-            };
-            cells.push(cell);
-        }
-    }
+    let ((start_col, start_row), (end_col, end_row)) = a1_range_bounds(range)?;
 
-    Ok(cells)
+    Ok(Box::new((start_row..=end_row).flat_map(move |row_index| {
+        (start_col..=end_col).map(move |col_index| {
+            let value =
+                value_at(value_range, row_index - start_row, col_index - start_col).cloned();
+            Ok(new_cell(sheet_id, sheet_title, row_index, col_index, value))
+        })
+    })))
 }
 
 /// Converts a ValueRange response to a HashMap of column-to-row Cell mappings.
@@ -272,6 +811,8 @@ pub fn value_range_to_cells(
 /// This function creates a nested HashMap structure where the outer key is the
 /// column letter (A, B, C, etc.) and the inner key is the row number, with Cell
 /// structures as values. This provides efficient lookup of cells by address.
+/// A ValueRange holding no values, such as the read of an empty sheet, gives
+/// an empty map.
 ///
 /// # Arguments
 /// * `sheet_id` - The ID of the spreadsheet
@@ -280,53 +821,295 @@ pub fn value_range_to_cells(
 ///
 /// # Returns
 /// A `Result` containing a HashMap of column-to-row Cell mappings or a [`GSheetError`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::{Dimension, ValueRange};
+/// use gsheet_api::utils::value_range_to_hash_cell_map;
+///
+/// let value_range = ValueRange {
+///     range: Some("Sheet1!B2:C3".to_string()),
+///     major_dimension: Some(Dimension::Columns),
+///     values: Some(vec![vec!["B2".into(), "B3".into()], vec!["C2".into()]]),
+/// };
+/// let map = value_range_to_hash_cell_map("id", "Sheet1", &value_range).unwrap();
+/// assert_eq!(map["B"][&3].value.as_deref(), Some("B3"));
+/// assert_eq!(map["C"][&2].value.as_deref(), Some("C2"));
+/// assert_eq!(map["C"][&3].value, None);
+///
+/// let empty = ValueRange {
+///     range: Some("Sheet1!A1".to_string()),
+///     ..Default::default()
+/// };
+/// assert!(value_range_to_hash_cell_map("id", "Sheet1", &empty).unwrap().is_empty());
+/// ```
 pub fn value_range_to_hash_cell_map(
     sheet_id: &str,
     sheet_title: &str,
     value_range: &ValueRange,
 ) -> Result<std::collections::HashMap<String, HashMap<usize, Cell>>, GSheetError> {
+    if value_range.is_empty() {
+        return Ok(HashMap::new());
+    }
     let range = value_range
         .range
         .as_ref()
         .ok_or_else(|| GSheetError::UtilsError("ValueRange.range is None".into()))?;
 
-    let grid_range = a1_to_grid_range(range)?;
-
-    let all_values = vec![vec![]];
-    let all_values = value_range.values.as_ref().unwrap_or(&all_values);
+    let ((start_col, start_row), (end_col, end_row)) = a1_range_bounds(range)?;
 
     let mut hash_map: HashMap<String, HashMap<usize, Cell>> = HashMap::new();
 
-    for _row_index in grid_range.start_row_index..=grid_range.end_row_index {
-        for _col_index in grid_range.start_column_index..=grid_range.end_column_index {
-            let i = _row_index - grid_range.start_row_index;
-            let j = _col_index - grid_range.start_column_index;
-            let col = col_index_to_a1(_col_index)?;
-
-            let _cell_value = all_values.get(i).and_then(|r| r.get(j)).cloned();
-
-            let cell = Cell {
-                sheet_id: sheet_id.to_string(),
-                sheet_title: sheet_title.to_string(),
-                address: format!("{}{}", col, _row_index),
-                value: _cell_value,
-                col: col.clone(),
-                col_index: _col_index,
-                row_index: _row_index,
-            };
-
-            if hash_map.contains_key(&cell.col) {
-                hash_map
-                    .get_mut(&cell.col)
-                    .unwrap()
-                    .insert(cell.row_index, cell);
-            } else {
-                let mut row_map = HashMap::new();
-                row_map.insert(cell.row_index, cell);
-                hash_map.insert(col, row_map);
-            }
+    for row_index in start_row..=end_row {
+        for col_index in start_col..=end_col {
+            let value =
+                value_at(value_range, row_index - start_row, col_index - start_col).cloned();
+            let cell = new_cell(sheet_id, sheet_title, row_index, col_index, value);
+            hash_map
+                .entry(col_index_to_a1(col_index)?)
+                .or_default()
+                .insert(row_index, cell);
         }
     }
 
     Ok(hash_map)
 }
+
+/// Converts a ValueRange response to a flat map of the cells holding a value.
+///
+/// Unlike [`value_range_to_hash_cell_map`], empty cells get no entry, so the
+/// size of the map is proportional to the data rather than to the range.
+///
+/// # Arguments
+/// * `sheet_id` - The ID of the spreadsheet
+/// * `sheet_title` - The title of the sheet
+/// * `value_range` - The ValueRange response from the API
+///
+/// # Returns
+/// A `Result` containing a map of the non-empty [`Cell`]s by [`CellAddress`], or
+/// a [`GSheetError`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::{CellAddress, CellMapExt, ValueRange};
+/// use gsheet_api::utils::value_range_to_cell_map;
+///
+/// let value_range = ValueRange {
+///     range: Some("Sheet1!A1:Z1000".to_string()),
+///     values: Some(vec![vec!["Name".into(), "".into(), "Age".into()]]),
+///     ..Default::default()
+/// };
+/// let cells = value_range_to_cell_map("id", "Sheet1", &value_range).unwrap();
+/// assert_eq!(cells.len(), 2);
+/// assert_eq!(cells[&CellAddress::new(1, 3)].value.as_deref(), Some("Age"));
+/// assert_eq!(cells.row(1).len(), 2);
+/// ```
+///
+/// # Errors
+/// This function will return an error if the range of the ValueRange is missing
+/// or malformed.
+pub fn value_range_to_cell_map(
+    sheet_id: &str,
+    sheet_title: &str,
+    value_range: &ValueRange,
+) -> Result<HashMap<CellAddress, Cell>, GSheetError> {
+    filled_cells(sheet_id, sheet_title, value_range)?
+        .map(|cell| cell.map(|cell| (CellAddress::from(&cell), cell)))
+        .collect()
+}
+
+/// Converts a ValueRange response to an ordered map of the cells holding a value.
+///
+/// The map iterates in reading order, row by row. Empty cells get no entry.
+///
+/// # Arguments
+/// * `sheet_id` - The ID of the spreadsheet
+/// * `sheet_title` - The title of the sheet
+/// * `value_range` - The ValueRange response from the API
+///
+/// # Returns
+/// A `Result` containing an ordered map of the non-empty [`Cell`]s by
+/// [`CellAddress`], or a [`GSheetError`].
+///
+/// # Errors
+/// This function will return an error if the range of the ValueRange is missing
+/// or malformed.
+pub fn value_range_to_ordered_cell_map(
+    sheet_id: &str,
+    sheet_title: &str,
+    value_range: &ValueRange,
+) -> Result<BTreeMap<CellAddress, Cell>, GSheetError> {
+    filled_cells(sheet_id, sheet_title, value_range)?
+        .map(|cell| cell.map(|cell| (CellAddress::from(&cell), cell)))
+        .collect()
+}
+
+/// Copies the value of each merged region to every cell of the region.
+///
+/// The API only returns the value of a merge in its top-left cell, leaving the
+/// other cells empty. With the merges of the sheet, as returned by
+/// `sheet.merges()`, the value is propagated across the part of the region
+/// within the ValueRange, whose values grow as needed. Merges whose top-left
+/// cell is outside the ValueRange, or empty, are left as they are.
+///
+/// # Arguments
+/// * `value_range` - The ValueRange response from the API
+/// * `merges` - The merged ranges of the sheet the values were read from
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::ValueRange;
+/// use gsheet_api::utils::{a1_to_grid_range, fill_merged_values};
+///
+/// let mut value_range = ValueRange {
+///     range: Some("Sheet1!A1:Z1000".to_string()),
+///     values: Some(vec![vec!["Region".into(), "".into(), "Total".into()], vec!["North".into()]]),
+///     ..Default::default()
+/// };
+/// let merges = [a1_to_grid_range("A1:B1").unwrap(), a1_to_grid_range("A2:A3").unwrap()];
+/// fill_merged_values(&mut value_range, &merges).unwrap();
+/// assert_eq!(
+///     value_range.values.unwrap(),
+///     vec![vec!["Region", "Region", "Total"], vec!["North"], vec!["North"]]
+/// );
+/// ```
+///
+/// # Errors
+/// This function will return an error if the range of the ValueRange is missing
+/// or malformed.
+pub fn fill_merged_values(
+    value_range: &mut ValueRange,
+    merges: &[GridRange],
+) -> Result<(), GSheetError> {
+    if value_range.is_empty() {
+        return Ok(());
+    }
+    let range = value_range
+        .range
+        .as_ref()
+        .ok_or_else(|| GSheetError::UtilsError("ValueRange.range is None".into()))?;
+    let ((start_col, start_row), (end_col, end_row)) = a1_range_bounds(range)?;
+
+    for merge in merges {
+        // 1-based inclusive bounds of the merge, unbounded sides ending with the range.
+        let index = |value: Option<i32>| value.and_then(|value| usize::try_from(value).ok());
+        let top = index(merge.start_row_index).unwrap_or(0) + 1;
+        let left = index(merge.start_column_index).unwrap_or(0) + 1;
+        let bottom = index(merge.end_row_index).unwrap_or(end_row).min(end_row);
+        let right = index(merge.end_column_index)
+            .unwrap_or(end_col)
+            .min(end_col);
+        if top < start_row || left < start_col || top > end_row || left > end_col {
+            continue;
+        }
+
+        let Some(value) = value_at(value_range, top - start_row, left - start_col)
+            .filter(|value| !value.is_empty())
+            .cloned()
+        else {
+            continue;
+        };
+        for row in top..=bottom {
+            for col in left..=right {
+                set_value_at(value_range, row - start_row, col - start_col, value.clone());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Iterates over the cells of a ValueRange that hold a non-empty value.
+///
+/// A ValueRange holding no values has no cells, so its range is not needed.
+fn filled_cells<'a>(
+    sheet_id: &'a str,
+    sheet_title: &'a str,
+    value_range: &'a ValueRange,
+) -> Result<impl Iterator<Item = Result<Cell, GSheetError>> + 'a, GSheetError> {
+    let (start_col, start_row) = if value_range.is_empty() {
+        (1, 1)
+    } else {
+        let range = value_range
+            .range
+            .as_ref()
+            .ok_or_else(|| GSheetError::UtilsError("ValueRange.range is None".into()))?;
+        a1_range_bounds(range)?.0
+    };
+
+    let filled = value_range
+        .iter_cells()
+        .filter(|(_, _, value)| !value.is_empty());
+    // Values read column by column are sorted back into row order.
+    let filled: Box<dyn Iterator<Item = (usize, usize, &'a str)> + 'a> =
+        if value_range.is_column_major() {
+            let mut filled: Vec<_> = filled.collect();
+            filled.sort_unstable_by_key(|(i, j, _)| (*i, *j));
+            Box::new(filled.into_iter())
+        } else {
+            Box::new(filled)
+        };
+
+    Ok(filled.map(move |(i, j, value)| {
+        let value = Some(value.to_string());
+        Ok(new_cell(
+            sheet_id,
+            sheet_title,
+            start_row + i,
+            start_col + j,
+            value,
+        ))
+    }))
+}
+
+/// Creates the cell at 1-based row and column indexes.
+fn new_cell(
+    sheet_id: &str,
+    sheet_title: &str,
+    row: usize,
+    col: usize,
+    value: Option<String>,
+) -> Cell {
+    let address = CellAddress::new(row, col);
+    Cell {
+        address,
+        sheet_id: sheet_id.to_string(),
+        sheet_title: sheet_title.to_string(),
+        value,
+        col_index: address.col,
+        row_index: address.row,
+    }
+}
+
+/// Returns the stored value at row and column offsets from the top-left cell
+/// of a ValueRange, honoring its major dimension.
+fn value_at(value_range: &ValueRange, row: usize, col: usize) -> Option<&String> {
+    let (major, minor) = if value_range.is_column_major() {
+        (col, row)
+    } else {
+        (row, col)
+    };
+    value_range
+        .values
+        .as_deref()?
+        .get(major)
+        .and_then(|line| line.get(minor))
+}
+
+/// Sets the value at row and column offsets from the top-left cell of a
+/// ValueRange, honoring its major dimension and growing its values as needed.
+fn set_value_at(value_range: &mut ValueRange, row: usize, col: usize, value: String) {
+    let (major, minor) = if value_range.is_column_major() {
+        (col, row)
+    } else {
+        (row, col)
+    };
+    let values = value_range.values.get_or_insert_with(Vec::new);
+    if values.len() <= major {
+        values.resize_with(major + 1, Vec::new);
+    }
+    let line = &mut values[major];
+    if line.len() <= minor {
+        line.resize_with(minor + 1, String::new);
+    }
+    line[minor] = value;
+}
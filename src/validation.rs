@@ -0,0 +1,545 @@
+//! # Validation Module
+//!
+//! This module provides client-side validation of requests before they are sent.
+//!
+//! ## Overview
+//!
+//! Malformed requests are rejected by the API anyway, but only after a round trip
+//! that consumes quota, and with an error message that rarely points at the
+//! offending input. The operations of this crate validate their input with the
+//! functions of this module first, and fail early with a
+//! [`GSheetError::ValidationError`] describing what is wrong.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use gsheet_api::models::Dimension;
+//! use gsheet_api::validation::{validate_a1_range, validate_values};
+//!
+//! assert!(validate_a1_range("A1:C10").is_ok());
+//! assert!(validate_a1_range("A1:C10:D4").is_err());
+//!
+//! // Three columns of values don't fit in a two-column range.
+//! let values = vec![vec!["a".to_string(), "b".to_string(), "c".to_string()]];
+//! assert!(validate_values("A1:B1", &values, &Dimension::Rows).is_err());
+//! ```
+
+use crate::error::GSheetError;
+use crate::models::{Dimension, GridProperties, Request, SheetProperties};
+use crate::types::RangeFit;
+use crate::utils::{a1_range_from_shape, col_index_to_a1};
+
+/// The maximum number of characters of a sheet title.
+pub const MAX_SHEET_TITLE_LENGTH: usize = 100;
+
+/// The maximum number of cells of a spreadsheet, summed over the grids of all its sheets.
+pub const MAX_SPREADSHEET_CELLS: u64 = 10_000_000;
+
+/// One side of an A1 range, as `(column, row)`, 1-based. A missing index means
+/// the range is unbounded on that side, as in "A:B" or "1:3".
+type Endpoint = (Option<usize>, Option<usize>);
+
+/// Validates a range relative to a sheet, such as "A1", "A1:C10", "A:C" or "2:5".
+///
+/// # Arguments
+/// * `range` - The A1 notation range, without sheet title
+///
+/// # Returns
+/// `Ok(())` if the range is well-formed, or a [`GSheetError`].
+///
+/// # Errors
+/// This function will return a [`GSheetError::ValidationError`] if:
+/// - The range is empty
+/// - The range contains a sheet title or more than two cells
+/// - A cell reference is malformed
+/// - The start of the range is after its end
+pub fn validate_a1_range(range: &str) -> Result<(), GSheetError> {
+    parse_range(range).map(|_| ())
+}
+
+/// Validates a range that may be qualified with a sheet title, such as
+/// "Sheet1!A1:C10" or "'My Sheet'!A:C".
+///
+/// A range without a "!" can be a sheet title or a named range, so only its
+/// emptiness is checked.
+///
+/// # Arguments
+/// * `range` - The A1 notation range
+///
+/// # Returns
+/// `Ok(())` if the range is well-formed, or a [`GSheetError`].
+///
+/// # Errors
+/// This function will return a [`GSheetError::ValidationError`] if:
+/// - The range or its sheet title is empty
+/// - The cells part of the range is malformed
+pub fn validate_range(range: &str) -> Result<(), GSheetError> {
+    let range = range.trim();
+    if range.is_empty() {
+        return Err(GSheetError::ValidationError(
+            "Range must not be empty".into(),
+        ));
+    }
+
+    match range.rsplit_once('!') {
+        Some((sheet_title, cells)) => {
+            let sheet_title = sheet_title
+                .strip_prefix('\'')
+                .and_then(|title| title.strip_suffix('\''))
+                .unwrap_or(sheet_title);
+            if sheet_title.is_empty() {
+                return Err(GSheetError::ValidationError(format!(
+                    "Range '{range}' has an empty sheet title"
+                )));
+            }
+            validate_a1_range(cells)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Validates a sheet title.
+///
+/// # Arguments
+/// * `title` - The title of the sheet
+///
+/// # Returns
+/// `Ok(())` if the title is valid, or a [`GSheetError`].
+///
+/// # Errors
+/// This function will return a [`GSheetError::ValidationError`] if the title is
+/// empty or longer than [`MAX_SHEET_TITLE_LENGTH`] characters.
+pub fn validate_sheet_title(title: &str) -> Result<(), GSheetError> {
+    if title.trim().is_empty() {
+        return Err(GSheetError::ValidationError(
+            "Sheet title must not be empty".into(),
+        ));
+    }
+
+    let length = title.chars().count();
+    if length > MAX_SHEET_TITLE_LENGTH {
+        return Err(GSheetError::ValidationError(format!(
+            "Sheet title '{title}' is {length} characters long, the maximum is {MAX_SHEET_TITLE_LENGTH}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validates a batch update request.
+///
+/// # Arguments
+/// * `request` - The request to validate
+///
+/// # Returns
+/// `Ok(())` if the request is valid, or a [`GSheetError`].
+///
+/// # Errors
+/// This function will return a [`GSheetError::ValidationError`] if a sheet title
+/// set by the request is invalid, or if a table is added without a name.
+pub fn validate_request(request: &Request) -> Result<(), GSheetError> {
+    let sheet_title = match request {
+        Request::AddSheet(request) => request.properties.title.as_deref(),
+        Request::UpdateSheetProperties(request) => request.properties.title.as_deref(),
+        Request::DuplicateSheet(request) => request.new_sheet_name.as_deref(),
+        Request::AddTable(request) => {
+            if request
+                .table
+                .name
+                .as_deref()
+                .is_none_or(|name| name.trim().is_empty())
+            {
+                return Err(GSheetError::ValidationError(
+                    "Table name must not be empty".into(),
+                ));
+            }
+            None
+        }
+        _ => None,
+    };
+
+    match sheet_title {
+        Some(title) => validate_sheet_title(title),
+        None => Ok(()),
+    }
+}
+
+/// Validates values to be written to a range relative to a sheet.
+///
+/// The values must not be empty, and must fit in the range when it is bounded.
+/// Values smaller than the range are accepted, as the remaining cells are left
+/// untouched by the API.
+///
+/// # Arguments
+/// * `range` - The A1 notation range, without sheet title
+/// * `values` - The values to write
+/// * `major_dimension` - Whether the inner vectors are rows or columns
+///
+/// # Returns
+/// `Ok(())` if the values fit in the range, or a [`GSheetError`].
+///
+/// # Errors
+/// This function will return a [`GSheetError::ValidationError`] if:
+/// - The range is malformed
+/// - The values are empty
+/// - The values have more rows or columns than the range
+pub fn validate_values(
+    range: &str,
+    values: &[Vec<String>],
+    major_dimension: &Dimension,
+) -> Result<(), GSheetError> {
+    let (range_rows, range_cols) = range_spans(range)?;
+    let (rows, cols) = values_shape(range, values, major_dimension)?;
+
+    if let Some(range_rows) = range_rows
+        && rows > range_rows
+    {
+        return Err(GSheetError::ValidationError(format!(
+            "Values have {rows} rows but range '{range}' only spans {range_rows}"
+        )));
+    }
+
+    if let Some(range_cols) = range_cols
+        && cols > range_cols
+    {
+        return Err(GSheetError::ValidationError(format!(
+            "Values have {cols} columns but range '{range}' only spans {range_cols}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validates that a range lies within the grid of a sheet.
+///
+/// The grid properties are returned by
+/// [`SheetOperations::dimensions`](crate::operations::sheet::SheetOperations::dimensions).
+/// Unknown counts are not checked.
+///
+/// # Arguments
+/// * `range` - The A1 notation range, without sheet title
+/// * `grid` - The grid properties of the sheet
+///
+/// # Returns
+/// `Ok(())` if the range lies within the grid, or a [`GSheetError`].
+///
+/// # Errors
+/// This function will return a [`GSheetError::ValidationError`] if the range is
+/// malformed or extends past the last row or column of the grid.
+pub fn validate_within_grid(range: &str, grid: &GridProperties) -> Result<(), GSheetError> {
+    let ((start_col, start_row), (end_col, end_row)) = parse_range(range)?;
+
+    let last = |start: Option<usize>, end: Option<usize>| end.or(start).unwrap_or(0);
+    let exceeds = |index: usize, count: Option<i32>| {
+        count.is_some_and(|count| index > usize::try_from(count).unwrap_or(0))
+    };
+
+    if exceeds(last(start_row, end_row), grid.row_count) {
+        return Err(GSheetError::ValidationError(format!(
+            "Range '{range}' extends past the {} rows of the sheet",
+            grid.row_count.unwrap_or_default()
+        )));
+    }
+    if exceeds(last(start_col, end_col), grid.column_count) {
+        return Err(GSheetError::ValidationError(format!(
+            "Range '{range}' extends past the {} columns of the sheet",
+            grid.column_count.unwrap_or_default()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validates that giving a sheet a grid of the given size keeps the spreadsheet
+/// within [`MAX_SPREADSHEET_CELLS`].
+///
+/// The projected total replaces the grid of the sheet with the given id by the
+/// new size, or adds a grid of that size when no id is given, as for a new sheet. The
+/// sheet properties are typically those of
+/// [`SpreadsheetMetadata`](crate::metadata::SpreadsheetMetadata), which the
+/// client cache keeps between calls. Sheets without grid properties, such as
+/// object sheets, count as empty.
+///
+/// # Arguments
+/// * `sheets` - The properties of the sheets of the spreadsheet
+/// * `sheet_id` - The id of the sheet being resized, or `None` for a new sheet
+/// * `rows` - The number of rows the sheet would have
+/// * `columns` - The number of columns the sheet would have
+///
+/// # Returns
+/// `Ok(())` if the spreadsheet stays within the limit, or a [`GSheetError`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::{GridProperties, SheetProperties};
+/// use gsheet_api::validation::validate_cell_limit;
+///
+/// let sheets = vec![SheetProperties {
+///     sheet_id: Some(0),
+///     grid_properties: Some(GridProperties {
+///         row_count: Some(200_000),
+///         column_count: Some(26),
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// }];
+///
+/// // Resizing the sheet replaces its 5.2 million cells.
+/// assert!(validate_cell_limit(&sheets, Some(0), 300_000, 26).is_ok());
+///
+/// // A new sheet adds to them.
+/// assert!(validate_cell_limit(&sheets, None, 180_000, 26).is_ok());
+/// assert!(validate_cell_limit(&sheets, None, 200_000, 26).is_err());
+/// ```
+///
+/// # Errors
+/// This function will return a [`GSheetError::ValidationError`] if the projected
+/// number of cells exceeds [`MAX_SPREADSHEET_CELLS`].
+pub fn validate_cell_limit(
+    sheets: &[SheetProperties],
+    sheet_id: Option<i32>,
+    rows: i32,
+    columns: i32,
+) -> Result<(), GSheetError> {
+    let cells = |rows: Option<i32>, columns: Option<i32>| {
+        u64::try_from(rows.unwrap_or_default()).unwrap_or(0)
+            * u64::try_from(columns.unwrap_or_default()).unwrap_or(0)
+    };
+
+    let sheet_cells = cells(Some(rows), Some(columns));
+    let other_cells: u64 = sheets
+        .iter()
+        .filter(|properties| sheet_id.is_none() || properties.sheet_id != sheet_id)
+        .filter_map(|properties| properties.grid_properties.as_ref())
+        .map(|grid| cells(grid.row_count, grid.column_count))
+        .sum();
+    let total = other_cells + sheet_cells;
+
+    if total > MAX_SPREADSHEET_CELLS {
+        return Err(GSheetError::ValidationError(format!(
+            "A grid of {rows} rows by {columns} columns ({sheet_cells} cells) would bring the \
+             spreadsheet to {total} cells, over the limit of {MAX_SPREADSHEET_CELLS}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Reconciles values to be written with their target range according to a [`RangeFit`] policy.
+///
+/// # Arguments
+/// * `range` - The A1 notation range, without sheet title
+/// * `values` - The values to write
+/// * `major_dimension` - Whether the inner vectors are rows or columns
+/// * `fit` - The policy applied when the shape of the values disagrees with the range
+///
+/// # Returns
+/// A `Result` containing the range and values to send, or a [`GSheetError`].
+///
+/// # Examples
+/// ```rust
+/// use gsheet_api::models::Dimension;
+/// use gsheet_api::types::RangeFit;
+/// use gsheet_api::validation::fit_values;
+///
+/// let values = vec![vec!["a".to_string(), "b".to_string(), "c".to_string()]];
+///
+/// let (range, _) = fit_values("B2:C2", values.clone(), &Dimension::Rows, RangeFit::Expand).unwrap();
+/// assert_eq!(range, "B2:D2");
+///
+/// let (_, values) = fit_values("B2:C2", values, &Dimension::Rows, RangeFit::Truncate).unwrap();
+/// assert_eq!(values, vec![vec!["a".to_string(), "b".to_string()]]);
+///
+/// let short = vec![vec!["a".to_string()]];
+/// assert!(fit_values("B2:C2", short.clone(), &Dimension::Rows, RangeFit::Reject).is_ok());
+/// assert!(fit_values("B2:C2", short, &Dimension::Rows, RangeFit::Exact).is_err());
+/// ```
+///
+/// # Errors
+/// This function will return a [`GSheetError::ValidationError`] if:
+/// - The range is malformed
+/// - The values are empty
+/// - The policy is [`RangeFit::Reject`] and the values overflow the range
+/// - The policy is [`RangeFit::Exact`] and the values don't exactly fill the bounded sides of the range
+/// - The policy is [`RangeFit::Expand`] and the range doesn't start at a cell
+pub fn fit_values(
+    range: &str,
+    values: Vec<Vec<String>>,
+    major_dimension: &Dimension,
+    fit: RangeFit,
+) -> Result<(String, Vec<Vec<String>>), GSheetError> {
+    match fit {
+        RangeFit::Reject => {
+            validate_values(range, &values, major_dimension)?;
+            Ok((range.to_string(), values))
+        }
+        RangeFit::Exact => {
+            validate_values(range, &values, major_dimension)?;
+
+            let (range_rows, range_cols) = range_spans(range)?;
+            let (rows, cols) = values_shape(range, &values, major_dimension)?;
+            if let Some(range_rows) = range_rows.filter(|range_rows| rows < *range_rows) {
+                return Err(GSheetError::ValidationError(format!(
+                    "Values have {rows} rows but range '{range}' spans {range_rows}"
+                )));
+            }
+            if let Some(range_cols) = range_cols.filter(|range_cols| cols < *range_cols) {
+                return Err(GSheetError::ValidationError(format!(
+                    "Values have {cols} columns but range '{range}' spans {range_cols}"
+                )));
+            }
+
+            Ok((range.to_string(), values))
+        }
+        RangeFit::Expand => {
+            let ((start_col, start_row), _) = parse_range(range)?;
+            let (rows, cols) = values_shape(range, &values, major_dimension)?;
+            let (Some(start_col), Some(start_row)) = (start_col, start_row) else {
+                return Err(GSheetError::ValidationError(format!(
+                    "Range '{range}' must start at a cell to be resized to the values"
+                )));
+            };
+
+            let start = format!("{}{}", col_index_to_a1(start_col)?, start_row);
+            Ok((a1_range_from_shape(&start, rows, cols)?, values))
+        }
+        RangeFit::Truncate => {
+            let (range_rows, range_cols) = range_spans(range)?;
+            let (outer_limit, inner_limit) = match major_dimension {
+                Dimension::Columns => (range_cols, range_rows),
+                _ => (range_rows, range_cols),
+            };
+
+            let mut values = values;
+            if let Some(outer_limit) = outer_limit {
+                values.truncate(outer_limit);
+            }
+            if let Some(inner_limit) = inner_limit {
+                values
+                    .iter_mut()
+                    .for_each(|inner| inner.truncate(inner_limit));
+            }
+
+            values_shape(range, &values, major_dimension)?;
+            Ok((range.to_string(), values))
+        }
+    }
+}
+
+/// Returns the number of rows and columns a range spans, or `None` on unbounded sides.
+fn range_spans(range: &str) -> Result<(Option<usize>, Option<usize>), GSheetError> {
+    let ((start_col, start_row), (end_col, end_row)) = parse_range(range)?;
+    let span = |start: Option<usize>, end: Option<usize>| match (start, end) {
+        (Some(start), Some(end)) => Some(end - start + 1),
+        _ => None,
+    };
+
+    Ok((span(start_row, end_row), span(start_col, end_col)))
+}
+
+/// Returns the number of rows and columns of non-empty values.
+fn values_shape(
+    range: &str,
+    values: &[Vec<String>],
+    major_dimension: &Dimension,
+) -> Result<(usize, usize), GSheetError> {
+    if values.iter().all(|inner| inner.is_empty()) {
+        return Err(GSheetError::ValidationError(format!(
+            "Values written to '{range}' must not be empty"
+        )));
+    }
+
+    let outer = values.len();
+    let inner = values.iter().map(Vec::len).max().unwrap_or(0);
+    Ok(match major_dimension {
+        Dimension::Columns => (inner, outer),
+        _ => (outer, inner),
+    })
+}
+
+/// Parses a range relative to a sheet into its start and end endpoints.
+fn parse_range(range: &str) -> Result<(Endpoint, Endpoint), GSheetError> {
+    let trimmed = range.trim();
+    if trimmed.is_empty() {
+        return Err(GSheetError::ValidationError(
+            "Range must not be empty".into(),
+        ));
+    }
+    if trimmed.contains('!') {
+        return Err(GSheetError::ValidationError(format!(
+            "Range '{range}' must not include a sheet title"
+        )));
+    }
+
+    let parts: Vec<&str> = trimmed.split(':').collect();
+    let (start, end) = match parts.as_slice() {
+        [cell] => {
+            let endpoint = parse_endpoint(cell, range)?;
+            if endpoint.0.is_none() || endpoint.1.is_none() {
+                return Err(GSheetError::ValidationError(format!(
+                    "Range '{range}' is not a cell reference"
+                )));
+            }
+            (endpoint, endpoint)
+        }
+        [start, end] => (parse_endpoint(start, range)?, parse_endpoint(end, range)?),
+        _ => {
+            return Err(GSheetError::ValidationError(format!(
+                "Range '{range}' must have at most two cells separated by ':'"
+            )));
+        }
+    };
+
+    let reversed =
+        |a: Option<usize>, b: Option<usize>| matches!((a, b), (Some(a), Some(b)) if a > b);
+    if reversed(start.0, end.0) || reversed(start.1, end.1) {
+        return Err(GSheetError::ValidationError(format!(
+            "Range '{range}' starts after it ends"
+        )));
+    }
+
+    Ok((start, end))
+}
+
+/// Parses one side of a range, such as "B3", "B" or "3".
+fn parse_endpoint(token: &str, range: &str) -> Result<Endpoint, GSheetError> {
+    let invalid = || {
+        GSheetError::ValidationError(format!(
+            "Invalid cell reference '{token}' in range '{range}'"
+        ))
+    };
+
+    let letters_end = token
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(token.len());
+    let (letters, digits) = token.split_at(letters_end);
+
+    if token.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    let col = if letters.is_empty() {
+        None
+    } else {
+        let mut col: usize = 0;
+        for c in letters.chars() {
+            let value = c.to_ascii_uppercase() as usize - 'A' as usize + 1;
+            col = col
+                .checked_mul(26)
+                .and_then(|col| col.checked_add(value))
+                .ok_or_else(invalid)?;
+        }
+        Some(col)
+    };
+
+    let row = if digits.is_empty() {
+        None
+    } else {
+        match digits.parse::<usize>() {
+            Ok(0) | Err(_) => return Err(invalid()),
+            Ok(row) => Some(row),
+        }
+    };
+
+    Ok((col, row))
+}
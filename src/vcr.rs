@@ -0,0 +1,342 @@
+//! # VCR Module
+//!
+//! This module records live HTTP interactions to fixture files and replays them,
+//! for deterministic integration tests of code built on this crate. It requires
+//! the `vcr` feature.
+//!
+//! ## Overview
+//!
+//! A [`Cassette`] is attached to a client with
+//! [`GoogleSheetClientBuilder::cassette`](crate::client::GoogleSheetClientBuilder::cassette).
+//! In [`VcrMode::Record`] every request is sent to the API and its response is
+//! recorded; the interactions are written to the fixture file by
+//! [`Cassette::save`], or when the cassette is dropped. In [`VcrMode::Replay`] no
+//! request reaches the network: each request is answered with the first unused
+//! recorded interaction having the same method, URL and body.
+//!
+//! Authentication headers are never recorded. Replayed clients don't need
+//! credentials, and can use [`ReplayAuth`] as their auth provider.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use gsheet_api::client::GoogleSheetClient;
+//! use gsheet_api::vcr::{Cassette, ReplayAuth, VcrMode};
+//! use std::sync::Arc;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//!
+//! // In a test: replay interactions recorded earlier against the live API
+//! let cassette = Cassette::open("tests/fixtures/read_orders.json", VcrMode::Replay)?;
+//! let gsheet_client = GoogleSheetClient::builder()
+//!     .auth_client(Arc::new(ReplayAuth))
+//!     .cassette(Arc::new(cassette))
+//!     .build()?;
+//!
+//! let values = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .sheet("Orders")
+//!     .get_all_value()
+//!     .execute()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::auth::{AuthError, AuthProvider, SecretString};
+use crate::dry_run::DryRunRequest;
+use crate::error::GSheetError;
+
+/// Whether a [`Cassette`] records or replays interactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VcrMode {
+    /// Requests are sent to the API and their responses are recorded.
+    Record,
+    /// Requests are answered with recorded responses, without network access.
+    Replay,
+}
+
+/// A recorded request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// The HTTP method of the request (e.g., "GET").
+    pub method: String,
+    /// The full URL of the request, including its query parameters.
+    pub url: String,
+    /// The JSON body of the request, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<serde_json::Value>,
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The headers of the response, by lowercase name.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// The body of the response.
+    #[serde(default)]
+    pub body: String,
+}
+
+/// A recorded request with its response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The request sent to the API.
+    pub request: RecordedRequest,
+    /// The response returned by the API.
+    pub response: RecordedResponse,
+}
+
+/// The content of a fixture file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Fixture {
+    /// The recorded interactions, in order.
+    interactions: Vec<Interaction>,
+}
+
+/// Mutable state of a [`Cassette`].
+#[derive(Debug, Default)]
+struct CassetteState {
+    /// The interactions, in recording order.
+    interactions: Vec<Interaction>,
+    /// Whether each interaction was already replayed.
+    used: Vec<bool>,
+    /// Whether interactions were recorded since the last save.
+    dirty: bool,
+}
+
+/// A fixture file of recorded HTTP interactions.
+#[derive(Debug)]
+pub struct Cassette {
+    /// The path of the fixture file.
+    path: PathBuf,
+    /// Whether the cassette records or replays.
+    mode: VcrMode,
+    /// The interactions and their replay state.
+    state: Mutex<CassetteState>,
+}
+
+impl Cassette {
+    /// Opens a cassette backed by a fixture file.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the fixture file
+    /// * `mode` - Whether to record or replay interactions
+    ///
+    /// # Returns
+    /// A `Result` containing the [`Cassette`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the fixture file of a replaying
+    /// cassette cannot be read or parsed. Recording cassettes start empty and
+    /// overwrite their file.
+    pub fn open(path: impl AsRef<Path>, mode: VcrMode) -> Result<Self, GSheetError> {
+        let path = path.as_ref().to_path_buf();
+        let interactions = match mode {
+            VcrMode::Record => Vec::new(),
+            VcrMode::Replay => {
+                let content = std::fs::read_to_string(&path).map_err(|e| {
+                    GSheetError::Other(format!("Failed to read cassette {}: {}", path.display(), e))
+                })?;
+                serde_json::from_str::<Fixture>(&content)
+                    .map_err(|e| {
+                        GSheetError::ResponseParseError(format!(
+                            "Failed to parse cassette {}: {}",
+                            path.display(),
+                            e
+                        ))
+                    })?
+                    .interactions
+            }
+        };
+
+        Ok(Self {
+            path,
+            mode,
+            state: Mutex::new(CassetteState {
+                used: vec![false; interactions.len()],
+                interactions,
+                dirty: false,
+            }),
+        })
+    }
+
+    /// Returns whether the cassette records or replays interactions.
+    pub fn mode(&self) -> VcrMode {
+        self.mode
+    }
+
+    /// Returns a copy of the recorded interactions.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.interactions.clone()
+    }
+
+    /// Writes the recorded interactions to the fixture file.
+    ///
+    /// Does nothing for replaying cassettes.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the fixture file cannot be written.
+    pub fn save(&self) -> Result<(), GSheetError> {
+        if self.mode == VcrMode::Replay {
+            return Ok(());
+        }
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let fixture = Fixture {
+            interactions: state.interactions.clone(),
+        };
+        let content = serde_json::to_string_pretty(&fixture)
+            .map_err(|e| GSheetError::Other(format!("Failed to serialize cassette: {}", e)))?;
+        if let Some(parent) = self
+            .path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                GSheetError::Other(format!("Failed to create {}: {}", parent.display(), e))
+            })?;
+        }
+        std::fs::write(&self.path, content).map_err(|e| {
+            GSheetError::Other(format!(
+                "Failed to write cassette {}: {}",
+                self.path.display(),
+                e
+            ))
+        })?;
+        state.dirty = false;
+        Ok(())
+    }
+
+    /// Sends a request, recording or replaying it.
+    ///
+    /// # Arguments
+    /// * `client` - The HTTP client sending recorded requests
+    /// * `request` - The request to send
+    ///
+    /// # Returns
+    /// A `Result` containing the live or replayed response, or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the request could not be sent, or if
+    /// no recorded interaction matches it while replaying.
+    pub(crate) async fn execute(
+        &self,
+        client: &reqwest::Client,
+        request: reqwest::Request,
+    ) -> Result<reqwest::Response, GSheetError> {
+        let captured = DryRunRequest::capture(&request);
+        let recorded = RecordedRequest {
+            method: captured.method,
+            url: captured.url,
+            body: captured.body,
+        };
+
+        match self.mode {
+            VcrMode::Replay => {
+                let response = self.replay(&recorded).ok_or_else(|| {
+                    GSheetError::Other(format!(
+                        "No recorded interaction for {} {}",
+                        recorded.method, recorded.url
+                    ))
+                })?;
+                to_response(&response)
+            }
+            VcrMode::Record => {
+                let response = client.execute(request).await?;
+                let status = response.status().as_u16();
+                let headers = response
+                    .headers()
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+                    })
+                    .collect();
+                let body = response.text().await?;
+                let response = RecordedResponse {
+                    status,
+                    headers,
+                    body,
+                };
+
+                let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+                state.interactions.push(Interaction {
+                    request: recorded,
+                    response: response.clone(),
+                });
+                state.used.push(true);
+                state.dirty = true;
+                drop(state);
+                to_response(&response)
+            }
+        }
+    }
+
+    /// Returns the response of the first unused interaction matching a request.
+    fn replay(&self, request: &RecordedRequest) -> Option<RecordedResponse> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let CassetteState {
+            interactions, used, ..
+        } = &mut *state;
+        let index = interactions
+            .iter()
+            .zip(used.iter())
+            .position(|(interaction, used)| !used && interaction.request == *request)?;
+        used[index] = true;
+        Some(interactions[index].response.clone())
+    }
+}
+
+impl Drop for Cassette {
+    fn drop(&mut self) {
+        let dirty = self.state.get_mut().map_or(true, |state| state.dirty);
+        if dirty {
+            let _ = self.save();
+        }
+    }
+}
+
+/// Builds a response from a recorded one.
+fn to_response(recorded: &RecordedResponse) -> Result<reqwest::Response, GSheetError> {
+    let mut builder = http::Response::builder().status(recorded.status);
+    for (name, value) in &recorded.headers {
+        // The body is stored decoded, so transfer headers no longer apply.
+        if matches!(
+            name.as_str(),
+            "content-encoding" | "content-length" | "transfer-encoding"
+        ) {
+            continue;
+        }
+        builder = builder.header(name, value);
+    }
+    let response = builder.body(recorded.body.clone()).map_err(|e| {
+        GSheetError::ResponseParseError(format!("Invalid recorded response: {}", e))
+    })?;
+    Ok(reqwest::Response::from(response))
+}
+
+/// An auth provider for replayed clients, which never need a token.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReplayAuth;
+
+#[async_trait::async_trait]
+impl AuthProvider for ReplayAuth {
+    fn get_token(&self) -> SecretString {
+        SecretString::from("replay")
+    }
+
+    async fn ensure_valid_token(&self) -> Result<(), AuthError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,590 @@
+//! # Watch Module
+//!
+//! This module provides change watching for spreadsheets through Google Drive push
+//! notifications.
+//!
+//! ## Overview
+//!
+//! A watch registers a Drive `files.watch` channel for a spreadsheet. Drive then
+//! sends a notification to a webhook address owned by the application whenever the
+//! spreadsheet changes. The application forwards these notifications to a
+//! [`WatchNotifier`], and consumes them as a stream of [`ChangeEvent`]s from the
+//! [`ChangeWatcher`].
+//!
+//! Drive channels expire (after at most one day for files), so the watcher renews
+//! its channel shortly before expiration. Renewal is driven by polling the
+//! watcher, so the stream should be consumed continuously. Notifications from
+//! channels the watcher did not register are ignored.
+//!
+//! ## Requirements
+//!
+//! - The webhook address must be served over HTTPS with a valid certificate.
+//! - The access token needs a Drive scope, such as
+//!   [`DRIVE_READONLY_SCOPE`](crate::auth::service_account::DRIVE_READONLY_SCOPE).
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use futures::StreamExt;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! let mut watcher = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .watch("https://example.com/drive-notifications")
+//!     .start()
+//!     .await?;
+//!
+//! // Hand the notifier to the webhook handler, which calls
+//! // `notifier.notify_headers(request.headers())` for every incoming request.
+//! let notifier = watcher.notifier();
+//!
+//! while let Some(event) = watcher.next().await {
+//!     let event = event?;
+//!     if !event.is_sync() {
+//!         println!("Spreadsheet changed: {:?}", event.changed);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Polling Fallback
+//!
+//! Applications without a public HTTPS endpoint can poll instead. Without ranges,
+//! polling compares the Drive revision of the spreadsheet, which needs a Drive
+//! scope. With ranges, it compares the values of these ranges and reports the
+//! cells that changed, which only needs the Sheets scope:
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use futures::StreamExt;
+//! use gsheet_api::watch::PolledChange;
+//! use std::time::Duration;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! let changes = gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .poll_changes(Duration::from_secs(30))
+//!     .range("Orders!A1:F500")
+//!     .start();
+//! let mut changes = std::pin::pin!(changes);
+//!
+//! while let Some(change) = changes.next().await {
+//!     if let PolledChange::Values { range, changes } = change? {
+//!         println!("{} cells changed in {}", changes.len(), range);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod poll;
+
+pub use poll::{CellChange, PollChangesBuilder, PolledChange};
+
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+use crate::error::{GSheetError, decode_json};
+use crate::operations::spreadsheet::SpreadsheetOperations;
+
+/// Counter making channel IDs generated by this process unique.
+static CHANNEL_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Delay before retrying a failed channel renewal.
+const RENEWAL_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// A Drive notification channel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Channel {
+    /// The ID of the channel.
+    pub id: Option<String>,
+    /// An opaque ID identifying the watched resource.
+    pub resource_id: Option<String>,
+    /// A version-specific identifier for the watched resource.
+    pub resource_uri: Option<String>,
+    /// The token sent with every notification of the channel.
+    pub token: Option<String>,
+    /// The expiration time of the channel, in milliseconds since the Unix epoch.
+    pub expiration: Option<String>,
+    /// The address notifications are delivered to.
+    pub address: Option<String>,
+    /// The type of delivery mechanism used for the channel.
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+}
+
+impl Channel {
+    /// Returns the expiration time of the channel, if known.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expiration
+            .as_deref()
+            .and_then(|expiration| expiration.parse::<i64>().ok())
+            .and_then(DateTime::from_timestamp_millis)
+    }
+}
+
+/// The state of the watched resource reported by a notification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceState {
+    /// The channel was created. Sent once, before any change notification.
+    Sync,
+    /// The resource was created or shared.
+    Add,
+    /// The resource was deleted or unshared.
+    Remove,
+    /// One or more properties or the content of the resource changed.
+    Update,
+    /// The resource was moved to the trash.
+    Trash,
+    /// The resource was restored from the trash.
+    Untrash,
+    /// A state not known to this library.
+    Other(String),
+}
+
+impl From<&str> for ResourceState {
+    fn from(state: &str) -> Self {
+        match state {
+            "sync" => ResourceState::Sync,
+            "add" => ResourceState::Add,
+            "remove" => ResourceState::Remove,
+            "update" => ResourceState::Update,
+            "trash" => ResourceState::Trash,
+            "untrash" => ResourceState::Untrash,
+            other => ResourceState::Other(other.to_string()),
+        }
+    }
+}
+
+/// A change notification received on a watch channel.
+#[derive(Debug, Clone)]
+pub struct ChangeEvent {
+    /// The ID of the channel the notification was sent on.
+    pub channel_id: String,
+    /// An opaque ID identifying the watched resource.
+    pub resource_id: String,
+    /// A version-specific identifier for the watched resource.
+    pub resource_uri: Option<String>,
+    /// The state of the resource.
+    pub resource_state: ResourceState,
+    /// What changed for [`ResourceState::Update`] notifications
+    /// (e.g. "content", "properties", "permissions").
+    pub changed: Vec<String>,
+    /// The sequence number of the notification on its channel.
+    pub message_number: u64,
+    /// The expiration time of the channel.
+    pub channel_expiration: Option<DateTime<Utc>>,
+    /// The token the channel was registered with.
+    pub channel_token: Option<String>,
+}
+
+impl ChangeEvent {
+    /// Parses a notification from the headers of a Drive webhook request.
+    ///
+    /// # Arguments
+    /// * `headers` - The headers of the incoming notification request
+    ///
+    /// # Returns
+    /// A `Result` containing the [`ChangeEvent`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if a required `X-Goog-*` header is missing
+    /// or malformed.
+    pub fn from_headers(headers: &HeaderMap) -> Result<Self, GSheetError> {
+        let header = |name: &str| -> Option<String> {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_string())
+        };
+        let required = |name: &str| -> Result<String, GSheetError> {
+            header(name).ok_or_else(|| {
+                GSheetError::ResponseParseError(format!("Missing notification header {}", name))
+            })
+        };
+
+        let message_number = required("X-Goog-Message-Number")?
+            .parse::<u64>()
+            .map_err(|e| {
+                GSheetError::ResponseParseError(format!("Invalid X-Goog-Message-Number: {}", e))
+            })?;
+
+        Ok(ChangeEvent {
+            channel_id: required("X-Goog-Channel-ID")?,
+            resource_id: required("X-Goog-Resource-ID")?,
+            resource_uri: header("X-Goog-Resource-URI"),
+            resource_state: ResourceState::from(required("X-Goog-Resource-State")?.as_str()),
+            changed: header("X-Goog-Changed")
+                .map(|changed| changed.split(',').map(|c| c.trim().to_string()).collect())
+                .unwrap_or_default(),
+            message_number,
+            channel_expiration: header("X-Goog-Channel-Expiration")
+                .and_then(|expiration| DateTime::parse_from_rfc2822(&expiration).ok())
+                .map(|expiration| expiration.with_timezone(&Utc)),
+            channel_token: header("X-Goog-Channel-Token"),
+        })
+    }
+
+    /// Returns whether this is the sync message sent when a channel is created.
+    pub fn is_sync(&self) -> bool {
+        self.resource_state == ResourceState::Sync
+    }
+}
+
+/// Builder for configuring a change watch on a spreadsheet.
+#[derive(Clone)]
+pub struct WatchBuilder {
+    /// The spreadsheet to watch.
+    spreadsheet: SpreadsheetOperations,
+    /// The webhook address notifications are delivered to.
+    address: String,
+    /// The token sent with every notification, if any.
+    token: Option<String>,
+    /// The requested lifetime of each channel.
+    ttl: Duration,
+    /// How long before expiration a channel is renewed.
+    renew_before: Duration,
+    /// Timeout override for the registration requests.
+    timeout: Option<Duration>,
+}
+
+impl WatchBuilder {
+    /// Creates a new watch builder for the specified spreadsheet.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    /// * `address` - The HTTPS webhook address notifications are delivered to
+    ///
+    /// # Returns
+    /// A new [`WatchBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations, address: &str) -> Self {
+        Self {
+            spreadsheet: spreadsheet.clone(),
+            address: address.to_string(),
+            token: None,
+            ttl: Duration::from_secs(60 * 60),
+            renew_before: Duration::from_secs(5 * 60),
+            timeout: None,
+        }
+    }
+
+    /// Sets a token sent with every notification.
+    ///
+    /// Notifications carrying a different token are rejected by the
+    /// [`WatchNotifier`], which protects the webhook against forged requests.
+    ///
+    /// # Arguments
+    /// * `token` - An arbitrary string of up to 256 characters
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn token(mut self, token: &str) -> Self {
+        self.token = Some(token.to_string());
+        self
+    }
+
+    /// Sets the requested lifetime of each channel.
+    ///
+    /// Drive caps the lifetime of file channels at one day and defaults to one hour.
+    ///
+    /// # Arguments
+    /// * `ttl` - The requested channel lifetime
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets how long before expiration a channel is renewed.
+    ///
+    /// # Arguments
+    /// * `renew_before` - The renewal margin before channel expiration
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn renew_before(mut self, renew_before: Duration) -> Self {
+        self.renew_before = renew_before;
+        self
+    }
+
+    /// Sets the timeout of the registration requests, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of a request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Registers the channel and starts watching the spreadsheet.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`ChangeWatcher`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if:
+    /// - Authentication fails
+    /// - The HTTP request fails
+    /// - Drive rejects the webhook address or the access token lacks a Drive scope
+    pub async fn start(self) -> Result<ChangeWatcher, GSheetError> {
+        let channel = self.register().await?;
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let active = Arc::new(Mutex::new(HashSet::from_iter(channel.id.clone())));
+        let current = Arc::new(Mutex::new(channel));
+
+        let notifier = WatchNotifier {
+            sender: sender.clone(),
+            active: active.clone(),
+            token: self.token.clone(),
+        };
+
+        let renewal = Box::pin(renew_channels(
+            self.clone(),
+            current.clone(),
+            active,
+            sender,
+        ));
+
+        Ok(ChangeWatcher {
+            builder: self,
+            notifier,
+            receiver,
+            current,
+            renewal,
+        })
+    }
+
+    /// Registers a new channel for the spreadsheet.
+    async fn register(&self) -> Result<Channel, GSheetError> {
+        let gsheet_client = &self.spreadsheet.gsheet_client;
+        let url = gsheet_client.drive_file_url(&self.spreadsheet.spreadsheet_id, "/watch");
+        let expiration = Utc::now()
+            + chrono::Duration::from_std(self.ttl)
+                .map_err(|e| GSheetError::Other(format!("Invalid channel ttl: {}", e)))?;
+
+        let body = serde_json::json!({
+            "id": new_channel_id(),
+            "type": "web_hook",
+            "address": self.address,
+            "token": self.token,
+            "expiration": expiration.timestamp_millis().to_string(),
+        });
+
+        let request = gsheet_client
+            .request(reqwest::Method::POST, &url, self.timeout)
+            .await?
+            .json(&body);
+        let response = gsheet_client.send_read(request).await?;
+
+        if response.status().is_success() {
+            let channel: Channel = decode_json(response).await?;
+            Ok(channel)
+        } else {
+            Err(GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await)
+        }
+    }
+
+    /// Stops a channel so Drive no longer sends notifications on it.
+    async fn stop(&self, channel: &Channel) -> Result<(), GSheetError> {
+        let gsheet_client = &self.spreadsheet.gsheet_client;
+        let url = format!("{}/channels/stop", gsheet_client.drive_base_url);
+
+        let body = serde_json::json!({
+            "id": channel.id,
+            "resourceId": channel.resource_id,
+        });
+
+        let request = gsheet_client
+            .request(reqwest::Method::POST, &url, self.timeout)
+            .await?
+            .json(&body);
+        let response = gsheet_client.send_read(request).await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(GSheetError::from_response(response, &self.spreadsheet.spreadsheet_id).await)
+        }
+    }
+}
+
+/// Renews the watch channel shortly before each expiration, for as long as it is polled.
+///
+/// A new channel is registered before the old one is stopped, so no notification
+/// is lost during the handover. Renewal failures are reported on the event stream
+/// and retried.
+async fn renew_channels(
+    builder: WatchBuilder,
+    current: Arc<Mutex<Channel>>,
+    active: Arc<Mutex<HashSet<String>>>,
+    sender: mpsc::UnboundedSender<Result<ChangeEvent, GSheetError>>,
+) {
+    loop {
+        let expires_at = current.lock().ok().and_then(|channel| channel.expires_at());
+        let wait = expires_at
+            .and_then(|expires_at| (expires_at - Utc::now()).to_std().ok())
+            .map(|remaining| remaining.saturating_sub(builder.renew_before))
+            .unwrap_or(builder.ttl.saturating_sub(builder.renew_before));
+        tokio::time::sleep(wait).await;
+
+        let channel = match builder.register().await {
+            Ok(channel) => channel,
+            Err(e) => {
+                let _ = sender.send(Err(e));
+                tokio::time::sleep(RENEWAL_RETRY_DELAY).await;
+                continue;
+            }
+        };
+
+        if let (Some(id), Ok(mut active)) = (channel.id.clone(), active.lock()) {
+            active.insert(id);
+        }
+        let previous = match current.lock() {
+            Ok(mut current) => std::mem::replace(&mut *current, channel),
+            Err(_) => return,
+        };
+
+        if let Err(e) = builder.stop(&previous).await {
+            let _ = sender.send(Err(e));
+        }
+        if let (Some(id), Ok(mut active)) = (previous.id, active.lock()) {
+            active.remove(&id);
+        }
+    }
+}
+
+/// Generates a channel ID unique to this process.
+fn new_channel_id() -> String {
+    format!(
+        "gsheet-{}-{}",
+        Utc::now().timestamp_millis(),
+        CHANNEL_COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Handle forwarding webhook notifications to a [`ChangeWatcher`].
+///
+/// The notifier is cheap to clone and is meant to be shared with the HTTP
+/// handler serving the webhook address.
+#[derive(Clone)]
+pub struct WatchNotifier {
+    /// The sender feeding the event stream.
+    sender: mpsc::UnboundedSender<Result<ChangeEvent, GSheetError>>,
+    /// The IDs of the channels currently registered by the watcher.
+    active: Arc<Mutex<HashSet<String>>>,
+    /// The token notifications must carry, if any.
+    token: Option<String>,
+}
+
+impl WatchNotifier {
+    /// Forwards a notification to the watcher.
+    ///
+    /// # Arguments
+    /// * `event` - The parsed notification
+    ///
+    /// # Returns
+    /// `true` if the event was forwarded, or `false` if it was ignored because it
+    /// belongs to an unknown channel, carries the wrong token, or the watcher was
+    /// dropped.
+    pub fn notify(&self, event: ChangeEvent) -> bool {
+        let known = self
+            .active
+            .lock()
+            .map(|active| active.contains(&event.channel_id))
+            .unwrap_or(false);
+        if !known || event.channel_token != self.token {
+            return false;
+        }
+        self.sender.send(Ok(event)).is_ok()
+    }
+
+    /// Parses the headers of a webhook request and forwards the notification.
+    ///
+    /// # Arguments
+    /// * `headers` - The headers of the incoming notification request
+    ///
+    /// # Returns
+    /// A `Result` containing whether the event was forwarded, or a [`GSheetError`]
+    /// if the headers are not a valid notification.
+    pub fn notify_headers(&self, headers: &HeaderMap) -> Result<bool, GSheetError> {
+        Ok(self.notify(ChangeEvent::from_headers(headers)?))
+    }
+}
+
+/// A running change watch on a spreadsheet.
+///
+/// The watcher is a [`Stream`] of change events, including errors raised while
+/// renewing its channel. The channel is renewed while the stream is polled.
+/// Dropping the watcher stops the renewal, while [`stop`](ChangeWatcher::stop)
+/// also unregisters the channel from Drive.
+pub struct ChangeWatcher {
+    /// The configuration the watch was started with.
+    builder: WatchBuilder,
+    /// The notifier feeding this watcher.
+    notifier: WatchNotifier,
+    /// The receiving end of the event stream.
+    receiver: mpsc::UnboundedReceiver<Result<ChangeEvent, GSheetError>>,
+    /// The currently registered channel.
+    current: Arc<Mutex<Channel>>,
+    /// The renewal loop, driven by polling the stream.
+    renewal: Pin<Box<dyn Future<Output = ()> + Send>>,
+}
+
+impl ChangeWatcher {
+    /// Returns a notifier to be used by the webhook handler.
+    pub fn notifier(&self) -> WatchNotifier {
+        self.notifier.clone()
+    }
+
+    /// Returns the currently registered channel.
+    pub fn channel(&self) -> Option<Channel> {
+        self.current.lock().ok().map(|channel| channel.clone())
+    }
+
+    /// Stops watching and unregisters the current channel from Drive.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or a [`GSheetError`].
+    pub async fn stop(self) -> Result<(), GSheetError> {
+        let channel = self
+            .channel()
+            .ok_or_else(|| GSheetError::Other("Watch channel state is poisoned".into()))?;
+        self.builder.stop(&channel).await
+    }
+}
+
+impl Stream for ChangeWatcher {
+    type Item = Result<ChangeEvent, GSheetError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // The renewal loop never completes; polling it only advances the renewal.
+        let _ = self.renewal.as_mut().poll(cx);
+        self.receiver.poll_recv(cx)
+    }
+}
@@ -0,0 +1,316 @@
+//! Polling-based change detection.
+//!
+//! This module provides a fallback for applications that cannot host a webhook.
+//! A [`PollChangesBuilder`] periodically checks the spreadsheet and yields a
+//! [`PolledChange`] whenever something changed since the previous check.
+//!
+//! Two detection modes are supported:
+//!
+//! - **Revision** (default): compares the Drive `version` of the spreadsheet file,
+//!   which changes on every edit. This needs a Drive scope.
+//! - **Values**: when ranges are added, compares a content hash of their values
+//!   and reports the cells that changed. This only needs the Sheets scope.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use serde::Deserialize;
+use tokio::time::{Interval, MissedTickBehavior};
+
+use crate::client::url::QueryParams;
+use crate::error::{GSheetError, decode_json};
+use crate::models::{BatchValueRanges, ValueRange};
+use crate::operations::spreadsheet::SpreadsheetOperations;
+use crate::utils::{col_index_to_a1, parse_a1_cell, split_sheet_range};
+
+/// A change detected by polling.
+#[derive(Debug, Clone)]
+pub enum PolledChange {
+    /// The Drive revision of the spreadsheet changed.
+    Revision {
+        /// The version of the spreadsheet before the change.
+        previous_version: String,
+        /// The version of the spreadsheet after the change.
+        version: String,
+        /// The time of the last modification.
+        modified_time: Option<DateTime<Utc>>,
+    },
+    /// The values of a watched range changed.
+    Values {
+        /// The A1 notation range, as returned by the API.
+        range: String,
+        /// The cells whose value changed.
+        changes: Vec<CellChange>,
+    },
+}
+
+/// A single cell whose value changed between two polls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellChange {
+    /// The A1 address of the cell (e.g. "B3").
+    pub address: String,
+    /// The value before the change, or `None` if the cell was empty.
+    pub previous: Option<String>,
+    /// The value after the change, or `None` if the cell is now empty.
+    pub current: Option<String>,
+}
+
+/// File metadata returned by Drive, restricted to the fields used for polling.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileRevision {
+    version: Option<String>,
+    modified_time: Option<DateTime<Utc>>,
+}
+
+/// Builder for configuring polling-based change detection.
+pub struct PollChangesBuilder {
+    /// The spreadsheet to poll.
+    spreadsheet: SpreadsheetOperations,
+    /// The time between two polls.
+    interval: Duration,
+    /// The ranges whose values are compared, if any.
+    ranges: Vec<String>,
+    /// Timeout override for the polling requests.
+    timeout: Option<Duration>,
+}
+
+impl PollChangesBuilder {
+    /// Creates a new polling builder for the specified spreadsheet.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    /// * `interval` - The time between two polls
+    ///
+    /// # Returns
+    /// A new [`PollChangesBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations, interval: Duration) -> Self {
+        Self {
+            spreadsheet: spreadsheet.clone(),
+            interval,
+            ranges: Vec::new(),
+            timeout: None,
+        }
+    }
+
+    /// Adds a range whose values are compared between polls.
+    ///
+    /// Once a range is added, changes are detected from values instead of the
+    /// Drive revision.
+    ///
+    /// # Arguments
+    /// * `range` - The A1 notation range, including the sheet (e.g., "Sheet1!A1:D20")
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn range(mut self, range: &str) -> Self {
+        self.ranges.push(range.to_string());
+        self
+    }
+
+    /// Sets the timeout of the polling requests, overriding the client default.
+    ///
+    /// # Arguments
+    /// * `timeout` - The maximum duration of a request
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Starts polling and returns the stream of detected changes.
+    ///
+    /// The first poll only records the initial state. Errors are yielded as they
+    /// occur and polling continues on the next tick.
+    ///
+    /// # Returns
+    /// A stream of [`PolledChange`] results.
+    pub fn start(self) -> impl Stream<Item = Result<PolledChange, GSheetError>> {
+        let mut interval = tokio::time::interval(self.interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let state = PollState {
+            builder: self,
+            interval,
+            version: None,
+            hashes: HashMap::new(),
+            pending: VecDeque::new(),
+        };
+
+        futures::stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(item) = state.pending.pop_front() {
+                    return Some((item, state));
+                }
+                state.interval.tick().await;
+                match state.poll().await {
+                    Ok(changes) => state.pending.extend(changes.into_iter().map(Ok)),
+                    Err(e) => state.pending.push_back(Err(e)),
+                }
+            }
+        })
+    }
+}
+
+/// The state carried between two polls.
+struct PollState {
+    /// The polling configuration.
+    builder: PollChangesBuilder,
+    /// The polling timer.
+    interval: Interval,
+    /// The last seen Drive version.
+    version: Option<String>,
+    /// The last seen content hash and values of every watched range.
+    hashes: HashMap<String, (u64, ValueRange)>,
+    /// Items detected but not yet yielded.
+    pending: VecDeque<Result<PolledChange, GSheetError>>,
+}
+
+impl PollState {
+    /// Performs one poll and returns the changes since the previous one.
+    async fn poll(&mut self) -> Result<Vec<PolledChange>, GSheetError> {
+        if self.builder.ranges.is_empty() {
+            self.poll_revision().await
+        } else {
+            self.poll_values().await
+        }
+    }
+
+    /// Compares the Drive version of the spreadsheet file.
+    async fn poll_revision(&mut self) -> Result<Vec<PolledChange>, GSheetError> {
+        let gsheet_client = &self.builder.spreadsheet.gsheet_client;
+        let url = gsheet_client.drive_file_url(&self.builder.spreadsheet.spreadsheet_id, "");
+
+        let query = QueryParams::new().fields("version,modifiedTime");
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.builder.timeout)
+            .await?;
+        let response = gsheet_client.send(request).await?;
+
+        if !response.status().is_success() {
+            return Err(GSheetError::from_response(
+                response,
+                &self.builder.spreadsheet.spreadsheet_id,
+            )
+            .await);
+        }
+
+        let revision: FileRevision = decode_json(response).await?;
+        let version = revision.version.ok_or_else(|| {
+            GSheetError::ResponseParseError("File version missing from Drive response".into())
+        })?;
+
+        let previous_version = self.version.replace(version.clone());
+        Ok(match previous_version {
+            Some(previous_version) if previous_version != version => {
+                vec![PolledChange::Revision {
+                    previous_version,
+                    version,
+                    modified_time: revision.modified_time,
+                }]
+            }
+            _ => vec![],
+        })
+    }
+
+    /// Compares the content hash of every watched range.
+    async fn poll_values(&mut self) -> Result<Vec<PolledChange>, GSheetError> {
+        let gsheet_client = &self.builder.spreadsheet.gsheet_client;
+        let url = gsheet_client
+            .spreadsheet_url(&self.builder.spreadsheet.spreadsheet_id, "/values:batchGet");
+
+        // Polling talks to the API directly, as a cached read would hide changes.
+        let query = QueryParams::new().ranges(&self.builder.ranges);
+        let request = gsheet_client
+            .request(reqwest::Method::GET, &query.url(&url), self.builder.timeout)
+            .await?;
+
+        let response = gsheet_client.send(request).await?;
+        if !response.status().is_success() {
+            return Err(GSheetError::from_response(
+                response,
+                &self.builder.spreadsheet.spreadsheet_id,
+            )
+            .await);
+        }
+
+        let batch: BatchValueRanges = decode_json(response).await?;
+        let mut changes = Vec::new();
+
+        for (requested, value_range) in self.builder.ranges.iter().zip(batch.value_ranges) {
+            let hash = hash_values(&value_range);
+            let previous = self
+                .hashes
+                .insert(requested.clone(), (hash, value_range.clone()));
+
+            if let Some((previous_hash, previous_range)) = previous
+                && previous_hash != hash
+            {
+                changes.push(PolledChange::Values {
+                    range: value_range.range.clone().unwrap_or(requested.clone()),
+                    changes: diff_values(&previous_range, &value_range)?,
+                });
+            }
+        }
+
+        Ok(changes)
+    }
+}
+
+/// Computes the content hash of the values of a range.
+fn hash_values(value_range: &ValueRange) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value_range.values.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Lists the cells whose value differs between two reads of the same range.
+fn diff_values(
+    previous: &ValueRange,
+    current: &ValueRange,
+) -> Result<Vec<CellChange>, GSheetError> {
+    let range = current
+        .range
+        .as_deref()
+        .ok_or_else(|| GSheetError::UtilsError("ValueRange.range is None".into()))?;
+    let range = split_sheet_range(range)
+        .map(|(_, range)| range)
+        .unwrap_or(range);
+    let start = range.split(':').next().unwrap_or(range);
+    let (start_col, start_row) = parse_a1_cell(start)?;
+
+    let empty = Vec::new();
+    let previous_values = previous.values.as_ref().unwrap_or(&empty);
+    let current_values = current.values.as_ref().unwrap_or(&empty);
+
+    let mut changes = Vec::new();
+    for i in 0..previous_values.len().max(current_values.len()) {
+        let previous_row = previous_values.get(i);
+        let current_row = current_values.get(i);
+        let width = previous_row
+            .map_or(0, |r| r.len())
+            .max(current_row.map_or(0, |r| r.len()));
+
+        for j in 0..width {
+            let previous_value = previous_row
+                .and_then(|r| r.get(j))
+                .filter(|v| !v.is_empty());
+            let current_value = current_row.and_then(|r| r.get(j)).filter(|v| !v.is_empty());
+            if previous_value != current_value {
+                changes.push(CellChange {
+                    address: format!("{}{}", col_index_to_a1(start_col + j)?, start_row + i),
+                    previous: previous_value.cloned(),
+                    current: current_value.cloned(),
+                });
+            }
+        }
+    }
+
+    Ok(changes)
+}
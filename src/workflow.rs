@@ -0,0 +1,256 @@
+//! # Workflow Module
+//!
+//! This module provides [`Workflow`], which runs a sequence of operations on a
+//! spreadsheet and undoes the completed ones when a later step fails.
+//!
+//! ## Overview
+//!
+//! Building a spreadsheet usually takes several calls: structure changes such
+//! as adding sheets, then value writes. Each call is atomic on its own, but a
+//! failure halfway leaves a half-built spreadsheet. A workflow runs its steps
+//! in order; steps register rollback hooks through their [`WorkflowContext`] as
+//! they change the spreadsheet, and when a step fails the hooks of the steps
+//! that ran are called in reverse order before the error is returned.
+//!
+//! [`WorkflowContext::add_sheet`] adds a sheet and registers its deletion, which
+//! covers the common case of sheets created for the values written next.
+//! Rollback is best effort: a hook that fails does not stop the others, and its
+//! error is reported in [`GSheetError::WorkflowFailed`].
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gsheet_api::{auth::ServiceAccountAuthClient, client::GoogleSheetClient};
+//! # use std::sync::Arc;
+//! use gsheet_api::workflow::WorkflowContext;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let auth_client = Arc::new(ServiceAccountAuthClient::builder().service_account_path("").build().await.unwrap());
+//! # let gsheet_client = GoogleSheetClient::builder().auth_client(auth_client).build().unwrap();
+//!
+//! gsheet_client
+//!     .spreadsheet("spreadsheet-id")
+//!     .workflow()
+//!     .step("add sheet", |ctx: WorkflowContext| async move {
+//!         ctx.add_sheet("Q3 Report").await?;
+//!         Ok(())
+//!     })
+//!     .step("write values", |ctx: WorkflowContext| async move {
+//!         ctx.spreadsheet()
+//!             .sheet("Q3 Report")
+//!             .update_from("A1", vec![vec!["Region".to_string(), "Revenue".to_string()]])
+//!             .execute()
+//!             .await?;
+//!         Ok(())
+//!     })
+//!     .build()?
+//!     .execute()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+use futures::future::BoxFuture;
+
+use crate::error::GSheetError;
+use crate::models::{DeleteSheetRequest, Request, SheetProperties};
+use crate::operations::spreadsheet::SpreadsheetOperations;
+
+/// The action of a workflow step.
+type StepFn =
+    Arc<dyn Fn(WorkflowContext) -> BoxFuture<'static, Result<(), GSheetError>> + Send + Sync>;
+
+/// A rollback hook, undoing the changes of a step.
+type RollbackFn =
+    Box<dyn FnOnce(SpreadsheetOperations) -> BoxFuture<'static, Result<(), GSheetError>> + Send>;
+
+/// A named step of a workflow.
+#[derive(Clone)]
+struct Step {
+    /// The name of the step, reported when it fails.
+    name: String,
+    /// The action of the step.
+    run: StepFn,
+}
+
+/// The handle given to workflow steps, for reaching the spreadsheet and
+/// registering rollback hooks.
+#[derive(Clone)]
+pub struct WorkflowContext {
+    /// The spreadsheet the workflow changes.
+    spreadsheet: SpreadsheetOperations,
+    /// The rollback hooks registered so far, with their names, in order.
+    rollbacks: Arc<Mutex<Vec<(String, RollbackFn)>>>,
+}
+
+impl WorkflowContext {
+    /// Returns the spreadsheet the workflow changes.
+    pub fn spreadsheet(&self) -> &SpreadsheetOperations {
+        &self.spreadsheet
+    }
+
+    /// Registers a hook undoing a change, called if a later step fails.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the hook, reported if it fails
+    /// * `rollback` - The hook, given the spreadsheet
+    pub fn on_rollback<F, Fut>(&self, name: &str, rollback: F)
+    where
+        F: FnOnce(SpreadsheetOperations) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), GSheetError>> + Send + 'static,
+    {
+        let rollback: RollbackFn = Box::new(move |spreadsheet| Box::pin(rollback(spreadsheet)));
+        self.rollbacks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((name.to_string(), rollback));
+    }
+
+    /// Adds a sheet and registers its deletion as rollback hook.
+    ///
+    /// # Arguments
+    /// * `title` - The title of the new sheet
+    ///
+    /// # Returns
+    /// A `Result` containing the properties of the new sheet or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the sheet cannot be added, such as
+    /// when a sheet with the same title exists.
+    pub async fn add_sheet(&self, title: &str) -> Result<SheetProperties, GSheetError> {
+        let properties = self.spreadsheet.add_sheet(title).build()?.execute().await?;
+        if let Some(sheet_id) = properties.sheet_id {
+            self.on_rollback(
+                &format!("delete sheet '{}'", title),
+                move |spreadsheet| async move {
+                    spreadsheet
+                        .batch_update()
+                        .request(Request::DeleteSheet(DeleteSheetRequest { sheet_id }))
+                        .build()?
+                        .execute()
+                        .await?;
+                    Ok(())
+                },
+            );
+        }
+        Ok(properties)
+    }
+}
+
+/// Builder for configuring a [`Workflow`].
+#[derive(Clone, Default)]
+pub struct WorkflowBuilder {
+    /// The spreadsheet the workflow changes.
+    spreadsheet: Option<SpreadsheetOperations>,
+    /// The steps, in order.
+    steps: Vec<Step>,
+}
+
+impl WorkflowBuilder {
+    /// Creates a new builder for a workflow on a spreadsheet.
+    ///
+    /// # Arguments
+    /// * `spreadsheet` - The spreadsheet operations instance
+    ///
+    /// # Returns
+    /// A new [`WorkflowBuilder`] instance.
+    pub fn new(spreadsheet: &SpreadsheetOperations) -> Self {
+        Self {
+            spreadsheet: Some(spreadsheet.clone()),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Appends a step to the workflow.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the step, reported if it fails
+    /// * `step` - The action of the step, given the workflow context
+    ///
+    /// # Returns
+    /// The builder instance for method chaining.
+    pub fn step<F, Fut>(mut self, name: &str, step: F) -> Self
+    where
+        F: Fn(WorkflowContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), GSheetError>> + Send + 'static,
+    {
+        self.steps.push(Step {
+            name: name.to_string(),
+            run: Arc::new(move |ctx| Box::pin(step(ctx))),
+        });
+        self
+    }
+
+    /// Builds the [`Workflow`] instance.
+    ///
+    /// # Returns
+    /// A `Result` containing the configured [`Workflow`] or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return an error if the spreadsheet is not set or if the
+    /// workflow has no step.
+    pub fn build(self) -> Result<Workflow, GSheetError> {
+        let spreadsheet = self.spreadsheet.ok_or_else(|| {
+            GSheetError::Other("SpreadsheetOperations is required to build Workflow".into())
+        })?;
+        if self.steps.is_empty() {
+            return Err(GSheetError::ValidationError(
+                "A workflow needs at least one step".into(),
+            ));
+        }
+
+        Ok(Workflow {
+            spreadsheet,
+            steps: self.steps,
+        })
+    }
+}
+
+/// A sequence of steps on a spreadsheet, rolled back when a step fails.
+#[derive(Clone)]
+pub struct Workflow {
+    /// The spreadsheet the workflow changes.
+    spreadsheet: SpreadsheetOperations,
+    /// The steps, in order.
+    steps: Vec<Step>,
+}
+
+impl Workflow {
+    /// Runs the steps in order, rolling back the completed changes if a step
+    /// fails.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or a [`GSheetError`].
+    ///
+    /// # Errors
+    /// This method will return a [`GSheetError::WorkflowFailed`] naming the
+    /// failed step, with its error and the errors of failed rollback hooks.
+    pub async fn execute(&self) -> Result<(), GSheetError> {
+        let ctx = WorkflowContext {
+            spreadsheet: self.spreadsheet.clone(),
+            rollbacks: Arc::new(Mutex::new(Vec::new())),
+        };
+
+        for step in &self.steps {
+            let Err(error) = (step.run)(ctx.clone()).await else {
+                continue;
+            };
+
+            let rollbacks =
+                std::mem::take(&mut *ctx.rollbacks.lock().unwrap_or_else(|e| e.into_inner()));
+            let mut rollback_errors = Vec::new();
+            for (name, rollback) in rollbacks.into_iter().rev() {
+                if let Err(e) = rollback(self.spreadsheet.clone()).await {
+                    rollback_errors.push(format!("{}: {}", name, e));
+                }
+            }
+            return Err(GSheetError::WorkflowFailed {
+                step: step.name.clone(),
+                source: Box::new(error),
+                rollback_errors,
+            });
+        }
+        Ok(())
+    }
+}
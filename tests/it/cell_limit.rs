@@ -0,0 +1,70 @@
+//! Cell limit checks of the operations that grow a spreadsheet, served by a
+//! local stub of the Sheets API.
+
+use crate::stub_server;
+use gsheet_api::client::GoogleSheetClient;
+use gsheet_api::error::GSheetError;
+use gsheet_api::models::{DuplicateSheetRequest, Request, ShiftDimension};
+use gsheet_api::vcr::ReplayAuth;
+use std::sync::Arc;
+
+/// A spreadsheet with a single sheet of 6 million cells.
+const SPREADSHEET: &str = r#"{"spreadsheetId": "spreadsheet-id", "properties": {"title": "Limits"}, "sheets": [{"properties": {"sheetId": 0, "title": "Data", "gridProperties": {"rowCount": 6000000, "columnCount": 1}}}]}"#;
+
+fn client(base_url: &str) -> GoogleSheetClient {
+    GoogleSheetClient::builder()
+        .auth_client(Arc::new(ReplayAuth))
+        .api_base_url(base_url)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn duplicate_sheet_over_cell_limit_is_rejected() {
+    let (base_url, received) = stub_server(vec![SPREADSHEET]).await;
+
+    let result = client(&base_url)
+        .spreadsheet("spreadsheet-id")
+        .batch_update()
+        .request(Request::DuplicateSheet(DuplicateSheetRequest {
+            source_sheet_id: 0,
+            ..Default::default()
+        }))
+        .build()
+        .unwrap()
+        .execute()
+        .await;
+
+    assert!(
+        matches!(&result, Err(GSheetError::ValidationError(message)) if message.contains("limit")),
+        "{:?}",
+        result
+    );
+    let received = received.lock().unwrap().clone();
+    assert_eq!(received.len(), 1, "{:?}", received);
+    assert!(received[0].starts_with("GET "), "{:?}", received);
+}
+
+#[tokio::test]
+async fn insert_range_over_cell_limit_is_rejected() {
+    let (base_url, received) = stub_server(vec![SPREADSHEET, SPREADSHEET]).await;
+
+    let result = client(&base_url)
+        .spreadsheet("spreadsheet-id")
+        .sheet("Data")
+        .insert_range("A1:A5000000", ShiftDimension::Rows)
+        .execute()
+        .await;
+
+    assert!(
+        matches!(&result, Err(GSheetError::ValidationError(message)) if message.contains("limit")),
+        "{:?}",
+        result
+    );
+    let received = received.lock().unwrap().clone();
+    assert!(
+        received.iter().all(|line| line.starts_with("GET ")),
+        "{:?}",
+        received
+    );
+}
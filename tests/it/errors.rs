@@ -0,0 +1,28 @@
+//! Errors of malformed responses, replayed from cassettes.
+
+use gsheet_api::error::GSheetError;
+
+use crate::replay;
+
+#[tokio::test]
+async fn malformed_response_names_request_and_field() {
+    let gsheet_client = replay("malformed_values.json");
+    let error = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Orders")
+        .get_all_value()
+        .execute()
+        .await
+        .expect_err("values is not an array");
+
+    let context = error.context().expect("request context");
+    assert_eq!(context.method, "GET");
+    assert_eq!(context.spreadsheet_id.as_deref(), Some("spreadsheet-id"));
+    assert_eq!(context.ranges, ["Orders"]);
+
+    let GSheetError::ResponseParseError(message) = error.without_context() else {
+        panic!("expected a parse error, got {:?}", error);
+    };
+    assert!(message.contains("path 'values'"), "{}", message);
+    assert!(message.contains("\"values\": 42"), "{}", message);
+}
@@ -0,0 +1,93 @@
+//! Golden-file tests: responses of `spreadsheets.get` must survive a round trip
+//! through the models without losing or renaming a field.
+
+use gsheet_api::models::Spreadsheet;
+use serde_json::Value;
+
+use crate::fixture;
+
+/// Deserializes a golden file into a [`Spreadsheet`] and serializes it back,
+/// failing on the first field that differs.
+fn assert_round_trip(name: &str) {
+    let original: Value = serde_json::from_str(&fixture(&format!("golden/{}", name))).unwrap();
+    let spreadsheet: Spreadsheet = serde_json::from_value(original.clone())
+        .unwrap_or_else(|e| panic!("{}: cannot deserialize: {}", name, e));
+    let round_tripped = serde_json::to_value(&spreadsheet).unwrap();
+
+    if let Some(difference) = difference("$", &normalize(original), &normalize(round_tripped)) {
+        panic!("{}: {}", name, difference);
+    }
+}
+
+/// Drops null fields and turns every number into a float, since the models
+/// write absent fields as null and integral floats as `1.0`.
+fn normalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, normalize(value)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(normalize).collect()),
+        Value::Number(number) => number
+            .as_f64()
+            .and_then(serde_json::Number::from_f64)
+            .map_or(Value::Number(number), Value::Number),
+        value => value,
+    }
+}
+
+/// Describes the first difference between two values, by JSON path.
+fn difference(path: &str, expected: &Value, actual: &Value) -> Option<String> {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, value) in expected {
+                let path = format!("{}.{}", path, key);
+                match actual.get(key) {
+                    Some(other) => {
+                        if let Some(difference) = difference(&path, value, other) {
+                            return Some(difference);
+                        }
+                    }
+                    None => return Some(format!("{} was dropped", path)),
+                }
+            }
+            actual
+                .keys()
+                .find(|key| !expected.contains_key(*key))
+                .map(|key| format!("{}.{} was added", path, key))
+        }
+        (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+            expected
+                .iter()
+                .zip(actual)
+                .enumerate()
+                .find_map(|(index, (expected, actual))| {
+                    difference(&format!("{}[{}]", path, index), expected, actual)
+                })
+        }
+        _ if expected == actual => None,
+        _ => Some(format!("{} changed from {} to {}", path, expected, actual)),
+    }
+}
+
+#[test]
+fn charts() {
+    assert_round_trip("charts.json");
+}
+
+#[test]
+fn pivot_tables() {
+    assert_round_trip("pivot_tables.json");
+}
+
+#[test]
+fn data_sources() {
+    assert_round_trip("data_sources.json");
+}
+
+#[test]
+fn tables() {
+    assert_round_trip("tables.json");
+}
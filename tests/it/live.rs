@@ -0,0 +1,122 @@
+//! End-to-end tests against a sandbox spreadsheet.
+
+use std::sync::Arc;
+
+use gsheet_api::auth::ServiceAccountAuthClient;
+use gsheet_api::client::GoogleSheetClient;
+use gsheet_api::error::GSheetError;
+use gsheet_api::models::{DeleteSheetRequest, Request};
+use gsheet_api::operations::spreadsheet::SpreadsheetOperations;
+
+/// Returns the operations of the sandbox spreadsheet, or `None` when the
+/// environment does not configure one.
+async fn sandbox() -> Option<SpreadsheetOperations> {
+    let (Ok(key_path), Ok(spreadsheet_id)) = (
+        std::env::var("GSHEET_IT_SERVICE_ACCOUNT"),
+        std::env::var("GSHEET_IT_SPREADSHEET_ID"),
+    ) else {
+        eprintln!("GSHEET_IT_SERVICE_ACCOUNT or GSHEET_IT_SPREADSHEET_ID not set, skipping");
+        return None;
+    };
+
+    let auth_client = ServiceAccountAuthClient::builder()
+        .service_account_path(&key_path)
+        .build()
+        .await
+        .expect("service account authentication");
+    let gsheet_client = GoogleSheetClient::builder()
+        .auth_client(Arc::new(auth_client))
+        .build()
+        .expect("client");
+    Some(gsheet_client.spreadsheet(&spreadsheet_id))
+}
+
+/// Returns a sheet title unique to this run.
+fn scratch_title() -> String {
+    format!("it-{}", chrono::Utc::now().format("%Y%m%d-%H%M%S-%f"))
+}
+
+#[tokio::test]
+async fn create_write_read_format_delete() {
+    let Some(spreadsheet) = sandbox().await else {
+        return;
+    };
+    let title = scratch_title();
+
+    let properties = spreadsheet
+        .add_sheet(&title)
+        .rows(20)
+        .columns(5)
+        .build()
+        .unwrap()
+        .execute()
+        .await
+        .expect("add sheet");
+    let sheet_id = properties.sheet_id.expect("sheet ID");
+
+    // The sheet is deleted whatever the outcome of the steps.
+    let outcome = exercise(&spreadsheet, &title).await;
+    let deleted = spreadsheet
+        .batch_update()
+        .request(Request::DeleteSheet(DeleteSheetRequest { sheet_id }))
+        .build()
+        .unwrap()
+        .execute()
+        .await;
+
+    outcome.expect("steps");
+    deleted.expect("delete sheet");
+    assert!(!spreadsheet.sheet_exists(&title).await.unwrap());
+}
+
+/// Writes, reads and formats the scratch sheet.
+async fn exercise(spreadsheet: &SpreadsheetOperations, title: &str) -> Result<(), GSheetError> {
+    let sheet = spreadsheet.sheet(title);
+    let values = vec![
+        vec!["Name".to_string(), "Age".to_string()],
+        vec!["Ada".to_string(), "36".to_string()],
+        vec!["Alan".to_string(), "41".to_string()],
+    ];
+
+    let written = sheet.update_from("A1", values.clone()).execute().await?;
+    assert_eq!(written.updated_cells, 6);
+
+    let read = sheet.get_all_value().use_cache(false).execute().await?;
+    assert_eq!(read.values, Some(values));
+
+    let cells = sheet.get_all_cell().use_cache(false).execute().await?;
+    assert_eq!(cells.len(), 6);
+
+    sheet.style_as_table("A1:B3").execute().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_empty_sheet() {
+    let Some(spreadsheet) = sandbox().await else {
+        return;
+    };
+    let title = scratch_title();
+
+    let properties = spreadsheet
+        .add_sheet(&title)
+        .build()
+        .unwrap()
+        .execute()
+        .await
+        .expect("add sheet");
+
+    let cells = spreadsheet.sheet(&title).get_all_cell().execute().await;
+    spreadsheet
+        .batch_update()
+        .request(Request::DeleteSheet(DeleteSheetRequest {
+            sheet_id: properties.sheet_id.expect("sheet ID"),
+        }))
+        .build()
+        .unwrap()
+        .execute()
+        .await
+        .expect("delete sheet");
+
+    assert!(cells.expect("read").is_empty());
+}
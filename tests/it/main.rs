@@ -0,0 +1,109 @@
+//! Integration tests.
+//!
+//! The `models` tests deserialize fixtures of API responses and run offline.
+//! The `errors` and `requests` tests replay cassettes, of malformed responses
+//! and of the exact URLs sent; they are compiled with the `vcr` feature, as are
+//! the `cell_limit` and `watch` tests, served by a local stub of the API.
+//! The `live` tests exercise a real spreadsheet; they are compiled with the
+//! `integration` feature and skipped unless the sandbox is configured:
+//!
+//! ```text
+//! GSHEET_IT_SERVICE_ACCOUNT=path/to/key.json \
+//! GSHEET_IT_SPREADSHEET_ID=spreadsheet-id \
+//! cargo test --features integration --test it
+//! ```
+//!
+//! The service account must be an editor of the sandbox spreadsheet. Every run
+//! works on a sheet of its own, deleted at the end of the run.
+
+#[cfg(feature = "vcr")]
+mod cell_limit;
+#[cfg(feature = "vcr")]
+mod errors;
+mod golden;
+#[cfg(feature = "integration")]
+mod live;
+mod models;
+#[cfg(feature = "vcr")]
+mod requests;
+#[cfg(feature = "vcr")]
+mod watch;
+
+/// Reads a fixture of `tests/it/fixtures`.
+pub fn fixture(name: &str) -> String {
+    let path = format!("{}/tests/it/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("cannot read {}: {}", path, e))
+}
+
+/// Builds a client replaying a cassette of `tests/it/fixtures/cassettes`.
+#[cfg(feature = "vcr")]
+pub fn replay(name: &str) -> gsheet_api::client::GoogleSheetClient {
+    use gsheet_api::vcr::{Cassette, ReplayAuth, VcrMode};
+    use std::sync::Arc;
+
+    let path = format!(
+        "{}/tests/it/fixtures/cassettes/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    );
+    let cassette = Cassette::open(path, VcrMode::Replay).expect("cassette");
+    gsheet_api::client::GoogleSheetClient::builder()
+        .auth_client(Arc::new(ReplayAuth))
+        .cassette(Arc::new(cassette))
+        .build()
+        .expect("client")
+}
+
+/// Serves the responses in order on a local port, one per connection, and
+/// returns its base URL along with the request lines received so far.
+#[cfg(feature = "vcr")]
+pub async fn stub_server(
+    responses: Vec<&'static str>,
+) -> (String, std::sync::Arc<std::sync::Mutex<Vec<String>>>) {
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("local port");
+    let base_url = format!("http://{}", listener.local_addr().expect("address"));
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let log = received.clone();
+
+    tokio::spawn(async move {
+        for body in responses {
+            let Ok((stream, _)) = listener.accept().await else {
+                return;
+            };
+            let mut stream = BufReader::new(stream);
+            let mut request_line = String::new();
+            let _ = stream.read_line(&mut request_line).await;
+            let mut length = 0;
+            loop {
+                let mut header = String::new();
+                if stream.read_line(&mut header).await.unwrap_or(0) == 0 || header == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    length = value.trim().parse().unwrap_or(0);
+                }
+            }
+            let mut content = vec![0; length];
+            let _ = stream.read_exact(&mut content).await;
+            log.lock()
+                .unwrap()
+                .push(request_line.trim_end().to_string());
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = stream.get_mut().write_all(response.as_bytes()).await;
+        }
+    });
+
+    (base_url, received)
+}
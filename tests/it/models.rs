@@ -0,0 +1,170 @@
+//! Offline deserialization of API responses into the models.
+
+use gsheet_api::models::{
+    AppendValuesResponse, BatchUpdateSpreadsheetResponse, BatchUpdateValuesResponse,
+    BatchValueRanges, Dimension, Response, Spreadsheet, UpdateValuesResponse, ValueRange,
+};
+use gsheet_api::utils::value_range_to_cells;
+
+use crate::fixture;
+
+fn parse<T: serde::de::DeserializeOwned>(name: &str) -> T {
+    serde_json::from_str(&fixture(name)).unwrap_or_else(|e| panic!("{}: {}", name, e))
+}
+
+#[test]
+fn spreadsheet_get() {
+    let spreadsheet: Spreadsheet = parse("spreadsheet_get.json");
+
+    let properties = spreadsheet.properties.unwrap();
+    assert_eq!(properties.title.as_deref(), Some("Class Data"));
+    assert_eq!(properties.time_zone.as_deref(), Some("America/Los_Angeles"));
+    let theme = properties.spreadsheet_theme.unwrap();
+    assert_eq!(theme.theme_colors.unwrap().len(), 4);
+
+    let sheets = spreadsheet.sheets.unwrap();
+    assert_eq!(sheets.len(), 2);
+    let class_data = &sheets[0];
+    let grid = class_data
+        .properties
+        .as_ref()
+        .and_then(|properties| properties.grid_properties.as_ref())
+        .unwrap();
+    assert_eq!(grid.row_count, Some(101));
+    assert_eq!(grid.frozen_row_count, Some(1));
+    assert_eq!(class_data.merges.as_ref().unwrap().len(), 1);
+    let protected = &class_data.protected_ranges.as_ref().unwrap()[0];
+    assert_eq!(protected.warning_only, Some(true));
+    assert!(class_data.basic_filter.is_some());
+    assert_eq!(
+        sheets[1].properties.as_ref().and_then(|p| p.hidden),
+        Some(true)
+    );
+
+    let named_ranges = spreadsheet.named_ranges.unwrap();
+    assert_eq!(named_ranges[0].name.as_deref(), Some("Students"));
+}
+
+#[test]
+fn spreadsheet_get_grid_data() {
+    let spreadsheet: Spreadsheet = parse("spreadsheet_get_grid_data.json");
+
+    let sheets = spreadsheet.sheets.unwrap();
+    let grid = &sheets[0].data.as_ref().unwrap()[0];
+    let rows = grid.row_data.as_ref().unwrap();
+    assert_eq!(rows.len(), 3);
+
+    let header = rows[0].values.as_ref().unwrap();
+    assert_eq!(header[0].formatted_value.as_deref(), Some("Student Name"));
+    assert_eq!(header[0].note.as_deref(), Some("One row per student"));
+    let date = header[1].effective_value.as_ref().unwrap();
+    assert_eq!(date.number_value, Some(45292.0));
+
+    let row = rows[1].values.as_ref().unwrap();
+    assert_eq!(
+        row[1]
+            .user_entered_value
+            .as_ref()
+            .and_then(|value| value.formula_value.as_deref()),
+        Some("=1/0")
+    );
+    assert!(
+        row[1]
+            .effective_value
+            .as_ref()
+            .unwrap()
+            .error_value
+            .is_some()
+    );
+    assert_eq!(
+        row[2].effective_value.as_ref().unwrap().bool_value,
+        Some(true)
+    );
+    assert!(rows[2].values.is_none());
+
+    let row_metadata = grid.row_metadata.as_ref().unwrap();
+    assert_eq!(row_metadata[2].hidden_by_user, Some(true));
+}
+
+#[test]
+fn values_get() {
+    let value_range: ValueRange = parse("values_get.json");
+
+    assert!(matches!(value_range.major_dimension, Some(Dimension::Rows)));
+    let values = value_range.values.as_ref().unwrap();
+    assert_eq!(values.len(), 4);
+    // Trailing empty cells are omitted by the API.
+    assert_eq!(values[3].len(), 5);
+
+    let cells = value_range_to_cells("id", "Class Data", &value_range).unwrap();
+    assert_eq!(cells.len(), 23);
+}
+
+#[test]
+fn values_get_empty() {
+    let value_range: ValueRange = parse("values_get_empty.json");
+
+    assert!(value_range.is_empty());
+    let cells = value_range_to_cells("id", "Sheet1", &value_range).unwrap();
+    assert!(cells.is_empty());
+}
+
+#[test]
+fn values_batch_get() {
+    let batch: BatchValueRanges = parse("values_batch_get.json");
+
+    assert_eq!(batch.len(), 2);
+    let column = &batch.value_ranges[0];
+    assert!(matches!(column.major_dimension, Some(Dimension::Columns)));
+    assert_eq!(column.to_rows()[2], vec!["Andrew".to_string()]);
+    assert!(batch.value_ranges[1].is_empty());
+}
+
+#[test]
+fn values_update() {
+    let response: UpdateValuesResponse = parse("values_update.json");
+
+    assert_eq!(response.updated_range, "'Class Data'!A2:B3");
+    assert_eq!(response.updated_cells, 4);
+    assert_eq!(response.updated_data.unwrap().values.unwrap().len(), 2);
+}
+
+#[test]
+fn values_batch_update() {
+    let response: BatchUpdateValuesResponse = parse("values_batch_update.json");
+
+    assert_eq!(response.total_updated_cells, 5);
+    assert_eq!(response.responses.len(), 2);
+    assert_eq!(response.responses[1].updated_range, "'Class Data'!D5");
+}
+
+#[test]
+fn values_append() {
+    let response: AppendValuesResponse = parse("values_append.json");
+
+    assert_eq!(response.table_range.as_deref(), Some("'Class Data'!A1:F31"));
+    assert_eq!(response.updates.updated_range, "'Class Data'!A32:B32");
+}
+
+#[test]
+fn batch_update() {
+    let response: BatchUpdateSpreadsheetResponse = parse("batch_update.json");
+
+    let replies = &response.replies;
+    assert_eq!(replies.len(), 5);
+    match &replies[0] {
+        Response::AddSheet(reply) => {
+            let properties = reply.properties.as_ref().unwrap();
+            assert_eq!(properties.title.as_deref(), Some("Archive"));
+        }
+        other => panic!("unexpected reply {:?}", other),
+    }
+    assert!(replies[1].is_empty());
+    match &replies[2] {
+        Response::FindReplace(reply) => assert_eq!(reply.occurrences_changed, Some(3)),
+        other => panic!("unexpected reply {:?}", other),
+    }
+    assert!(matches!(replies[3], Response::AddProtectedRange(_)));
+    // Replies of unknown kinds are kept rather than failing the whole response.
+    assert!(matches!(replies[4], Response::Other(_)));
+}
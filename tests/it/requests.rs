@@ -0,0 +1,391 @@
+//! URLs of requests, replayed from cassettes that only match the exact URL.
+
+use crate::replay;
+
+#[tokio::test]
+async fn batch_get_encodes_quoted_titles_in_query() {
+    let gsheet_client = replay("batch_get_quoted_title.json");
+    let batch = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Q1 Sales")
+        .batch_get_value_range()
+        .range("A1:B2")
+        .range("D:D")
+        .execute()
+        .await
+        .expect("ranges are encoded as recorded");
+
+    assert_eq!(batch.value_ranges.len(), 2);
+    assert_eq!(
+        batch.value_ranges[0].range.as_deref(),
+        Some("'Q1 Sales'!A1:B2")
+    );
+}
+
+#[tokio::test]
+async fn values_get_encodes_title_in_path() {
+    let gsheet_client = replay("values_get_special_title.json");
+    let values = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Café #2/Q1")
+        .get_all_value()
+        .execute()
+        .await
+        .expect("title is encoded as recorded");
+
+    assert_eq!(values.values.as_ref().map(Vec::len), Some(2));
+}
+
+#[tokio::test]
+async fn cell_map_fills_merged_regions() {
+    use gsheet_api::models::CellAddress;
+
+    let gsheet_client = replay("cell_map_fill_merges.json");
+    let cells = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Report")
+        .get_cell_map()
+        .fill_merges(true)
+        .execute()
+        .await
+        .expect("values and merges are read as recorded");
+
+    let value = |row, col| cells[&CellAddress::new(row, col)].value.as_deref();
+    assert_eq!(value(1, 2), Some("Region"));
+    assert_eq!(value(3, 1), Some("North"));
+    assert_eq!(cells.len(), 9);
+}
+
+#[tokio::test]
+async fn cells_skip_hidden_rows_and_columns() {
+    let gsheet_client = replay("cells_skip_hidden.json");
+    let cells = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Report")
+        .get_all_cell()
+        .skip_hidden(true)
+        .execute()
+        .await
+        .expect("values and dimensions are read as recorded");
+
+    let addresses: Vec<_> = cells.iter().map(|cell| cell.address.to_string()).collect();
+    assert_eq!(addresses, ["A1", "C1", "A3", "C3"]);
+}
+
+#[tokio::test]
+async fn export_csv_reproduces_filtered_view() {
+    let gsheet_client = replay("export_csv_visible_only.json");
+    let csv = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Orders")
+        .export_csv()
+        .visible_only(true)
+        .execute()
+        .await
+        .expect("values and dimensions are read as recorded");
+
+    assert_eq!(csv, "Customer,Total\nAda,42\n\"Eve, Inc.\",8\n");
+}
+
+#[tokio::test]
+async fn clear_posts_to_sheet_range() {
+    let gsheet_client = replay("values_clear.json");
+    let response = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Q1 Staging")
+        .clear()
+        .execute()
+        .await
+        .expect("sheet is cleared as recorded");
+
+    assert_eq!(response.cleared_range, "'Q1 Staging'!A1:Z1000");
+}
+
+#[tokio::test]
+async fn resize_refuses_to_delete_values() {
+    let gsheet_client = replay("resize_over_values.json");
+    let error = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Staging")
+        .resize(100, 2)
+        .execute()
+        .await
+        .expect_err("column C holds a value");
+
+    assert!(error.to_string().contains("up to C3"), "{}", error);
+}
+
+#[tokio::test]
+async fn find_tagged_rows_follows_metadata() {
+    use gsheet_api::models::RowIndex;
+
+    let gsheet_client = replay("find_tagged_rows.json");
+    let rows = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Orders")
+        .find_tagged_rows("order-id", "A-1042")
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(rows, vec![RowIndex::new(7), RowIndex::new(12)]);
+}
+
+#[tokio::test]
+async fn find_tagged_rows_is_a_read() {
+    use gsheet_api::models::RowIndex;
+
+    let gsheet_client = replay("find_tagged_rows.json").with_read_only(true);
+    let rows = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Orders")
+        .find_tagged_rows("order-id", "A-1042")
+        .execute()
+        .await
+        .expect("looking up tagged rows is allowed on a read-only client");
+
+    assert_eq!(rows, vec![RowIndex::new(7), RowIndex::new(12)]);
+}
+
+#[tokio::test]
+async fn values_by_metadata_reads_tagged_ranges() {
+    let gsheet_client = replay("values_by_metadata.json");
+    let batch = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .get_values_by_metadata("config", "thresholds")
+        .build()
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(batch.len(), 1);
+    assert_eq!(
+        batch.value_ranges[0].range.as_deref(),
+        Some("Settings!A4:Z5")
+    );
+    assert_eq!(
+        batch.value_ranges[0].values,
+        Some(vec![
+            vec!["warn".to_string(), "80".to_string()],
+            vec!["fail".to_string(), "95".to_string()],
+        ])
+    );
+}
+
+#[tokio::test]
+async fn values_by_data_filter_accepts_ranges_and_grid_ranges() {
+    use gsheet_api::models::GridRange;
+
+    let gsheet_client = replay("values_by_data_filter.json");
+    let batch = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .get_values_by_data_filter()
+        .filter("Settings!A1:B2")
+        .filter(GridRange {
+            sheet_id: Some(7),
+            start_row_index: Some(0),
+            end_row_index: Some(1),
+            ..Default::default()
+        })
+        .build()
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(batch.len(), 2);
+    assert_eq!(batch.value_ranges[1].range.as_deref(), Some("Orders!A1:Z1"));
+}
+
+#[tokio::test]
+async fn values_by_data_filter_is_a_read() {
+    let gsheet_client = replay("values_by_data_filter.json").with_read_only(true);
+    let batch = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .get_values_by_data_filter()
+        .filter("Settings!A1:B2")
+        .filter(gsheet_api::models::GridRange {
+            sheet_id: Some(7),
+            start_row_index: Some(0),
+            end_row_index: Some(1),
+            ..Default::default()
+        })
+        .build()
+        .unwrap()
+        .execute()
+        .await
+        .expect("reading by data filter is allowed on a read-only client");
+
+    assert_eq!(batch.len(), 2);
+}
+
+#[test]
+fn values_by_metadata_requires_a_key() {
+    use gsheet_api::error::GSheetError;
+
+    let error = replay("values_by_metadata.json")
+        .spreadsheet("spreadsheet-id")
+        .get_values_by_metadata("", "thresholds")
+        .build()
+        .err()
+        .expect("an empty key would match every metadata");
+
+    assert!(
+        matches!(error, GSheetError::ValidationError(_)),
+        "{}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn logger_appends_buffered_records_on_flush() {
+    use chrono::{TimeZone, Utc};
+    use gsheet_api::logger::{LogLevel, LogRecord};
+    use std::time::Duration;
+
+    let gsheet_client = replay("logger_append.json");
+    let logger = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Log")
+        .logger()
+        .flush_interval(Duration::from_secs(3600))
+        .min_level(LogLevel::Info)
+        .build()
+        .unwrap();
+
+    let at = |second| Utc.with_ymd_and_hms(2026, 10, 17, 9, 30, second).unwrap();
+    let mut started = LogRecord::new(LogLevel::Info, "Sync started");
+    started.timestamp = at(0);
+    let mut skipped = LogRecord::new(LogLevel::Warn, "=Row skipped").field("row", 42);
+    skipped.timestamp = at(1);
+
+    logger.log(started).await.unwrap();
+    logger
+        .log(LogRecord::new(LogLevel::Debug, "Below the minimum level"))
+        .await
+        .unwrap();
+    logger.log(skipped).await.unwrap();
+    logger.flush().await.unwrap();
+}
+
+#[tokio::test]
+async fn logger_flush_reports_failure_with_a_full_buffer() {
+    use gsheet_api::logger::{LogLevel, LogRecord};
+    use std::time::Duration;
+
+    // The cassette records no append, so every append fails.
+    let gsheet_client = replay("values_clear.json");
+    let logger = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Log")
+        .logger()
+        .flush_interval(Duration::from_secs(3600))
+        .capacity(1)
+        .build()
+        .unwrap();
+
+    logger.info("Sync started").await.unwrap();
+    assert!(
+        logger
+            .try_log(LogRecord::new(LogLevel::Info, "Sync finished"))
+            .is_err()
+    );
+    let flushed = tokio::time::timeout(Duration::from_secs(5), logger.flush())
+        .await
+        .expect("flush is received while the buffer is full");
+    assert!(flushed.is_err());
+}
+
+#[tokio::test]
+async fn batch_queue_fails_requests_missing_from_the_response() {
+    use gsheet_api::error::GSheetError;
+
+    let gsheet_client = replay("batch_queue_short_response.json");
+    let queue = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .batch_queue()
+        .build()
+        .unwrap();
+
+    let (prices, stock) = tokio::join!(queue.get("Prices!A1:B2"), queue.get("Stock!A1:C2"));
+
+    assert_eq!(prices.unwrap().range.as_deref(), Some("Prices!A1:B2"));
+    let error = stock.expect_err("the response holds a single range");
+    assert!(
+        matches!(error, GSheetError::ResponseParseError(_)),
+        "{}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn kv_store_updates_the_first_row_of_a_key() {
+    let gsheet_client = replay("kv_store_upsert.json");
+    let store = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Config")
+        .kv_store()
+        .header(true)
+        .build()
+        .unwrap();
+
+    store.set("timeout", "45").await.unwrap();
+
+    // Served from the snapshot refreshed by the write, without another read.
+    let reader = store.clone();
+    let all = tokio::spawn(async move { reader.all().await })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(store.get("region").await.unwrap().as_deref(), Some("eu"));
+    assert_eq!(store.get("timeout").await.unwrap().as_deref(), Some("45"));
+}
+
+#[tokio::test]
+async fn resize_refuses_to_exceed_cell_limit() {
+    let gsheet_client = replay("resize_over_cell_limit.json");
+    let error = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .sheet("Staging")
+        .resize(100_000, 26)
+        .execute()
+        .await
+        .expect_err("the spreadsheet would hold 10.4 million cells");
+
+    assert!(error.to_string().contains("10400000 cells"), "{}", error);
+}
+
+#[test]
+fn raw_request_refuses_hosts_outside_google_apis() {
+    use gsheet_api::error::GSheetError;
+
+    let gsheet_client = replay("values_by_metadata.json");
+    for url in [
+        "https://example.com/collect",
+        "https://googleapis.com.example.com/v1/files",
+        "https://example.com@evil.test/v1/files",
+    ] {
+        let error = gsheet_client
+            .raw_request(reqwest::Method::GET, url)
+            .build()
+            .err()
+            .expect("the credentials would leak to another host");
+        assert!(
+            matches!(error, GSheetError::ValidationError(_)),
+            "{}",
+            error
+        );
+    }
+
+    assert!(
+        gsheet_client
+            .raw_request(
+                reqwest::Method::GET,
+                "https://www.googleapis.com/drive/v3/files/spreadsheet-id"
+            )
+            .build()
+            .is_ok()
+    );
+}
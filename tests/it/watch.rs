@@ -0,0 +1,60 @@
+//! Change watch registration, served by a local stub of the Drive API.
+
+use crate::stub_server;
+use gsheet_api::client::GoogleSheetClient;
+use gsheet_api::vcr::ReplayAuth;
+use std::sync::Arc;
+
+const CHANNEL: &str = r#"{"kind": "api#channel", "id": "channel-1", "resourceId": "resource-1", "expiration": "4102444800000"}"#;
+
+/// Registers a watch and stops it, returning the requests Drive received.
+async fn register_and_stop(configure: fn(GoogleSheetClient) -> GoogleSheetClient) -> Vec<String> {
+    let (base_url, received) = stub_server(vec![CHANNEL, ""]).await;
+    let gsheet_client = GoogleSheetClient::builder()
+        .auth_client(Arc::new(ReplayAuth))
+        .drive_base_url(&base_url)
+        .build()
+        .unwrap();
+    let gsheet_client = configure(gsheet_client);
+
+    let watcher = gsheet_client
+        .spreadsheet("spreadsheet-id")
+        .watch("https://example.com/hook")
+        .start()
+        .await
+        .expect("watching changes no spreadsheet data");
+    assert_eq!(
+        watcher.channel().and_then(|channel| channel.id).as_deref(),
+        Some("channel-1")
+    );
+    watcher
+        .stop()
+        .await
+        .expect("stopping changes no spreadsheet data");
+
+    received.lock().unwrap().clone()
+}
+
+#[tokio::test]
+async fn read_only_client_can_watch() {
+    let received = register_and_stop(|client| client.with_read_only(true)).await;
+
+    assert_eq!(received.len(), 2);
+    assert!(
+        received[0].starts_with("POST /files/spreadsheet-id/watch"),
+        "{:?}",
+        received
+    );
+    assert!(
+        received[1].starts_with("POST /channels/stop"),
+        "{:?}",
+        received
+    );
+}
+
+#[tokio::test]
+async fn dry_run_client_registers_watch() {
+    let received = register_and_stop(|client| client.with_dry_run(true)).await;
+
+    assert_eq!(received.len(), 2);
+}